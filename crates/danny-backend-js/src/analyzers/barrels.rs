@@ -0,0 +1,573 @@
+//! Barrel file analysis - unused re-exports and side-effectful barrels.
+//!
+//! Barrels (`index.ts` files re-exporting their siblings) hide unused code:
+//! an export looks used as long as the barrel re-exports it, and an unused
+//! re-export is reported on the barrel instead of the declaration that should
+//! be deleted. This analyzer follows what consumers request through each
+//! barrel, reports re-exports nobody pulls through, and traces them to the
+//! original declaration.
+
+use super::test_only::ImportedNames;
+use danny_core::{BarrelModule, Finding, ReExportOrigin, SourceLocation};
+use std::collections::{BTreeSet, HashSet};
+use std::path::PathBuf;
+
+/// Side-effectful modules at least this large make a barrel expensive to import.
+pub const LARGE_MODULE_BYTES: usize = 10 * 1024;
+
+/// An export of a module, as far as barrel analysis is concerned.
+#[derive(Debug, Clone)]
+pub enum ModuleExport {
+    /// Export declared in the module itself
+    Declared {
+        /// Export name
+        name: String,
+        /// Declaration location
+        span: Option<SourceLocation>,
+    },
+    /// `export { name } from 'source'`
+    ReExport {
+        /// Re-exported name
+        name: String,
+        /// Source specifier
+        source: String,
+        /// Index of the resolved source module
+        target: Option<usize>,
+        /// Re-export location
+        span: Option<SourceLocation>,
+    },
+    /// `export * from 'source'`
+    Star {
+        /// Source specifier
+        source: String,
+        /// Index of the resolved source module
+        target: Option<usize>,
+        /// Re-export location
+        span: Option<SourceLocation>,
+    },
+}
+
+/// A module of the graph, reduced to what barrel analysis needs.
+#[derive(Debug, Clone)]
+pub struct BarrelNode {
+    /// Module path
+    pub path: PathBuf,
+    /// Module size in bytes
+    pub size: usize,
+    /// Whether evaluating the module has side effects
+    pub has_side_effects: bool,
+    /// Whether the module is an entry point (its exports are public API)
+    pub is_entry: bool,
+    /// Exports and re-exports of the module
+    pub exports: Vec<ModuleExport>,
+    /// Imports (excluding re-exports) as (target index, used names)
+    pub imports: Vec<(usize, ImportedNames)>,
+}
+
+impl BarrelNode {
+    /// Returns true if this module is a barrel: an `index` file with
+    /// re-exports, or a module that only re-exports.
+    pub fn is_barrel(&self) -> bool {
+        let re_exports = self
+            .exports
+            .iter()
+            .filter(|e| !matches!(e, ModuleExport::Declared { .. }))
+            .count();
+        if re_exports == 0 {
+            return false;
+        }
+
+        let is_index = self
+            .path
+            .file_stem()
+            .is_some_and(|stem| stem.to_string_lossy() == "index");
+        is_index || re_exports == self.exports.len()
+    }
+}
+
+/// Names requested from a module by its importers.
+#[derive(Debug, Clone, Default)]
+struct Requested {
+    all: bool,
+    names: BTreeSet<String>,
+}
+
+impl Requested {
+    fn contains(&self, name: &str) -> bool {
+        self.all || self.names.contains(name)
+    }
+
+    fn is_empty(&self) -> bool {
+        !self.all && self.names.is_empty()
+    }
+}
+
+/// Analyzer for barrel file findings.
+pub struct BarrelAnalyzer;
+
+impl BarrelAnalyzer {
+    /// Reports re-exports that no consumer pulls through a barrel, and
+    /// barrels whose importers are forced to load large side-effectful modules.
+    ///
+    /// Entry barrels are skipped: their re-exports are the package's public API.
+    pub fn analyze(nodes: &[BarrelNode], large_module_bytes: usize) -> Vec<Finding> {
+        let requested = Self::propagate_requests(nodes);
+        let mut findings = Vec::new();
+
+        for (i, node) in nodes.iter().enumerate() {
+            if node.is_entry || !node.is_barrel() {
+                continue;
+            }
+
+            for export in &node.exports {
+                match export {
+                    ModuleExport::Declared { .. } => {}
+                    ModuleExport::ReExport {
+                        name,
+                        source,
+                        target,
+                        span,
+                    } => {
+                        if requested[i].contains(name) {
+                            continue;
+                        }
+
+                        let (chain, origin) = match target {
+                            Some(target) => Self::trace(nodes, *target, name),
+                            None => (Vec::new(), None),
+                        };
+                        let origin = origin.map(|(module, span)| ReExportOrigin {
+                            module: nodes[module].path.clone(),
+                            export_name: name.clone(),
+                            span,
+                            is_used: requested[module].contains(name),
+                        });
+
+                        findings.push(Finding::UnusedReExport {
+                            barrel: node.path.clone(),
+                            export_name: name.clone(),
+                            source: source.clone(),
+                            span: span.clone(),
+                            origin,
+                            chain: std::iter::once(node.path.clone())
+                                .chain(chain.into_iter().map(|m| nodes[m].path.clone()))
+                                .collect(),
+                        });
+                    }
+                    ModuleExport::Star {
+                        source,
+                        target,
+                        span,
+                    } => {
+                        if requested[i].all {
+                            continue;
+                        }
+                        let provided = target
+                            .map(|t| Self::provided_names(nodes, t, &mut HashSet::new()))
+                            .unwrap_or_default();
+                        if requested[i].names.iter().any(|n| provided.contains(n)) {
+                            continue;
+                        }
+
+                        findings.push(Finding::UnusedReExport {
+                            barrel: node.path.clone(),
+                            export_name: "*".to_string(),
+                            source: source.clone(),
+                            span: span.clone(),
+                            origin: None,
+                            chain: std::iter::once(node.path.clone())
+                                .chain(target.map(|t| nodes[t].path.clone()))
+                                .collect(),
+                        });
+                    }
+                }
+            }
+
+            if requested[i].is_empty() {
+                continue;
+            }
+
+            let modules: Vec<BarrelModule> = Self::re_exported_modules(nodes, i)
+                .into_iter()
+                .filter(|&m| nodes[m].has_side_effects && nodes[m].size >= large_module_bytes)
+                .map(|m| BarrelModule {
+                    path: nodes[m].path.clone(),
+                    size: nodes[m].size,
+                })
+                .collect();
+
+            if !modules.is_empty() {
+                findings.push(Finding::SideEffectfulBarrel {
+                    barrel: node.path.clone(),
+                    total_size: modules.iter().map(|m| m.size).sum(),
+                    modules,
+                });
+            }
+        }
+
+        findings
+    }
+
+    /// Computes, for every module, the names its importers request, following
+    /// requests through re-exports until nothing changes.
+    fn propagate_requests(nodes: &[BarrelNode]) -> Vec<Requested> {
+        let mut requested = vec![Requested::default(); nodes.len()];
+
+        for node in nodes {
+            for (target, names) in &node.imports {
+                match names {
+                    ImportedNames::All => requested[*target].all = true,
+                    ImportedNames::Named(names) => {
+                        requested[*target].names.extend(names.iter().cloned())
+                    }
+                }
+            }
+        }
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+
+            for (i, node) in nodes.iter().enumerate() {
+                if requested[i].is_empty() {
+                    continue;
+                }
+
+                let own_names: HashSet<&str> = node
+                    .exports
+                    .iter()
+                    .filter_map(|e| match e {
+                        ModuleExport::Declared { name, .. }
+                        | ModuleExport::ReExport { name, .. } => Some(name.as_str()),
+                        ModuleExport::Star { .. } => None,
+                    })
+                    .collect();
+
+                let mut forwarded: Vec<(usize, Option<String>)> = Vec::new();
+                for export in &node.exports {
+                    match export {
+                        ModuleExport::ReExport {
+                            name,
+                            target: Some(target),
+                            ..
+                        } if requested[i].contains(name) => {
+                            forwarded.push((*target, Some(name.clone())));
+                        }
+                        ModuleExport::Star {
+                            target: Some(target),
+                            ..
+                        } => {
+                            if requested[i].all {
+                                forwarded.push((*target, None));
+                            } else {
+                                forwarded.extend(
+                                    requested[i]
+                                        .names
+                                        .iter()
+                                        .filter(|n| !own_names.contains(n.as_str()))
+                                        .map(|n| (*target, Some(n.clone()))),
+                                );
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+
+                for (target, name) in forwarded {
+                    let entry = &mut requested[target];
+                    changed |= match name {
+                        None => !std::mem::replace(&mut entry.all, true),
+                        Some(name) => !entry.all && entry.names.insert(name),
+                    };
+                }
+            }
+        }
+
+        requested
+    }
+
+    /// Follows `name` from `start` through re-exports to its declaration.
+    ///
+    /// Returns the modules visited (starting with `start`) and the declaring
+    /// module with the declaration's location, if found.
+    #[allow(clippy::type_complexity)]
+    fn trace(
+        nodes: &[BarrelNode],
+        start: usize,
+        name: &str,
+    ) -> (Vec<usize>, Option<(usize, Option<SourceLocation>)>) {
+        let mut chain = Vec::new();
+        let mut current = start;
+
+        loop {
+            if chain.contains(&current) {
+                return (chain, None);
+            }
+            chain.push(current);
+
+            let node = &nodes[current];
+            let mut next = None;
+
+            for export in &node.exports {
+                match export {
+                    ModuleExport::Declared { name: n, span } if n == name => {
+                        return (chain, Some((current, span.clone())));
+                    }
+                    ModuleExport::ReExport {
+                        name: n,
+                        target: Some(target),
+                        ..
+                    } if n == name => next = Some(*target),
+                    _ => {}
+                }
+            }
+
+            if next.is_none() {
+                next = node.exports.iter().find_map(|export| match export {
+                    ModuleExport::Star {
+                        target: Some(target),
+                        ..
+                    } if Self::provided_names(nodes, *target, &mut HashSet::new())
+                        .contains(name) =>
+                    {
+                        Some(*target)
+                    }
+                    _ => None,
+                });
+            }
+
+            match next {
+                Some(target) => current = target,
+                None => return (chain, None),
+            }
+        }
+    }
+
+    /// Names a module makes available, including through star re-exports.
+    fn provided_names(
+        nodes: &[BarrelNode],
+        module: usize,
+        visited: &mut HashSet<usize>,
+    ) -> HashSet<String> {
+        let mut names = HashSet::new();
+        if !visited.insert(module) {
+            return names;
+        }
+
+        for export in &nodes[module].exports {
+            match export {
+                ModuleExport::Declared { name, .. } | ModuleExport::ReExport { name, .. } => {
+                    names.insert(name.clone());
+                }
+                ModuleExport::Star {
+                    target: Some(target),
+                    ..
+                } => {
+                    // `export *` never forwards the default export
+                    names.extend(
+                        Self::provided_names(nodes, *target, visited)
+                            .into_iter()
+                            .filter(|n| n != "default"),
+                    );
+                }
+                ModuleExport::Star { target: None, .. } => {}
+            }
+        }
+
+        names
+    }
+
+    /// Modules loaded by importing `barrel`, through any depth of re-exports.
+    fn re_exported_modules(nodes: &[BarrelNode], barrel: usize) -> Vec<usize> {
+        let mut visited = HashSet::from([barrel]);
+        let mut queue = vec![barrel];
+        let mut modules = Vec::new();
+
+        while let Some(i) = queue.pop() {
+            for export in &nodes[i].exports {
+                let target = match export {
+                    ModuleExport::ReExport { target, .. } | ModuleExport::Star { target, .. } => {
+                        *target
+                    }
+                    ModuleExport::Declared { .. } => None,
+                };
+                if let Some(target) = target {
+                    if visited.insert(target) {
+                        modules.push(target);
+                        queue.push(target);
+                    }
+                }
+            }
+        }
+
+        modules.sort();
+        modules
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(path: &str, exports: Vec<ModuleExport>) -> BarrelNode {
+        BarrelNode {
+            path: PathBuf::from(path),
+            size: 100,
+            has_side_effects: false,
+            is_entry: false,
+            exports,
+            imports: Vec::new(),
+        }
+    }
+
+    fn declared(name: &str) -> ModuleExport {
+        ModuleExport::Declared {
+            name: name.to_string(),
+            span: None,
+        }
+    }
+
+    fn re_export(name: &str, target: usize) -> ModuleExport {
+        ModuleExport::ReExport {
+            name: name.to_string(),
+            source: format!("./{}", target),
+            target: Some(target),
+            span: None,
+        }
+    }
+
+    fn star(target: usize) -> ModuleExport {
+        ModuleExport::Star {
+            source: format!("./{}", target),
+            target: Some(target),
+            span: None,
+        }
+    }
+
+    fn named(names: &[&str]) -> ImportedNames {
+        ImportedNames::Named(names.iter().map(|n| n.to_string()).collect())
+    }
+
+    /// 0: app.ts imports { Button } from './components'
+    /// 1: components/index.ts re-exports Button, Card, and * from './forms'
+    /// 2: components/Button.ts, 3: components/Card.ts
+    /// 4: components/forms/index.ts re-exports Input
+    /// 5: components/forms/Input.ts
+    fn project() -> Vec<BarrelNode> {
+        let mut app = node("app.ts", vec![]);
+        app.is_entry = true;
+        app.imports.push((1, named(&["Button"])));
+
+        vec![
+            app,
+            node(
+                "components/index.ts",
+                vec![re_export("Button", 2), re_export("Card", 3), star(4)],
+            ),
+            node("components/Button.ts", vec![declared("Button")]),
+            node("components/Card.ts", vec![declared("Card")]),
+            node("components/forms/index.ts", vec![re_export("Input", 5)]),
+            node("components/forms/Input.ts", vec![declared("Input")]),
+        ]
+    }
+
+    fn unused(findings: &[Finding]) -> Vec<(String, String, Option<String>, usize)> {
+        findings
+            .iter()
+            .filter_map(|f| match f {
+                Finding::UnusedReExport {
+                    barrel,
+                    export_name,
+                    origin,
+                    chain,
+                    ..
+                } => Some((
+                    barrel.to_string_lossy().into_owned(),
+                    export_name.clone(),
+                    origin
+                        .as_ref()
+                        .map(|o| o.module.to_string_lossy().into_owned()),
+                    chain.len(),
+                )),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_unused_re_exports_trace_to_declaration() {
+        let findings = BarrelAnalyzer::analyze(&project(), LARGE_MODULE_BYTES);
+
+        assert_eq!(
+            unused(&findings),
+            vec![
+                (
+                    "components/index.ts".to_string(),
+                    "Card".to_string(),
+                    Some("components/Card.ts".to_string()),
+                    2
+                ),
+                ("components/index.ts".to_string(), "*".to_string(), None, 2),
+                (
+                    "components/forms/index.ts".to_string(),
+                    "Input".to_string(),
+                    Some("components/forms/Input.ts".to_string()),
+                    2
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_requests_flow_through_nested_barrels() {
+        let mut nodes = project();
+        nodes[0].imports = vec![(1, named(&["Button", "Input"]))];
+
+        let findings = BarrelAnalyzer::analyze(&nodes, LARGE_MODULE_BYTES);
+        assert_eq!(
+            unused(&findings),
+            vec![(
+                "components/index.ts".to_string(),
+                "Card".to_string(),
+                Some("components/Card.ts".to_string()),
+                2
+            )]
+        );
+
+        let (chain, origin) = BarrelAnalyzer::trace(&nodes, 1, "Input");
+        assert_eq!(chain, vec![1, 4, 5]);
+        assert_eq!(origin.map(|(module, _)| module), Some(5));
+    }
+
+    #[test]
+    fn test_namespace_import_uses_every_re_export() {
+        let mut nodes = project();
+        nodes[0].imports = vec![(1, ImportedNames::All)];
+
+        assert!(unused(&BarrelAnalyzer::analyze(&nodes, LARGE_MODULE_BYTES)).is_empty());
+    }
+
+    #[test]
+    fn test_side_effectful_barrel() {
+        let mut nodes = project();
+        nodes[5].has_side_effects = true;
+        nodes[5].size = 50 * 1024;
+
+        let findings = BarrelAnalyzer::analyze(&nodes, LARGE_MODULE_BYTES);
+        let barrels: Vec<_> = findings
+            .iter()
+            .filter_map(|f| match f {
+                Finding::SideEffectfulBarrel {
+                    barrel, total_size, ..
+                } => Some((barrel.to_string_lossy().into_owned(), *total_size)),
+                _ => None,
+            })
+            .collect();
+
+        // Only the imported barrel forces the load
+        assert_eq!(
+            barrels,
+            vec![("components/index.ts".to_string(), 50 * 1024)]
+        );
+    }
+}
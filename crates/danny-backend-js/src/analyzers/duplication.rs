@@ -0,0 +1,617 @@
+//! Duplicated code analysis - token-based clone detection.
+//!
+//! Every module is tokenized with identifiers and literals normalized, so
+//! copies that only rename variables or change constants (type-2 clones)
+//! still match. Windows of `min_tokens` normalized tokens are hashed;
+//! every set of equal windows seeds a clone class, which is extended once
+//! for all of its copies and split wherever the copies diverge.
+
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+
+/// Multiplier of the rolling window hash.
+const HASH_BASE: u64 = 1_000_003;
+
+/// Windows occurring more often than this are skipped. They come from
+/// boilerplate such as generated code and would dominate the run time.
+const MAX_WINDOW_OCCURRENCES: usize = 5_000;
+
+/// Reserved words kept verbatim; every other identifier is normalized.
+const KEYWORDS: &[&str] = &[
+    "abstract",
+    "as",
+    "async",
+    "await",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "declare",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "from",
+    "function",
+    "get",
+    "if",
+    "implements",
+    "import",
+    "in",
+    "instanceof",
+    "interface",
+    "keyof",
+    "let",
+    "new",
+    "null",
+    "of",
+    "private",
+    "protected",
+    "public",
+    "readonly",
+    "return",
+    "satisfies",
+    "set",
+    "static",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "type",
+    "typeof",
+    "undefined",
+    "var",
+    "void",
+    "while",
+    "with",
+    "yield",
+];
+
+/// Punctuators, longest first so matching is greedy.
+const PUNCTUATORS: &[&str] = &[
+    ">>>=", "...", "===", "!==", "**=", "<<=", ">>=", ">>>", "&&=", "||=", "??=", "=>", "==", "!=",
+    "<=", ">=", "&&", "||", "??", "?.", "++", "--", "+=", "-=", "*=", "/=", "%=", "&=", "|=", "^=",
+    "**", "<<", ">>",
+];
+
+/// Lexical class of a token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    /// Reserved word
+    Keyword,
+    /// Identifier or private name
+    Identifier,
+    /// String, template or numeric literal
+    Literal,
+    /// Operator or delimiter
+    Punctuator,
+}
+
+/// A token of a module, normalized for clone matching.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    /// Normalized text: `$id` for identifiers, `$lit` for literals,
+    /// keywords and punctuators verbatim
+    pub text: String,
+    /// Lexical class
+    pub kind: TokenKind,
+    /// Byte offset where the token starts
+    pub start: usize,
+    /// Byte offset just past the token
+    pub end: usize,
+    /// Line where the token starts (1-indexed)
+    pub line: u32,
+    /// Column where the token starts (0-indexed, in bytes)
+    pub column: u32,
+    /// Line where the token ends
+    pub end_line: u32,
+}
+
+/// One occurrence of a cloned code block.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CloneInstance {
+    /// Module containing the copy
+    pub file: PathBuf,
+    /// First line of the copy
+    pub start_line: u32,
+    /// Column of the first token
+    pub start_column: u32,
+    /// Last line of the copy
+    pub end_line: u32,
+}
+
+impl CloneInstance {
+    /// Number of lines the copy spans.
+    pub fn line_count(&self) -> u32 {
+        self.end_line - self.start_line + 1
+    }
+}
+
+/// A block of code that appears in several places.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CloneGroup {
+    /// Length of the cloned block in tokens
+    pub tokens: usize,
+    /// Every copy of the block
+    pub instances: Vec<CloneInstance>,
+}
+
+/// Analyzer for duplicated code.
+pub struct DuplicationAnalyzer;
+
+impl DuplicationAnalyzer {
+    /// Splits JavaScript/TypeScript source into normalized tokens.
+    ///
+    /// Comments and whitespace are dropped. Template literals become a
+    /// single literal token, and regular expression literals are split
+    /// into ordinary tokens, which is close enough for clone matching.
+    pub fn tokenize(source: &str) -> Vec<Token> {
+        let bytes = source.as_bytes();
+        let mut tokens = Vec::new();
+        let mut i = 0;
+        let mut line = 1;
+        let mut line_start = 0;
+
+        while i < bytes.len() {
+            let start = i;
+            let start_line = line;
+            let column = (i - line_start) as u32;
+            let c = bytes[i];
+
+            let (kind, text) = match c {
+                b'\n' => {
+                    i += 1;
+                    line += 1;
+                    line_start = i;
+                    continue;
+                }
+                _ if c.is_ascii_whitespace() => {
+                    i += 1;
+                    continue;
+                }
+                b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                    while i < bytes.len() && bytes[i] != b'\n' {
+                        i += 1;
+                    }
+                    continue;
+                }
+                b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                    i += 2;
+                    while i < bytes.len() && !(bytes[i] == b'*' && bytes.get(i + 1) == Some(&b'/'))
+                    {
+                        if bytes[i] == b'\n' {
+                            line += 1;
+                            line_start = i + 1;
+                        }
+                        i += 1;
+                    }
+                    i = (i + 2).min(bytes.len());
+                    continue;
+                }
+                b'\'' | b'"' | b'`' => {
+                    i += 1;
+                    while i < bytes.len() && bytes[i] != c {
+                        if bytes[i] == b'\\' {
+                            i += 1;
+                            if bytes.get(i) == Some(&b'\n') {
+                                line += 1;
+                                line_start = i + 1;
+                            }
+                        } else if bytes[i] == b'\n' {
+                            if c != b'`' {
+                                break;
+                            }
+                            line += 1;
+                            line_start = i + 1;
+                        }
+                        i += 1;
+                    }
+                    i = (i + 1).min(bytes.len());
+                    (TokenKind::Literal, "$lit".to_string())
+                }
+                _ if c.is_ascii_digit()
+                    || (c == b'.' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)) =>
+                {
+                    while i < bytes.len()
+                        && (bytes[i].is_ascii_alphanumeric() || b"._".contains(&bytes[i]))
+                    {
+                        i += 1;
+                    }
+                    (TokenKind::Literal, "$lit".to_string())
+                }
+                _ if c.is_ascii_alphabetic()
+                    || c == b'_'
+                    || c == b'$'
+                    || c == b'#'
+                    || c >= 0x80 =>
+                {
+                    i += 1;
+                    while i < bytes.len()
+                        && (bytes[i].is_ascii_alphanumeric()
+                            || bytes[i] == b'_'
+                            || bytes[i] == b'$'
+                            || bytes[i] >= 0x80)
+                    {
+                        i += 1;
+                    }
+                    let word = &source[start..i];
+                    if KEYWORDS.contains(&word) {
+                        (TokenKind::Keyword, word.to_string())
+                    } else {
+                        (TokenKind::Identifier, "$id".to_string())
+                    }
+                }
+                _ => {
+                    let rest = &source[i..];
+                    let len = PUNCTUATORS
+                        .iter()
+                        .find(|p| rest.starts_with(**p))
+                        .map_or(1, |p| p.len());
+                    i += len;
+                    (TokenKind::Punctuator, rest[..len].to_string())
+                }
+            };
+
+            tokens.push(Token {
+                text,
+                kind,
+                start,
+                end: i,
+                line: start_line,
+                column,
+                end_line: line,
+            });
+        }
+
+        tokens
+    }
+
+    /// Finds blocks of at least `min_tokens` tokens and `min_lines` lines
+    /// that appear more than once across `files`.
+    ///
+    /// Each group lists every copy; overlapping copies within one file
+    /// (a block repeating itself) are not reported. Windows repeated more
+    /// than a few thousand times, such as generated tables, seed no clones.
+    pub fn find_clones(
+        files: &[(PathBuf, Vec<Token>)],
+        min_tokens: usize,
+        min_lines: u32,
+    ) -> Vec<CloneGroup> {
+        if min_tokens == 0 {
+            return Vec::new();
+        }
+
+        // Intern normalized token texts so windows compare as integers
+        let mut interner: HashMap<&str, u64> = HashMap::new();
+        let streams: Vec<Vec<u64>> = files
+            .iter()
+            .map(|(_, tokens)| {
+                tokens
+                    .iter()
+                    .map(|token| {
+                        let next = interner.len() as u64 + 1;
+                        *interner.entry(token.text.as_str()).or_insert(next)
+                    })
+                    .collect()
+            })
+            .collect();
+
+        // Hash every window of `min_tokens` tokens
+        let top_power = (1..min_tokens).fold(1u64, |p, _| p.wrapping_mul(HASH_BASE));
+        let mut windows: HashMap<u64, Vec<(usize, usize)>> = HashMap::new();
+        for (f, stream) in streams.iter().enumerate() {
+            if stream.len() < min_tokens {
+                continue;
+            }
+            let mut hash = stream[..min_tokens]
+                .iter()
+                .fold(0u64, |h, &t| h.wrapping_mul(HASH_BASE).wrapping_add(t));
+            windows.entry(hash).or_default().push((f, 0));
+            for i in 1..=stream.len() - min_tokens {
+                hash = hash
+                    .wrapping_sub(stream[i - 1].wrapping_mul(top_power))
+                    .wrapping_mul(HASH_BASE)
+                    .wrapping_add(stream[i + min_tokens - 1]);
+                windows.entry(hash).or_default().push((f, i));
+            }
+        }
+
+        // Each class of equal windows is extended once. A class whose copies
+        // are all preceded by the same token is skipped: the class one token
+        // earlier contains the same clone.
+        let mut groups: HashMap<&[u64], HashSet<(usize, usize)>> = HashMap::new();
+        for locations in windows.values() {
+            if locations.len() < 2
+                || locations.len() > MAX_WINDOW_OCCURRENCES
+                || !is_left_maximal(&streams, locations)
+            {
+                continue;
+            }
+
+            // Split hash collisions into classes of truly equal windows
+            let mut classes: HashMap<&[u64], Vec<(usize, usize)>> = HashMap::new();
+            for &(f, i) in locations {
+                classes
+                    .entry(&streams[f][i..i + min_tokens])
+                    .or_default()
+                    .push((f, i));
+            }
+            for class in classes.into_values() {
+                if class.len() > 1 && is_left_maximal(&streams, &class) {
+                    extend_class(&streams, class, min_tokens, &mut groups);
+                }
+            }
+        }
+
+        let mut clones: Vec<CloneGroup> = groups
+            .into_iter()
+            .filter_map(|(block, starts)| {
+                let mut starts: Vec<(usize, usize)> = starts.into_iter().collect();
+                starts.sort();
+                let instances: Vec<CloneInstance> = starts
+                    .into_iter()
+                    .map(|(f, i)| {
+                        let (path, tokens) = &files[f];
+                        CloneInstance {
+                            file: path.clone(),
+                            start_line: tokens[i].line,
+                            start_column: tokens[i].column,
+                            end_line: tokens[i + block.len() - 1].end_line,
+                        }
+                    })
+                    .filter(|instance| instance.line_count() >= min_lines)
+                    .collect();
+                (instances.len() > 1).then_some(CloneGroup {
+                    tokens: block.len(),
+                    instances,
+                })
+            })
+            .collect();
+
+        // A group whose copies all sit inside copies of a longer clone adds nothing
+        let subsumed: Vec<bool> = clones
+            .iter()
+            .map(|group| {
+                clones.iter().any(|longer| {
+                    longer.tokens > group.tokens
+                        && group.instances.iter().all(|inner| {
+                            longer.instances.iter().any(|outer| {
+                                outer.file == inner.file
+                                    && outer.start_line <= inner.start_line
+                                    && inner.end_line <= outer.end_line
+                            })
+                        })
+                })
+            })
+            .collect();
+        let mut subsumed = subsumed.into_iter();
+        clones.retain(|_| !subsumed.next().unwrap_or(false));
+
+        clones.sort_by(|a, b| {
+            b.tokens
+                .cmp(&a.tokens)
+                .then_with(|| a.instances.cmp(&b.instances))
+        });
+        clones
+    }
+}
+
+/// Returns false if every location is preceded by the same token.
+fn is_left_maximal(streams: &[Vec<u64>], locations: &[(usize, usize)]) -> bool {
+    let previous = |&(f, i): &(usize, usize)| i.checked_sub(1).map(|p| streams[f][p]);
+    let first = previous(&locations[0]);
+    first.is_none() || locations.iter().any(|location| previous(location) != first)
+}
+
+/// Grows a class of equal blocks as long as every copy agrees, records it,
+/// and continues with each subset of copies that still agree.
+fn extend_class<'a>(
+    streams: &'a [Vec<u64>],
+    members: Vec<(usize, usize)>,
+    len: usize,
+    groups: &mut HashMap<&'a [u64], HashSet<(usize, usize)>>,
+) {
+    let next = |&(f, i): &(usize, usize), len: usize| streams[f].get(i + len).copied();
+    let mut pending = vec![(members, len)];
+
+    while let Some((members, mut len)) = pending.pop() {
+        while let Some(token) = next(&members[0], len) {
+            if members
+                .iter()
+                .any(|member| next(member, len) != Some(token))
+            {
+                break;
+            }
+            len += 1;
+        }
+
+        let copies = non_overlapping(&members, len);
+        if copies.len() > 1 {
+            let (f, i) = copies[0];
+            groups
+                .entry(&streams[f][i..i + len])
+                .or_default()
+                .extend(copies);
+        }
+
+        let mut continuations: HashMap<u64, Vec<(usize, usize)>> = HashMap::new();
+        for member in members {
+            if let Some(token) = next(&member, len) {
+                continuations.entry(token).or_default().push(member);
+            }
+        }
+        pending.extend(
+            continuations
+                .into_values()
+                .filter(|continuation| continuation.len() > 1)
+                .map(|continuation| (continuation, len + 1)),
+        );
+    }
+}
+
+/// Drops copies that overlap an earlier copy in the same file.
+fn non_overlapping(members: &[(usize, usize)], len: usize) -> Vec<(usize, usize)> {
+    let mut sorted = members.to_vec();
+    sorted.sort_unstable();
+
+    let mut kept: Vec<(usize, usize)> = Vec::with_capacity(sorted.len());
+    for (f, i) in sorted {
+        if !kept.last().is_some_and(|&(kf, ki)| kf == f && i < ki + len) {
+            kept.push((f, i));
+        }
+    }
+    kept
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(source: &str) -> Vec<String> {
+        DuplicationAnalyzer::tokenize(source)
+            .into_iter()
+            .map(|token| token.text)
+            .collect()
+    }
+
+    #[test]
+    fn test_tokenize_normalizes_identifiers_and_literals() {
+        assert_eq!(
+            texts("const total = price * 1.2; // tax\n/* note */ return `${total}` ?? 'x';"),
+            vec!["const", "$id", "=", "$id", "*", "$lit", ";", "return", "$lit", "??", "$lit", ";"]
+        );
+        assert_eq!(
+            texts("a >>>= b?.c"),
+            vec!["$id", ">>>=", "$id", "?.", "$id"]
+        );
+    }
+
+    #[test]
+    fn test_tokenize_tracks_lines() {
+        let tokens = DuplicationAnalyzer::tokenize("a\n/* x\ny */ b `t\nu` c");
+        let lines: Vec<(u32, u32)> = tokens.iter().map(|t| (t.line, t.end_line)).collect();
+        assert_eq!(lines, vec![(1, 1), (3, 3), (3, 4), (4, 4)]);
+        assert_eq!(tokens[1].column, 5);
+        assert_eq!(tokens[2].kind, TokenKind::Literal);
+        assert_eq!(tokens[2].start..tokens[2].end, 14..19);
+    }
+
+    const ORIGINAL: &str = "\
+export function total(items) {
+  let sum = 0;
+  for (const item of items) {
+    sum += item.price * item.quantity;
+  }
+  return sum;
+}
+";
+
+    const RENAMED: &str = "\
+import { x } from './x';
+
+function computeCost(lines) {
+  let acc = 10;
+  for (const line of lines) {
+    acc += line.cost * line.count;
+  }
+  return acc;
+}
+";
+
+    fn files(sources: &[(&str, &str)]) -> Vec<(PathBuf, Vec<Token>)> {
+        sources
+            .iter()
+            .map(|(path, source)| (PathBuf::from(path), DuplicationAnalyzer::tokenize(source)))
+            .collect()
+    }
+
+    #[test]
+    fn test_type_2_clone_across_files() {
+        let copy = format!("let ready = 1\n{}", ORIGINAL.replace("export ", ""));
+        let files = files(&[("a.js", ORIGINAL), ("b.js", RENAMED), ("c.js", &copy)]);
+        let clones = DuplicationAnalyzer::find_clones(&files, 20, 5);
+
+        assert_eq!(clones.len(), 1);
+        let group = &clones[0];
+        assert_eq!(
+            group
+                .instances
+                .iter()
+                .map(|i| (
+                    i.file.to_string_lossy().into_owned(),
+                    i.start_line,
+                    i.end_line
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                ("a.js".to_string(), 1, 7),
+                ("b.js".to_string(), 3, 9),
+                ("c.js".to_string(), 2, 8),
+            ]
+        );
+        // `export` only appears in a.js, so the shared block starts at `function`
+        assert_eq!(group.instances[0].start_column, 7);
+        assert_eq!(group.instances[1].start_column, 0);
+    }
+
+    #[test]
+    fn test_nested_clone_is_subsumed() {
+        let files = files(&[("a.js", ORIGINAL), ("b.js", RENAMED), ("c.js", ORIGINAL)]);
+        let clones = DuplicationAnalyzer::find_clones(&files, 20, 5);
+
+        // a.js and c.js are identical (including `export`); b.js shares the
+        // function. Both groups stay: neither fully contains the other.
+        assert_eq!(clones.len(), 2);
+        assert_eq!(clones[0].instances.len(), 2);
+        assert_eq!(clones[1].instances.len(), 3);
+    }
+
+    #[test]
+    fn test_thresholds() {
+        let files = files(&[("a.js", ORIGINAL), ("b.js", RENAMED)]);
+        assert!(DuplicationAnalyzer::find_clones(&files, 200, 5).is_empty());
+        assert!(DuplicationAnalyzer::find_clones(&files, 20, 10).is_empty());
+    }
+
+    #[test]
+    fn test_block_shared_by_many_files() {
+        let sources: Vec<(String, String)> = (0..200)
+            .map(|n| {
+                let path = format!("gen/{n}.js");
+                let source = format!("{}\nexport const id{n} = {n};\n", ORIGINAL);
+                (path, source)
+            })
+            .collect();
+        let sources: Vec<(&str, &str)> = sources
+            .iter()
+            .map(|(path, source)| (path.as_str(), source.as_str()))
+            .collect();
+        let clones = DuplicationAnalyzer::find_clones(&files(&sources), 20, 5);
+
+        assert_eq!(clones.len(), 1);
+        assert_eq!(clones[0].instances.len(), 200);
+        assert!(clones[0].instances.iter().all(|i| i.start_line == 1));
+    }
+
+    #[test]
+    fn test_clone_within_one_file() {
+        let source = format!("{}\n{}", ORIGINAL, ORIGINAL.replace("total", "other"));
+        let files = files(&[("a.js", &source)]);
+        let clones = DuplicationAnalyzer::find_clones(&files, 20, 5);
+
+        assert_eq!(clones.len(), 1);
+        let starts: Vec<u32> = clones[0].instances.iter().map(|i| i.start_line).collect();
+        assert_eq!(starts, vec![1, 9]);
+    }
+}
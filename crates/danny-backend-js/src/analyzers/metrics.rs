@@ -0,0 +1,459 @@
+//! Complexity and maintainability metrics.
+//!
+//! Cognitive complexity follows SonarSource's definition: every structure
+//! that breaks the linear flow adds one, plus one for each level of nesting
+//! it sits in, and nested functions (callbacks) deepen the nesting of the
+//! function that contains them. Halstead measures count the module's
+//! tokens; together with cyclomatic complexity and lines of code they give
+//! the maintainability index.
+
+use super::duplication::{Token, TokenKind};
+use super::syntax_smells::member_name;
+use danny_core::HalsteadMetrics;
+use oxc_ast::ast::{
+    BreakStatement, CatchClause, ConditionalExpression, ContinueStatement, Declaration,
+    DoWhileStatement, ExportDefaultDeclaration, ExportDefaultDeclarationKind, Expression,
+    ForInStatement, ForOfStatement, ForStatement, FunctionBody, IfStatement, LogicalExpression,
+    LogicalOperator, MethodDefinition, ObjectProperty, PropertyDefinition, Statement,
+    SwitchStatement, VariableDeclarator, WhileStatement,
+};
+use oxc_ast_visit::{walk, Visit};
+use std::collections::HashSet;
+
+/// Cognitive complexity of one function.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FunctionComplexity {
+    /// Byte offset of the function body
+    pub offset: u32,
+    /// Function, method or variable name (`None` for anonymous callbacks)
+    pub name: Option<String>,
+    /// Cognitive complexity score
+    pub complexity: usize,
+}
+
+/// Measures cognitive complexity per function and decision points per module.
+///
+/// Only outermost functions and methods are reported; functions nested in
+/// them count towards their complexity. Recursion isn't counted.
+#[derive(Default)]
+pub struct ComplexityVisitor {
+    /// Functions measured so far
+    pub functions: Vec<FunctionComplexity>,
+    /// Branches, loops, catches and logical operators in the whole module
+    pub decision_points: usize,
+    nesting: usize,
+    function_depth: usize,
+    pending_name: Option<String>,
+    logical_parent: Option<LogicalOperator>,
+}
+
+impl ComplexityVisitor {
+    /// Cyclomatic complexity of the module (decision points + 1).
+    pub fn cyclomatic_complexity(&self) -> usize {
+        self.decision_points + 1
+    }
+
+    /// Adds one to the current function, plus the nesting level if `nested`.
+    fn increment(&mut self, nested: bool) {
+        if self.function_depth == 0 {
+            return;
+        }
+        let nesting = if nested { self.nesting } else { 0 };
+        if let Some(function) = self.functions.last_mut() {
+            function.complexity += 1 + nesting;
+        }
+    }
+
+    /// Records a flow-breaking structure that also increases nesting.
+    fn structure(&mut self) {
+        self.decision_points += 1;
+        self.increment(true);
+    }
+
+    fn nested(&mut self, f: impl FnOnce(&mut Self)) {
+        self.nesting += 1;
+        f(self);
+        self.nesting -= 1;
+    }
+
+    /// `else if` and `else` add one without a nesting increment.
+    fn visit_if_chain(&mut self, it: &IfStatement<'_>) {
+        self.visit_expression(&it.test);
+        self.nested(|v| v.visit_statement(&it.consequent));
+        match &it.alternate {
+            Some(Statement::IfStatement(else_if)) => {
+                self.decision_points += 1;
+                self.increment(false);
+                self.visit_if_chain(else_if);
+            }
+            Some(alternate) => {
+                self.increment(false);
+                self.nested(|v| v.visit_statement(alternate));
+            }
+            None => {}
+        }
+    }
+}
+
+impl<'a> Visit<'a> for ComplexityVisitor {
+    fn visit_function_body(&mut self, it: &FunctionBody<'a>) {
+        let name = self.pending_name.take();
+        self.function_depth += 1;
+        if self.function_depth == 1 {
+            self.functions.push(FunctionComplexity {
+                offset: it.span.start,
+                name,
+                complexity: 0,
+            });
+            let outer_nesting = std::mem::take(&mut self.nesting);
+            walk::walk_function_body(self, it);
+            self.nesting = outer_nesting;
+        } else {
+            self.nested(|v| walk::walk_function_body(v, it));
+        }
+        self.function_depth -= 1;
+    }
+
+    fn visit_declaration(&mut self, it: &Declaration<'a>) {
+        if let Declaration::FunctionDeclaration(function) = it {
+            self.pending_name = function.id.as_ref().map(|id| id.name.to_string());
+        }
+        walk::walk_declaration(self, it);
+    }
+
+    fn visit_export_default_declaration(&mut self, it: &ExportDefaultDeclaration<'a>) {
+        if let ExportDefaultDeclarationKind::FunctionDeclaration(function) = &it.declaration {
+            let name = function
+                .id
+                .as_ref()
+                .map_or("default", |id| id.name.as_str());
+            self.pending_name = Some(name.to_string());
+        }
+        walk::walk_export_default_declaration(self, it);
+    }
+
+    fn visit_method_definition(&mut self, it: &MethodDefinition<'a>) {
+        self.pending_name = member_name(&it.key);
+        walk::walk_method_definition(self, it);
+    }
+
+    fn visit_property_definition(&mut self, it: &PropertyDefinition<'a>) {
+        if it.value.as_ref().is_some_and(is_function) {
+            self.pending_name = member_name(&it.key);
+        }
+        walk::walk_property_definition(self, it);
+    }
+
+    fn visit_object_property(&mut self, it: &ObjectProperty<'a>) {
+        if is_function(&it.value) {
+            self.pending_name = member_name(&it.key);
+        }
+        walk::walk_object_property(self, it);
+    }
+
+    fn visit_variable_declarator(&mut self, it: &VariableDeclarator<'a>) {
+        if it.init.as_ref().is_some_and(is_function) {
+            self.pending_name = it.id.get_identifier_name().map(|name| name.to_string());
+        }
+        walk::walk_variable_declarator(self, it);
+    }
+
+    fn visit_if_statement(&mut self, it: &IfStatement<'a>) {
+        self.structure();
+        self.visit_if_chain(it);
+    }
+
+    fn visit_conditional_expression(&mut self, it: &ConditionalExpression<'a>) {
+        self.structure();
+        self.visit_expression(&it.test);
+        self.nested(|v| {
+            v.visit_expression(&it.consequent);
+            v.visit_expression(&it.alternate);
+        });
+    }
+
+    fn visit_switch_statement(&mut self, it: &SwitchStatement<'a>) {
+        // One increment for the whole switch; every case is a path
+        self.increment(true);
+        self.decision_points += it.cases.iter().filter(|case| case.test.is_some()).count();
+        self.visit_expression(&it.discriminant);
+        self.nested(|v| {
+            for case in &it.cases {
+                v.visit_switch_case(case);
+            }
+        });
+    }
+
+    fn visit_for_statement(&mut self, it: &ForStatement<'a>) {
+        self.structure();
+        self.nested(|v| walk::walk_for_statement(v, it));
+    }
+
+    fn visit_for_in_statement(&mut self, it: &ForInStatement<'a>) {
+        self.structure();
+        self.nested(|v| walk::walk_for_in_statement(v, it));
+    }
+
+    fn visit_for_of_statement(&mut self, it: &ForOfStatement<'a>) {
+        self.structure();
+        self.nested(|v| walk::walk_for_of_statement(v, it));
+    }
+
+    fn visit_while_statement(&mut self, it: &WhileStatement<'a>) {
+        self.structure();
+        self.nested(|v| walk::walk_while_statement(v, it));
+    }
+
+    fn visit_do_while_statement(&mut self, it: &DoWhileStatement<'a>) {
+        self.structure();
+        self.nested(|v| walk::walk_do_while_statement(v, it));
+    }
+
+    fn visit_catch_clause(&mut self, it: &CatchClause<'a>) {
+        self.structure();
+        self.nested(|v| walk::walk_catch_clause(v, it));
+    }
+
+    fn visit_expression(&mut self, it: &Expression<'a>) {
+        if !matches!(it, Expression::LogicalExpression(_)) {
+            self.logical_parent = None;
+        }
+        walk::walk_expression(self, it);
+    }
+
+    // Each run of the same operator counts once: `a && b && c` adds one,
+    // `a && b || c` adds two
+    fn visit_logical_expression(&mut self, it: &LogicalExpression<'a>) {
+        self.decision_points += 1;
+        if self.logical_parent.take() != Some(it.operator) {
+            self.increment(false);
+        }
+        self.logical_parent = Some(it.operator);
+        self.visit_expression(&it.left);
+        self.logical_parent = Some(it.operator);
+        self.visit_expression(&it.right);
+        self.logical_parent = None;
+    }
+
+    fn visit_break_statement(&mut self, it: &BreakStatement<'a>) {
+        if it.label.is_some() {
+            self.increment(false);
+        }
+    }
+
+    fn visit_continue_statement(&mut self, it: &ContinueStatement<'a>) {
+        if it.label.is_some() {
+            self.increment(false);
+        }
+    }
+}
+
+/// Token-based module metrics.
+pub struct MetricsAnalyzer;
+
+impl MetricsAnalyzer {
+    /// Computes Halstead measures from a module's tokens.
+    ///
+    /// Identifiers and literals are operands, keywords and punctuators are
+    /// operators. Closing brackets are counted with their opening bracket.
+    pub fn halstead(source: &str, tokens: &[Token]) -> HalsteadMetrics {
+        let mut operators = HashSet::new();
+        let mut operands = HashSet::new();
+        let mut total_operators = 0;
+        let mut total_operands = 0;
+
+        for token in tokens {
+            let raw = &source[token.start..token.end];
+            match token.kind {
+                TokenKind::Identifier | TokenKind::Literal => {
+                    operands.insert(raw);
+                    total_operands += 1;
+                }
+                TokenKind::Keyword | TokenKind::Punctuator => {
+                    if matches!(raw, ")" | "]" | "}") {
+                        continue;
+                    }
+                    operators.insert(raw);
+                    total_operators += 1;
+                }
+            }
+        }
+
+        let vocabulary = operators.len() + operands.len();
+        let length = total_operators + total_operands;
+        let volume = if vocabulary > 1 {
+            length as f64 * (vocabulary as f64).log2()
+        } else {
+            0.0
+        };
+        let difficulty = if operands.is_empty() {
+            0.0
+        } else {
+            operators.len() as f64 / 2.0 * (total_operands as f64 / operands.len() as f64)
+        };
+
+        HalsteadMetrics {
+            distinct_operators: operators.len(),
+            distinct_operands: operands.len(),
+            total_operators,
+            total_operands,
+            volume,
+            difficulty,
+            effort: difficulty * volume,
+        }
+    }
+
+    /// Counts lines holding at least part of a token.
+    pub fn lines_of_code(tokens: &[Token]) -> usize {
+        tokens
+            .iter()
+            .flat_map(|token| token.line..=token.end_line)
+            .collect::<HashSet<_>>()
+            .len()
+    }
+
+    /// Maintainability index, normalized to 0-100.
+    ///
+    /// Uses the formula `171 - 5.2 ln(V) - 0.23 G - 16.2 ln(LOC)` scaled by
+    /// `100 / 171`, where `V` is the Halstead volume and `G` the cyclomatic
+    /// complexity. Below 20 is commonly treated as hard to maintain.
+    pub fn maintainability_index(volume: f64, cyclomatic: usize, lines_of_code: usize) -> f64 {
+        let raw = 171.0
+            - 5.2 * volume.max(1.0).ln()
+            - 0.23 * cyclomatic as f64
+            - 16.2 * (lines_of_code.max(1) as f64).ln();
+        (raw * 100.0 / 171.0).clamp(0.0, 100.0)
+    }
+}
+
+fn is_function(expression: &Expression<'_>) -> bool {
+    matches!(
+        expression,
+        Expression::ArrowFunctionExpression(_) | Expression::FunctionExpression(_)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzers::duplication::DuplicationAnalyzer;
+    use crate::syntax;
+    use std::path::Path;
+
+    fn complexity(source: &str) -> ComplexityVisitor {
+        syntax::with_program(Path::new("test.ts"), source, |program| {
+            let mut visitor = ComplexityVisitor::default();
+            visitor.visit_program(program);
+            visitor
+        })
+        .unwrap()
+    }
+
+    fn scores(source: &str) -> Vec<(Option<String>, usize)> {
+        complexity(source)
+            .functions
+            .into_iter()
+            .map(|function| (function.name, function.complexity))
+            .collect()
+    }
+
+    #[test]
+    fn test_nesting_increments() {
+        let source = "\
+            function sumOfPrimes(max) {\n\
+              let total = 0;\n\
+              OUT: for (let i = 1; i <= max; ++i) {\n\
+                for (let j = 2; j < i; ++j) {\n\
+                  if (i % j == 0) {\n\
+                    continue OUT;\n\
+                  }\n\
+                }\n\
+                total += i;\n\
+              }\n\
+              return total;\n\
+            }\n";
+        assert_eq!(scores(source), vec![(Some("sumOfPrimes".to_string()), 7)]);
+    }
+
+    #[test]
+    fn test_else_if_and_switch() {
+        let source = "\
+            export default function (kind) {\n\
+              if (kind === 1) { return 'a'; }\n\
+              else if (kind === 2) { return 'b'; }\n\
+              else { switch (kind) { case 3: return 'c'; default: return 'd'; } }\n\
+            }\n";
+        // if +1, else if +1, else +1, switch nested in else +2
+        assert_eq!(scores(source), vec![(Some("default".to_string()), 5)]);
+
+        let visitor = complexity(source);
+        assert_eq!(visitor.cyclomatic_complexity(), 4);
+    }
+
+    #[test]
+    fn test_nested_callbacks_count_towards_outer_function() {
+        let source = "\
+            export const load = (ids) => {\n\
+              ids.forEach((id) => {\n\
+                if (id) {\n\
+                  fetch(id).then((r) => r.ok ? r.json() : null);\n\
+                }\n\
+              });\n\
+            };\n";
+        // if at nesting 1 (+2), ternary at nesting 3 (+4)
+        assert_eq!(scores(source), vec![(Some("load".to_string()), 6)]);
+    }
+
+    #[test]
+    fn test_logical_operator_sequences() {
+        let source = "\
+            class Guard {\n\
+              allows(a, b, c, d) { return a && b && c || d; }\n\
+              check = (a, b) => a ?? b;\n\
+            }\n";
+        assert_eq!(
+            scores(source),
+            vec![
+                (Some("allows".to_string()), 2),
+                (Some("check".to_string()), 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_halstead() {
+        let source = "const x = a + 1;";
+        let tokens = DuplicationAnalyzer::tokenize(source);
+        let halstead = MetricsAnalyzer::halstead(source, &tokens);
+
+        assert_eq!(halstead.distinct_operators, 4);
+        assert_eq!(halstead.distinct_operands, 3);
+        assert_eq!(halstead.total_operators, 4);
+        assert_eq!(halstead.total_operands, 3);
+        assert!((halstead.volume - 7.0 * 7f64.log2()).abs() < 1e-9);
+        assert!((halstead.difficulty - 2.0).abs() < 1e-9);
+        assert!((halstead.effort - 2.0 * halstead.volume).abs() < 1e-9);
+
+        assert_eq!(
+            MetricsAnalyzer::halstead("", &[]),
+            HalsteadMetrics::default()
+        );
+    }
+
+    #[test]
+    fn test_lines_of_code_skips_blank_and_comment_lines() {
+        let tokens = DuplicationAnalyzer::tokenize("a;\n\n// note\nb(`x\ny`);\n");
+        assert_eq!(MetricsAnalyzer::lines_of_code(&tokens), 3);
+    }
+
+    #[test]
+    fn test_maintainability_index() {
+        assert!(MetricsAnalyzer::maintainability_index(0.0, 1, 0) > 99.0);
+        let index = MetricsAnalyzer::maintainability_index(1000.0, 10, 100);
+        assert!((index - 34.02).abs() < 0.01, "got {}", index);
+        assert_eq!(
+            MetricsAnalyzer::maintainability_index(1e12, 500, 100_000),
+            0.0
+        );
+    }
+}
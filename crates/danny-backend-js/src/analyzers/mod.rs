@@ -7,25 +7,45 @@
 //! - `dynamic_imports`: Dynamic import extraction
 //! - `class_members`: Class member analysis
 //! - `enum_members`: Enum member analysis
+//! - `type_members`: Interface and type alias member analysis
 //! - `npm_dependencies`: NPM dependency analysis
+//! - `unlisted_dependencies`: Imported packages missing from package.json
 //! - `dependency_chains`: Dependency chain analysis
+//! - `duplication`: Token-based duplicated code detection
+//! - `syntax_smells`: AST visitors for syntax-level code smells
+//! - `metrics`: Cognitive complexity, Halstead and maintainability metrics
+//! - `barrels`: Unused barrel re-exports and side-effectful barrels
+//! - `test_only`: Files and exports used only by tests
 
+pub mod barrels;
 pub mod bundle_size;
 pub mod class_members;
 pub mod dependency_chains;
+pub mod duplication;
 pub mod dynamic_imports;
 pub mod enum_members;
+pub mod metrics;
 pub mod npm_dependencies;
 pub mod quality;
 pub mod side_effects;
+pub mod syntax_smells;
+pub mod test_only;
+pub mod type_members;
 pub mod type_only;
+pub mod unlisted_dependencies;
 
+pub use barrels::BarrelAnalyzer;
 pub use bundle_size::BundleSizeAnalyzer;
 pub use class_members::ClassMemberAnalyzer;
 pub use dependency_chains::DependencyChainAnalyzer;
+pub use duplication::DuplicationAnalyzer;
 pub use dynamic_imports::DynamicImportAnalyzer;
 pub use enum_members::EnumMemberAnalyzer;
+pub use metrics::MetricsAnalyzer;
 pub use npm_dependencies::NpmDependencyAnalyzer;
 pub use quality::QualityAnalyzer;
 pub use side_effects::SideEffectAnalyzer;
+pub use test_only::TestOnlyAnalyzer;
+pub use type_members::TypeMemberAnalyzer;
 pub use type_only::{CategorizedExports, TypeOnlyAnalyzer, UnusedExport};
+pub use unlisted_dependencies::UnlistedDependencyAnalyzer;
@@ -1,9 +1,19 @@
-//! Code quality analyzer - detects code smells using Fob's symbol data.
+//! Code quality analyzer - detects code smells using Fob's symbol data
+//! and, for syntax-level smells, the parsed module source.
 
+use super::duplication::DuplicationAnalyzer;
+use super::metrics::{ComplexityVisitor, MetricsAnalyzer};
+use super::syntax_smells::{
+    CohesionVisitor, EmptyCatchVisitor, MagicNumberVisitor, MessageChainVisitor,
+};
+use crate::syntax::{self, LineIndex};
 use crate::toml_config::CodeQualityConfig;
-use danny_core::types::{CodeSmellDetails, CodeSmellType, SmellSeverity};
+use danny_core::types::{CodeSmellDetails, CodeSmellType, FileMetrics, SmellSeverity};
 use danny_core::Finding;
+use danny_fs::FileSystem;
 use fob::graph::{ModuleGraph, SymbolKind, UnusedSymbol};
+use oxc_ast::ast::Program;
+use oxc_ast_visit::Visit;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use thiserror::Error;
@@ -34,6 +44,19 @@ pub enum QualityAnalysisError {
     /// A module referenced by a symbol was not found in the graph.
     #[error("Module not found: {0}")]
     ModuleNotFound(String),
+
+    /// Failed to list the modules of the graph.
+    #[error("Failed to get modules: {0}")]
+    ModuleListing(String),
+}
+
+/// Source text of a module, for detectors that walk its syntax tree.
+#[derive(Debug, Clone)]
+pub struct ModuleSource {
+    /// Module path
+    pub path: PathBuf,
+    /// Module source text
+    pub source: String,
 }
 
 /// Analyzer for code quality issues (code smells).
@@ -486,46 +509,462 @@ impl QualityAnalyzer {
         Ok(findings)
     }
 
-    /// Detects magic numbers (hard-coded numeric literals).
+    /// Reads the source of every analyzable module in the graph.
+    ///
+    /// Syntax-level detectors (magic numbers, message chains) work on the
+    /// module source instead of Fob's symbol table. Virtual modules and
+    /// `node_modules` are skipped, as are files that can't be read.
     ///
-    /// **NOTE**: This is a Tier 2 detector requiring AST traversal.
-    /// Currently unimplemented and returns an empty vector. This placeholder
-    /// exists for future implementation.
+    /// # Errors
+    /// - `QualityAnalysisError::ModuleListing`: If retrieving modules fails
+    pub async fn load_sources<FS: FileSystem>(
+        graph: &ModuleGraph,
+        fs: &FS,
+    ) -> Result<Vec<ModuleSource>, QualityAnalysisError> {
+        let modules = graph
+            .modules()
+            .await
+            .map_err(|e| QualityAnalysisError::ModuleListing(e.to_string()))?;
+
+        let mut sources = Vec::new();
+        for module in modules.iter() {
+            if Self::is_virtual_path(&module.path)
+                || module
+                    .path
+                    .components()
+                    .any(|c| c.as_os_str() == "node_modules")
+            {
+                continue;
+            }
+
+            if let Ok(source) = fs.read_to_string(&module.path).await {
+                sources.push(ModuleSource {
+                    path: module.path.clone(),
+                    source,
+                });
+            }
+        }
+
+        Ok(sources)
+    }
+
+    /// Detects magic numbers (hard-coded numeric literals).
     ///
     /// Magic numbers are numeric literals that appear in code without explanation.
     /// They should typically be replaced with named constants for clarity.
     ///
-    /// # Future Implementation
-    /// Would require AST traversal to find all numeric literals and filter out
-    /// acceptable cases like 0, 1, array indices, etc.
-    pub async fn detect_magic_numbers(
-        _graph: &ModuleGraph,
-        _config: &CodeQualityConfig,
-    ) -> Result<Vec<Finding>, QualityAnalysisError> {
-        // Would require AST traversal to find numeric literals
-        // For MVP, placeholder - can be implemented later
-        Ok(Vec::new())
+    /// Literals listed in `config.magic_numbers.allowed` are never reported.
+    /// Array indices, enum initializers and literals that name a constant
+    /// (`const TIMEOUT_MS = 5000`) are skipped unless their exclusion is
+    /// turned off in `[quality.magic_numbers]`.
+    pub fn detect_magic_numbers(
+        sources: &[ModuleSource],
+        config: &CodeQualityConfig,
+    ) -> Vec<Finding> {
+        Self::detect_syntax_smells(
+            sources,
+            |program, source| {
+                let mut visitor = MagicNumberVisitor::new(&config.magic_numbers, source);
+                visitor.visit_program(program);
+                visitor.found
+            },
+            |module, lines, magic_number| {
+                let (line, column) = lines.line_col(magic_number.offset);
+                Self::create_smell_finding(
+                    CodeSmellType::MagicNumber,
+                    module.path.clone(),
+                    Some(magic_number.raw.clone()),
+                    Some(line),
+                    Some(column),
+                    SmellSeverity::Info,
+                    format!("Magic number {}", magic_number.raw),
+                    Some("Replace with a named constant".to_string()),
+                    None,
+                    None,
+                )
+            },
+        )
     }
 
     /// Detects message chains (excessive method/property chaining).
     ///
-    /// **NOTE**: This is a Tier 2 detector requiring AST traversal.
-    /// Currently unimplemented and returns an empty vector. This placeholder
-    /// exists for future implementation.
-    ///
     /// Message chains like `obj.prop1.prop2.method1().prop3.method2()` violate
-    /// the Law of Demeter and create tight coupling between objects.
+    /// the Law of Demeter and create tight coupling between objects. Chains
+    /// with more member accesses than `config.max_message_chain` are flagged;
+    /// calls along the chain don't count as links.
+    pub fn detect_message_chains(
+        sources: &[ModuleSource],
+        config: &CodeQualityConfig,
+    ) -> Vec<Finding> {
+        Self::detect_syntax_smells(
+            sources,
+            |program, _source| {
+                let mut visitor = MessageChainVisitor::new(config.max_message_chain);
+                visitor.visit_program(program);
+                visitor.found
+            },
+            |module, lines, chain| {
+                let (line, column) = lines.line_col(chain.offset);
+                Self::create_smell_finding(
+                    CodeSmellType::MessageChain,
+                    module.path.clone(),
+                    Some(chain.text.clone()),
+                    Some(line),
+                    Some(column),
+                    SmellSeverity::Warning,
+                    format!(
+                        "Chain '{}' has {} links (recommended: {})",
+                        chain.text, chain.length, config.max_message_chain
+                    ),
+                    Some("Hide the navigation behind a method on the nearest object".to_string()),
+                    Some(chain.length),
+                    Some(config.max_message_chain),
+                )
+            },
+        )
+    }
+
+    /// Detects empty `catch` blocks that silently swallow errors.
     ///
-    /// # Future Implementation
-    /// Would require AST traversal to find member expression chains exceeding
-    /// the configured threshold (typically 3-4 levels).
-    pub async fn detect_message_chains(
-        _graph: &ModuleGraph,
-        _config: &CodeQualityConfig,
-    ) -> Result<Vec<Finding>, QualityAnalysisError> {
-        // Would require AST traversal to find member expression chains
-        // For MVP, placeholder - can be implemented later
-        Ok(Vec::new())
+    /// Blocks holding only a comment count as empty unless
+    /// `config.allow_commented_catch` is set.
+    pub fn detect_empty_catch_blocks(
+        sources: &[ModuleSource],
+        config: &CodeQualityConfig,
+    ) -> Vec<Finding> {
+        Self::detect_syntax_smells(
+            sources,
+            |program, source| {
+                let mut visitor = EmptyCatchVisitor::new(source);
+                visitor.visit_program(program);
+                visitor
+                    .found
+                    .into_iter()
+                    .filter(|catch| !(catch.has_comment && config.allow_commented_catch))
+                    .collect()
+            },
+            |module, lines, catch| {
+                let (line, column) = lines.line_col(catch.offset);
+                let message = if catch.has_comment {
+                    "Catch block only contains a comment; the error is discarded"
+                } else {
+                    "Empty catch block; the error is discarded"
+                };
+                Self::create_smell_finding(
+                    CodeSmellType::EmptyCatchBlock,
+                    module.path.clone(),
+                    None,
+                    Some(line),
+                    Some(column),
+                    SmellSeverity::Warning,
+                    message.to_string(),
+                    Some("Handle, log or rethrow the error".to_string()),
+                    None,
+                    None,
+                )
+            },
+        )
+    }
+
+    /// Detects classes with low cohesion using the LCOM4 metric.
+    ///
+    /// LCOM4 counts the groups of instance methods that share no fields and
+    /// don't call each other. A class with more than `config.max_lcom` groups
+    /// (and at least `config.min_cohesion_methods` methods) is doing several
+    /// unrelated jobs. The groups are listed in the finding's `components`
+    /// metadata.
+    pub fn detect_low_cohesion(
+        sources: &[ModuleSource],
+        config: &CodeQualityConfig,
+    ) -> Vec<Finding> {
+        Self::detect_syntax_smells(
+            sources,
+            |program, _source| {
+                let mut visitor = CohesionVisitor::default();
+                visitor.visit_program(program);
+                visitor
+                    .found
+                    .into_iter()
+                    .filter(|class| {
+                        class.method_count >= config.min_cohesion_methods
+                            && class.components.len() > config.max_lcom
+                    })
+                    .collect()
+            },
+            |module, lines, class| {
+                let (line, column) = lines.line_col(class.offset);
+                let lcom = class.components.len();
+                let mut finding = Self::create_smell_finding(
+                    CodeSmellType::LowCohesion,
+                    module.path.clone(),
+                    class.class_name.clone(),
+                    Some(line),
+                    Some(column),
+                    SmellSeverity::Warning,
+                    format!(
+                        "Class '{}' has {} unrelated method groups (LCOM4, recommended: {})",
+                        class.class_name.as_deref().unwrap_or("<anonymous>"),
+                        lcom,
+                        config.max_lcom
+                    ),
+                    Some("Split the class along its method groups".to_string()),
+                    Some(lcom),
+                    Some(config.max_lcom),
+                );
+                if let Finding::CodeSmell { details, .. } = &mut finding {
+                    details.metadata.insert(
+                        "components".to_string(),
+                        serde_json::json!(class.components),
+                    );
+                }
+                finding
+            },
+        )
+    }
+
+    /// Detects duplicated code blocks across all modules.
+    ///
+    /// Identifiers and literals are normalized, so copies that only rename
+    /// variables or change constants still match. Each copy of a clone group
+    /// gets its own finding; the other copies are listed in the message and
+    /// all of them in the `instances` metadata.
+    pub fn detect_duplicated_code(
+        sources: &[ModuleSource],
+        config: &CodeQualityConfig,
+    ) -> Vec<Finding> {
+        let files: Vec<_> = sources
+            .iter()
+            .map(|module| {
+                (
+                    module.path.clone(),
+                    DuplicationAnalyzer::tokenize(&module.source),
+                )
+            })
+            .collect();
+        let groups = DuplicationAnalyzer::find_clones(
+            &files,
+            config.min_duplicate_tokens,
+            config.min_duplicate_lines as u32,
+        );
+
+        let mut findings = Vec::new();
+        for (group_index, group) in groups.iter().enumerate() {
+            let instances: Vec<serde_json::Value> = group
+                .instances
+                .iter()
+                .map(|instance| {
+                    serde_json::json!({
+                        "file": instance.file,
+                        "start_line": instance.start_line,
+                        "end_line": instance.end_line,
+                    })
+                })
+                .collect();
+
+            for instance in &group.instances {
+                let others: Vec<String> = group
+                    .instances
+                    .iter()
+                    .filter(|other| *other != instance)
+                    .map(|other| format!("{}:{}", other.file.display(), other.start_line))
+                    .collect();
+                let mut finding = Self::create_smell_finding(
+                    CodeSmellType::DuplicatedCode,
+                    instance.file.clone(),
+                    None,
+                    Some(instance.start_line),
+                    Some(instance.start_column),
+                    SmellSeverity::Warning,
+                    format!(
+                        "{} lines ({} tokens) duplicated in {}",
+                        instance.line_count(),
+                        group.tokens,
+                        others.join(", ")
+                    ),
+                    Some("Extract the shared code into a function or component".to_string()),
+                    Some(group.tokens),
+                    Some(config.min_duplicate_tokens),
+                );
+                if let Finding::CodeSmell { details, .. } = &mut finding {
+                    details
+                        .metadata
+                        .insert("group".to_string(), serde_json::json!(group_index));
+                    details
+                        .metadata
+                        .insert("end_line".to_string(), serde_json::json!(instance.end_line));
+                    details
+                        .metadata
+                        .insert("instances".to_string(), serde_json::json!(instances));
+                }
+                findings.push(finding);
+            }
+        }
+
+        findings
+    }
+
+    /// Detects functions with high cognitive complexity.
+    ///
+    /// Unlike cyclomatic complexity, nested structures cost more the deeper
+    /// they sit, and callbacks count towards the function that contains them.
+    pub fn detect_cognitive_complexity(
+        sources: &[ModuleSource],
+        config: &CodeQualityConfig,
+    ) -> Vec<Finding> {
+        Self::detect_syntax_smells(
+            sources,
+            |program, _source| {
+                let mut visitor = ComplexityVisitor::default();
+                visitor.visit_program(program);
+                visitor
+                    .functions
+                    .into_iter()
+                    .filter(|function| function.complexity > config.max_cognitive_complexity)
+                    .collect()
+            },
+            |module, lines, function| {
+                let (line, column) = lines.line_col(function.offset);
+                Self::create_smell_finding(
+                    CodeSmellType::CognitiveComplexity,
+                    module.path.clone(),
+                    function.name.clone(),
+                    Some(line),
+                    Some(column),
+                    SmellSeverity::Warning,
+                    format!(
+                        "Function '{}' has cognitive complexity {} (recommended: {})",
+                        function.name.as_deref().unwrap_or("<anonymous>"),
+                        function.complexity,
+                        config.max_cognitive_complexity
+                    ),
+                    Some(
+                        "Flatten nesting with early returns and extract nested callbacks"
+                            .to_string(),
+                    ),
+                    Some(function.complexity),
+                    Some(config.max_cognitive_complexity),
+                )
+            },
+        )
+    }
+
+    /// Computes complexity and maintainability metrics for each module.
+    ///
+    /// Modules that can't be parsed as JavaScript/TypeScript are skipped.
+    /// The result is sorted by path.
+    pub fn compute_file_metrics(sources: &[ModuleSource]) -> Vec<FileMetrics> {
+        let mut metrics: Vec<FileMetrics> = sources
+            .iter()
+            .filter_map(|module| {
+                let complexity = syntax::with_program(&module.path, &module.source, |program| {
+                    let mut visitor = ComplexityVisitor::default();
+                    visitor.visit_program(program);
+                    visitor
+                })?;
+
+                let tokens = DuplicationAnalyzer::tokenize(&module.source);
+                let halstead = MetricsAnalyzer::halstead(&module.source, &tokens);
+                let lines_of_code = MetricsAnalyzer::lines_of_code(&tokens);
+                let cyclomatic_complexity = complexity.cyclomatic_complexity();
+
+                Some(FileMetrics {
+                    path: module.path.clone(),
+                    lines_of_code,
+                    functions: complexity.functions.len(),
+                    cyclomatic_complexity,
+                    cognitive_complexity: complexity
+                        .functions
+                        .iter()
+                        .map(|function| function.complexity)
+                        .sum(),
+                    max_cognitive_complexity: complexity
+                        .functions
+                        .iter()
+                        .map(|function| function.complexity)
+                        .max()
+                        .unwrap_or(0),
+                    maintainability_index: MetricsAnalyzer::maintainability_index(
+                        halstead.volume,
+                        cyclomatic_complexity,
+                        lines_of_code,
+                    ),
+                    halstead,
+                })
+            })
+            .collect();
+
+        metrics.sort_by(|a, b| a.path.cmp(&b.path));
+        metrics
+    }
+
+    /// Detects modules whose maintainability index is below
+    /// `config.min_maintainability_index`.
+    pub fn detect_low_maintainability(
+        metrics: &[FileMetrics],
+        config: &CodeQualityConfig,
+    ) -> Vec<Finding> {
+        metrics
+            .iter()
+            .filter(|file| file.maintainability_index < config.min_maintainability_index)
+            .map(|file| {
+                let mut finding = Self::create_smell_finding(
+                    CodeSmellType::LowMaintainability,
+                    file.path.clone(),
+                    None,
+                    None,
+                    None,
+                    SmellSeverity::Warning,
+                    format!(
+                        "Maintainability index {:.1} (recommended: at least {})",
+                        file.maintainability_index, config.min_maintainability_index
+                    ),
+                    Some("Split the module and simplify its most complex functions".to_string()),
+                    None,
+                    None,
+                );
+                if let Finding::CodeSmell { details, .. } = &mut finding {
+                    details.score = Some(file.maintainability_index);
+                    details.score_threshold = Some(config.min_maintainability_index);
+                    details.metadata.insert(
+                        "halstead_volume".to_string(),
+                        serde_json::json!(file.halstead.volume),
+                    );
+                    details.metadata.insert(
+                        "cyclomatic_complexity".to_string(),
+                        serde_json::json!(file.cyclomatic_complexity),
+                    );
+                    details.metadata.insert(
+                        "lines_of_code".to_string(),
+                        serde_json::json!(file.lines_of_code),
+                    );
+                }
+                finding
+            })
+            .collect()
+    }
+
+    /// Parses each module and converts the hits of a syntax visitor into findings.
+    ///
+    /// Modules that can't be parsed as JavaScript/TypeScript are skipped.
+    fn detect_syntax_smells<T>(
+        sources: &[ModuleSource],
+        collect: impl Fn(&Program<'_>, &str) -> Vec<T>,
+        to_finding: impl Fn(&ModuleSource, &LineIndex, T) -> Finding,
+    ) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        for module in sources {
+            let Some(hits) = syntax::with_program(&module.path, &module.source, |program| {
+                collect(program, &module.source)
+            }) else {
+                continue;
+            };
+
+            let lines = LineIndex::new(&module.source);
+            findings.extend(hits.into_iter().map(|hit| to_finding(module, &lines, hit)));
+        }
+
+        findings
     }
 
     /// Creates a code smell finding with the specified parameters.
@@ -569,6 +1008,8 @@ impl QualityAnalyzer {
                 recommendation,
                 current_value,
                 recommended_threshold,
+                score: None,
+                score_threshold: None,
                 metadata: HashMap::new(),
             },
         }
@@ -695,6 +1136,207 @@ mod tests {
         }
     }
 
+    /// Test that syntax-level detectors report module locations and thresholds.
+    #[test]
+    fn test_syntax_smell_findings() {
+        let sources = vec![ModuleSource {
+            path: PathBuf::from("src/order.ts"),
+            source:
+                "export const total = (o: Order) =>\n  o.lines.first().price.amount.value * 1.2;\n"
+                    .to_string(),
+        }];
+        let config = CodeQualityConfig::default();
+
+        let magic = QualityAnalyzer::detect_magic_numbers(&sources, &config);
+        assert_eq!(magic.len(), 1);
+        match &magic[0] {
+            Finding::CodeSmell {
+                smell_type: CodeSmellType::MagicNumber,
+                symbol_name,
+                line,
+                ..
+            } => {
+                assert_eq!(symbol_name.as_deref(), Some("1.2"));
+                assert_eq!(*line, Some(2));
+            }
+            other => panic!("Expected magic number, got {:?}", other),
+        }
+
+        let chains = QualityAnalyzer::detect_message_chains(&sources, &config);
+        assert_eq!(chains.len(), 1);
+        match &chains[0] {
+            Finding::CodeSmell {
+                smell_type: CodeSmellType::MessageChain,
+                details,
+                column,
+                ..
+            } => {
+                assert_eq!(details.current_value, Some(5));
+                assert_eq!(details.recommended_threshold, Some(4));
+                assert_eq!(*column, Some(2));
+            }
+            other => panic!("Expected message chain, got {:?}", other),
+        }
+    }
+
+    /// Test catch block and cohesion detectors against their thresholds.
+    #[test]
+    fn test_catch_and_cohesion_thresholds() {
+        let sources = vec![ModuleSource {
+            path: PathBuf::from("src/session.ts"),
+            source: "\
+                export class Session {\n\
+                  open() { this.socket = connect(); }\n\
+                  close() { this.socket.end(); }\n\
+                  theme() { return this.colors; }\n\
+                }\n\
+                try { load(); } catch { /* optional */ }\n"
+                .to_string(),
+        }];
+
+        let mut config = CodeQualityConfig::default();
+        assert_eq!(
+            QualityAnalyzer::detect_empty_catch_blocks(&sources, &config).len(),
+            1
+        );
+        config.allow_commented_catch = true;
+        assert!(QualityAnalyzer::detect_empty_catch_blocks(&sources, &config).is_empty());
+
+        let findings = QualityAnalyzer::detect_low_cohesion(&sources, &config);
+        assert_eq!(findings.len(), 1);
+        match &findings[0] {
+            Finding::CodeSmell {
+                smell_type: CodeSmellType::LowCohesion,
+                symbol_name,
+                details,
+                ..
+            } => {
+                assert_eq!(symbol_name.as_deref(), Some("Session"));
+                assert_eq!(details.current_value, Some(2));
+                assert_eq!(
+                    details.metadata["components"],
+                    serde_json::json!([["open", "close"], ["theme"]])
+                );
+            }
+            other => panic!("Expected low cohesion, got {:?}", other),
+        }
+
+        config.max_lcom = 2;
+        assert!(QualityAnalyzer::detect_low_cohesion(&sources, &config).is_empty());
+    }
+
+    /// Test that every copy of a clone group is reported.
+    #[test]
+    fn test_duplicated_code_findings() {
+        let body = "\
+            export function area(shape) {\n\
+              if (shape.kind === 'circle') {\n\
+                return Math.PI * shape.radius * shape.radius;\n\
+              }\n\
+              return shape.width * shape.height;\n\
+            }\n";
+        let sources = vec![
+            ModuleSource {
+                path: PathBuf::from("src/a.js"),
+                source: body.to_string(),
+            },
+            ModuleSource {
+                path: PathBuf::from("src/b.js"),
+                source: body.replace("area", "size").replace("shape", "s"),
+            },
+        ];
+
+        let mut config = CodeQualityConfig {
+            min_duplicate_tokens: 30,
+            ..Default::default()
+        };
+        let findings = QualityAnalyzer::detect_duplicated_code(&sources, &config);
+        assert_eq!(findings.len(), 2);
+        match &findings[1] {
+            Finding::CodeSmell {
+                smell_type: CodeSmellType::DuplicatedCode,
+                location,
+                line,
+                details,
+                ..
+            } => {
+                assert_eq!(location, &PathBuf::from("src/b.js"));
+                assert_eq!(*line, Some(1));
+                assert!(details.message.contains("src/a.js:1"));
+                assert_eq!(details.metadata["end_line"], serde_json::json!(6));
+                assert_eq!(details.metadata["instances"].as_array().unwrap().len(), 2);
+            }
+            other => panic!("Expected duplicated code, got {:?}", other),
+        }
+
+        config.min_duplicate_lines = 7;
+        assert!(QualityAnalyzer::detect_duplicated_code(&sources, &config).is_empty());
+    }
+
+    /// Test cognitive complexity findings and the per-file metrics table.
+    #[test]
+    fn test_complexity_metrics() {
+        let sources = vec![ModuleSource {
+            path: PathBuf::from("src/sync.ts"),
+            source: "\
+                export function sync(items) {\n\
+                  for (const item of items) {\n\
+                    if (item.dirty) {\n\
+                      item.save().then((ok) => {\n\
+                        if (!ok && item.retries) {\n\
+                          retry(item);\n\
+                        }\n\
+                      });\n\
+                    }\n\
+                  }\n\
+                }\n"
+            .to_string(),
+        }];
+        // for +1, if +2, callback if +4, && +1
+        let mut config = CodeQualityConfig {
+            max_cognitive_complexity: 7,
+            ..Default::default()
+        };
+
+        let findings = QualityAnalyzer::detect_cognitive_complexity(&sources, &config);
+        assert_eq!(findings.len(), 1);
+        match &findings[0] {
+            Finding::CodeSmell {
+                smell_type: CodeSmellType::CognitiveComplexity,
+                symbol_name,
+                line,
+                details,
+                ..
+            } => {
+                assert_eq!(symbol_name.as_deref(), Some("sync"));
+                assert_eq!(*line, Some(1));
+                assert_eq!(details.current_value, Some(8));
+            }
+            other => panic!("Expected cognitive complexity, got {:?}", other),
+        }
+        config.max_cognitive_complexity = 8;
+        assert!(QualityAnalyzer::detect_cognitive_complexity(&sources, &config).is_empty());
+
+        let metrics = QualityAnalyzer::compute_file_metrics(&sources);
+        assert_eq!(metrics.len(), 1);
+        assert_eq!(metrics[0].lines_of_code, 11);
+        assert_eq!(metrics[0].functions, 1);
+        assert_eq!(metrics[0].cyclomatic_complexity, 5);
+        assert_eq!(metrics[0].max_cognitive_complexity, 8);
+        assert!(metrics[0].maintainability_index > 0.0);
+
+        config.min_maintainability_index = 100.0;
+        let findings = QualityAnalyzer::detect_low_maintainability(&metrics, &config);
+        assert_eq!(findings.len(), 1);
+        match &findings[0] {
+            Finding::CodeSmell { details, .. } => {
+                assert_eq!(details.score, Some(metrics[0].maintainability_index));
+                assert_eq!(details.score_threshold, Some(100.0));
+            }
+            other => panic!("Expected low maintainability, got {:?}", other),
+        }
+    }
+
     // Note: Integration tests for detect_* methods require a mock ModuleGraph.
     // These should be placed in the integration tests directory with proper
     // test fixtures. The tests above cover unit-testable components.
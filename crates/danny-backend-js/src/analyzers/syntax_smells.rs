@@ -0,0 +1,601 @@
+//! Syntax-level code smell visitors.
+//!
+//! These detectors need the module's syntax tree rather than Fob's symbol
+//! table: literals, call chains, catch blocks, per-method field access.
+//! Each visitor walks one parsed program and records raw hits (byte offset
+//! plus detail); `QualityAnalyzer` turns them into findings.
+
+use crate::toml_config::MagicNumberConfig;
+use oxc_ast::ast::{
+    CatchClause, Class, ClassElement, ComputedMemberExpression, Expression, MethodDefinitionKind,
+    PrivateFieldExpression, PropertyDefinition, PropertyKey, Statement, StaticMemberExpression,
+    TSEnumMember, TSLiteralType, UnaryExpression, UnaryOperator, VariableDeclarationKind,
+    VariableDeclarator,
+};
+use oxc_ast_visit::{walk, Visit};
+use oxc_span::{GetSpan, Span};
+use std::collections::HashSet;
+
+/// A numeric literal that should be a named constant.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MagicNumber {
+    /// Byte offset of the literal
+    pub offset: u32,
+    /// Literal as written (`86_400`, `-1.5`, `0xff`)
+    pub raw: String,
+}
+
+/// Finds numeric literals that aren't allowed or excluded by configuration.
+pub struct MagicNumberVisitor<'s> {
+    config: &'s MagicNumberConfig,
+    source: &'s str,
+    /// Magic numbers found so far
+    pub found: Vec<MagicNumber>,
+}
+
+impl<'s> MagicNumberVisitor<'s> {
+    /// Creates a visitor for one module's source.
+    pub fn new(config: &'s MagicNumberConfig, source: &'s str) -> Self {
+        Self {
+            config,
+            source,
+            found: Vec::new(),
+        }
+    }
+
+    fn check(&mut self, value: f64, span: Span) {
+        if self.config.allowed.contains(&value) {
+            return;
+        }
+        self.found.push(MagicNumber {
+            offset: span.start,
+            raw: text(self.source, span),
+        });
+    }
+}
+
+impl<'a> Visit<'a> for MagicNumberVisitor<'_> {
+    fn visit_expression(&mut self, it: &Expression<'a>) {
+        match it {
+            Expression::NumericLiteral(literal) => self.check(literal.value, literal.span),
+            _ => walk::walk_expression(self, it),
+        }
+    }
+
+    // `-1` is a negation of the literal `1`; check it as a single value
+    fn visit_unary_expression(&mut self, it: &UnaryExpression<'a>) {
+        match (&it.operator, &it.argument) {
+            (UnaryOperator::UnaryNegation, Expression::NumericLiteral(literal)) => {
+                self.check(-literal.value, it.span);
+            }
+            _ => walk::walk_unary_expression(self, it),
+        }
+    }
+
+    fn visit_computed_member_expression(&mut self, it: &ComputedMemberExpression<'a>) {
+        let is_index = matches!(
+            &it.expression,
+            Expression::NumericLiteral(literal) if literal.value.fract() == 0.0
+        );
+        if self.config.ignore_array_indexes && is_index {
+            self.visit_expression(&it.object);
+        } else {
+            walk::walk_computed_member_expression(self, it);
+        }
+    }
+
+    fn visit_ts_enum_member(&mut self, it: &TSEnumMember<'a>) {
+        if !self.config.ignore_enums {
+            walk::walk_ts_enum_member(self, it);
+        }
+    }
+
+    fn visit_variable_declarator(&mut self, it: &VariableDeclarator<'a>) {
+        let names_constant = it.kind == VariableDeclarationKind::Const
+            && it.init.as_ref().is_some_and(is_numeric_constant);
+        if !(self.config.ignore_const_declarations && names_constant) {
+            walk::walk_variable_declarator(self, it);
+        }
+    }
+
+    fn visit_property_definition(&mut self, it: &PropertyDefinition<'a>) {
+        let names_constant = it.readonly && it.value.as_ref().is_some_and(is_numeric_constant);
+        if !(self.config.ignore_const_declarations && names_constant) {
+            walk::walk_property_definition(self, it);
+        }
+    }
+
+    // Literal types (`type Port = 80`) are declarations, not magic values
+    fn visit_ts_literal_type(&mut self, _it: &TSLiteralType<'a>) {}
+}
+
+/// A chain of member accesses longer than the configured maximum.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MessageChain {
+    /// Byte offset of the chain
+    pub offset: u32,
+    /// Number of member accesses in the chain
+    pub length: usize,
+    /// Chain shape with arguments elided (`order.customer.getAddress().city`)
+    pub text: String,
+}
+
+/// Finds member access chains (`a.b().c.d()`) longer than `max_length`.
+///
+/// Only the outermost expression of a chain is reported; calls don't add
+/// to the length, so `user.getAddress().getCity()` has two links.
+pub struct MessageChainVisitor {
+    max_length: usize,
+    /// Chains found so far
+    pub found: Vec<MessageChain>,
+}
+
+impl MessageChainVisitor {
+    /// Creates a visitor with the given chain length limit.
+    pub fn new(max_length: usize) -> Self {
+        Self {
+            max_length,
+            found: Vec::new(),
+        }
+    }
+
+    /// The next expression down a chain's spine, and whether the step is a member access.
+    fn next_link<'e, 'a>(expression: &'e Expression<'a>) -> Option<(&'e Expression<'a>, bool)> {
+        match expression {
+            Expression::StaticMemberExpression(member) => Some((&member.object, true)),
+            Expression::ComputedMemberExpression(member) => Some((&member.object, true)),
+            Expression::PrivateFieldExpression(member) => Some((&member.object, true)),
+            Expression::CallExpression(call) => Some((&call.callee, false)),
+            Expression::TSNonNullExpression(non_null) => Some((&non_null.expression, false)),
+            _ => None,
+        }
+    }
+
+    /// Compact rendering of one link (`.name`, `()`, `[…]`) or of the chain's base.
+    fn link_text(expression: &Expression<'_>) -> String {
+        match expression {
+            Expression::StaticMemberExpression(member) => format!(".{}", member.property.name),
+            Expression::ComputedMemberExpression(_) => "[…]".to_string(),
+            Expression::PrivateFieldExpression(member) => format!(".#{}", member.field.name),
+            Expression::CallExpression(_) => "()".to_string(),
+            Expression::TSNonNullExpression(_) => "!".to_string(),
+            Expression::Identifier(ident) => ident.name.to_string(),
+            Expression::ThisExpression(_) => "this".to_string(),
+            _ => "(…)".to_string(),
+        }
+    }
+}
+
+impl<'a> Visit<'a> for MessageChainVisitor {
+    fn visit_expression(&mut self, it: &Expression<'a>) {
+        if Self::next_link(it).is_none() {
+            walk::walk_expression(self, it);
+            return;
+        }
+
+        let mut length = 0;
+        let mut links = Vec::new();
+        let mut current = it;
+        while let Some((next, is_access)) = Self::next_link(current) {
+            length += usize::from(is_access);
+            links.push(Self::link_text(current));
+
+            // Arguments and computed keys may hold chains of their own
+            match current {
+                Expression::CallExpression(call) => self.visit_arguments(&call.arguments),
+                Expression::ComputedMemberExpression(member) => {
+                    self.visit_expression(&member.expression);
+                }
+                _ => {}
+            }
+            current = next;
+        }
+        links.push(Self::link_text(current));
+        self.visit_expression(current);
+
+        if length > self.max_length {
+            links.reverse();
+            self.found.push(MessageChain {
+                offset: it.span().start,
+                length,
+                text: links.concat(),
+            });
+        }
+    }
+}
+
+/// A `catch` block without statements.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmptyCatch {
+    /// Byte offset of the catch clause
+    pub offset: u32,
+    /// Whether the block holds a comment (`catch { /* ignore */ }`)
+    pub has_comment: bool,
+}
+
+/// Finds catch blocks that swallow errors without handling them.
+pub struct EmptyCatchVisitor<'s> {
+    source: &'s str,
+    /// Empty catch blocks found so far
+    pub found: Vec<EmptyCatch>,
+}
+
+impl<'s> EmptyCatchVisitor<'s> {
+    /// Creates a visitor for one module's source.
+    pub fn new(source: &'s str) -> Self {
+        Self {
+            source,
+            found: Vec::new(),
+        }
+    }
+}
+
+impl<'a> Visit<'a> for EmptyCatchVisitor<'_> {
+    fn visit_catch_clause(&mut self, it: &CatchClause<'a>) {
+        if it.body.body.is_empty() {
+            // Without statements, anything between the braces is a comment
+            let block = it.body.span;
+            let inner = Span::new(
+                block.start + 1,
+                block.end.saturating_sub(1).max(block.start + 1),
+            );
+            self.found.push(EmptyCatch {
+                offset: it.span.start,
+                has_comment: !text(self.source, inner).trim().is_empty(),
+            });
+        }
+        walk::walk_catch_clause(self, it);
+    }
+}
+
+/// Method groups of a class, for LCOM4.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClassCohesion {
+    /// Byte offset of the class
+    pub offset: u32,
+    /// Class name (`None` for anonymous class expressions)
+    pub class_name: Option<String>,
+    /// Number of instance methods measured
+    pub method_count: usize,
+    /// Methods grouped by shared fields and calls; LCOM4 is the group count
+    pub components: Vec<Vec<String>>,
+}
+
+/// Measures LCOM4 cohesion for every class with instance methods.
+///
+/// Instance methods (including arrow-function properties) are connected
+/// when one calls the other through `this` or both use the same field.
+/// Constructors and static members are left out, as constructors touch
+/// every field by design.
+#[derive(Default)]
+pub struct CohesionVisitor {
+    /// Classes measured so far
+    pub found: Vec<ClassCohesion>,
+}
+
+impl<'a> Visit<'a> for CohesionVisitor {
+    fn visit_class(&mut self, it: &Class<'a>) {
+        let mut methods: Vec<(String, HashSet<String>)> = Vec::new();
+
+        for element in &it.body.body {
+            let mut accesses = ThisAccesses::default();
+            let name = match element {
+                ClassElement::MethodDefinition(method)
+                    if !method.r#static && method.kind != MethodDefinitionKind::Constructor =>
+                {
+                    let Some(body) = &method.value.body else {
+                        continue;
+                    };
+                    accesses.visit_function_body(body);
+                    member_name(&method.key)
+                }
+                ClassElement::PropertyDefinition(property) if !property.r#static => {
+                    let Some(Expression::ArrowFunctionExpression(arrow)) = &property.value else {
+                        continue;
+                    };
+                    accesses.visit_function_body(&arrow.body);
+                    member_name(&property.key)
+                }
+                _ => continue,
+            };
+            if let Some(name) = name {
+                methods.push((name, accesses.names));
+            }
+        }
+
+        if !methods.is_empty() {
+            self.found.push(ClassCohesion {
+                offset: it.span.start,
+                class_name: it.id.as_ref().map(|id| id.name.to_string()),
+                method_count: methods.len(),
+                components: cohesion_components(&methods),
+            });
+        }
+
+        walk::walk_class(self, it);
+    }
+}
+
+/// Members accessed through `this` in a method body.
+#[derive(Default)]
+struct ThisAccesses {
+    names: HashSet<String>,
+}
+
+impl<'a> Visit<'a> for ThisAccesses {
+    fn visit_static_member_expression(&mut self, it: &StaticMemberExpression<'a>) {
+        if matches!(it.object, Expression::ThisExpression(_)) {
+            self.names.insert(it.property.name.to_string());
+        }
+        walk::walk_static_member_expression(self, it);
+    }
+
+    fn visit_private_field_expression(&mut self, it: &PrivateFieldExpression<'a>) {
+        if matches!(it.object, Expression::ThisExpression(_)) {
+            self.names.insert(format!("#{}", it.field.name));
+        }
+        walk::walk_private_field_expression(self, it);
+    }
+
+    // `this` is rebound inside regular functions and nested classes
+    fn visit_expression(&mut self, it: &Expression<'a>) {
+        if !matches!(
+            it,
+            Expression::FunctionExpression(_) | Expression::ClassExpression(_)
+        ) {
+            walk::walk_expression(self, it);
+        }
+    }
+
+    fn visit_statement(&mut self, it: &Statement<'a>) {
+        if !matches!(
+            it,
+            Statement::FunctionDeclaration(_) | Statement::ClassDeclaration(_)
+        ) {
+            walk::walk_statement(self, it);
+        }
+    }
+}
+
+pub(crate) fn member_name(key: &PropertyKey<'_>) -> Option<String> {
+    match key {
+        PropertyKey::PrivateIdentifier(ident) => Some(format!("#{}", ident.name)),
+        _ => key.static_name().map(|name| name.into_owned()),
+    }
+}
+
+/// Groups methods into connected components (LCOM4).
+///
+/// `methods` pairs each method name with the members it accesses through
+/// `this`. Two methods are connected when one accesses the other, or when
+/// both access the same non-method member (a field).
+pub fn cohesion_components(methods: &[(String, HashSet<String>)]) -> Vec<Vec<String>> {
+    let names: HashSet<&str> = methods.iter().map(|(name, _)| name.as_str()).collect();
+    let mut parent: Vec<usize> = (0..methods.len()).collect();
+
+    fn root(parent: &mut [usize], mut i: usize) -> usize {
+        while parent[i] != i {
+            parent[i] = parent[parent[i]];
+            i = parent[i];
+        }
+        i
+    }
+
+    for i in 0..methods.len() {
+        for j in i + 1..methods.len() {
+            let (a_name, a_uses) = &methods[i];
+            let (b_name, b_uses) = &methods[j];
+            let connected = a_uses.contains(b_name)
+                || b_uses.contains(a_name)
+                || a_uses
+                    .iter()
+                    .any(|field| !names.contains(field.as_str()) && b_uses.contains(field));
+            if connected {
+                let (a, b) = (root(&mut parent, i), root(&mut parent, j));
+                parent[a] = b;
+            }
+        }
+    }
+
+    let mut components: Vec<(usize, Vec<String>)> = Vec::new();
+    for (i, (name, _)) in methods.iter().enumerate() {
+        let r = root(&mut parent, i);
+        match components.iter_mut().find(|(root, _)| *root == r) {
+            Some((_, members)) => members.push(name.clone()),
+            None => components.push((r, vec![name.clone()])),
+        }
+    }
+
+    components.into_iter().map(|(_, members)| members).collect()
+}
+
+/// Whether an expression is built only from numeric literals (`60 * 60 * 1000`).
+fn is_numeric_constant(expression: &Expression<'_>) -> bool {
+    match expression {
+        Expression::NumericLiteral(_) => true,
+        Expression::UnaryExpression(unary) => {
+            matches!(
+                unary.operator,
+                UnaryOperator::UnaryNegation | UnaryOperator::UnaryPlus
+            ) && is_numeric_constant(&unary.argument)
+        }
+        Expression::BinaryExpression(binary) => {
+            is_numeric_constant(&binary.left) && is_numeric_constant(&binary.right)
+        }
+        Expression::ParenthesizedExpression(paren) => is_numeric_constant(&paren.expression),
+        _ => false,
+    }
+}
+
+fn text(source: &str, span: Span) -> String {
+    source
+        .get(span.start as usize..span.end as usize)
+        .unwrap_or_default()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::syntax;
+    use std::path::Path;
+
+    fn magic_numbers(source: &str, config: &MagicNumberConfig) -> Vec<String> {
+        syntax::with_program(Path::new("test.ts"), source, |program| {
+            let mut visitor = MagicNumberVisitor::new(config, source);
+            visitor.visit_program(program);
+            visitor.found.into_iter().map(|hit| hit.raw).collect()
+        })
+        .unwrap()
+    }
+
+    fn message_chains(source: &str, max_length: usize) -> Vec<(String, usize)> {
+        syntax::with_program(Path::new("test.ts"), source, |program| {
+            let mut visitor = MessageChainVisitor::new(max_length);
+            visitor.visit_program(program);
+            visitor
+                .found
+                .into_iter()
+                .map(|chain| (chain.text, chain.length))
+                .collect()
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_magic_numbers() {
+        let config = MagicNumberConfig::default();
+        let source = "\
+            const TIMEOUT_MS = 60 * 1000;\n\
+            enum Port { Http = 80 }\n\
+            type Retries = 3;\n\
+            class Cache { static readonly SIZE = 512; ttl = 3600; }\n\
+            export function price(items: number[], total: number) {\n\
+              let discount = total > 100 ? 0.15 : 0;\n\
+              return items[3] + discount * -1 + total * -7 + 1;\n\
+            }\n";
+
+        assert_eq!(
+            magic_numbers(source, &config),
+            vec!["3600", "100", "0.15", "-7"]
+        );
+    }
+
+    #[test]
+    fn test_magic_number_exclusions_are_configurable() {
+        let config = MagicNumberConfig {
+            allowed: vec![0.0, 100.0],
+            ignore_array_indexes: false,
+            ignore_enums: false,
+            ignore_const_declarations: false,
+        };
+        let source = "\
+            const LIMIT = 50;\n\
+            enum Port { Http = 80 }\n\
+            export const pick = (items: number[]) => items[3] > 100;\n";
+
+        assert_eq!(magic_numbers(source, &config), vec!["50", "80", "3"]);
+    }
+
+    #[test]
+    fn test_message_chains() {
+        let source = "\
+            const city = order.customer.getAddress().city.name.trim();\n\
+            const short = user.profile.name;\n\
+            run(a.b.c.d.e.f, x.y);\n";
+
+        assert_eq!(
+            message_chains(source, 4),
+            vec![
+                (
+                    "order.customer.getAddress().city.name.trim()".to_string(),
+                    5
+                ),
+                ("a.b.c.d.e.f".to_string(), 5),
+            ]
+        );
+        assert!(message_chains(source, 5).is_empty());
+    }
+
+    #[test]
+    fn test_empty_catch_blocks() {
+        let source = "\
+            try { run(); } catch (e) {}\n\
+            try { run(); } catch { /* best effort */ }\n\
+            try { run(); } catch (e) { log(e); }\n";
+
+        let found = syntax::with_program(Path::new("test.ts"), source, |program| {
+            let mut visitor = EmptyCatchVisitor::new(source);
+            visitor.visit_program(program);
+            visitor.found
+        })
+        .unwrap();
+
+        assert_eq!(
+            found.iter().map(|c| c.has_comment).collect::<Vec<_>>(),
+            vec![false, true]
+        );
+    }
+
+    #[test]
+    fn test_class_cohesion() {
+        let source = "\
+            class Report {\n\
+              #rows = [];\n\
+              constructor(title) { this.title = title; this.#rows = []; }\n\
+              add(row) { this.#rows.push(row); }\n\
+              total() { return this.#rows.length; }\n\
+              heading() { return this.title.toUpperCase(); }\n\
+              print = () => `${this.heading()}`;\n\
+              static create() { return new Report(); }\n\
+              format(n) { return function () { return this.title; }; }\n\
+            }\n";
+
+        let found = syntax::with_program(Path::new("test.ts"), source, |program| {
+            let mut visitor = CohesionVisitor::default();
+            visitor.visit_program(program);
+            visitor.found
+        })
+        .unwrap();
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].class_name.as_deref(), Some("Report"));
+        assert_eq!(found[0].method_count, 5);
+        assert_eq!(
+            found[0].components,
+            vec![
+                vec!["add".to_string(), "total".to_string()],
+                vec!["heading".to_string(), "print".to_string()],
+                vec!["format".to_string()],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cohesion_components() {
+        let uses = |names: &[&str]| names.iter().map(|n| n.to_string()).collect::<HashSet<_>>();
+        let methods = vec![
+            ("load".to_string(), uses(&["cache", "parse"])),
+            ("parse".to_string(), uses(&[])),
+            ("clear".to_string(), uses(&["cache"])),
+            ("render".to_string(), uses(&["template"])),
+        ];
+
+        assert_eq!(
+            cohesion_components(&methods),
+            vec![
+                vec!["load".to_string(), "parse".to_string(), "clear".to_string()],
+                vec!["render".to_string()],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_nested_chain_in_arguments() {
+        let source = "list.filter(x => x.a.b.c.d.e).map(f);\n";
+        assert_eq!(
+            message_chains(source, 3),
+            vec![("x.a.b.c.d.e".to_string(), 5)]
+        );
+    }
+}
@@ -0,0 +1,250 @@
+//! Test-only usage analysis - files and exports that only tests depend on.
+//!
+//! Test files are entry points, so anything they import looks used. This
+//! analyzer separates test usage from production usage: a module reachable
+//! only from tests, or an export imported only by tests, is reported as
+//! used only in tests instead of as used (or dead) code.
+
+use danny_core::{Finding, SourceLocation};
+use std::collections::{BTreeSet, HashSet};
+use std::path::PathBuf;
+
+/// Which exports an import uses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImportedNames {
+    /// Every export (namespace imports, dynamic imports, re-exports)
+    All,
+    /// Only the listed exports (`default` for default imports)
+    Named(Vec<String>),
+}
+
+/// An import edge between two modules of the graph.
+#[derive(Debug, Clone)]
+pub struct UsageEdge {
+    /// Index of the imported module
+    pub target: usize,
+    /// Exports used through this import
+    pub names: ImportedNames,
+}
+
+/// A module of the graph, reduced to what test-only analysis needs.
+#[derive(Debug, Clone)]
+pub struct UsageNode {
+    /// Module path
+    pub path: PathBuf,
+    /// Whether the module is a test file
+    pub is_test: bool,
+    /// Whether the module is an entry point
+    pub is_entry: bool,
+    /// Exports that can be reported (name and location)
+    pub exports: Vec<(String, Option<SourceLocation>)>,
+    /// Resolved imports of this module
+    pub imports: Vec<UsageEdge>,
+}
+
+/// Analyzer for test-only usage findings.
+pub struct TestOnlyAnalyzer;
+
+impl TestOnlyAnalyzer {
+    /// Finds modules reachable only from tests and exports imported only by tests.
+    ///
+    /// Entry points that aren't tests (including tool configs) are production
+    /// roots. Test files themselves are never reported.
+    pub fn find_test_only(nodes: &[UsageNode]) -> Vec<Finding> {
+        let production = Self::reachable(nodes, |node| node.is_entry && !node.is_test);
+        let from_tests = Self::reachable(nodes, |node| node.is_test);
+
+        let mut importers: Vec<Vec<usize>> = vec![Vec::new(); nodes.len()];
+        for (i, node) in nodes.iter().enumerate() {
+            for edge in &node.imports {
+                if edge.target != i && !importers[edge.target].contains(&i) {
+                    importers[edge.target].push(i);
+                }
+            }
+        }
+
+        let mut findings = Vec::new();
+
+        for (i, node) in nodes.iter().enumerate() {
+            if node.is_test || !from_tests[i] {
+                continue;
+            }
+
+            if !production[i] {
+                findings.push(Finding::UsedOnlyInTests {
+                    module: node.path.clone(),
+                    export_name: None,
+                    span: None,
+                    used_by: importers[i]
+                        .iter()
+                        .map(|&j| nodes[j].path.clone())
+                        .collect(),
+                });
+                continue;
+            }
+
+            // Production module: look for exports only tests import
+            let mut production_names: HashSet<&str> = HashSet::new();
+            let mut test_users: Vec<(&str, usize)> = Vec::new();
+            let mut all_used = false;
+
+            for &j in &importers[i] {
+                let importer = &nodes[j];
+                for edge in importer.imports.iter().filter(|e| e.target == i) {
+                    match (&edge.names, importer.is_test || !production[j]) {
+                        (ImportedNames::All, false) => all_used = true,
+                        (ImportedNames::All, true) => {}
+                        (ImportedNames::Named(names), false) => {
+                            production_names.extend(names.iter().map(String::as_str));
+                        }
+                        (ImportedNames::Named(names), true) => {
+                            test_users.extend(names.iter().map(|n| (n.as_str(), j)));
+                        }
+                    }
+                }
+            }
+
+            if all_used {
+                continue;
+            }
+
+            for (name, span) in &node.exports {
+                if production_names.contains(name.as_str()) {
+                    continue;
+                }
+
+                let used_by: BTreeSet<PathBuf> = test_users
+                    .iter()
+                    .filter(|(used, _)| *used == name)
+                    .map(|&(_, j)| nodes[j].path.clone())
+                    .collect();
+                if used_by.is_empty() {
+                    continue;
+                }
+
+                findings.push(Finding::UsedOnlyInTests {
+                    module: node.path.clone(),
+                    export_name: Some(name.clone()),
+                    span: span.clone(),
+                    used_by: used_by.into_iter().collect(),
+                });
+            }
+        }
+
+        findings
+    }
+
+    fn reachable(nodes: &[UsageNode], is_root: impl Fn(&UsageNode) -> bool) -> Vec<bool> {
+        let mut visited = vec![false; nodes.len()];
+        let mut queue: Vec<usize> = (0..nodes.len()).filter(|&i| is_root(&nodes[i])).collect();
+
+        while let Some(i) = queue.pop() {
+            if std::mem::replace(&mut visited[i], true) {
+                continue;
+            }
+            queue.extend(
+                nodes[i]
+                    .imports
+                    .iter()
+                    .map(|edge| edge.target)
+                    .filter(|&target| !visited[target]),
+            );
+        }
+
+        visited
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(path: &str, is_test: bool, is_entry: bool, exports: &[&str]) -> UsageNode {
+        UsageNode {
+            path: PathBuf::from(path),
+            is_test,
+            is_entry,
+            exports: exports.iter().map(|e| (e.to_string(), None)).collect(),
+            imports: Vec::new(),
+        }
+    }
+
+    fn import(target: usize, names: &[&str]) -> UsageEdge {
+        UsageEdge {
+            target,
+            names: ImportedNames::Named(names.iter().map(|n| n.to_string()).collect()),
+        }
+    }
+
+    fn summarize(findings: &[Finding]) -> Vec<(String, Option<String>)> {
+        findings
+            .iter()
+            .filter_map(|f| match f {
+                Finding::UsedOnlyInTests {
+                    module,
+                    export_name,
+                    ..
+                } => Some((module.to_string_lossy().into_owned(), export_name.clone())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_export_used_only_by_tests() {
+        // 0: index.ts -> math.ts { add }
+        // 1: math.ts exports add, clamp
+        // 2: math.test.ts -> math.ts { add, clamp }
+        let mut nodes = vec![
+            node("index.ts", false, true, &[]),
+            node("math.ts", false, false, &["add", "clamp"]),
+            node("math.test.ts", true, true, &[]),
+        ];
+        nodes[0].imports.push(import(1, &["add"]));
+        nodes[2].imports.push(import(1, &["add", "clamp"]));
+
+        let findings = TestOnlyAnalyzer::find_test_only(&nodes);
+        assert_eq!(
+            summarize(&findings),
+            vec![("math.ts".to_string(), Some("clamp".to_string()))]
+        );
+    }
+
+    #[test]
+    fn test_file_reachable_only_from_tests() {
+        // 0: index.ts (entry), 1: fixtures.ts, 2: builders.ts, 3: app.test.ts
+        let mut nodes = vec![
+            node("index.ts", false, true, &[]),
+            node("fixtures.ts", false, false, &["user"]),
+            node("builders.ts", false, false, &["build"]),
+            node("app.test.ts", true, true, &[]),
+        ];
+        nodes[3].imports.push(import(1, &["user"]));
+        nodes[1].imports.push(import(2, &["build"]));
+
+        let findings = TestOnlyAnalyzer::find_test_only(&nodes);
+        assert_eq!(
+            summarize(&findings),
+            vec![
+                ("fixtures.ts".to_string(), None),
+                ("builders.ts".to_string(), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_namespace_import_uses_every_export() {
+        let mut nodes = vec![
+            node("index.ts", false, true, &[]),
+            node("utils.ts", false, false, &["a", "b"]),
+            node("utils.test.ts", true, true, &[]),
+        ];
+        nodes[0].imports.push(UsageEdge {
+            target: 1,
+            names: ImportedNames::All,
+        });
+        nodes[2].imports.push(import(1, &["b"]));
+
+        assert!(TestOnlyAnalyzer::find_test_only(&nodes).is_empty());
+    }
+}
@@ -0,0 +1,580 @@
+//! Type member analysis - unused properties of exported interfaces and type aliases.
+//!
+//! Fob tracks types as whole symbols, so a DTO interface that is imported
+//! anywhere looks fully used. This analyzer parses each module, collects the
+//! members of exported interfaces and object type aliases, and reports the
+//! members whose name is never accessed, assigned, destructured, passed as a
+//! JSX attribute, or implemented anywhere in the project.
+//!
+//! Matching is by member name across the whole project, so a member is only
+//! reported when no code touches any property with that name: common names
+//! like `id` or `name` are effectively never reported. The reverse gap is
+//! members read without naming them, e.g. through `{ ...dto }`,
+//! `Object.keys(dto)`, `JSON.stringify(dto)` or `dto[key]`. Types whose
+//! annotated values a module uses that way are skipped entirely; values that
+//! aren't annotated (or reach a serializer through a framework, such as a
+//! response body) can still produce false positives.
+
+use crate::syntax::{self, LineIndex};
+use danny_core::{Finding, SymbolSpan, TypeMemberKind};
+use oxc_ast::ast::{
+    AssignmentTargetPropertyIdentifier, AssignmentTargetPropertyProperty, BindingPattern,
+    BindingPatternKind, BindingProperty, CallExpression, ComputedMemberExpression, Declaration,
+    ExportDefaultDeclarationKind, Expression, ForInStatement, JSXAttribute, JSXAttributeName,
+    JSXSpreadAttribute, MethodDefinition, ObjectProperty, Program, PropertyDefinition, PropertyKey,
+    SpreadElement, Statement, StaticMemberExpression, TSLiteral, TSLiteralType,
+    TSMethodSignatureKind, TSSignature, TSType, TSTypeName, TSTypeReference,
+};
+use oxc_ast_visit::{walk, Visit};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// A member declared by an exported interface or object type alias.
+#[derive(Debug, Clone)]
+pub struct DeclaredMember {
+    /// Interface or type alias name
+    pub type_name: String,
+    /// Member name
+    pub member_name: String,
+    /// Property or method signature
+    pub kind: TypeMemberKind,
+    /// Declaration location
+    pub span: SymbolSpan,
+}
+
+/// Type members declared by one module and the property names it uses.
+#[derive(Debug, Clone, Default)]
+pub struct TypeMemberScan {
+    /// Module path
+    pub path: PathBuf,
+    /// Members of the module's exported object types
+    pub declared: Vec<DeclaredMember>,
+    /// Property names read, written or implemented by the module
+    pub used: HashSet<String>,
+    /// Types whose values the module spreads, serializes, enumerates or
+    /// indexes with a computed key, reading members without naming them
+    pub dynamic_types: HashSet<String>,
+}
+
+/// Analyzer for unused type member findings.
+pub struct TypeMemberAnalyzer;
+
+impl TypeMemberAnalyzer {
+    /// Parses a module and collects its exported type members and used property names.
+    ///
+    /// Returns `None` if the file can't be parsed as JavaScript/TypeScript.
+    pub fn scan(path: &Path, source: &str) -> Option<TypeMemberScan> {
+        let lines = LineIndex::new(source);
+
+        syntax::with_program(path, source, |program| {
+            let mut collector = UsageCollector::default();
+            collector.visit_program(program);
+
+            TypeMemberScan {
+                path: path.to_path_buf(),
+                declared: Self::exported_members(program, path, &lines),
+                dynamic_types: collector.dynamic_types(),
+                used: collector.used,
+            }
+        })
+    }
+
+    /// Reports declared members whose name no module uses.
+    ///
+    /// Underscore-prefixed members are treated as intentionally unused, and
+    /// members of types any module spreads or serializes are never reported.
+    pub fn find_unused(scans: &[TypeMemberScan]) -> Vec<Finding> {
+        let used: HashSet<&str> = scans
+            .iter()
+            .flat_map(|scan| scan.used.iter().map(String::as_str))
+            .collect();
+        let dynamic_types: HashSet<&str> = scans
+            .iter()
+            .flat_map(|scan| scan.dynamic_types.iter().map(String::as_str))
+            .collect();
+
+        scans
+            .iter()
+            .flat_map(|scan| scan.declared.iter().map(move |member| (&scan.path, member)))
+            .filter(|(_, member)| {
+                !member.member_name.starts_with('_')
+                    && !used.contains(member.member_name.as_str())
+                    && !dynamic_types.contains(member.type_name.as_str())
+            })
+            .map(|(path, member)| Finding::UnusedTypeMember {
+                module: path.clone(),
+                type_name: member.type_name.clone(),
+                member_name: member.member_name.clone(),
+                member_kind: member.kind.clone(),
+                span: member.span.clone(),
+            })
+            .collect()
+    }
+
+    fn exported_members(
+        program: &Program<'_>,
+        path: &Path,
+        lines: &LineIndex,
+    ) -> Vec<DeclaredMember> {
+        // (type name, members, exported at the declaration)
+        let mut types: Vec<(String, &[TSSignature<'_>], bool)> = Vec::new();
+        let mut exported_names: HashSet<String> = HashSet::new();
+
+        for statement in &program.body {
+            match statement {
+                Statement::ExportNamedDeclaration(export) => {
+                    if let Some(declaration) = &export.declaration {
+                        types.extend(Self::object_type(declaration).map(|(n, m)| (n, m, true)));
+                    }
+                    if export.source.is_none() {
+                        exported_names.extend(
+                            export
+                                .specifiers
+                                .iter()
+                                .map(|specifier| specifier.local.name().to_string()),
+                        );
+                    }
+                }
+                Statement::ExportDefaultDeclaration(export) => match &export.declaration {
+                    ExportDefaultDeclarationKind::TSInterfaceDeclaration(interface) => {
+                        types.push((
+                            interface.id.name.to_string(),
+                            &interface.body.body[..],
+                            true,
+                        ));
+                    }
+                    ExportDefaultDeclarationKind::Identifier(ident) => {
+                        exported_names.insert(ident.name.to_string());
+                    }
+                    _ => {}
+                },
+                Statement::TSInterfaceDeclaration(interface) => {
+                    types.push((
+                        interface.id.name.to_string(),
+                        &interface.body.body[..],
+                        false,
+                    ));
+                }
+                Statement::TSTypeAliasDeclaration(alias) => {
+                    if let TSType::TSTypeLiteral(literal) = &alias.type_annotation {
+                        types.push((alias.id.name.to_string(), &literal.members[..], false));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        types
+            .into_iter()
+            .filter(|(name, _, exported)| *exported || exported_names.contains(name))
+            .flat_map(|(type_name, members, _)| {
+                members.iter().filter_map(move |signature| {
+                    let (key, kind, offset) = match signature {
+                        TSSignature::TSPropertySignature(property) if !property.computed => {
+                            (&property.key, TypeMemberKind::Property, property.span.start)
+                        }
+                        TSSignature::TSMethodSignature(method) if !method.computed => {
+                            let kind = match method.kind {
+                                TSMethodSignatureKind::Method => TypeMemberKind::Method,
+                                TSMethodSignatureKind::Get | TSMethodSignatureKind::Set => {
+                                    TypeMemberKind::Property
+                                }
+                            };
+                            (&method.key, kind, method.span.start)
+                        }
+                        _ => return None,
+                    };
+
+                    let (line, column) = lines.line_col(offset);
+                    Some(DeclaredMember {
+                        type_name: type_name.clone(),
+                        member_name: key.static_name()?.into_owned(),
+                        kind,
+                        span: SymbolSpan {
+                            file: path.to_path_buf(),
+                            line,
+                            column,
+                            offset,
+                        },
+                    })
+                })
+            })
+            .collect()
+    }
+
+    fn object_type<'b, 'a>(
+        declaration: &'b Declaration<'a>,
+    ) -> Option<(String, &'b [TSSignature<'a>])> {
+        match declaration {
+            Declaration::TSInterfaceDeclaration(interface) => {
+                Some((interface.id.name.to_string(), &interface.body.body[..]))
+            }
+            Declaration::TSTypeAliasDeclaration(alias) => match &alias.type_annotation {
+                TSType::TSTypeLiteral(literal) => {
+                    Some((alias.id.name.to_string(), &literal.members[..]))
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+/// Named types in a binding's type annotation.
+#[derive(Default)]
+struct AnnotatedTypes {
+    /// `T` in `value: T`
+    direct: HashSet<String>,
+    /// Every type named anywhere in the annotation, e.g. `T` in `value: T[]`
+    nested: HashSet<String>,
+}
+
+/// Collects every property name a module touches, and the types of values
+/// whose members it reads without naming them.
+#[derive(Default)]
+struct UsageCollector {
+    used: HashSet<String>,
+    /// Annotated bindings by name; same-named bindings in different scopes
+    /// are merged
+    annotations: HashMap<String, AnnotatedTypes>,
+    /// Bindings spread, serialized or enumerated as a whole
+    enumerated: HashSet<String>,
+    /// Bindings indexed with a computed key, `value[key]`
+    indexed: HashSet<String>,
+}
+
+impl UsageCollector {
+    fn add_key(&mut self, key: &PropertyKey<'_>) {
+        if let Some(name) = key.static_name() {
+            self.used.insert(name.into_owned());
+        }
+    }
+
+    fn add_enumerated(&mut self, expression: &Expression<'_>) {
+        if let Expression::Identifier(ident) = expression.get_inner_expression() {
+            self.enumerated.insert(ident.name.to_string());
+        }
+    }
+
+    /// Types of the bindings whose members are read dynamically.
+    ///
+    /// Serializing `value: T[]` reads the members of `T`, while indexing it
+    /// only reads elements, so computed keys count the direct type only.
+    fn dynamic_types(&self) -> HashSet<String> {
+        let enumerated = self
+            .enumerated
+            .iter()
+            .filter_map(|name| self.annotations.get(name))
+            .flat_map(|types| types.nested.iter());
+        let indexed = self
+            .indexed
+            .iter()
+            .filter_map(|name| self.annotations.get(name))
+            .flat_map(|types| types.direct.iter());
+        enumerated.chain(indexed).cloned().collect()
+    }
+
+    /// `Object.keys(value)`, `JSON.stringify(value)`, `structuredClone(value)`, ...
+    fn reads_all_members(callee: &Expression<'_>) -> bool {
+        match callee {
+            Expression::StaticMemberExpression(member) => {
+                let Expression::Identifier(object) = &member.object else {
+                    return false;
+                };
+                matches!(
+                    (object.name.as_str(), member.property.name.as_str()),
+                    ("Object", "keys" | "values" | "entries" | "assign") | ("JSON", "stringify")
+                )
+            }
+            Expression::Identifier(ident) => ident.name == "structuredClone",
+            _ => false,
+        }
+    }
+}
+
+/// Collects the names of type references.
+#[derive(Default)]
+struct TypeReferenceCollector {
+    names: HashSet<String>,
+}
+
+impl<'a> Visit<'a> for TypeReferenceCollector {
+    fn visit_ts_type_reference(&mut self, it: &TSTypeReference<'a>) {
+        if let TSTypeName::IdentifierReference(ident) = &it.type_name {
+            self.names.insert(ident.name.to_string());
+        }
+        walk::walk_ts_type_reference(self, it);
+    }
+}
+
+impl<'a> Visit<'a> for UsageCollector {
+    // `obj.name`
+    fn visit_static_member_expression(&mut self, it: &StaticMemberExpression<'a>) {
+        self.used.insert(it.property.name.to_string());
+        walk::walk_static_member_expression(self, it);
+    }
+
+    // `obj["name"]`, or `obj[key]` reading any member
+    fn visit_computed_member_expression(&mut self, it: &ComputedMemberExpression<'a>) {
+        match &it.expression {
+            Expression::StringLiteral(literal) => {
+                self.used.insert(literal.value.to_string());
+            }
+            Expression::NumericLiteral(_) => {}
+            _ => {
+                if let Expression::Identifier(ident) = it.object.get_inner_expression() {
+                    self.indexed.insert(ident.name.to_string());
+                }
+            }
+        }
+        walk::walk_computed_member_expression(self, it);
+    }
+
+    // `{ ...obj }`, `[...obj]`, `f(...obj)`
+    fn visit_spread_element(&mut self, it: &SpreadElement<'a>) {
+        self.add_enumerated(&it.argument);
+        walk::walk_spread_element(self, it);
+    }
+
+    // `<Component {...props} />`
+    fn visit_jsx_spread_attribute(&mut self, it: &JSXSpreadAttribute<'a>) {
+        self.add_enumerated(&it.argument);
+        walk::walk_jsx_spread_attribute(self, it);
+    }
+
+    // `for (const key in obj)`
+    fn visit_for_in_statement(&mut self, it: &ForInStatement<'a>) {
+        self.add_enumerated(&it.right);
+        walk::walk_for_in_statement(self, it);
+    }
+
+    fn visit_call_expression(&mut self, it: &CallExpression<'a>) {
+        if Self::reads_all_members(&it.callee) {
+            for argument in &it.arguments {
+                if let Some(expression) = argument.as_expression() {
+                    self.add_enumerated(expression);
+                }
+            }
+        }
+        walk::walk_call_expression(self, it);
+    }
+
+    // `value: T` in declarations and parameters
+    fn visit_binding_pattern(&mut self, it: &BindingPattern<'a>) {
+        if let (BindingPatternKind::BindingIdentifier(ident), Some(annotation)) =
+            (&it.kind, &it.type_annotation)
+        {
+            let mut references = TypeReferenceCollector::default();
+            references.visit_ts_type(&annotation.type_annotation);
+
+            let types = self.annotations.entry(ident.name.to_string()).or_default();
+            if let TSType::TSTypeReference(reference) = &annotation.type_annotation {
+                if let TSTypeName::IdentifierReference(name) = &reference.type_name {
+                    types.direct.insert(name.name.to_string());
+                }
+            }
+            types.nested.extend(references.names);
+        }
+        walk::walk_binding_pattern(self, it);
+    }
+
+    // `{ name: value }` object literals
+    fn visit_object_property(&mut self, it: &ObjectProperty<'a>) {
+        self.add_key(&it.key);
+        walk::walk_object_property(self, it);
+    }
+
+    // `const { name } = obj`
+    fn visit_binding_property(&mut self, it: &BindingProperty<'a>) {
+        self.add_key(&it.key);
+        walk::walk_binding_property(self, it);
+    }
+
+    // `({ name: target } = obj)`
+    fn visit_assignment_target_property_property(
+        &mut self,
+        it: &AssignmentTargetPropertyProperty<'a>,
+    ) {
+        self.add_key(&it.name);
+        walk::walk_assignment_target_property_property(self, it);
+    }
+
+    // `({ name } = obj)`
+    fn visit_assignment_target_property_identifier(
+        &mut self,
+        it: &AssignmentTargetPropertyIdentifier<'a>,
+    ) {
+        self.used.insert(it.binding.name.to_string());
+        walk::walk_assignment_target_property_identifier(self, it);
+    }
+
+    // `<Component name={value} />`
+    fn visit_jsx_attribute(&mut self, it: &JSXAttribute<'a>) {
+        if let JSXAttributeName::Identifier(ident) = &it.name {
+            self.used.insert(ident.name.to_string());
+        }
+        walk::walk_jsx_attribute(self, it);
+    }
+
+    // Classes implementing the interface
+    fn visit_method_definition(&mut self, it: &MethodDefinition<'a>) {
+        self.add_key(&it.key);
+        walk::walk_method_definition(self, it);
+    }
+
+    fn visit_property_definition(&mut self, it: &PropertyDefinition<'a>) {
+        self.add_key(&it.key);
+        walk::walk_property_definition(self, it);
+    }
+
+    // `Pick<T, "name">`, `T["name"]`
+    fn visit_ts_literal_type(&mut self, it: &TSLiteralType<'a>) {
+        if let TSLiteral::StringLiteral(literal) = &it.literal {
+            self.used.insert(literal.value.to_string());
+        }
+        walk::walk_ts_literal_type(self, it);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unused(files: &[(&str, &str)]) -> Vec<(String, String)> {
+        let scans: Vec<TypeMemberScan> = files
+            .iter()
+            .map(|(path, source)| TypeMemberAnalyzer::scan(Path::new(path), source).unwrap())
+            .collect();
+
+        TypeMemberAnalyzer::find_unused(&scans)
+            .into_iter()
+            .filter_map(|finding| match finding {
+                Finding::UnusedTypeMember {
+                    type_name,
+                    member_name,
+                    ..
+                } => Some((type_name, member_name)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    const USER_DTO: &str =
+        "export interface UserDto {\n  id: string;\n  name: string;\n  legacyId: number;\n}\n";
+
+    #[test]
+    fn test_unused_interface_property() {
+        let findings = unused(&[
+            ("dto.ts", USER_DTO),
+            (
+                "app.ts",
+                "import type { UserDto } from './dto';\n\
+                 export const show = (user: UserDto) => user.name;\n\
+                 export const make = (): UserDto => ({ id: '1', name: 'a', legacyId: 0 });\n",
+            ),
+        ]);
+        assert!(findings.is_empty());
+
+        let findings = unused(&[
+            ("dto.ts", USER_DTO),
+            (
+                "app.ts",
+                "import type { UserDto } from './dto';\n\
+                 export function show(user: UserDto) {\n\
+                   const { id } = user;\n\
+                   return id + user['name'];\n\
+                 }\n",
+            ),
+        ]);
+        assert_eq!(
+            findings,
+            vec![("UserDto".to_string(), "legacyId".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_object_type_alias_and_methods() {
+        let findings = unused(&[(
+            "api.ts",
+            "type Client = { fetch(): void; close(): void; _internal: number };\n\
+             export type { Client };\n\
+             export function run(client: Client) { client.fetch(); }\n",
+        )]);
+        assert_eq!(findings, vec![("Client".to_string(), "close".to_string())]);
+    }
+
+    #[test]
+    fn test_non_exported_and_non_object_types_are_ignored() {
+        let findings = unused(&[(
+            "types.ts",
+            "interface Local { unused: string }\n\
+             export type Id = string | number;\n\
+             export type Both = Local & { alsoUnused: boolean };\n",
+        )]);
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn test_jsx_attributes_and_implementations_count_as_usage() {
+        let findings = unused(&[
+            (
+                "props.ts",
+                "export interface ButtonProps { label: string; onPress(): void }\n\
+                 export interface Store { load(): void }\n",
+            ),
+            (
+                "button.tsx",
+                "export const App = () => <Button label=\"ok\" />;\n\
+                 export class MemoryStore { load() {} onPress() {} }\n",
+            ),
+        ]);
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn test_spread_and_serialized_types_are_skipped() {
+        let findings = unused(&[
+            (
+                "dto.ts",
+                "export interface UserDto { id: string; legacyId: number }\n\
+                 export interface Settings { theme: string; beta: boolean }\n\
+                 export interface Flags { dark: boolean }\n\
+                 export interface Row { cell: string }\n",
+            ),
+            (
+                "app.ts",
+                "import type { UserDto, Settings, Flags, Row } from './dto';\n\
+                 export const save = (user: UserDto) => JSON.stringify({ ...user, id: user.id });\n\
+                 export const keys = (settings: Settings) => Object.keys(settings);\n\
+                 export const flag = (flags: Flags, key: keyof Flags) => flags[key];\n\
+                 export const first = (rows: Row[], i: number) => rows[i];\n",
+            ),
+        ]);
+        assert_eq!(findings, vec![("Row".to_string(), "cell".to_string())]);
+    }
+
+    #[test]
+    fn test_serialized_arrays_skip_their_element_type() {
+        let findings = unused(&[
+            ("dto.ts", USER_DTO),
+            (
+                "app.ts",
+                "import type { UserDto } from './dto';\n\
+                 export const send = (users: UserDto[]) => JSON.stringify(users);\n",
+            ),
+        ]);
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn test_member_span() {
+        let scan = TypeMemberAnalyzer::scan(
+            Path::new("dto.ts"),
+            "export interface A {\n  first: string;\n}\n",
+        )
+        .unwrap();
+        assert_eq!(scan.declared.len(), 1);
+        assert_eq!(scan.declared[0].span.line, 2);
+        assert_eq!(scan.declared[0].span.column, 2);
+    }
+}
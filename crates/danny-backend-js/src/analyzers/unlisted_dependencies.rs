@@ -0,0 +1,352 @@
+//! Unlisted npm dependency analysis - imports of packages missing from package.json.
+//!
+//! Complements unused dependency detection: instead of declared packages
+//! that are never imported, this reports imported packages that aren't
+//! declared in the nearest package.json (or are only declared in
+//! devDependencies but used by production code).
+
+use danny_core::{Finding, Result, SourceLocation, UnlistedDependencyReason};
+use danny_fs::FileSystem;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// Node.js built-in modules that may be imported without the `node:` prefix.
+const NODE_BUILTINS: &[&str] = &[
+    "assert",
+    "async_hooks",
+    "buffer",
+    "child_process",
+    "cluster",
+    "console",
+    "constants",
+    "crypto",
+    "dgram",
+    "diagnostics_channel",
+    "dns",
+    "domain",
+    "events",
+    "fs",
+    "http",
+    "http2",
+    "https",
+    "inspector",
+    "module",
+    "net",
+    "os",
+    "path",
+    "perf_hooks",
+    "process",
+    "punycode",
+    "querystring",
+    "readline",
+    "repl",
+    "stream",
+    "string_decoder",
+    "sys",
+    "timers",
+    "tls",
+    "trace_events",
+    "tty",
+    "url",
+    "util",
+    "v8",
+    "vm",
+    "wasi",
+    "worker_threads",
+    "zlib",
+];
+
+/// An import of an external package, with the context needed to check it.
+#[derive(Debug, Clone)]
+pub struct ExternalImport {
+    /// Package name (`lodash`, `@scope/pkg`)
+    pub package: String,
+    /// Nearest package.json of the importing module
+    pub manifest: PathBuf,
+    /// Import location
+    pub location: SourceLocation,
+    /// Whether the importer is reachable from production entry points
+    pub is_production: bool,
+    /// Whether the import only brings in types (`import type`)
+    pub is_type_only: bool,
+}
+
+/// Declared dependencies of a single package.json.
+#[derive(Debug, Clone, Default)]
+pub struct PackageManifest {
+    /// The package's own name (self-references are always allowed)
+    pub name: Option<String>,
+    /// dependencies, peerDependencies and optionalDependencies
+    pub production: HashSet<String>,
+    /// devDependencies
+    pub development: HashSet<String>,
+}
+
+impl PackageManifest {
+    /// Builds a manifest from parsed package.json content.
+    pub fn from_json(package_json: &serde_json::Value) -> Self {
+        let keys = |fields: &[&str]| -> HashSet<String> {
+            fields
+                .iter()
+                .filter_map(|field| package_json.get(*field).and_then(|v| v.as_object()))
+                .flat_map(|deps| deps.keys().cloned())
+                .collect()
+        };
+
+        Self {
+            name: package_json
+                .get("name")
+                .and_then(|v| v.as_str())
+                .map(String::from),
+            production: keys(&["dependencies", "peerDependencies", "optionalDependencies"]),
+            development: keys(&["devDependencies"]),
+        }
+    }
+
+    fn declares(&self, package: &str) -> bool {
+        self.production.contains(package) || self.development.contains(package)
+    }
+}
+
+/// Analyzer for unlisted npm dependency findings.
+pub struct UnlistedDependencyAnalyzer;
+
+impl UnlistedDependencyAnalyzer {
+    /// Returns the npm package an import specifier refers to.
+    ///
+    /// Returns `None` for relative paths, Node.js built-ins, protocol imports
+    /// (`node:`, `virtual:`) and common path aliases (`@/`, `~/`, `#internal`).
+    pub fn package_for_specifier(specifier: &str) -> Option<String> {
+        if specifier.starts_with('#') || specifier.starts_with('~') {
+            return None;
+        }
+
+        let package = crate::plugins::config::package_name(specifier)?;
+        if package.starts_with("@/") || NODE_BUILTINS.contains(&package.as_str()) {
+            return None;
+        }
+
+        Some(package)
+    }
+
+    /// Checks external imports against their manifests and groups unlisted
+    /// packages into one finding per (manifest, package).
+    pub fn find_unlisted(
+        imports: &[ExternalImport],
+        manifests: &HashMap<PathBuf, PackageManifest>,
+    ) -> Vec<Finding> {
+        let mut unlisted: BTreeMap<(PathBuf, String), (UnlistedDependencyReason, Vec<_>)> =
+            BTreeMap::new();
+
+        for import in imports {
+            let Some(manifest) = manifests.get(&import.manifest) else {
+                continue;
+            };
+
+            let Some(reason) = Self::check_import(import, manifest) else {
+                continue;
+            };
+
+            let entry = unlisted
+                .entry((import.manifest.clone(), import.package.clone()))
+                .or_insert_with(|| (reason.clone(), Vec::new()));
+
+            // A missing declaration outranks a misplaced one
+            if reason == UnlistedDependencyReason::NotDeclared {
+                entry.0 = reason;
+            }
+            entry.1.push(import.location.clone());
+        }
+
+        unlisted
+            .into_iter()
+            .map(
+                |((manifest, package), (reason, locations))| Finding::UnlistedNpmDependency {
+                    package,
+                    reason,
+                    manifest,
+                    locations,
+                },
+            )
+            .collect()
+    }
+
+    fn check_import(
+        import: &ExternalImport,
+        manifest: &PackageManifest,
+    ) -> Option<UnlistedDependencyReason> {
+        if manifest.name.as_deref() == Some(import.package.as_str()) {
+            return None;
+        }
+
+        if import.is_type_only {
+            // Types are erased at build time: any declaration will do,
+            // including the DefinitelyTyped package
+            let types_package = Self::types_package(&import.package);
+            return (!manifest.declares(&import.package) && !manifest.declares(&types_package))
+                .then_some(UnlistedDependencyReason::NotDeclared);
+        }
+
+        if manifest.production.contains(&import.package) {
+            None
+        } else if manifest.development.contains(&import.package) {
+            import
+                .is_production
+                .then_some(UnlistedDependencyReason::DevDependencyInProduction)
+        } else {
+            Some(UnlistedDependencyReason::NotDeclared)
+        }
+    }
+
+    /// `react` -> `@types/react`, `@scope/pkg` -> `@types/scope__pkg`
+    fn types_package(package: &str) -> String {
+        match package.strip_prefix('@') {
+            Some(scoped) => format!("@types/{}", scoped.replacen('/', "__", 1)),
+            None => format!("@types/{}", package),
+        }
+    }
+
+    /// Finds the nearest package.json for `importer`, without leaving `root`.
+    ///
+    /// Results are cached per directory since most modules share a manifest.
+    pub async fn nearest_manifest<F: FileSystem>(
+        fs: &F,
+        root: &Path,
+        importer: &Path,
+        cache: &mut HashMap<PathBuf, Option<PathBuf>>,
+    ) -> Result<Option<PathBuf>> {
+        let mut visited = Vec::new();
+        let mut found = None;
+        let mut dir = importer.parent();
+
+        while let Some(current) = dir {
+            if !current.starts_with(root) {
+                break;
+            }
+
+            if let Some(cached) = cache.get(current) {
+                found = cached.clone();
+                break;
+            }
+
+            visited.push(current.to_path_buf());
+
+            let candidate = current.join("package.json");
+            if fs.exists(&candidate).await? {
+                found = Some(candidate);
+                break;
+            }
+
+            dir = current.parent();
+        }
+
+        for dir in visited {
+            cache.insert(dir, found.clone());
+        }
+
+        Ok(found)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest() -> PackageManifest {
+        PackageManifest::from_json(&serde_json::json!({
+            "name": "my-app",
+            "dependencies": { "react": "^18.0.0" },
+            "peerDependencies": { "react-dom": "^18.0.0" },
+            "devDependencies": { "vitest": "^3.0.0", "@types/node-fetch": "^2.0.0" }
+        }))
+    }
+
+    fn import(package: &str, is_production: bool, is_type_only: bool) -> ExternalImport {
+        ExternalImport {
+            package: package.to_string(),
+            manifest: PathBuf::from("/project/package.json"),
+            location: SourceLocation {
+                file: PathBuf::from("/project/src/index.ts"),
+                start: 0,
+                end: 10,
+            },
+            is_production,
+            is_type_only,
+        }
+    }
+
+    fn unlisted(imports: &[ExternalImport]) -> Vec<(String, UnlistedDependencyReason, usize)> {
+        let manifests = HashMap::from([(PathBuf::from("/project/package.json"), manifest())]);
+        UnlistedDependencyAnalyzer::find_unlisted(imports, &manifests)
+            .into_iter()
+            .filter_map(|finding| match finding {
+                Finding::UnlistedNpmDependency {
+                    package,
+                    reason,
+                    locations,
+                    ..
+                } => Some((package, reason, locations.len())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_package_for_specifier() {
+        let package = UnlistedDependencyAnalyzer::package_for_specifier;
+        assert_eq!(package("lodash/fp"), Some("lodash".to_string()));
+        assert_eq!(package("@scope/pkg/sub"), Some("@scope/pkg".to_string()));
+        assert_eq!(package("./local"), None);
+        assert_eq!(package("fs"), None);
+        assert_eq!(package("node:fs/promises"), None);
+        assert_eq!(package("@/components/Button"), None);
+        assert_eq!(package("~/utils"), None);
+        assert_eq!(package("#internal"), None);
+    }
+
+    #[test]
+    fn test_undeclared_imports_are_grouped() {
+        let result = unlisted(&[
+            import("react", true, false),
+            import("lodash", true, false),
+            import("lodash", false, false),
+            import("my-app", true, false),
+        ]);
+        assert_eq!(
+            result,
+            vec![(
+                "lodash".to_string(),
+                UnlistedDependencyReason::NotDeclared,
+                2
+            )]
+        );
+    }
+
+    #[test]
+    fn test_dev_dependency_in_production() {
+        let result = unlisted(&[
+            import("vitest", true, false),
+            import("react-dom", true, false),
+        ]);
+        assert_eq!(
+            result,
+            vec![(
+                "vitest".to_string(),
+                UnlistedDependencyReason::DevDependencyInProduction,
+                1
+            )]
+        );
+
+        assert!(unlisted(&[import("vitest", false, false)]).is_empty());
+    }
+
+    #[test]
+    fn test_type_only_imports_accept_types_packages() {
+        assert!(unlisted(&[import("node-fetch", true, true)]).is_empty());
+        assert_eq!(unlisted(&[import("express", true, true)]).len(), 1);
+        assert_eq!(
+            UnlistedDependencyAnalyzer::types_package("@scope/pkg"),
+            "@types/scope__pkg"
+        );
+    }
+}
@@ -6,17 +6,24 @@ use danny_fs::{FileSystem, NativeFileSystem};
 use std::sync::Arc;
 
 use crate::analyzers::{
-    BundleSizeAnalyzer, ClassMemberAnalyzer, DependencyChainAnalyzer, DynamicImportAnalyzer,
-    EnumMemberAnalyzer, NpmDependencyAnalyzer, QualityAnalyzer, SideEffectAnalyzer,
-    TypeOnlyAnalyzer, UnusedExport as AnalyzerUnusedExport,
+    BarrelAnalyzer, BundleSizeAnalyzer, ClassMemberAnalyzer, DependencyChainAnalyzer,
+    DynamicImportAnalyzer, EnumMemberAnalyzer, NpmDependencyAnalyzer, QualityAnalyzer,
+    SideEffectAnalyzer, TestOnlyAnalyzer, TypeMemberAnalyzer, TypeOnlyAnalyzer,
+    UnlistedDependencyAnalyzer, UnusedExport as AnalyzerUnusedExport,
 };
 use danny_core::circular_deps::CircularDependencyDetector;
-use danny_core::{AnalysisError, Dependency, ErrorSeverity, Statistics};
+use danny_core::{
+    AnalysisError, Dependency, ErrorSeverity, ExportOutcome, RuleCheck, RuleExplanation,
+    RuleSeverity, Statistics,
+};
 use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 use std::time::Instant;
 
+use crate::analyzers::test_only::ImportedNames;
 use crate::toml_config::DannyConfig;
+use danny_rule_engine::TomlFrameworkRule;
+use fob::graph::FrameworkRule;
 use std::path::Path;
 
 /// JavaScript/TypeScript analysis backend using Fob.
@@ -141,51 +148,154 @@ impl<F: FileSystem> JsBackend<F> {
 
         let start = Instant::now();
 
-        // Create Fob analysis options with Danny's TOML-based framework rules
-        let framework_rules =
-            danny_rule_engine::load_built_in_rules().map_err(|err| danny_core::Error::Backend {
-                backend: "JavaScript".to_string(),
-                message: format!("Failed to load TOML framework rules: {err}"),
-            })?;
+        // Run THREE async tasks in parallel:
+        // 1. File discovery (I/O-bound: walking directories)
+        // 2. Framework rules: built-in rules plus user, project and
+        //    `rules_dirs` rule files
+        // 3. Framework detection: built-in frameworks plus `[framework]`
+        //    sections of the same rule files
+        // Fob analysis has to wait for discovery: tool plugins match their
+        // entry patterns (tests, stories, setup files) against the discovered set
+        let discovery_config = crate::file_discovery::DiscoveryConfig::default();
+        let (discovered_files, framework_rules, framework_detector) = tokio::try_join!(
+            crate::file_discovery::discover_source_files(
+                &options,
+                &discovery_config,
+                Arc::clone(&fs),
+            ),
+            Self::load_framework_rules(&options, &fs),
+            Self::load_framework_detector(&options, &fs),
+        )?;
 
+        // Create Fob analysis options with Danny's TOML-based framework rules
         let fob_options = fob::analysis::AnalyzeOptions {
-            framework_rules,             // Use TOML-based rules from danny-rule-engine
+            framework_rules: framework_rules
+                .iter()
+                .map(|rule| rule.clone_box())
+                .collect(),
             compute_usage_counts: false, // Default to false for performance
         };
 
-        // Run TWO async tasks in parallel:
-        // 1. Fob analysis (CPU-bound: parsing + bundling)
-        // 2. File discovery (I/O-bound: walking directories)
-        let fob_future = analysis::analyze_with_options(&options.entry_points, fob_options);
-        let discovery_config = crate::file_discovery::DiscoveryConfig::default();
-        let fs_clone = Arc::clone(&fs);
-        let discovery_future =
-            crate::file_discovery::discover_source_files(&options, &discovery_config, fs_clone);
-
-        // Wait for both to complete
-        let (fob_result, discovered_files) = tokio::try_join!(
-            async {
-                fob_future.await.map_err(|e| danny_core::Error::Backend {
-                    backend: "JavaScript".to_string(),
-                    message: format!("Fob analysis failed: {}", e),
-                })
-            },
-            discovery_future
-        )?;
+        // Tool plugins (enabled by default, opt-out via tool_plugins = false)
+        let use_tool_plugins = options
+            .backend_options
+            .get("tool_plugins")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true);
+
+        let plugin_resolution = if use_tool_plugins {
+            crate::plugins::resolve_plugins(fs.as_ref(), &discovered_files).await?
+        } else {
+            crate::plugins::PluginResolution::default()
+        };
+
+        // Test classification (enabled by default, opt-out via test_only = false):
+        // configured globs plus files loaded by test runners
+        let detect_test_only = options
+            .backend_options
+            .get("test_only")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true);
+
+        let mut test_files: Vec<PathBuf> = if detect_test_only {
+            let config = self.load_config(&options, &fs).await?;
+            crate::plugins::match_patterns(
+                fs.project_root(),
+                &config.tests.patterns,
+                &discovered_files,
+            )
+        } else {
+            Vec::new()
+        };
+        test_files.extend(plugin_resolution.test_files.iter().cloned());
+        test_files.sort();
+        test_files.dedup();
+
+        // Tests are entry points: they're neither dead code nor production usage
+        let mut entry_points = options.entry_points.clone();
+        for entry in plugin_resolution.entry_points.iter().chain(&test_files) {
+            if !entry_points.contains(entry) {
+                entry_points.push(entry.clone());
+            }
+        }
+
+        let fob_result = analysis::analyze_with_options(&entry_points, fob_options)
+            .await
+            .map_err(|e| danny_core::Error::Backend {
+                backend: "JavaScript".to_string(),
+                message: format!("Fob analysis failed: {}", e),
+            })?;
 
         // Convert Fob's graph to Danny findings
-        let mut findings = self.convert_graph_to_findings(&fob_result.graph).await?;
+        let mut findings = self
+            .convert_graph_to_findings(&fob_result.graph, &framework_rules, &framework_detector)
+            .await?;
+
+        // Detected frameworks, with confidence and the files that gave them away
+        let detected_frameworks =
+            Self::detect_frameworks(&framework_detector, &fob_result.graph, fs.as_ref()).await?;
+        let frameworks_detected: Vec<String> = detected_frameworks
+            .iter()
+            .map(|result| result.framework.clone())
+            .collect();
+        findings.extend(
+            detected_frameworks
+                .into_iter()
+                .map(|result| Finding::Framework {
+                    evidence: result.evidence_files(),
+                    name: result.framework,
+                    confidence: result.confidence,
+                }),
+        );
 
         // Find unreachable files by comparing discovered files with module graph
         let unreachable_findings = crate::file_discovery::find_unreachable_files(
             discovered_files,
             &fob_result.graph,
-            &options.entry_points,
+            &entry_points,
         )
         .await?;
 
         findings.extend(unreachable_findings);
 
+        // Barrel analysis (opt-in via detect_barrels)
+        let detect_barrels = options
+            .backend_options
+            .get("detect_barrels")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
+        if detect_barrels {
+            let barrel_findings = Self::find_barrel_issues(&fob_result.graph).await?;
+
+            // Unused re-exports are reported with their origin instead of on the barrel
+            let barrels: HashSet<PathBuf> = barrel_findings
+                .iter()
+                .filter_map(|finding| match finding {
+                    Finding::UnusedReExport { barrel, .. } => Some(barrel.clone()),
+                    _ => None,
+                })
+                .collect();
+            findings.retain(|finding| {
+                !matches!(
+                    finding,
+                    Finding::UnusedExport {
+                        module,
+                        kind: danny_core::ExportKind::ReExport
+                            | danny_core::ExportKind::StarReExport,
+                        ..
+                    } if barrels.contains(module)
+                )
+            });
+            findings.extend(barrel_findings);
+        }
+
+        if detect_test_only && !test_files.is_empty() {
+            let test_only_findings =
+                Self::find_test_only_usage(&fob_result.graph, &test_files).await?;
+            findings.extend(test_only_findings);
+        }
+
         // NEW: Optionally collect unused symbols
         let analyze_symbols = options
             .backend_options
@@ -325,6 +435,25 @@ impl<F: FileSystem> JsBackend<F> {
                         message: format!("Multiple returns detection failed: {}", e),
                     })?;
 
+            // Syntax-level smells walk the parsed module source
+            let sources = QualityAnalyzer::load_sources(&fob_result.graph, fs.as_ref())
+                .await
+                .map_err(|e| danny_core::Error::Backend {
+                    backend: "JavaScript".to_string(),
+                    message: format!("Failed to read module sources: {}", e),
+                })?;
+            let magic_numbers = QualityAnalyzer::detect_magic_numbers(&sources, quality_config);
+            let message_chains = QualityAnalyzer::detect_message_chains(&sources, quality_config);
+            let empty_catches =
+                QualityAnalyzer::detect_empty_catch_blocks(&sources, quality_config);
+            let low_cohesion = QualityAnalyzer::detect_low_cohesion(&sources, quality_config);
+            let duplicates = QualityAnalyzer::detect_duplicated_code(&sources, quality_config);
+            let cognitive_complexity =
+                QualityAnalyzer::detect_cognitive_complexity(&sources, quality_config);
+            let file_metrics = QualityAnalyzer::compute_file_metrics(&sources);
+            let low_maintainability =
+                QualityAnalyzer::detect_low_maintainability(&file_metrics, quality_config);
+
             code_smell_findings.extend(long_functions);
             code_smell_findings.extend(too_many_params);
             code_smell_findings.extend(large_classes);
@@ -333,6 +462,13 @@ impl<F: FileSystem> JsBackend<F> {
             code_smell_findings.extend(complex_conditionals);
             code_smell_findings.extend(deep_nesting);
             code_smell_findings.extend(multiple_returns);
+            code_smell_findings.extend(magic_numbers);
+            code_smell_findings.extend(message_chains);
+            code_smell_findings.extend(empty_catches);
+            code_smell_findings.extend(low_cohesion);
+            code_smell_findings.extend(duplicates);
+            code_smell_findings.extend(cognitive_complexity);
+            code_smell_findings.extend(low_maintainability);
 
             // Build code smell statistics
             use danny_core::types::{CodeSmellStats, CodeSmellType, SmellSeverity};
@@ -357,6 +493,7 @@ impl<F: FileSystem> JsBackend<F> {
                 total_smells: code_smell_findings.len(),
                 by_type: by_type.into_iter().collect(),
                 by_severity: by_severity.into_iter().collect(),
+                file_metrics,
             });
 
             findings.extend(code_smell_findings);
@@ -665,6 +802,18 @@ impl<F: FileSystem> JsBackend<F> {
 
         findings.extend(enum_findings);
 
+        // Interface and type alias members (opt-in via detect_type_members).
+        // Matched by member name; see `TypeMemberAnalyzer` for what it misses
+        let detect_type_members = options
+            .backend_options
+            .get("detect_type_members")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
+        if detect_type_members {
+            findings.extend(Self::find_unused_type_members(&fob_result.graph, fs.as_ref()).await?);
+        }
+
         // NEW: NPM Dependencies (opt-in via detect_npm_dependencies)
         let detect_npm_dependencies = options
             .backend_options
@@ -707,7 +856,17 @@ impl<F: FileSystem> JsBackend<F> {
                         message: format!("Failed to get dependency coverage: {}", e),
                     })?;
 
-                let npm_findings = NpmDependencyAnalyzer::convert_unused_dependencies(&unused_deps);
+                let mut npm_findings =
+                    NpmDependencyAnalyzer::convert_unused_dependencies(&unused_deps);
+
+                // Dependencies used by tools (config files, scripts) aren't imported
+                npm_findings.retain(|finding| {
+                    !matches!(
+                        finding,
+                        Finding::UnusedNpmDependency { package, .. }
+                            if plugin_resolution.used_dependencies.contains(package)
+                    )
+                });
                 let coverage_stats = Some(NpmDependencyAnalyzer::convert_coverage_stats(&coverage));
 
                 (npm_findings, coverage_stats)
@@ -720,6 +879,23 @@ impl<F: FileSystem> JsBackend<F> {
 
         findings.extend(npm_findings);
 
+        // Unlisted dependencies: imported packages missing from package.json
+        if detect_npm_dependencies {
+            let tool_entry_points: Vec<PathBuf> = plugin_resolution
+                .entry_points
+                .iter()
+                .chain(&test_files)
+                .cloned()
+                .collect();
+            let unlisted_findings = Self::find_unlisted_dependencies(
+                &fob_result.graph,
+                fs.as_ref(),
+                &tool_entry_points,
+            )
+            .await?;
+            findings.extend(unlisted_findings);
+        }
+
         // NEW: Import Patterns (opt-in via detect_import_patterns)
         let detect_import_patterns = options
             .backend_options
@@ -975,11 +1151,24 @@ impl<F: FileSystem> JsBackend<F> {
             }
         }
 
+        // Rule explanations (opt-in via explain_rules): `true` explains every
+        // reported export, an array of "path#export" explains just those
+        let rule_explanations = match options.backend_options.get("explain_rules") {
+            Some(targets) => Self::explain_rules(
+                targets,
+                &modules,
+                &findings,
+                &framework_rules,
+                fs.project_root(),
+            )?,
+            None => Vec::new(),
+        };
+
         let statistics = Statistics {
             total_modules: modules.len(),
             total_dependencies: modules.iter().map(|m| m.imports.len()).sum(),
             external_dependencies: fob_result.stats.external_dependency_count,
-            frameworks_detected: self.detect_frameworks(&fob_result.graph).await,
+            frameworks_detected,
             unused_exports_count: unused_exports.len(),
             unreachable_modules_count: unreachable_modules.len(),
             unreachable_files_count,
@@ -1001,10 +1190,30 @@ impl<F: FileSystem> JsBackend<F> {
                 .iter()
                 .filter(|f| matches!(f, Finding::UnusedEnumMember { .. }))
                 .count(),
+            unused_type_members_count: findings
+                .iter()
+                .filter(|f| matches!(f, Finding::UnusedTypeMember { .. }))
+                .count(),
             unused_npm_dependencies_count: findings
                 .iter()
                 .filter(|f| matches!(f, Finding::UnusedNpmDependency { .. }))
                 .count(),
+            unlisted_npm_dependencies_count: findings
+                .iter()
+                .filter(|f| matches!(f, Finding::UnlistedNpmDependency { .. }))
+                .count(),
+            used_only_in_tests_count: findings
+                .iter()
+                .filter(|f| matches!(f, Finding::UsedOnlyInTests { .. }))
+                .count(),
+            unused_re_exports_count: findings
+                .iter()
+                .filter(|f| matches!(f, Finding::UnusedReExport { .. }))
+                .count(),
+            side_effectful_barrels_count: findings
+                .iter()
+                .filter(|f| matches!(f, Finding::SideEffectfulBarrel { .. }))
+                .count(),
             side_effect_only_imports_count: findings
                 .iter()
                 .filter(|f| matches!(f, Finding::SideEffectOnlyImport { .. }))
@@ -1059,13 +1268,543 @@ impl<F: FileSystem> JsBackend<F> {
             statistics,
             errors,
             ignored_findings: vec![], // CLI will populate this during filtering
+            rule_explanations,
+        })
+    }
+
+    /// Explains how every loaded rule evaluated the requested exports.
+    ///
+    /// Fails if a requested path is a suffix of several module paths.
+    fn explain_rules(
+        targets: &serde_json::Value,
+        modules: &[fob::graph::Module],
+        findings: &[Finding],
+        framework_rules: &[TomlFrameworkRule],
+        project_root: &Path,
+    ) -> Result<Vec<RuleExplanation>> {
+        let unused: HashSet<(&Path, &str)> = findings
+            .iter()
+            .filter_map(|f| match f {
+                Finding::UnusedExport {
+                    module,
+                    export_name,
+                    ..
+                } => Some((module.as_path(), export_name.as_str())),
+                _ => None,
+            })
+            .collect();
+
+        let targets: Vec<(PathBuf, String)> = match targets {
+            serde_json::Value::Bool(true) => findings
+                .iter()
+                .filter_map(|f| match f {
+                    Finding::UnusedExport {
+                        module,
+                        export_name,
+                        ..
+                    }
+                    | Finding::FrameworkExport {
+                        module,
+                        export_name,
+                        ..
+                    } => Some((module.clone(), export_name.clone())),
+                    _ => None,
+                })
+                .collect(),
+            serde_json::Value::Array(items) => items
+                .iter()
+                .filter_map(|item| item.as_str()?.rsplit_once('#'))
+                .map(|(path, export)| (PathBuf::from(path), export.to_string()))
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        let mut explanations = Vec::new();
+        for (path, export_name) in targets {
+            let Some(module) = Self::find_explained_module(modules, &path, project_root)? else {
+                continue;
+            };
+            let Some(export) = module.exports.iter().find(|e| e.name == export_name) else {
+                continue;
+            };
+
+            let outcome = if export.is_framework_used {
+                ExportOutcome::FrameworkUsed
+            } else if unused.contains(&(module.path.as_path(), export.name.as_str())) {
+                ExportOutcome::Unused
+            } else {
+                ExportOutcome::Used
+            };
+
+            let rules = framework_rules
+                .iter()
+                .flat_map(|rules| {
+                    rules
+                        .engine()
+                        .explain(module, export)
+                        .into_iter()
+                        .map(move |evaluation| RuleCheck {
+                            framework: rules.framework_name().to_string(),
+                            rule: evaluation.rule,
+                            matched: evaluation.matched,
+                            failed_condition: evaluation.failed_condition,
+                            action: evaluation.action.to_string(),
+                        })
+                })
+                .collect();
+
+            explanations.push(RuleExplanation {
+                module: module.path.clone(),
+                export_name,
+                outcome,
+                rules,
+            });
+        }
+
+        Ok(explanations)
+    }
+
+    /// Finds the module an `explain_rules` path refers to.
+    ///
+    /// Paths may be absolute, relative to the project root, or a suffix of
+    /// a single module's path; an exact match wins over suffix matches.
+    fn find_explained_module<'a>(
+        modules: &'a [fob::graph::Module],
+        path: &Path,
+        project_root: &Path,
+    ) -> Result<Option<&'a fob::graph::Module>> {
+        let exact = project_root.join(path);
+        if let Some(module) = modules.iter().find(|m| m.path == exact) {
+            return Ok(Some(module));
+        }
+
+        let mut matches = modules.iter().filter(|m| m.path.ends_with(path));
+        let first = matches.next();
+        let rest: Vec<_> = matches.collect();
+        if rest.is_empty() {
+            return Ok(first);
+        }
+
+        let candidates = first
+            .into_iter()
+            .chain(rest)
+            .map(|m| m.path.display().to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        Err(danny_core::Error::Backend {
+            backend: "JavaScript".to_string(),
+            message: format!(
+                "Ambiguous path '{}' matches several modules: {candidates}",
+                path.display()
+            ),
         })
     }
 
+    /// Finds modules and exports that only test files depend on.
+    async fn find_test_only_usage(
+        graph: &fob::graph::ModuleGraph,
+        test_files: &[PathBuf],
+    ) -> Result<Vec<Finding>> {
+        use crate::analyzers::test_only::{UsageEdge, UsageNode};
+        use fob::graph::ExportKind;
+
+        let modules = graph
+            .modules()
+            .await
+            .map_err(|e| danny_core::Error::Backend {
+                backend: "JavaScript".to_string(),
+                message: format!("Failed to get modules: {}", e),
+            })?;
+
+        let index: HashMap<String, usize> = modules
+            .iter()
+            .enumerate()
+            .map(|(i, m)| (m.id.to_string(), i))
+            .collect();
+
+        let nodes: Vec<UsageNode> = modules
+            .iter()
+            .map(|module| {
+                // Barrels forward whatever their importers ask for
+                let forwards_all = module.exports.iter().any(|export| {
+                    matches!(export.kind, ExportKind::ReExport | ExportKind::StarReExport)
+                });
+
+                let imports = module
+                    .imports
+                    .iter()
+                    .filter_map(|import| {
+                        let target = *index.get(&import.resolved_to.as_ref()?.to_string())?;
+                        let names = if forwards_all {
+                            ImportedNames::All
+                        } else {
+                            Self::imported_names(import)
+                        };
+                        Some(UsageEdge { target, names })
+                    })
+                    .collect();
+
+                UsageNode {
+                    path: module.path.clone(),
+                    is_test: test_files.contains(&module.path),
+                    is_entry: module.is_entry,
+                    exports: module
+                        .exports
+                        .iter()
+                        .filter(|export| {
+                            !matches!(export.kind, ExportKind::ReExport | ExportKind::StarReExport)
+                        })
+                        .map(|export| (export.name.clone(), Some(Self::convert_span(&export.span))))
+                        .collect(),
+                    imports,
+                }
+            })
+            .collect();
+
+        Ok(TestOnlyAnalyzer::find_test_only(&nodes)
+            .into_iter()
+            .filter(|finding| match finding {
+                Finding::UsedOnlyInTests { module, .. } => !Self::is_virtual_path(module),
+                _ => true,
+            })
+            .collect())
+    }
+
+    /// Finds members of exported interfaces and object type aliases no module uses.
+    ///
+    /// Modules that can't be read or parsed are skipped.
+    async fn find_unused_type_members<FS: FileSystem>(
+        graph: &fob::graph::ModuleGraph,
+        fs: &FS,
+    ) -> Result<Vec<Finding>> {
+        let modules = graph
+            .modules()
+            .await
+            .map_err(|e| danny_core::Error::Backend {
+                backend: "JavaScript".to_string(),
+                message: format!("Failed to get modules: {}", e),
+            })?;
+
+        let mut scans = Vec::new();
+        for module in modules.iter() {
+            if Self::is_virtual_path(&module.path)
+                || module
+                    .path
+                    .components()
+                    .any(|c| c.as_os_str() == "node_modules")
+            {
+                continue;
+            }
+
+            // Unreadable or unparsable modules contribute neither members nor usages
+            let Ok(source) = fs.read_to_string(&module.path).await else {
+                continue;
+            };
+            scans.extend(TypeMemberAnalyzer::scan(&module.path, &source));
+        }
+
+        Ok(TypeMemberAnalyzer::find_unused(&scans))
+    }
+
+    /// Analyzes barrel files: unused re-exports and side-effectful barrels.
+    async fn find_barrel_issues(graph: &fob::graph::ModuleGraph) -> Result<Vec<Finding>> {
+        use crate::analyzers::barrels::{BarrelNode, ModuleExport, LARGE_MODULE_BYTES};
+        use fob::graph::ExportKind;
+
+        let modules = graph
+            .modules()
+            .await
+            .map_err(|e| danny_core::Error::Backend {
+                backend: "JavaScript".to_string(),
+                message: format!("Failed to get modules: {}", e),
+            })?;
+
+        let index: HashMap<String, usize> = modules
+            .iter()
+            .enumerate()
+            .map(|(i, m)| (m.id.to_string(), i))
+            .collect();
+
+        let nodes: Vec<BarrelNode> = modules
+            .iter()
+            .map(|module| {
+                // Re-exports show up as imports of their source as well
+                let resolve = |source: &str| {
+                    module
+                        .imports
+                        .iter()
+                        .find(|import| import.source == source)
+                        .and_then(|import| import.resolved_to.as_ref())
+                        .and_then(|id| index.get(&id.to_string()).copied())
+                };
+
+                let exports: Vec<ModuleExport> = module
+                    .exports
+                    .iter()
+                    .map(|export| {
+                        let span = Some(Self::convert_span(&export.span));
+                        let source = export.re_exported_from.clone().unwrap_or_default();
+                        match export.kind {
+                            ExportKind::ReExport => ModuleExport::ReExport {
+                                name: export.name.clone(),
+                                target: resolve(&source),
+                                source,
+                                span,
+                            },
+                            ExportKind::StarReExport => ModuleExport::Star {
+                                target: resolve(&source),
+                                source,
+                                span,
+                            },
+                            _ => ModuleExport::Declared {
+                                name: export.name.clone(),
+                                span,
+                            },
+                        }
+                    })
+                    .collect();
+
+                let re_export_sources: HashSet<&str> = module
+                    .exports
+                    .iter()
+                    .filter_map(|export| export.re_exported_from.as_deref())
+                    .collect();
+
+                let imports = module
+                    .imports
+                    .iter()
+                    .filter(|import| !re_export_sources.contains(import.source.as_str()))
+                    .filter_map(|import| {
+                        let target = *index.get(&import.resolved_to.as_ref()?.to_string())?;
+                        Some((target, Self::imported_names(import)))
+                    })
+                    .collect();
+
+                BarrelNode {
+                    path: module.path.clone(),
+                    size: module.original_size,
+                    has_side_effects: module.has_side_effects,
+                    is_entry: module.is_entry,
+                    exports,
+                    imports,
+                }
+            })
+            .collect();
+
+        Ok(BarrelAnalyzer::analyze(&nodes, LARGE_MODULE_BYTES)
+            .into_iter()
+            .filter(|finding| match finding {
+                Finding::UnusedReExport { barrel, .. }
+                | Finding::SideEffectfulBarrel { barrel, .. } => !Self::is_virtual_path(barrel),
+                _ => true,
+            })
+            .collect())
+    }
+
+    /// Finds external packages imported without a matching package.json declaration.
+    ///
+    /// Modules reachable from the project's own entry points count as production
+    /// code; modules only reachable from tool entries (tests, configs, stories)
+    /// may import devDependencies.
+    async fn find_unlisted_dependencies<FS: FileSystem>(
+        graph: &fob::graph::ModuleGraph,
+        fs: &FS,
+        tool_entry_points: &[PathBuf],
+    ) -> Result<Vec<Finding>> {
+        use crate::analyzers::unlisted_dependencies::{ExternalImport, PackageManifest};
+
+        let modules = graph
+            .modules()
+            .await
+            .map_err(|e| danny_core::Error::Backend {
+                backend: "JavaScript".to_string(),
+                message: format!("Failed to get modules: {}", e),
+            })?;
+        let type_only_imports =
+            graph
+                .type_only_imports()
+                .await
+                .map_err(|e| danny_core::Error::Backend {
+                    backend: "JavaScript".to_string(),
+                    message: format!("Failed to get type-only imports: {}", e),
+                })?;
+
+        let type_only: HashSet<(String, String)> = type_only_imports
+            .iter()
+            .map(|imp| (imp.importer.to_string(), imp.source.clone()))
+            .collect();
+
+        // Walk the graph from production entries only
+        let index: HashMap<String, usize> = modules
+            .iter()
+            .enumerate()
+            .map(|(i, m)| (m.id.to_string(), i))
+            .collect();
+        let mut production = vec![false; modules.len()];
+        let mut queue: Vec<usize> = modules
+            .iter()
+            .enumerate()
+            .filter(|(_, m)| m.is_entry && !tool_entry_points.contains(&m.path))
+            .map(|(i, _)| i)
+            .collect();
+        while let Some(i) = queue.pop() {
+            if std::mem::replace(&mut production[i], true) {
+                continue;
+            }
+            for import in &modules[i].imports {
+                if let Some(&next) = import
+                    .resolved_to
+                    .as_ref()
+                    .and_then(|id| index.get(&id.to_string()))
+                {
+                    if !production[next] {
+                        queue.push(next);
+                    }
+                }
+            }
+        }
+
+        let root = fs.project_root().to_path_buf();
+        let mut manifest_cache = HashMap::new();
+        let mut manifests = HashMap::new();
+        let mut external_imports = Vec::new();
+
+        for (i, module) in modules.iter().enumerate() {
+            if Self::is_virtual_path(&module.path) {
+                continue;
+            }
+
+            for import in module.imports.iter().filter(|imp| imp.is_external()) {
+                let Some(package) =
+                    UnlistedDependencyAnalyzer::package_for_specifier(&import.source)
+                else {
+                    continue;
+                };
+
+                let Some(manifest) = UnlistedDependencyAnalyzer::nearest_manifest(
+                    fs,
+                    &root,
+                    &module.path,
+                    &mut manifest_cache,
+                )
+                .await?
+                else {
+                    continue;
+                };
+
+                if !manifests.contains_key(&manifest) {
+                    let content = fs.read_to_string(&manifest).await?;
+                    let parsed = serde_json::from_str(&content)
+                        .map(|json| PackageManifest::from_json(&json))
+                        .unwrap_or_default();
+                    manifests.insert(manifest.clone(), parsed);
+                }
+
+                external_imports.push(ExternalImport {
+                    package,
+                    manifest,
+                    location: Self::convert_span(&import.span),
+                    is_production: production[i],
+                    is_type_only: type_only
+                        .contains(&(module.id.to_string(), import.source.clone())),
+                });
+            }
+        }
+
+        Ok(UnlistedDependencyAnalyzer::find_unlisted(
+            &external_imports,
+            &manifests,
+        ))
+    }
+
+    /// Loads built-in framework rules merged with rule files from disk.
+    ///
+    /// Rule files come from the user config dir, `.danny/rules/` and any
+    /// directories listed in the `rules_dirs` backend option, in increasing
+    /// priority; a rule replaces lower-priority rules with the same name.
+    async fn load_framework_rules<FS: FileSystem>(
+        options: &AnalysisOptions,
+        fs: &Arc<FS>,
+    ) -> Result<Vec<TomlFrameworkRule>> {
+        let loaded = Self::rule_loader(options, fs)?
+            .load_all()
+            .await
+            .map_err(|err| danny_core::Error::Backend {
+                backend: "JavaScript".to_string(),
+                message: format!("Failed to load rule files: {err}"),
+            })?;
+
+        danny_rule_engine::load_rules_with_overrides(loaded).map_err(|err| {
+            danny_core::Error::Backend {
+                backend: "JavaScript".to_string(),
+                message: format!("Failed to load TOML framework rules: {err}"),
+            }
+        })
+    }
+
+    /// Builds the framework detector from built-in frameworks and the
+    /// `[framework]` sections of user, project and `rules_dirs` rule files.
+    async fn load_framework_detector<FS: FileSystem>(
+        options: &AnalysisOptions,
+        fs: &Arc<FS>,
+    ) -> Result<danny_rule_engine::FrameworkDetector> {
+        let loaded = Self::rule_loader(options, fs)?
+            .load_frameworks()
+            .await
+            .map_err(|err| danny_core::Error::Backend {
+                backend: "JavaScript".to_string(),
+                message: format!("Failed to load rule files: {err}"),
+            })?;
+
+        danny_rule_engine::load_detector_with_overrides(loaded).map_err(|err| {
+            danny_core::Error::Backend {
+                backend: "JavaScript".to_string(),
+                message: format!("Failed to load framework detection rules: {err}"),
+            }
+        })
+    }
+
+    /// Rule loader for the project, including `rules_dirs` from the backend options.
+    ///
+    /// `rules_dirs` may lie outside the project; relative entries are
+    /// resolved against the current directory.
+    fn rule_loader<FS: FileSystem>(
+        options: &AnalysisOptions,
+        fs: &Arc<FS>,
+    ) -> Result<danny_rule_engine::RuleLoader<FS>> {
+        let rules_dirs = options
+            .backend_options
+            .get("rules_dirs")
+            .and_then(|v| v.as_array())
+            .map(|dirs| {
+                dirs.iter()
+                    .filter_map(|dir| dir.as_str())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        rules_dirs
+            .into_iter()
+            .try_fold(
+                danny_rule_engine::RuleLoader::new(Arc::clone(fs), &options.project_root),
+                |loader, dir| loader.with_shared_rules_dir(dir),
+            )
+            .map_err(|err| danny_core::Error::Backend {
+                backend: "JavaScript".to_string(),
+                message: format!("Failed to load rule files: {err}"),
+            })
+    }
+
     /// Converts Fob's module graph to Danny findings.
+    ///
+    /// `framework_rules` are the rules given to Fob; they attribute each
+    /// framework-used export to the rule that matched it, falling back to
+    /// `framework_detector` for exports Fob marked on its own.
     async fn convert_graph_to_findings(
         &self,
         graph: &fob::graph::ModuleGraph,
+        framework_rules: &[TomlFrameworkRule],
+        framework_detector: &danny_rule_engine::FrameworkDetector,
     ) -> Result<Vec<Finding>> {
         let mut findings = Vec::new();
 
@@ -1078,7 +1817,7 @@ impl<F: FileSystem> JsBackend<F> {
                 message: format!("Failed to get modules: {}", e),
             })?;
 
-        for module in modules {
+        for module in &modules {
             if Self::is_virtual_path(&module.path) {
                 continue;
             }
@@ -1206,17 +1945,53 @@ impl<F: FileSystem> JsBackend<F> {
                 continue;
             }
 
-            let framework = Self::infer_framework(&module, &export.name);
+            let matched = framework_rules.iter().find_map(|rules| {
+                rules
+                    .engine()
+                    .matching_rule(&module, &export)
+                    .map(|(rule, reason)| (rules.framework_name(), rule, reason))
+            });
+
+            let (framework, rule, explanation) = match matched {
+                Some((framework, rule, reason)) => (
+                    framework.to_string(),
+                    rule.to_string(),
+                    reason.map(str::to_string),
+                ),
+                None => (
+                    Self::infer_framework(framework_detector, &module),
+                    "Built-in".to_string(),
+                    None,
+                ),
+            };
 
             findings.push(Finding::FrameworkExport {
                 module: module.path.clone(),
                 export_name: export.name.clone(),
                 framework,
-                rule: "Built-in".to_string(),
-                explanation: None,
+                rule,
+                explanation,
             });
         }
 
+        // Report matches of `warn`/`severity` rules
+        for rules in framework_rules {
+            for rule_match in rules.engine().rule_matches(&modules) {
+                if Self::is_virtual_path(&rule_match.module) {
+                    continue;
+                }
+
+                findings.push(Finding::RuleMatch {
+                    rule: rule_match.rule,
+                    message: rule_match.message,
+                    severity: Self::convert_rule_severity(rule_match.severity),
+                    module: rule_match.module,
+                    export_name: rule_match.export_name,
+                    span: rule_match.span.as_ref().map(Self::convert_span),
+                });
+            }
+        }
+
         Ok(findings)
     }
 
@@ -1240,6 +2015,43 @@ impl<F: FileSystem> JsBackend<F> {
         }
     }
 
+    /// Converts a rule engine severity to Danny's RuleSeverity.
+    fn convert_rule_severity(severity: danny_rule_engine::Severity) -> RuleSeverity {
+        match severity {
+            danny_rule_engine::Severity::Error => RuleSeverity::Error,
+            danny_rule_engine::Severity::Warn => RuleSeverity::Warning,
+            danny_rule_engine::Severity::Info => RuleSeverity::Info,
+        }
+    }
+
+    /// Returns the exports an import uses.
+    ///
+    /// Namespace and dynamic imports can reach any export.
+    fn imported_names(import: &fob::graph::Import) -> ImportedNames {
+        use fob::graph::{ImportKind, ImportSpecifier};
+
+        if matches!(import.kind, ImportKind::Dynamic)
+            || import
+                .specifiers
+                .iter()
+                .any(|s| matches!(s, ImportSpecifier::Namespace(_)))
+        {
+            return ImportedNames::All;
+        }
+
+        ImportedNames::Named(
+            import
+                .specifiers
+                .iter()
+                .map(|s| match s {
+                    ImportSpecifier::Named(name) => name.clone(),
+                    ImportSpecifier::Default => "default".to_string(),
+                    ImportSpecifier::Namespace(name) => name.clone(),
+                })
+                .collect(),
+        )
+    }
+
     /// Converts Fob's SourceSpan to Danny's SourceLocation.
     fn convert_span(span: &fob::graph::SourceSpan) -> danny_core::SourceLocation {
         use danny_core::SourceLocation;
@@ -1293,88 +2105,84 @@ impl<F: FileSystem> JsBackend<F> {
         }
     }
 
-    /// Infers framework from module imports and export patterns using TOML-based detection.
-    fn infer_framework(module: &fob::graph::Module, _export_name: &str) -> String {
-        // Load TOML-based detector
-        let toml_files = danny_rule_engine::built_in::load_built_in_toml_files();
-        let detector = match danny_rule_engine::FrameworkDetector::from_toml_files(toml_files) {
-            Ok(d) => d,
-            Err(_) => {
-                // Fallback to "Unknown" if detection fails
-                return "Unknown".to_string();
-            }
-        };
-
-        // Extract imports
+    /// Infers the framework of a module from its imports, then its path.
+    fn infer_framework(
+        detector: &danny_rule_engine::FrameworkDetector,
+        module: &fob::graph::Module,
+    ) -> String {
         let imports: Vec<String> = module
             .imports
             .iter()
             .map(|imp| imp.source.clone())
             .collect();
 
-        // Detect from imports
-        let results = detector.detect_from_imports(&imports);
-        if let Some(result) = results.first() {
-            return result.framework.clone();
-        }
-
-        // Detect from file path
-        let path = std::path::Path::new(&module.path);
-        let path_results = detector.detect_from_path(path);
-        if let Some(result) = path_results.first() {
-            return result.framework.clone();
-        }
-
-        "Unknown".to_string()
+        detector
+            .detect_from_imports(&imports)
+            .into_iter()
+            .chain(detector.detect_from_path(&module.path))
+            .next()
+            .map(|result| result.framework)
+            .unwrap_or_else(|| "Unknown".to_string())
     }
 
-    /// Detects frameworks from the graph using TOML-based detection.
-    async fn detect_frameworks(&self, graph: &fob::graph::ModuleGraph) -> Vec<String> {
-        // Load TOML-based detector
-        let toml_files = danny_rule_engine::built_in::load_built_in_toml_files();
-        let detector = match danny_rule_engine::FrameworkDetector::from_toml_files(toml_files) {
-            Ok(d) => d,
-            Err(_) => {
-                // Return empty if detection fails
-                return Vec::new();
-            }
-        };
-
-        let modules = match graph.modules().await {
-            Ok(m) => m,
-            Err(_) => return Vec::new(),
-        };
+    /// Detects frameworks from package.json, module imports and module paths.
+    async fn detect_frameworks<FS: FileSystem>(
+        detector: &danny_rule_engine::FrameworkDetector,
+        graph: &fob::graph::ModuleGraph,
+        fs: &FS,
+    ) -> Result<Vec<danny_rule_engine::DetectionResult>> {
+        let modules = graph
+            .modules()
+            .await
+            .map_err(|e| danny_core::Error::Backend {
+                backend: "JavaScript".to_string(),
+                message: format!("Failed to get modules: {}", e),
+            })?;
 
-        // Collect all imports from all modules
-        let mut all_imports = Vec::new();
-        for module in &modules {
-            for import in &module.imports {
-                all_imports.push(import.source.clone());
+        // Detection is best-effort: an unreadable or malformed package.json
+        // only loses the dependency and script signals, never the analysis
+        let package_json_path = fs.project_root().join("package.json");
+        let mut signals = match Self::read_package_json(&package_json_path, fs).await {
+            Ok(Some(package)) => {
+                danny_rule_engine::ProjectSignals::from_package_json(package_json_path, &package)
             }
-        }
-
-        // Detect frameworks from imports
-        let results = detector.detect_from_imports(&all_imports);
+            Ok(None) => danny_rule_engine::ProjectSignals::default(),
+            Err(e) => {
+                tracing::warn!(
+                    "Skipping {} for framework detection: {}",
+                    package_json_path.display(),
+                    e
+                );
+                danny_rule_engine::ProjectSignals::default()
+            }
+        };
+        signals.modules = modules
+            .iter()
+            .filter(|module| !Self::is_virtual_path(&module.path))
+            .map(|module| {
+                let imports = module.imports.iter().map(|i| i.source.clone()).collect();
+                (module.path.clone(), imports)
+            })
+            .collect();
 
-        // Also check file paths
-        let mut path_results = Vec::new();
-        for module in &modules {
-            let path = std::path::Path::new(&module.path);
-            let results = detector.detect_from_path(path);
-            path_results.extend(results);
-        }
+        Ok(detector.detect_project(&signals))
+    }
 
-        // Combine results, preferring import-based detection
-        let mut detected_frameworks: std::collections::HashSet<String> =
-            std::collections::HashSet::new();
-        for result in results {
-            detected_frameworks.insert(result.framework);
+    /// Read and parse package.json, or `None` when the project has none
+    async fn read_package_json<FS: FileSystem>(
+        path: &Path,
+        fs: &FS,
+    ) -> Result<Option<serde_json::Value>> {
+        if !fs.exists(path).await? {
+            return Ok(None);
         }
-        for result in path_results {
-            detected_frameworks.insert(result.framework);
-        }
-
-        detected_frameworks.into_iter().collect()
+        let content = fs.read_to_string(path).await?;
+        serde_json::from_str(&content)
+            .map(Some)
+            .map_err(|e| danny_core::Error::Backend {
+                backend: "JavaScript".to_string(),
+                message: format!("Failed to parse package.json: {}", e),
+            })
     }
 }
 
@@ -29,6 +29,8 @@
 pub mod analyzers;
 pub mod backend;
 pub mod file_discovery;
+pub mod plugins;
+pub mod syntax;
 pub mod toml_config;
 
 pub use backend::JsBackend;
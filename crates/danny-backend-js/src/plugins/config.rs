@@ -0,0 +1,459 @@
+//! Lightweight extraction helpers for tool configuration files.
+//!
+//! Tool configs are arbitrary JavaScript/TypeScript (or JSON/YAML), so we
+//! don't evaluate them. Instead we pull string literals out of the source,
+//! optionally scoped to the value of a specific key. This is intentionally
+//! heuristic: it covers the common `key: './path'` and `key: ['a', 'b']`
+//! shapes used by every supported tool.
+
+use std::path::{Component, Path, PathBuf};
+
+/// Source file extensions that can be used as entry points.
+pub const SOURCE_EXTENSIONS: &[&str] = &["js", "jsx", "ts", "tsx", "mjs", "cjs", "mts", "cts"];
+
+/// Removes `//` and `/* */` comments while keeping string literals intact.
+pub fn strip_comments(source: &str) -> String {
+    let mut out = String::with_capacity(source.len());
+    let mut chars = source.chars().peekable();
+    let mut quote: Option<char> = None;
+
+    while let Some(c) = chars.next() {
+        if let Some(q) = quote {
+            out.push(c);
+            if c == '\\' {
+                if let Some(escaped) = chars.next() {
+                    out.push(escaped);
+                }
+            } else if c == q {
+                quote = None;
+            }
+            continue;
+        }
+
+        match c {
+            '"' | '\'' | '`' => {
+                quote = Some(c);
+                out.push(c);
+            }
+            '/' if chars.peek() == Some(&'/') => {
+                // Line comment: skip to end of line, keep the newline
+                for next in chars.by_ref() {
+                    if next == '\n' {
+                        out.push('\n');
+                        break;
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut prev = '\0';
+                for next in chars.by_ref() {
+                    if prev == '*' && next == '/' {
+                        break;
+                    }
+                    prev = next;
+                }
+                out.push(' ');
+            }
+            _ => out.push(c),
+        }
+    }
+
+    out
+}
+
+/// Returns every string literal in `source`.
+///
+/// Template literals containing `${...}` interpolation are skipped since
+/// their value can't be known statically.
+pub fn string_literals(source: &str) -> Vec<String> {
+    let mut literals = Vec::new();
+    let mut chars = source.chars();
+    let mut current: Option<(char, String)> = None;
+
+    while let Some(c) = chars.next() {
+        match current.as_mut() {
+            Some((q, value)) => {
+                if c == '\\' {
+                    if let Some(escaped) = chars.next() {
+                        value.push(escaped);
+                    }
+                } else if c == *q {
+                    let (q, value) = current.take().expect("literal in progress");
+                    if !(q == '`' && value.contains("${")) {
+                        literals.push(value);
+                    }
+                } else if c == '\n' && *q != '`' {
+                    // Unterminated literal, drop it
+                    current = None;
+                } else {
+                    value.push(c);
+                }
+            }
+            None => {
+                if matches!(c, '"' | '\'' | '`') {
+                    current = Some((c, String::new()));
+                }
+            }
+        }
+    }
+
+    literals
+}
+
+/// Returns the string literals found in the value of every `key: value`
+/// occurrence in `source`.
+///
+/// Object, array and call values (`{...}`, `[...]`, `(...)`) are scanned up
+/// to their matching close bracket; scalar values end at the next `,`, `}`
+/// or newline.
+pub fn values_for_key(source: &str, key: &str) -> Vec<String> {
+    let source = strip_comments(source);
+    let bytes = source.as_bytes();
+    let mut values = Vec::new();
+    let mut search_from = 0;
+
+    while let Some(found) = source[search_from..].find(key) {
+        let start = search_from + found;
+        let mut pos = start + key.len();
+        search_from = pos;
+
+        // The key must not be part of a longer identifier
+        if start > 0 {
+            let before = bytes[start - 1];
+            if before.is_ascii_alphanumeric() || before == b'_' || before == b'$' {
+                continue;
+            }
+        }
+        if pos < bytes.len() && (bytes[pos].is_ascii_alphanumeric() || bytes[pos] == b'_') {
+            continue;
+        }
+
+        // Allow quoted keys (JSON / quoted object keys)
+        if pos < bytes.len() && matches!(bytes[pos], b'"' | b'\'') {
+            pos += 1;
+        }
+        while pos < bytes.len() && bytes[pos].is_ascii_whitespace() {
+            pos += 1;
+        }
+        if pos >= bytes.len() || bytes[pos] != b':' {
+            continue;
+        }
+        pos += 1;
+        while pos < bytes.len() && bytes[pos].is_ascii_whitespace() {
+            pos += 1;
+        }
+
+        let end = value_end(&source, pos);
+        values.extend(string_literals(&source[pos..end]));
+        search_from = end.max(search_from);
+    }
+
+    values
+}
+
+/// Returns `source` with the values of every `key: value` occurrence removed.
+///
+/// Useful to ignore nested sections that reuse a key name, e.g. Vitest's
+/// `coverage.include` next to `test.include`.
+pub fn without_key(source: &str, key: &str) -> String {
+    let source = strip_comments(source);
+    let mut out = String::with_capacity(source.len());
+    let mut rest = source.as_str();
+
+    while let Some(found) = rest.find(key) {
+        let after_key = found + key.len();
+        let value_start = rest[after_key..]
+            .trim_start_matches(['"', '\'', ' ', '\t'])
+            .strip_prefix(':')
+            .map(|v| rest.len() - v.len());
+
+        let preceded_by_ident = rest[..found]
+            .chars()
+            .next_back()
+            .is_some_and(|c| c.is_alphanumeric() || c == '_' || c == '$');
+
+        match value_start {
+            Some(start) if !preceded_by_ident => {
+                let start = start + (rest[start..].len() - rest[start..].trim_start().len());
+                let end = value_end(rest, start);
+                out.push_str(&rest[..start]);
+                rest = &rest[end..];
+            }
+            _ => {
+                out.push_str(&rest[..after_key]);
+                rest = &rest[after_key..];
+            }
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+/// Finds the end of a value starting at `start`, honoring nested brackets
+/// and string literals.
+fn value_end(source: &str, start: usize) -> usize {
+    let mut depth = 0usize;
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+
+    for (offset, c) in source[start..].char_indices() {
+        if let Some(q) = quote {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+            continue;
+        }
+
+        match c {
+            '"' | '\'' | '`' => quote = Some(c),
+            '[' | '{' | '(' => depth += 1,
+            ']' | '}' | ')' => {
+                if depth == 0 {
+                    return start + offset;
+                }
+                depth -= 1;
+                if depth == 0 {
+                    return start + offset + 1;
+                }
+            }
+            ',' | '\n' | ';' if depth == 0 => return start + offset,
+            _ => {}
+        }
+    }
+
+    source.len()
+}
+
+/// Returns true if the value looks like a local file reference rather than a
+/// package name (e.g. `./setup.ts`, `<rootDir>/jest.setup.js`, `src/main.ts`).
+pub fn is_local_file(value: &str) -> bool {
+    if value.starts_with("./") || value.starts_with("../") || value.starts_with("<rootDir>/") {
+        return true;
+    }
+    if value.starts_with('@') || value.contains(':') || value.contains('*') {
+        return false;
+    }
+    Path::new(value)
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|ext| SOURCE_EXTENSIONS.contains(&ext))
+}
+
+/// Resolves a path found in a config file against the config's directory.
+///
+/// Returns a project-relative path, or `None` if the value is absolute or
+/// escapes the project root.
+pub fn resolve_relative(config_dir: &Path, value: &str) -> Option<PathBuf> {
+    let value = value.strip_prefix("<rootDir>/").unwrap_or(value);
+    let joined = config_dir.join(value);
+    let mut normalized = PathBuf::new();
+
+    for component in joined.components() {
+        match component {
+            Component::Normal(part) => normalized.push(part),
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    return None;
+                }
+            }
+            Component::RootDir | Component::Prefix(_) => return None,
+        }
+    }
+
+    Some(normalized)
+}
+
+/// Resolves a glob found in a config file against the config's directory and
+/// converts extglob groups (`@(a|b)`, `?(a|b)`) into brace alternation.
+///
+/// Optional groups can't be expressed in globset (empty alternatives never
+/// match), so each `?(...)` doubles the returned patterns: one with the
+/// group and one without.
+pub fn resolve_pattern(config_dir: &Path, pattern: &str) -> Vec<String> {
+    expand_extglob(pattern)
+        .into_iter()
+        .filter_map(|pattern| {
+            // Split off the leading literal directory part so `..` can be resolved
+            let (dir, rest) = match pattern.find(['*', '{', '?', '[']) {
+                Some(idx) => match pattern[..idx].rfind('/') {
+                    Some(slash) => (&pattern[..slash], &pattern[slash + 1..]),
+                    None => ("", pattern.as_str()),
+                },
+                None => ("", pattern.as_str()),
+            };
+
+            let base = resolve_relative(config_dir, if dir.is_empty() { "." } else { dir })?;
+            if base.as_os_str().is_empty() {
+                Some(rest.to_string())
+            } else {
+                Some(format!("{}/{}", base.to_string_lossy(), rest))
+            }
+        })
+        .collect()
+}
+
+/// Converts extglob groups into globset-compatible brace alternation.
+fn expand_extglob(pattern: &str) -> Vec<String> {
+    let mut outputs = vec![String::with_capacity(pattern.len())];
+    let mut chars = pattern.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if matches!(c, '@' | '?' | '+') && chars.peek() == Some(&'(') {
+            chars.next();
+            let mut group = String::new();
+            for next in chars.by_ref() {
+                if next == ')' {
+                    break;
+                }
+                group.push(next);
+            }
+            let alternation = format!("{{{}}}", group.replace('|', ","));
+
+            if c == '?' {
+                let without = outputs.clone();
+                for out in &mut outputs {
+                    out.push_str(&alternation);
+                }
+                outputs.extend(without);
+            } else {
+                for out in &mut outputs {
+                    out.push_str(&alternation);
+                }
+            }
+        } else {
+            for out in &mut outputs {
+                out.push(c);
+            }
+        }
+    }
+
+    outputs
+}
+
+/// Extracts the npm package name from an import specifier
+/// (`@scope/pkg/sub` → `@scope/pkg`, `pkg/sub` → `pkg`).
+pub fn package_name(specifier: &str) -> Option<String> {
+    if specifier.is_empty()
+        || specifier.starts_with('.')
+        || specifier.starts_with('/')
+        || specifier.contains(':')
+        || specifier.contains(char::is_whitespace)
+    {
+        return None;
+    }
+
+    let mut parts = specifier.split('/');
+    let first = parts.next()?;
+    if first.starts_with('@') {
+        let second = parts.next().filter(|s| !s.is_empty())?;
+        Some(format!("{}/{}", first, second))
+    } else {
+        Some(first.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_values_for_key_array() {
+        let source = r#"
+            export default defineConfig({
+              test: {
+                // setupFiles: ['./ignored.ts'],
+                setupFiles: ['./setup.ts', "./other.ts"],
+                environment: 'jsdom',
+              },
+            })
+        "#;
+        assert_eq!(
+            values_for_key(source, "setupFiles"),
+            vec!["./setup.ts", "./other.ts"]
+        );
+        assert_eq!(values_for_key(source, "environment"), vec!["jsdom"]);
+    }
+
+    #[test]
+    fn test_values_for_key_ignores_longer_identifiers() {
+        let source = "globalSetupFiles: ['./a.ts'], setupFiles: './b.ts'";
+        assert_eq!(values_for_key(source, "setupFiles"), vec!["./b.ts"]);
+    }
+
+    #[test]
+    fn test_values_for_key_quoted_json_key() {
+        let source = r#"{ "extends": ["airbnb", "prettier"] }"#;
+        assert_eq!(
+            values_for_key(source, "extends"),
+            vec!["airbnb", "prettier"]
+        );
+    }
+
+    #[test]
+    fn test_without_key() {
+        let source = "test: { include: ['src/**/*.test.ts'], coverage: { include: ['src/**'] } }";
+        let stripped = without_key(source, "coverage");
+        assert_eq!(
+            values_for_key(&stripped, "include"),
+            vec!["src/**/*.test.ts"]
+        );
+    }
+
+    #[test]
+    fn test_string_literals_skip_interpolated_templates() {
+        let literals = string_literals("a('x'); b(`y`); c(`${z}/w`)");
+        assert_eq!(literals, vec!["x", "y"]);
+    }
+
+    #[test]
+    fn test_resolve_relative() {
+        let dir = Path::new(".storybook");
+        assert_eq!(
+            resolve_relative(dir, "../src/preview.ts"),
+            Some(PathBuf::from("src/preview.ts"))
+        );
+        assert_eq!(
+            resolve_relative(Path::new(""), "<rootDir>/jest.setup.js"),
+            Some(PathBuf::from("jest.setup.js"))
+        );
+        assert_eq!(resolve_relative(Path::new(""), "../outside.js"), None);
+    }
+
+    #[test]
+    fn test_resolve_pattern_with_extglob() {
+        assert_eq!(
+            resolve_pattern(Path::new(".storybook"), "../src/**/*.stories.@(js|tsx)"),
+            vec!["src/**/*.stories.{js,tsx}"]
+        );
+        assert_eq!(
+            resolve_pattern(Path::new(""), "**/*.test.?(c|m)js"),
+            vec!["**/*.test.{c,m}js", "**/*.test.js"]
+        );
+    }
+
+    #[test]
+    fn test_package_name() {
+        assert_eq!(package_name("vitest/config"), Some("vitest".to_string()));
+        assert_eq!(
+            package_name("@storybook/react-vite"),
+            Some("@storybook/react-vite".to_string())
+        );
+        assert_eq!(package_name("./local"), None);
+        assert_eq!(package_name("node:path"), None);
+    }
+
+    #[test]
+    fn test_is_local_file() {
+        assert!(is_local_file("./setup.ts"));
+        assert!(is_local_file("src/main.ts"));
+        assert!(!is_local_file("jsdom"));
+        assert!(!is_local_file("@vitejs/plugin-vue"));
+    }
+}
@@ -0,0 +1,116 @@
+//! ESLint plugin.
+
+use super::config::package_name;
+use super::{PluginContribution, ToolPlugin};
+use std::path::Path;
+
+/// ESLint linter.
+pub struct EslintPlugin;
+
+impl ToolPlugin for EslintPlugin {
+    fn name(&self) -> &'static str {
+        "ESLint"
+    }
+
+    fn enablers(&self) -> &'static [&'static str] {
+        &["eslint"]
+    }
+
+    fn config_files(&self) -> &'static [&'static str] {
+        &[
+            "eslint.config.js",
+            "eslint.config.mjs",
+            "eslint.config.cjs",
+            "eslint.config.ts",
+            "eslint.config.mts",
+            "eslint.config.cts",
+            ".eslintrc",
+            ".eslintrc.js",
+            ".eslintrc.cjs",
+            ".eslintrc.json",
+            ".eslintrc.yml",
+            ".eslintrc.yaml",
+        ]
+    }
+
+    fn dependency_keys(&self) -> &'static [&'static str] {
+        &["extends", "plugins", "parser"]
+    }
+
+    fn parse_config(&self, config_path: &Path, content: &str, out: &mut PluginContribution) {
+        let is_yaml = config_path
+            .extension()
+            .is_some_and(|ext| ext == "yml" || ext == "yaml");
+
+        if is_yaml {
+            // YAML lists and values are usually unquoted
+            for line in content.lines() {
+                let line = line.trim();
+                let value = line
+                    .strip_prefix("- ")
+                    .or_else(|| line.split_once(": ").map(|(_, v)| v))
+                    .map(|v| v.trim().trim_matches(['"', '\'']));
+                if let Some(value) = value.filter(|v| !v.is_empty()) {
+                    out.referenced.push(value.to_string());
+                }
+            }
+        }
+    }
+
+    /// Resolves ESLint's shorthand names for shareable configs and plugins.
+    ///
+    /// - `plugin:react/recommended` → `eslint-plugin-react`
+    /// - `airbnb` → `eslint-config-airbnb`, `eslint-plugin-airbnb`
+    /// - `@scope` → `@scope/eslint-config`, `@scope/eslint-plugin`
+    /// - `@scope/name` → `@scope/eslint-config-name`, `@scope/eslint-plugin-name`
+    fn package_candidates(&self, specifier: &str) -> Vec<String> {
+        if let Some(plugin) = specifier.strip_prefix("plugin:") {
+            return plugin_package(plugin).into_iter().collect();
+        }
+
+        let mut candidates: Vec<String> = package_name(specifier).into_iter().collect();
+
+        match specifier.strip_prefix('@') {
+            Some(scoped) => match scoped.split_once('/') {
+                None => {
+                    candidates.push(format!("@{}/eslint-config", scoped));
+                    candidates.push(format!("@{}/eslint-plugin", scoped));
+                }
+                Some((scope, name)) if !name.starts_with("eslint-") && !name.contains('/') => {
+                    candidates.push(format!("@{}/eslint-config-{}", scope, name));
+                    candidates.push(format!("@{}/eslint-plugin-{}", scope, name));
+                }
+                Some(_) => {}
+            },
+            None if !specifier.starts_with("eslint-") && !specifier.contains('/') => {
+                candidates.push(format!("eslint-config-{}", specifier));
+                candidates.push(format!("eslint-plugin-{}", specifier));
+            }
+            None => {}
+        }
+
+        candidates
+    }
+}
+
+/// Returns the package behind a `plugin:<name>/<config>` reference.
+fn plugin_package(reference: &str) -> Option<String> {
+    let mut parts = reference.split('/');
+    let first = parts.next().filter(|s| !s.is_empty())?;
+
+    if let Some(scope) = first.strip_prefix('@') {
+        // `@scope/config` → @scope/eslint-plugin, `@scope/name/config` → @scope/eslint-plugin-name
+        let rest: Vec<&str> = parts.collect();
+        return Some(if rest.len() >= 2 {
+            format!("@{}/eslint-plugin-{}", scope, rest[0])
+        } else {
+            format!("@{}/eslint-plugin", scope)
+        });
+    }
+
+    if first.starts_with("eslint-plugin-") {
+        Some(first.to_string())
+    } else {
+        Some(format!("eslint-plugin-{}", first))
+    }
+}
@@ -0,0 +1,96 @@
+//! Jest plugin.
+
+use super::config::{
+    is_local_file, package_name, resolve_pattern, resolve_relative, values_for_key,
+};
+use super::{PluginContribution, ToolPlugin};
+use std::path::Path;
+
+/// Config keys whose values are files Jest loads.
+const FILE_KEYS: &[&str] = &[
+    "setupFiles",
+    "setupFilesAfterEnv",
+    "globalSetup",
+    "globalTeardown",
+    "testEnvironment",
+    "snapshotResolver",
+    "testSequencer",
+];
+
+/// Config keys whose values may name packages Jest loads.
+const DEPENDENCY_KEYS: &[&str] = &[
+    "preset",
+    "testEnvironment",
+    "testRunner",
+    "runner",
+    "transform",
+    "reporters",
+    "snapshotSerializers",
+    "watchPlugins",
+    "setupFiles",
+    "setupFilesAfterEnv",
+];
+
+/// Jest test runner.
+pub struct JestPlugin;
+
+impl ToolPlugin for JestPlugin {
+    fn name(&self) -> &'static str {
+        "Jest"
+    }
+
+    fn enablers(&self) -> &'static [&'static str] {
+        &["jest"]
+    }
+
+    fn config_files(&self) -> &'static [&'static str] {
+        &[
+            "jest.config.js",
+            "jest.config.ts",
+            "jest.config.mjs",
+            "jest.config.cjs",
+            "jest.config.json",
+        ]
+    }
+
+    fn is_test_runner(&self) -> bool {
+        true
+    }
+
+    fn default_entry_patterns(&self) -> &'static [&'static str] {
+        &[
+            "**/__tests__/**/*.{js,jsx,ts,tsx,mjs,cjs}",
+            "**/*.{test,spec}.{js,jsx,ts,tsx,mjs,cjs}",
+        ]
+    }
+
+    fn dependency_keys(&self) -> &'static [&'static str] {
+        DEPENDENCY_KEYS
+    }
+
+    fn parse_config(&self, config_path: &Path, content: &str, out: &mut PluginContribution) {
+        let config_dir = config_path.parent().unwrap_or(Path::new(""));
+
+        for key in FILE_KEYS {
+            for value in values_for_key(content, key) {
+                if is_local_file(&value) {
+                    out.entry_files.extend(resolve_relative(config_dir, &value));
+                }
+            }
+        }
+
+        for pattern in values_for_key(content, "testMatch") {
+            out.entry_patterns
+                .extend(resolve_pattern(config_dir, &pattern));
+        }
+    }
+
+    fn package_candidates(&self, specifier: &str) -> Vec<String> {
+        let mut candidates: Vec<String> = package_name(specifier).into_iter().collect();
+        // `testEnvironment: 'jsdom'` loads jest-environment-jsdom
+        if !specifier.contains('/') {
+            candidates.push(format!("jest-environment-{}", specifier));
+        }
+        candidates
+    }
+}
@@ -0,0 +1,510 @@
+//! Tool plugins for test runners, bundlers and linters.
+//!
+//! Tools like Vitest or Storybook load files that nothing imports: config
+//! files, setup files, test suites and stories. Without knowing about them
+//! those files (and everything they import) show up as unreachable, and the
+//! packages they reference show up as unused dependencies.
+//!
+//! Each [`ToolPlugin`] describes one tool: which packages enable it, which
+//! config files it reads, and which files it loads by default. The
+//! [`resolve_plugins`] function runs every enabled plugin against the project
+//! and returns extra entry points plus the dependencies the tools use.
+//! Files loaded by test runners are also reported as test files.
+//!
+//! Plugins:
+//! - `jest`: Jest test files, setup files and environments
+//! - `vitest`: Vitest test files and setup files
+//! - `playwright`: Playwright test directory and global setup
+//! - `storybook`: Stories and `.storybook` config
+//! - `eslint`: ESLint configs, plugins and shareable configs
+//! - `vite`: Vite config and build inputs
+//! - `webpack`: webpack config and entries
+//! - `nextjs`: Next.js config, middleware and instrumentation
+
+pub mod config;
+pub mod eslint;
+pub mod jest;
+pub mod nextjs;
+pub mod playwright;
+pub mod scripts;
+pub mod storybook;
+pub mod vite;
+pub mod vitest;
+pub mod webpack;
+
+pub use eslint::EslintPlugin;
+pub use jest::JestPlugin;
+pub use nextjs::NextJsPlugin;
+pub use playwright::PlaywrightPlugin;
+pub use storybook::StorybookPlugin;
+pub use vite::VitePlugin;
+pub use vitest::VitestPlugin;
+pub use webpack::WebpackPlugin;
+
+use danny_core::Result;
+use danny_fs::FileSystem;
+use globset::{GlobBuilder, GlobSetBuilder};
+use std::collections::{BTreeSet, HashSet};
+use std::path::{Path, PathBuf};
+
+/// A tool that loads project files outside of the import graph.
+pub trait ToolPlugin: Send + Sync {
+    /// Display name of the tool.
+    fn name(&self) -> &'static str;
+
+    /// Packages that enable this plugin when declared in package.json.
+    fn enablers(&self) -> &'static [&'static str];
+
+    /// Config files the tool reads, relative to the project root.
+    fn config_files(&self) -> &'static [&'static str];
+
+    /// Globs of files the tool loads when its config doesn't say otherwise.
+    fn default_entry_patterns(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Returns true if the files this tool loads (other than its config) are tests.
+    fn is_test_runner(&self) -> bool {
+        false
+    }
+
+    /// Returns true if the plugin should run for a project with these dependencies.
+    fn is_enabled(&self, dependencies: &BTreeSet<String>) -> bool {
+        self.enablers().iter().any(|e| dependencies.contains(*e))
+    }
+
+    /// Config keys whose string values may name packages the tool loads,
+    /// e.g. Jest's `testEnvironment` or ESLint's `extends`.
+    ///
+    /// Packages the config imports are already part of the module graph.
+    fn dependency_keys(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Extracts entry files, patterns and package references from a config file.
+    ///
+    /// `config_path` is relative to the project root.
+    fn parse_config(&self, _config_path: &Path, _content: &str, _out: &mut PluginContribution) {}
+
+    /// Maps a string referenced by the config to the packages that may provide it.
+    ///
+    /// The default treats the string as an import specifier.
+    fn package_candidates(&self, specifier: &str) -> Vec<String> {
+        config::package_name(specifier).into_iter().collect()
+    }
+}
+
+/// What a single plugin contributes for one project.
+#[derive(Debug, Default, Clone)]
+pub struct PluginContribution {
+    /// Files loaded by the tool, relative to the project root
+    pub entry_files: Vec<PathBuf>,
+    /// Globs of files loaded by the tool, relative to the project root.
+    /// When non-empty these replace the plugin's default patterns.
+    pub entry_patterns: Vec<String>,
+    /// Strings referenced by the config that may name packages
+    pub referenced: Vec<String>,
+}
+
+/// Combined result of running every enabled plugin.
+#[derive(Debug, Default, Clone)]
+pub struct PluginResolution {
+    /// Names of the plugins that were enabled
+    pub enabled_plugins: Vec<&'static str>,
+    /// Additional entry points (absolute paths)
+    pub entry_points: Vec<PathBuf>,
+    /// Declared dependencies used by tools, config files or scripts
+    pub used_dependencies: BTreeSet<String>,
+    /// Entry points loaded by test runners: test suites and setup files (absolute paths)
+    pub test_files: Vec<PathBuf>,
+}
+
+/// Returns all built-in tool plugins.
+pub fn built_in_plugins() -> Vec<Box<dyn ToolPlugin>> {
+    vec![
+        Box::new(JestPlugin),
+        Box::new(VitestPlugin),
+        Box::new(PlaywrightPlugin),
+        Box::new(StorybookPlugin),
+        Box::new(EslintPlugin),
+        Box::new(VitePlugin),
+        Box::new(WebpackPlugin),
+        Box::new(NextJsPlugin),
+    ]
+}
+
+/// Runs the built-in plugins and package.json scripts against the project.
+///
+/// `discovered` is the set of source files found during file discovery;
+/// entry patterns are matched against it so plugins never introduce files
+/// that discovery ignored (e.g. via .gitignore).
+pub async fn resolve_plugins<F: FileSystem>(
+    fs: &F,
+    discovered: &HashSet<PathBuf>,
+) -> Result<PluginResolution> {
+    resolve_with_plugins(fs, discovered, &built_in_plugins()).await
+}
+
+/// Runs the given plugins and package.json scripts against the project.
+pub async fn resolve_with_plugins<F: FileSystem>(
+    fs: &F,
+    discovered: &HashSet<PathBuf>,
+    plugins: &[Box<dyn ToolPlugin>],
+) -> Result<PluginResolution> {
+    let root = fs.project_root().to_path_buf();
+    let package_json = read_package_json(fs, &root).await?;
+    let declared = declared_dependencies(&package_json);
+
+    let mut resolution = PluginResolution::default();
+    let mut entry_files: BTreeSet<PathBuf> = BTreeSet::new();
+    let mut patterns: Vec<String> = Vec::new();
+    let mut test_files: BTreeSet<PathBuf> = BTreeSet::new();
+    let mut test_patterns: Vec<String> = Vec::new();
+
+    for plugin in plugins {
+        let mut existing_configs = Vec::new();
+        for config_file in plugin.config_files() {
+            if fs.exists(&root.join(config_file)).await? {
+                existing_configs.push(PathBuf::from(config_file));
+            }
+        }
+
+        if !plugin.is_enabled(&declared) && existing_configs.is_empty() {
+            continue;
+        }
+        resolution.enabled_plugins.push(plugin.name());
+
+        let mut contribution = PluginContribution::default();
+        for config_path in &existing_configs {
+            contribution.entry_files.push(config_path.clone());
+
+            let Ok(content) = fs.read_to_string(&root.join(config_path)).await else {
+                continue;
+            };
+            for key in plugin.dependency_keys() {
+                contribution
+                    .referenced
+                    .extend(config::values_for_key(&content, key));
+            }
+            plugin.parse_config(config_path, &content, &mut contribution);
+        }
+
+        let plugin_patterns: Vec<String> = if contribution.entry_patterns.is_empty() {
+            plugin
+                .default_entry_patterns()
+                .iter()
+                .map(|p| p.to_string())
+                .collect()
+        } else {
+            contribution.entry_patterns
+        };
+
+        if plugin.is_test_runner() {
+            test_patterns.extend(plugin_patterns.iter().cloned());
+            test_files.extend(
+                contribution
+                    .entry_files
+                    .iter()
+                    .filter(|file| !existing_configs.contains(file))
+                    .cloned(),
+            );
+        }
+        patterns.extend(plugin_patterns);
+        entry_files.extend(contribution.entry_files);
+
+        for specifier in &contribution.referenced {
+            for candidate in plugin.package_candidates(specifier) {
+                if declared.contains(&candidate) {
+                    resolution.used_dependencies.insert(candidate);
+                }
+            }
+        }
+    }
+
+    // package.json scripts: binaries are dependencies, runner arguments are entries
+    if let Some(scripts) = package_json
+        .as_ref()
+        .and_then(|p| p.get("scripts"))
+        .and_then(|s| s.as_object())
+    {
+        for script in scripts.values().filter_map(|v| v.as_str()) {
+            let usage = scripts::parse_script(script);
+            let packages = usage
+                .binaries
+                .iter()
+                .flat_map(|b| scripts::binary_packages(b))
+                .chain(usage.packages);
+            for package in packages {
+                if declared.contains(&package) {
+                    resolution.used_dependencies.insert(package);
+                }
+            }
+            entry_files.extend(usage.files.into_iter().map(PathBuf::from));
+        }
+    }
+
+    let mut entry_points = resolve_files(fs, &root, entry_files, discovered).await?;
+    entry_points.extend(match_patterns(&root, &patterns, discovered));
+
+    let mut tests = resolve_files(fs, &root, test_files, discovered).await?;
+    tests.extend(match_patterns(&root, &test_patterns, discovered));
+
+    resolution.entry_points = entry_points.into_iter().collect();
+    resolution.test_files = tests.into_iter().collect();
+    Ok(resolution)
+}
+
+/// Turns project-relative files into absolute paths, dropping missing ones.
+async fn resolve_files<F: FileSystem>(
+    fs: &F,
+    root: &Path,
+    files: BTreeSet<PathBuf>,
+    discovered: &HashSet<PathBuf>,
+) -> Result<BTreeSet<PathBuf>> {
+    let mut resolved = BTreeSet::new();
+
+    for file in files {
+        let absolute = root.join(&file);
+        if discovered.contains(&absolute) {
+            resolved.insert(absolute);
+        } else if fs.exists(&absolute).await? {
+            if let Ok(normalized) = fs.normalize_path(&absolute).await {
+                resolved.insert(normalized);
+            }
+        }
+    }
+
+    Ok(resolved)
+}
+
+/// Reads and parses package.json from the project root, if present.
+async fn read_package_json<F: FileSystem>(
+    fs: &F,
+    root: &Path,
+) -> Result<Option<serde_json::Value>> {
+    let path = root.join("package.json");
+    if !fs.exists(&path).await? {
+        return Ok(None);
+    }
+
+    let content = fs.read_to_string(&path).await?;
+    Ok(serde_json::from_str(&content).ok())
+}
+
+/// Collects every dependency name declared in package.json.
+fn declared_dependencies(package_json: &Option<serde_json::Value>) -> BTreeSet<String> {
+    let Some(package_json) = package_json else {
+        return BTreeSet::new();
+    };
+
+    [
+        "dependencies",
+        "devDependencies",
+        "peerDependencies",
+        "optionalDependencies",
+    ]
+    .iter()
+    .filter_map(|field| package_json.get(*field).and_then(|v| v.as_object()))
+    .flat_map(|deps| deps.keys().cloned())
+    .collect()
+}
+
+/// Matches discovered files against project-relative globs.
+pub(crate) fn match_patterns(
+    root: &Path,
+    patterns: &[String],
+    discovered: &HashSet<PathBuf>,
+) -> Vec<PathBuf> {
+    let mut builder = GlobSetBuilder::new();
+    let mut any_valid = false;
+
+    for pattern in patterns {
+        // Silently skip patterns globset can't compile
+        if let Ok(glob) = GlobBuilder::new(pattern).literal_separator(true).build() {
+            builder.add(glob);
+            any_valid = true;
+        }
+    }
+
+    if !any_valid {
+        return Vec::new();
+    }
+    let Ok(glob_set) = builder.build() else {
+        return Vec::new();
+    };
+
+    discovered
+        .iter()
+        .filter(|path| {
+            let Ok(relative) = path.strip_prefix(root) else {
+                return false;
+            };
+            !relative
+                .components()
+                .any(|c| c.as_os_str() == "node_modules")
+                && glob_set.is_match(relative)
+        })
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use danny_fs::NativeFileSystem;
+    use std::fs;
+    use tempfile::TempDir;
+
+    async fn resolve(root: &Path) -> PluginResolution {
+        let fs = NativeFileSystem::new(root).unwrap();
+        let discovered = crate::file_discovery::discover_source_files(
+            &danny_core::AnalysisOptions {
+                project_root: root.to_path_buf(),
+                ..Default::default()
+            },
+            &crate::file_discovery::DiscoveryConfig::default(),
+            std::sync::Arc::new(NativeFileSystem::new(root).unwrap()),
+        )
+        .await
+        .unwrap();
+        resolve_plugins(&fs, &discovered).await.unwrap()
+    }
+
+    fn relative_paths(paths: &[PathBuf], root: &Path) -> Vec<String> {
+        let root = root.canonicalize().unwrap();
+        paths
+            .iter()
+            .map(|p| {
+                p.strip_prefix(&root)
+                    .unwrap()
+                    .to_string_lossy()
+                    .into_owned()
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_vitest_and_playwright_entries() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        fs::create_dir_all(root.join("src/__tests__")).unwrap();
+        fs::create_dir_all(root.join("e2e")).unwrap();
+        fs::write(
+            root.join("package.json"),
+            r#"{
+              "scripts": { "test": "vitest", "e2e": "playwright test" },
+              "devDependencies": { "vitest": "^3.0.0", "@playwright/test": "^1.0.0", "jsdom": "^27.0.0" }
+            }"#,
+        )
+        .unwrap();
+        fs::write(
+            root.join("vitest.config.ts"),
+            "export default { test: { environment: 'jsdom', setupFiles: ['./test/setup.ts'] } }",
+        )
+        .unwrap();
+        fs::create_dir_all(root.join("test")).unwrap();
+        fs::write(root.join("test/setup.ts"), "").unwrap();
+        fs::write(
+            root.join("playwright.config.ts"),
+            "export default defineConfig({ testDir: './e2e' })",
+        )
+        .unwrap();
+        fs::write(root.join("src/__tests__/a.spec.ts"), "").unwrap();
+        fs::write(root.join("e2e/home.spec.ts"), "").unwrap();
+        fs::write(root.join("src/index.ts"), "").unwrap();
+
+        let resolution = resolve(root).await;
+        let entries = relative_paths(&resolution.entry_points, root);
+
+        assert!(entries.contains(&"vitest.config.ts".to_string()));
+        assert!(entries.contains(&"playwright.config.ts".to_string()));
+        assert!(entries.contains(&"test/setup.ts".to_string()));
+        assert!(entries.contains(&"src/__tests__/a.spec.ts".to_string()));
+        assert!(entries.contains(&"e2e/home.spec.ts".to_string()));
+        assert!(!entries.contains(&"src/index.ts".to_string()));
+
+        // Config files are entries, but only suites and setup files are tests
+        let mut tests = relative_paths(&resolution.test_files, root);
+        tests.sort();
+        assert_eq!(
+            tests,
+            vec![
+                "e2e/home.spec.ts",
+                "src/__tests__/a.spec.ts",
+                "test/setup.ts"
+            ]
+        );
+
+        assert!(resolution.used_dependencies.contains("vitest"));
+        assert!(resolution.used_dependencies.contains("@playwright/test"));
+        assert!(resolution.used_dependencies.contains("jsdom"));
+    }
+
+    #[tokio::test]
+    async fn test_only_dependency_keys_reference_packages() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        fs::write(
+            root.join("package.json"),
+            r#"{ "devDependencies": { "vitest": "^3.0.0", "jsdom": "^27.0.0", "debug": "^4.0.0" } }"#,
+        )
+        .unwrap();
+        // Output paths and env values are strings too, but don't load packages
+        fs::write(
+            root.join("vitest.config.ts"),
+            "export default { test: { environment: 'jsdom', env: { LOG_LEVEL: 'debug' } } }",
+        )
+        .unwrap();
+
+        let resolution = resolve(root).await;
+
+        assert!(resolution.used_dependencies.contains("jsdom"));
+        assert!(!resolution.used_dependencies.contains("debug"));
+    }
+
+    #[tokio::test]
+    async fn test_storybook_stories_are_entries() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        fs::create_dir_all(root.join("src/components")).unwrap();
+        fs::write(
+            root.join("package.json"),
+            r#"{ "devDependencies": { "@storybook/react-vite": "^8.0.0" } }"#,
+        )
+        .unwrap();
+        fs::write(root.join("src/components/Button.stories.tsx"), "").unwrap();
+        fs::write(root.join("src/components/Button.tsx"), "").unwrap();
+
+        let resolution = resolve(root).await;
+        let entries = relative_paths(&resolution.entry_points, root);
+
+        assert_eq!(resolution.enabled_plugins, vec!["Storybook"]);
+        assert_eq!(entries, vec!["src/components/Button.stories.tsx"]);
+    }
+
+    #[tokio::test]
+    async fn test_script_files_are_entries() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        fs::create_dir_all(root.join("scripts")).unwrap();
+        fs::write(
+            root.join("package.json"),
+            r#"{
+              "scripts": { "release": "node scripts/release.js", "build": "tsc -p ." },
+              "devDependencies": { "typescript": "^5.0.0", "left-pad": "^1.0.0" }
+            }"#,
+        )
+        .unwrap();
+        fs::write(root.join("scripts/release.js"), "").unwrap();
+
+        let resolution = resolve(root).await;
+        let entries = relative_paths(&resolution.entry_points, root);
+
+        assert_eq!(entries, vec!["scripts/release.js"]);
+        assert!(resolution.enabled_plugins.is_empty());
+        assert_eq!(
+            resolution.used_dependencies.into_iter().collect::<Vec<_>>(),
+            vec!["typescript"]
+        );
+    }
+}
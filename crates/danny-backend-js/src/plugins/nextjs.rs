@@ -0,0 +1,42 @@
+//! Next.js plugin.
+//!
+//! Pages, layouts and route handlers are covered by the Next.js framework
+//! rules; this plugin only adds the files Next.js loads by convention
+//! outside of `pages/` and `app/`.
+
+use super::ToolPlugin;
+
+/// Next.js framework tooling.
+pub struct NextJsPlugin;
+
+impl ToolPlugin for NextJsPlugin {
+    fn name(&self) -> &'static str {
+        "Next.js"
+    }
+
+    fn enablers(&self) -> &'static [&'static str] {
+        &["next"]
+    }
+
+    fn config_files(&self) -> &'static [&'static str] {
+        &[
+            "next.config.js",
+            "next.config.mjs",
+            "next.config.cjs",
+            "next.config.ts",
+            "next.config.mts",
+        ]
+    }
+
+    fn default_entry_patterns(&self) -> &'static [&'static str] {
+        &[
+            "middleware.{js,ts}",
+            "src/middleware.{js,ts}",
+            "instrumentation.{js,ts}",
+            "src/instrumentation.{js,ts}",
+            "instrumentation-client.{js,ts}",
+            "src/instrumentation-client.{js,ts}",
+            "next-env.d.ts",
+        ]
+    }
+}
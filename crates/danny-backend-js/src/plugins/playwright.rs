@@ -0,0 +1,84 @@
+//! Playwright plugin.
+
+use super::config::{is_local_file, resolve_pattern, resolve_relative, values_for_key};
+use super::{PluginContribution, ToolPlugin};
+use std::path::Path;
+
+/// Playwright's default `testMatch`.
+const DEFAULT_TEST_MATCH: &str = "**/*.{spec,test}.{js,jsx,ts,tsx,mjs,cjs,mts,cts}";
+
+/// Playwright test runner.
+pub struct PlaywrightPlugin;
+
+impl ToolPlugin for PlaywrightPlugin {
+    fn name(&self) -> &'static str {
+        "Playwright"
+    }
+
+    fn enablers(&self) -> &'static [&'static str] {
+        &["@playwright/test", "playwright"]
+    }
+
+    fn config_files(&self) -> &'static [&'static str] {
+        &[
+            "playwright.config.ts",
+            "playwright.config.js",
+            "playwright.config.mts",
+            "playwright.config.mjs",
+            "playwright.config.cts",
+            "playwright.config.cjs",
+        ]
+    }
+
+    fn is_test_runner(&self) -> bool {
+        true
+    }
+
+    fn default_entry_patterns(&self) -> &'static [&'static str] {
+        &[DEFAULT_TEST_MATCH]
+    }
+
+    fn dependency_keys(&self) -> &'static [&'static str] {
+        &["reporter", "globalSetup", "globalTeardown"]
+    }
+
+    fn parse_config(&self, config_path: &Path, content: &str, out: &mut PluginContribution) {
+        let config_dir = config_path.parent().unwrap_or(Path::new(""));
+
+        for key in ["globalSetup", "globalTeardown"] {
+            for value in values_for_key(content, key) {
+                if is_local_file(&value) {
+                    out.entry_files.extend(resolve_relative(config_dir, &value));
+                }
+            }
+        }
+
+        // testMatch globs are relative to testDir (which defaults to the config dir)
+        let test_dirs = values_for_key(content, "testDir");
+        let test_matches: Vec<String> = values_for_key(content, "testMatch");
+
+        if test_dirs.is_empty() && test_matches.is_empty() {
+            return;
+        }
+
+        let test_dirs = if test_dirs.is_empty() {
+            vec![".".to_string()]
+        } else {
+            test_dirs
+        };
+        let test_matches = if test_matches.is_empty() {
+            vec![DEFAULT_TEST_MATCH.to_string()]
+        } else {
+            test_matches
+        };
+
+        for dir in &test_dirs {
+            let Some(dir) = resolve_relative(config_dir, dir) else {
+                continue;
+            };
+            for test_match in &test_matches {
+                out.entry_patterns.extend(resolve_pattern(&dir, test_match));
+            }
+        }
+    }
+}
@@ -0,0 +1,313 @@
+//! package.json `scripts` parsing.
+//!
+//! Scripts reference tools by binary name (`vitest`, `run-p`, `tsc`) and
+//! local files via runners (`node scripts/build.js`). Both are invisible to
+//! the import graph, so we extract them here.
+
+use super::config::{is_local_file, package_name};
+
+/// What a single script references.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ScriptUsage {
+    /// Binaries invoked by the script (`vitest`, `playwright`, ...)
+    pub binaries: Vec<String>,
+    /// Packages loaded through `--require`/`--import`/`--loader`
+    pub packages: Vec<String>,
+    /// Local files executed or loaded by the script, relative to the project root
+    pub files: Vec<String>,
+}
+
+/// Binaries that execute a script file passed as their first positional argument.
+const FILE_RUNNERS: &[&str] = &[
+    "node",
+    "tsx",
+    "ts-node",
+    "ts-node-esm",
+    "babel-node",
+    "esno",
+    "esr",
+    "vite-node",
+    "bun",
+    "deno",
+    "nodemon",
+];
+
+/// Wrappers that run the following command (`npx vitest`, `cross-env FOO=1 jest`).
+const COMMAND_WRAPPERS: &[&str] = &["npx", "pnpx", "bunx", "cross-env", "dotenv"];
+
+/// Flags whose value is a file or package loaded by the command.
+const LOADER_FLAGS: &[&str] = &[
+    "-r",
+    "--require",
+    "--import",
+    "--loader",
+    "--experimental-loader",
+];
+
+/// Flags whose value is a config file for the command.
+const CONFIG_FLAGS: &[&str] = &["-c", "--config"];
+
+/// Parses a package.json script command line.
+pub fn parse_script(script: &str) -> ScriptUsage {
+    let mut usage = ScriptUsage::default();
+
+    for command in split_commands(&tokenize(script)) {
+        parse_command(&command, &mut usage);
+    }
+
+    usage
+}
+
+/// Returns the package names that may provide `binary`.
+///
+/// Most packages expose a binary with their own name; the exceptions that
+/// matter in practice are listed explicitly.
+pub fn binary_packages(binary: &str) -> Vec<String> {
+    let known: &[&str] = match binary {
+        "tsc" | "tsserver" => &["typescript"],
+        "run-p" | "run-s" | "npm-run-all" => &["npm-run-all", "npm-run-all2"],
+        "playwright" => &["@playwright/test", "playwright"],
+        "storybook" | "start-storybook" | "build-storybook" | "sb" => {
+            &["storybook", "@storybook/cli"]
+        }
+        "webpack" => &["webpack", "webpack-cli"],
+        "vue-cli-service" => &["@vue/cli-service"],
+        "ng" => &["@angular/cli"],
+        "tsx" => &["tsx"],
+        "ts-node" | "ts-node-esm" => &["ts-node"],
+        "babel-node" => &["@babel/node"],
+        "esno" | "esr" => &["esno", "esbuild-runner"],
+        "dotenv" => &["dotenv-cli"],
+        "nodemon" => &["nodemon"],
+        _ => &[],
+    };
+
+    if known.is_empty() {
+        vec![binary.to_string()]
+    } else {
+        known.iter().map(|s| s.to_string()).collect()
+    }
+}
+
+/// Splits a script into shell-like tokens, honoring single and double quotes.
+fn tokenize(script: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut quote: Option<char> = None;
+    let mut chars = script.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => current.push(c),
+            None => match c {
+                '"' | '\'' => quote = Some(c),
+                c if c.is_whitespace() => {
+                    if !current.is_empty() {
+                        tokens.push(std::mem::take(&mut current));
+                    }
+                }
+                ';' | '|' | '&' => {
+                    if !current.is_empty() {
+                        tokens.push(std::mem::take(&mut current));
+                    }
+                    let mut op = c.to_string();
+                    if chars.peek() == Some(&c) {
+                        op.push(c);
+                        chars.next();
+                    }
+                    tokens.push(op);
+                }
+                _ => current.push(c),
+            },
+        }
+    }
+
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+
+    tokens
+}
+
+/// Splits tokens into individual commands on shell operators.
+fn split_commands(tokens: &[String]) -> Vec<Vec<&str>> {
+    tokens
+        .split(|t| matches!(t.as_str(), "&&" | "||" | ";" | "|" | "&"))
+        .map(|command| command.iter().map(String::as_str).collect::<Vec<_>>())
+        .filter(|command| !command.is_empty())
+        .collect()
+}
+
+fn parse_command(tokens: &[&str], usage: &mut ScriptUsage) {
+    let mut rest = skip_env_assignments(tokens);
+
+    // Unwrap package-manager and env wrappers to find the real binary
+    loop {
+        match rest.first().copied() {
+            Some(wrapper) if COMMAND_WRAPPERS.contains(&wrapper) => {
+                if !matches!(wrapper, "npx" | "pnpx" | "bunx") {
+                    usage.binaries.push(wrapper.to_string());
+                }
+                if wrapper == "dotenv" {
+                    // dotenv-cli: everything after `--` is the command
+                    rest = match rest.iter().position(|t| *t == "--") {
+                        Some(idx) => &rest[idx + 1..],
+                        None => return,
+                    };
+                } else {
+                    rest = skip_flags(&rest[1..]);
+                }
+                rest = skip_env_assignments(rest);
+            }
+            Some("npm" | "yarn" | "pnpm" | "bun") if rest.len() > 1 => match rest[1] {
+                "exec" | "dlx" | "x" => rest = skip_flags(&rest[2..]),
+                // `npm run build`, `yarn test` refer to other scripts
+                _ => return,
+            },
+            _ => break,
+        }
+    }
+
+    let Some(binary) = rest.first().copied() else {
+        return;
+    };
+
+    // Relative binaries are local files (`./scripts/release.sh`)
+    if binary.starts_with("./") || binary.starts_with("../") {
+        if is_local_file(binary) {
+            usage.files.push(normalize_file(binary));
+        }
+        return;
+    }
+
+    if binary != "node" {
+        usage.binaries.push(binary.to_string());
+    }
+
+    let args = &rest[1..];
+    let mut saw_positional = false;
+    let mut i = 0;
+
+    while i < args.len() {
+        let arg = args[i];
+
+        if let Some((flag, value)) = arg.split_once('=') {
+            if flag.starts_with('-') {
+                record_flag_value(flag, value, usage);
+                i += 1;
+                continue;
+            }
+        }
+
+        if LOADER_FLAGS.contains(&arg) || CONFIG_FLAGS.contains(&arg) {
+            if let Some(value) = args.get(i + 1) {
+                record_flag_value(arg, value, usage);
+            }
+            i += 2;
+            continue;
+        }
+
+        if arg.starts_with('-') {
+            i += 1;
+            continue;
+        }
+
+        // `node scripts/x.js`, `tsx src/seed.ts`: the first positional is the entry
+        if !saw_positional && FILE_RUNNERS.contains(&binary) {
+            saw_positional = true;
+            if binary == "deno" && arg == "run" {
+                saw_positional = false;
+            } else if is_local_file(arg) {
+                usage.files.push(normalize_file(arg));
+            }
+        }
+
+        i += 1;
+    }
+}
+
+fn record_flag_value(flag: &str, value: &str, usage: &mut ScriptUsage) {
+    if is_local_file(value) {
+        usage.files.push(normalize_file(value));
+    } else if LOADER_FLAGS.contains(&flag) {
+        if let Some(name) = package_name(value) {
+            usage.packages.push(name);
+        }
+    }
+}
+
+fn skip_env_assignments<'a>(tokens: &'a [&'a str]) -> &'a [&'a str] {
+    let count = tokens
+        .iter()
+        .take_while(|t| {
+            t.split_once('=').is_some_and(|(name, _)| {
+                !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+            })
+        })
+        .count();
+    &tokens[count..]
+}
+
+fn skip_flags<'a>(tokens: &'a [&'a str]) -> &'a [&'a str] {
+    let count = tokens.iter().take_while(|t| t.starts_with('-')).count();
+    &tokens[count..]
+}
+
+fn normalize_file(value: &str) -> String {
+    value.strip_prefix("./").unwrap_or(value).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_node_script_is_file_entry() {
+        let usage = parse_script("node scripts/build.js --watch");
+        assert_eq!(usage.files, vec!["scripts/build.js"]);
+        assert!(usage.binaries.is_empty());
+    }
+
+    #[test]
+    fn test_chained_commands() {
+        let usage = parse_script("vue-tsc --build && vite build; tsx ./src/seed.ts");
+        assert_eq!(usage.binaries, vec!["vue-tsc", "vite", "tsx"]);
+        assert_eq!(usage.files, vec!["src/seed.ts"]);
+    }
+
+    #[test]
+    fn test_wrappers_and_env_assignments() {
+        let usage =
+            parse_script("cross-env NODE_ENV=test npx --yes jest --config jest.ci.config.js");
+        assert_eq!(usage.binaries, vec!["cross-env", "jest"]);
+        assert_eq!(usage.files, vec!["jest.ci.config.js"]);
+    }
+
+    #[test]
+    fn test_script_references_are_ignored() {
+        let usage = parse_script("npm run build && yarn lint");
+        assert_eq!(usage, ScriptUsage::default());
+    }
+
+    #[test]
+    fn test_quoted_arguments() {
+        let usage = parse_script("run-p type-check \"build-only {@}\" --");
+        assert_eq!(usage.binaries, vec!["run-p"]);
+        assert!(usage.files.is_empty());
+    }
+
+    #[test]
+    fn test_require_flag_packages_and_files() {
+        let usage = parse_script("node -r dotenv/config --import=./register.mjs server.js");
+        assert_eq!(usage.packages, vec!["dotenv"]);
+        assert_eq!(usage.files, vec!["register.mjs", "server.js"]);
+    }
+
+    #[test]
+    fn test_binary_packages() {
+        assert_eq!(binary_packages("tsc"), vec!["typescript"]);
+        assert_eq!(binary_packages("vitest"), vec!["vitest"]);
+        assert!(binary_packages("run-s").contains(&"npm-run-all2".to_string()));
+    }
+}
@@ -0,0 +1,82 @@
+//! Storybook plugin.
+
+use super::config::{resolve_pattern, resolve_relative, values_for_key};
+use super::{PluginContribution, ToolPlugin};
+use std::collections::BTreeSet;
+use std::path::Path;
+
+/// Storybook's default story file extensions.
+const STORY_EXTENSIONS: &str = "{js,jsx,ts,tsx,mjs,cjs,mts,cts}";
+
+/// Storybook component explorer.
+pub struct StorybookPlugin;
+
+impl ToolPlugin for StorybookPlugin {
+    fn name(&self) -> &'static str {
+        "Storybook"
+    }
+
+    fn enablers(&self) -> &'static [&'static str] {
+        &["storybook"]
+    }
+
+    fn is_enabled(&self, dependencies: &BTreeSet<String>) -> bool {
+        dependencies
+            .iter()
+            .any(|d| d == "storybook" || d.starts_with("@storybook/"))
+    }
+
+    fn config_files(&self) -> &'static [&'static str] {
+        &[
+            ".storybook/main.js",
+            ".storybook/main.ts",
+            ".storybook/main.mjs",
+            ".storybook/main.cjs",
+            ".storybook/main.mts",
+            ".storybook/preview.js",
+            ".storybook/preview.jsx",
+            ".storybook/preview.ts",
+            ".storybook/preview.tsx",
+            ".storybook/preview.mjs",
+            ".storybook/manager.js",
+            ".storybook/manager.ts",
+            ".storybook/manager.mjs",
+        ]
+    }
+
+    fn default_entry_patterns(&self) -> &'static [&'static str] {
+        &["**/*.stories.{js,jsx,ts,tsx,mjs,cjs,mts,cts}"]
+    }
+
+    fn dependency_keys(&self) -> &'static [&'static str] {
+        // `framework: { name: '@storybook/react-vite' }` is covered by `framework`
+        &["addons", "framework", "builder", "renderer"]
+    }
+
+    fn parse_config(&self, config_path: &Path, content: &str, out: &mut PluginContribution) {
+        let config_dir = config_path.parent().unwrap_or(Path::new(""));
+
+        for story in values_for_key(content, "stories") {
+            if story.contains('*') {
+                // `{ directory, files }` specifiers list bare file globs; the
+                // directory entry below already covers them
+                if story.contains('/') {
+                    out.entry_patterns
+                        .extend(resolve_pattern(config_dir, &story));
+                }
+            } else if let Some(dir) = resolve_relative(config_dir, &story) {
+                // Directory specifier: Storybook loads every story beneath it
+                let dir = dir.to_string_lossy();
+                let prefix = if dir.is_empty() {
+                    String::new()
+                } else {
+                    format!("{}/", dir)
+                };
+                out.entry_patterns.push(format!(
+                    "{}**/*.{{stories,story}}.{}",
+                    prefix, STORY_EXTENSIONS
+                ));
+            }
+        }
+    }
+}
@@ -0,0 +1,42 @@
+//! Vite plugin.
+
+use super::config::{is_local_file, resolve_relative, values_for_key};
+use super::{PluginContribution, ToolPlugin};
+use std::path::Path;
+
+/// Vite build tool.
+pub struct VitePlugin;
+
+impl ToolPlugin for VitePlugin {
+    fn name(&self) -> &'static str {
+        "Vite"
+    }
+
+    fn enablers(&self) -> &'static [&'static str] {
+        &["vite"]
+    }
+
+    fn config_files(&self) -> &'static [&'static str] {
+        &[
+            "vite.config.ts",
+            "vite.config.js",
+            "vite.config.mts",
+            "vite.config.mjs",
+            "vite.config.cts",
+            "vite.config.cjs",
+        ]
+    }
+
+    fn parse_config(&self, config_path: &Path, content: &str, out: &mut PluginContribution) {
+        let config_dir = config_path.parent().unwrap_or(Path::new(""));
+
+        // build.rollupOptions.input and build.lib.entry
+        for key in ["input", "entry"] {
+            for value in values_for_key(content, key) {
+                if is_local_file(&value) {
+                    out.entry_files.extend(resolve_relative(config_dir, &value));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,82 @@
+//! Vitest plugin.
+
+use super::config::{
+    is_local_file, package_name, resolve_pattern, resolve_relative, values_for_key, without_key,
+};
+use super::{PluginContribution, ToolPlugin};
+use std::path::Path;
+
+/// Vitest test runner.
+pub struct VitestPlugin;
+
+impl ToolPlugin for VitestPlugin {
+    fn name(&self) -> &'static str {
+        "Vitest"
+    }
+
+    fn enablers(&self) -> &'static [&'static str] {
+        &["vitest"]
+    }
+
+    fn config_files(&self) -> &'static [&'static str] {
+        &[
+            "vitest.config.ts",
+            "vitest.config.js",
+            "vitest.config.mts",
+            "vitest.config.mjs",
+            "vitest.config.cts",
+            "vitest.config.cjs",
+            "vitest.workspace.ts",
+            "vitest.workspace.js",
+            "vitest.workspace.json",
+        ]
+    }
+
+    fn is_test_runner(&self) -> bool {
+        true
+    }
+
+    fn default_entry_patterns(&self) -> &'static [&'static str] {
+        &["**/*.{test,spec}.{js,jsx,ts,tsx,mjs,cjs,mts,cts}"]
+    }
+
+    fn dependency_keys(&self) -> &'static [&'static str] {
+        &[
+            "environment",
+            "provider",
+            "reporters",
+            "setupFiles",
+            "globalSetup",
+        ]
+    }
+
+    fn parse_config(&self, config_path: &Path, content: &str, out: &mut PluginContribution) {
+        let config_dir = config_path.parent().unwrap_or(Path::new(""));
+
+        for key in ["setupFiles", "globalSetup"] {
+            for value in values_for_key(content, key) {
+                if is_local_file(&value) {
+                    out.entry_files.extend(resolve_relative(config_dir, &value));
+                }
+            }
+        }
+
+        // `coverage.include` lists source files, not tests
+        let test_section = without_key(content, "coverage");
+        for pattern in values_for_key(&test_section, "include") {
+            // `optimizeDeps.include` in merged Vite configs lists package names
+            if pattern.contains('*') || is_local_file(&pattern) {
+                out.entry_patterns
+                    .extend(resolve_pattern(config_dir, &pattern));
+            }
+        }
+    }
+
+    fn package_candidates(&self, specifier: &str) -> Vec<String> {
+        match specifier {
+            // `coverage: { provider: 'v8' }`
+            "v8" | "istanbul" => vec![format!("@vitest/coverage-{}", specifier)],
+            _ => package_name(specifier).into_iter().collect(),
+        }
+    }
+}
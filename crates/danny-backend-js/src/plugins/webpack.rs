@@ -0,0 +1,44 @@
+//! webpack plugin.
+
+use super::config::{is_local_file, resolve_relative, values_for_key};
+use super::{PluginContribution, ToolPlugin};
+use std::path::Path;
+
+/// webpack bundler.
+pub struct WebpackPlugin;
+
+impl ToolPlugin for WebpackPlugin {
+    fn name(&self) -> &'static str {
+        "webpack"
+    }
+
+    fn enablers(&self) -> &'static [&'static str] {
+        &["webpack", "webpack-cli"]
+    }
+
+    fn config_files(&self) -> &'static [&'static str] {
+        &[
+            "webpack.config.js",
+            "webpack.config.ts",
+            "webpack.config.mjs",
+            "webpack.config.cjs",
+        ]
+    }
+
+    fn dependency_keys(&self) -> &'static [&'static str] {
+        // `loader: 'babel-loader'`, `use: ['style-loader', 'css-loader']`
+        &["loader", "use"]
+    }
+
+    fn parse_config(&self, config_path: &Path, content: &str, out: &mut PluginContribution) {
+        let config_dir = config_path.parent().unwrap_or(Path::new(""));
+
+        // `entry: './src/index.js'`, `entry: { app: ['./src/app.js'] }`,
+        // `entry: path.resolve(__dirname, 'src/index.js')`
+        for value in values_for_key(content, "entry") {
+            if is_local_file(&value) {
+                out.entry_files.extend(resolve_relative(config_dir, &value));
+            }
+        }
+    }
+}
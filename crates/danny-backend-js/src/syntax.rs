@@ -0,0 +1,71 @@
+//! Source parsing for analyses that look inside declarations.
+//!
+//! Fob's module graph stops at module-level symbols. Analyzers that need
+//! the syntax tree itself (type members, literals, call chains) parse the
+//! module source with oxc through these helpers.
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::Program;
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use std::path::Path;
+
+/// Parses `source` and hands the program to `f`.
+///
+/// Returns `None` for files oxc can't parse as JavaScript/TypeScript
+/// (unknown extensions, `.vue`/`.svelte` components) or when the parser
+/// gives up. Recoverable syntax errors still produce a program.
+pub fn with_program<R>(path: &Path, source: &str, f: impl FnOnce(&Program<'_>) -> R) -> Option<R> {
+    let source_type = SourceType::from_path(path).ok()?;
+    let allocator = Allocator::default();
+    let parsed = Parser::new(&allocator, source, source_type).parse();
+
+    if parsed.panicked {
+        return None;
+    }
+
+    Some(f(&parsed.program))
+}
+
+/// Maps byte offsets to line and column numbers.
+#[derive(Debug, Clone)]
+pub struct LineIndex {
+    line_starts: Vec<u32>,
+}
+
+impl LineIndex {
+    /// Builds the index for `source`.
+    pub fn new(source: &str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(
+                source
+                    .bytes()
+                    .enumerate()
+                    .filter(|(_, b)| *b == b'\n')
+                    .map(|(i, _)| i as u32 + 1),
+            )
+            .collect();
+        Self { line_starts }
+    }
+
+    /// Returns the 1-indexed line and 0-indexed column of `offset`.
+    pub fn line_col(&self, offset: u32) -> (u32, u32) {
+        let line = self.line_starts.partition_point(|&start| start <= offset) - 1;
+        (line as u32 + 1, offset - self.line_starts[line])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_index() {
+        let index = LineIndex::new("a\nbc\n\nd");
+        assert_eq!(index.line_col(0), (1, 0));
+        assert_eq!(index.line_col(2), (2, 0));
+        assert_eq!(index.line_col(3), (2, 1));
+        assert_eq!(index.line_col(5), (3, 0));
+        assert_eq!(index.line_col(6), (4, 0));
+    }
+}
@@ -21,6 +21,10 @@ pub struct DannyConfig {
     /// Code quality configuration.
     #[serde(default)]
     pub quality: CodeQualityConfig,
+
+    /// Test file classification.
+    #[serde(default)]
+    pub tests: TestConfig,
 }
 
 /// Configuration for a specific framework.
@@ -83,6 +87,26 @@ pub struct AnalysisConfig {
     pub workers: Option<usize>,
 }
 
+/// Test file classification configuration.
+///
+/// Files matching these globs (plus files loaded by detected test runners)
+/// are treated as tests: code reachable only from them is reported as used
+/// only in tests.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TestConfig {
+    /// Glob patterns for test files, relative to the project root.
+    #[serde(default = "default_test_patterns")]
+    pub patterns: Vec<String>,
+}
+
+impl Default for TestConfig {
+    fn default() -> Self {
+        Self {
+            patterns: default_test_patterns(),
+        }
+    }
+}
+
 /// Code quality analysis configuration.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct CodeQualityConfig {
@@ -121,6 +145,73 @@ pub struct CodeQualityConfig {
     /// Maximum return statements before flagging.
     #[serde(default = "default_max_return_count")]
     pub max_return_count: usize,
+
+    /// Treat catch blocks that contain only a comment as intentional.
+    ///
+    /// By default `catch (e) { /* ignore */ }` is reported like an empty block.
+    #[serde(default)]
+    pub allow_commented_catch: bool,
+
+    /// Maximum LCOM4 (groups of methods sharing no fields or calls) before
+    /// flagging a class as having low cohesion.
+    #[serde(default = "default_max_lcom")]
+    pub max_lcom: usize,
+
+    /// Minimum instance methods a class needs before cohesion is measured.
+    #[serde(default = "default_min_cohesion_methods")]
+    pub min_cohesion_methods: usize,
+
+    /// Maximum cognitive complexity of a function before flagging.
+    #[serde(default = "default_max_cognitive_complexity")]
+    pub max_cognitive_complexity: usize,
+
+    /// Modules with a maintainability index (0-100) below this are flagged.
+    #[serde(default = "default_min_maintainability_index")]
+    pub min_maintainability_index: f64,
+
+    /// Minimum length in tokens of a block reported as duplicated code.
+    #[serde(default = "default_min_duplicate_tokens")]
+    pub min_duplicate_tokens: usize,
+
+    /// Minimum number of lines a duplicated block must span.
+    #[serde(default = "default_min_duplicate_lines")]
+    pub min_duplicate_lines: usize,
+
+    /// Magic number detection settings.
+    #[serde(default)]
+    pub magic_numbers: MagicNumberConfig,
+}
+
+/// Magic number detection settings (`[quality.magic_numbers]`).
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MagicNumberConfig {
+    /// Numeric literals that are never reported.
+    #[serde(default = "default_allowed_magic_numbers")]
+    pub allowed: Vec<f64>,
+
+    /// Skip integer literals used as array indices (`items[3]`).
+    #[serde(default = "default_true")]
+    pub ignore_array_indexes: bool,
+
+    /// Skip enum member initializers (`enum Port { Http = 80 }`).
+    #[serde(default = "default_true")]
+    pub ignore_enums: bool,
+
+    /// Skip literals that name a constant (`const TIMEOUT_MS = 5000`,
+    /// `static readonly MAX = 10`).
+    #[serde(default = "default_true")]
+    pub ignore_const_declarations: bool,
+}
+
+impl Default for MagicNumberConfig {
+    fn default() -> Self {
+        Self {
+            allowed: default_allowed_magic_numbers(),
+            ignore_array_indexes: true,
+            ignore_enums: true,
+            ignore_const_declarations: true,
+        }
+    }
 }
 
 impl Default for CodeQualityConfig {
@@ -135,6 +226,14 @@ impl Default for CodeQualityConfig {
             max_methods: 20,
             max_fields: 10,
             max_return_count: 3,
+            allow_commented_catch: false,
+            max_lcom: 1,
+            min_cohesion_methods: 3,
+            max_cognitive_complexity: 15,
+            min_maintainability_index: 20.0,
+            min_duplicate_tokens: 50,
+            min_duplicate_lines: 5,
+            magic_numbers: MagicNumberConfig::default(),
         }
     }
 }
@@ -244,6 +343,40 @@ impl CodeQualityConfig {
             return Err("max_return_count unreasonably large (>20)".to_string());
         }
 
+        // Validate max_lcom
+        if self.max_lcom == 0 {
+            return Err("max_lcom must be > 0".to_string());
+        }
+        if self.max_lcom > 50 {
+            return Err("max_lcom unreasonably large (>50)".to_string());
+        }
+
+        // Validate min_cohesion_methods
+        if self.min_cohesion_methods == 0 {
+            return Err("min_cohesion_methods must be > 0".to_string());
+        }
+
+        // Validate max_cognitive_complexity
+        if self.max_cognitive_complexity == 0 {
+            return Err("max_cognitive_complexity must be > 0".to_string());
+        }
+        if self.max_cognitive_complexity > 100 {
+            return Err("max_cognitive_complexity unreasonably large (>100)".to_string());
+        }
+
+        // Validate min_maintainability_index
+        if !(0.0..=100.0).contains(&self.min_maintainability_index) {
+            return Err("min_maintainability_index must be between 0 and 100".to_string());
+        }
+
+        // Validate duplicate thresholds
+        if self.min_duplicate_tokens == 0 {
+            return Err("min_duplicate_tokens must be > 0".to_string());
+        }
+        if self.min_duplicate_lines == 0 {
+            return Err("min_duplicate_lines must be > 0".to_string());
+        }
+
         Ok(())
     }
 }
@@ -285,6 +418,47 @@ fn default_max_return_count() -> usize {
     3
 }
 
+fn default_max_lcom() -> usize {
+    1
+}
+
+fn default_min_cohesion_methods() -> usize {
+    3
+}
+
+fn default_max_cognitive_complexity() -> usize {
+    15
+}
+
+fn default_min_maintainability_index() -> f64 {
+    20.0
+}
+
+fn default_min_duplicate_tokens() -> usize {
+    50
+}
+
+fn default_min_duplicate_lines() -> usize {
+    5
+}
+
+fn default_allowed_magic_numbers() -> Vec<f64> {
+    vec![-1.0, 0.0, 1.0, 2.0]
+}
+
+fn default_test_patterns() -> Vec<String> {
+    [
+        "**/*.{test,spec}.{js,jsx,ts,tsx,mjs,cjs,mts,cts}",
+        "**/__tests__/**",
+        "**/__mocks__/**",
+        "**/test/**",
+        "**/tests/**",
+    ]
+    .iter()
+    .map(|p| p.to_string())
+    .collect()
+}
+
 // Helper functions for serde defaults
 fn default_true() -> bool {
     true
@@ -358,6 +532,47 @@ mod tests {
         assert!(react.entry_patterns.is_empty());
     }
 
+    #[test]
+    fn test_test_patterns() {
+        let config: DannyConfig = toml::from_str("").unwrap();
+        assert!(config
+            .tests
+            .patterns
+            .contains(&"**/__tests__/**".to_string()));
+
+        let config: DannyConfig = toml::from_str(
+            r#"
+            [tests]
+            patterns = ["spec/**/*.ts"]
+        "#,
+        )
+        .unwrap();
+        assert_eq!(config.tests.patterns, vec!["spec/**/*.ts"]);
+    }
+
+    #[test]
+    fn test_magic_number_config() {
+        let config: DannyConfig = toml::from_str("").unwrap();
+        assert_eq!(
+            config.quality.magic_numbers.allowed,
+            vec![-1.0, 0.0, 1.0, 2.0]
+        );
+        assert!(config.quality.magic_numbers.ignore_enums);
+
+        let config: DannyConfig = toml::from_str(
+            r#"
+            [quality.magic_numbers]
+            allowed = [0, 100]
+            ignore_array_indexes = false
+        "#,
+        )
+        .unwrap();
+        let magic = &config.quality.magic_numbers;
+        assert_eq!(magic.allowed, vec![0.0, 100.0]);
+        assert!(!magic.ignore_array_indexes);
+        assert!(magic.ignore_const_declarations);
+    }
+
     /// Test that default quality configuration passes validation.
     #[test]
     fn test_quality_config_default_validation() {
@@ -425,6 +640,38 @@ mod tests {
             config.validate().unwrap_err(),
             "max_return_count must be > 0"
         );
+
+        config = CodeQualityConfig::default();
+        config.max_lcom = 0;
+        assert_eq!(config.validate().unwrap_err(), "max_lcom must be > 0");
+
+        config = CodeQualityConfig::default();
+        config.min_cohesion_methods = 0;
+        assert_eq!(
+            config.validate().unwrap_err(),
+            "min_cohesion_methods must be > 0"
+        );
+
+        config = CodeQualityConfig::default();
+        config.max_cognitive_complexity = 0;
+        assert_eq!(
+            config.validate().unwrap_err(),
+            "max_cognitive_complexity must be > 0"
+        );
+
+        config = CodeQualityConfig::default();
+        config.min_duplicate_tokens = 0;
+        assert_eq!(
+            config.validate().unwrap_err(),
+            "min_duplicate_tokens must be > 0"
+        );
+
+        config = CodeQualityConfig::default();
+        config.min_duplicate_lines = 0;
+        assert_eq!(
+            config.validate().unwrap_err(),
+            "min_duplicate_lines must be > 0"
+        );
     }
 
     /// Test validation catches unreasonably large values.
@@ -502,6 +749,20 @@ mod tests {
             .validate()
             .unwrap_err()
             .contains("max_return_count unreasonably large"));
+
+        config = CodeQualityConfig::default();
+        config.max_cognitive_complexity = 101;
+        assert!(config
+            .validate()
+            .unwrap_err()
+            .contains("max_cognitive_complexity unreasonably large"));
+
+        config = CodeQualityConfig::default();
+        config.min_maintainability_index = 120.0;
+        assert_eq!(
+            config.validate().unwrap_err(),
+            "min_maintainability_index must be between 0 and 100"
+        );
     }
 
     /// Test that reasonable custom values pass validation.
@@ -517,6 +778,7 @@ mod tests {
             max_methods: 30,
             max_fields: 15,
             max_return_count: 5,
+            ..Default::default()
         };
 
         assert!(config.validate().is_ok());
@@ -536,6 +798,7 @@ mod tests {
             max_methods: 200,
             max_fields: 100,
             max_return_count: 20,
+            ..Default::default()
         };
         assert!(config.validate().is_ok());
 
@@ -550,6 +813,7 @@ mod tests {
             max_methods: 1,
             max_fields: 1,
             max_return_count: 1,
+            ..Default::default()
         };
         assert!(config.validate().is_ok());
     }
@@ -3,12 +3,18 @@
 //! Tests the new analysis features:
 //! - Class member detection (private vs public)
 //! - Enum member detection
+//! - Interface and type alias member detection
 //! - NPM dependency analysis
 //! - Import pattern analysis
 //! - Dead code module detection
+//! - Barrel re-export analysis
+//! - Project rule files and rule matches
 
 use danny_backend_js::JsBackend;
-use danny_core::{AnalysisOptions, Finding, LanguageBackend};
+use danny_core::{
+    AnalysisOptions, ExportOutcome, Finding, LanguageBackend, RuleSeverity,
+    UnlistedDependencyReason,
+};
 use std::collections::HashMap;
 use tempfile::TempDir;
 
@@ -119,6 +125,77 @@ fn test_enum_member_analysis() {
     assert!(result.statistics.enum_stats.is_some());
 }
 
+#[test]
+fn test_type_member_analysis() {
+    let backend = JsBackend::new().unwrap();
+
+    let temp_dir = TempDir::new().unwrap();
+    let project_root = temp_dir.path();
+
+    std::fs::write(
+        project_root.join("dto.ts"),
+        r#"
+        export interface UserDto {
+            id: string;
+            name: string;
+            legacyId: number;
+        }
+
+        export type Settings = { theme: string; beta: boolean };
+        "#,
+    )
+    .unwrap();
+
+    let entry = project_root.join("index.ts");
+    std::fs::write(
+        &entry,
+        r#"
+        import type { UserDto, Settings } from './dto';
+
+        export function describe(user: UserDto, settings: Settings) {
+            const { id } = user;
+            return `${id}: ${user.name} (${settings.theme})`;
+        }
+        "#,
+    )
+    .unwrap();
+
+    let mut backend_options = HashMap::new();
+    backend_options.insert("detect_type_members".to_string(), serde_json::json!(true));
+
+    let options = AnalysisOptions {
+        entry_points: vec![entry],
+        project_root: project_root.to_path_buf(),
+        backend_options,
+        ..Default::default()
+    };
+
+    let result = backend.analyze(options).unwrap();
+
+    let mut unused: Vec<(String, String)> = result
+        .findings
+        .iter()
+        .filter_map(|f| match f {
+            Finding::UnusedTypeMember {
+                type_name,
+                member_name,
+                ..
+            } => Some((type_name.clone(), member_name.clone())),
+            _ => None,
+        })
+        .collect();
+    unused.sort();
+
+    assert_eq!(
+        unused,
+        vec![
+            ("Settings".to_string(), "beta".to_string()),
+            ("UserDto".to_string(), "legacyId".to_string()),
+        ]
+    );
+    assert_eq!(result.statistics.unused_type_members_count, 2);
+}
+
 #[test]
 fn test_npm_dependency_analysis() {
     let backend = JsBackend::new().unwrap();
@@ -184,6 +261,78 @@ fn test_npm_dependency_analysis() {
     assert!(result.statistics.dependency_coverage_stats.is_some());
 }
 
+#[test]
+fn test_unlisted_npm_dependency_detection() {
+    let backend = JsBackend::new().unwrap();
+
+    let temp_dir = TempDir::new().unwrap();
+    let project_root = temp_dir.path();
+
+    std::fs::write(
+        project_root.join("package.json"),
+        r#"
+        {
+            "name": "test",
+            "version": "1.0.0",
+            "dependencies": { "react": "^18.0.0" },
+            "devDependencies": { "chalk": "^5.0.0" }
+        }
+        "#,
+    )
+    .unwrap();
+
+    let test_file = project_root.join("test.ts");
+    std::fs::write(
+        &test_file,
+        r#"
+        import React from 'react';
+        import chalk from 'chalk';
+        import debounce from 'lodash/debounce';
+        import { readFileSync } from 'node:fs';
+        console.log(React, chalk, debounce, readFileSync);
+        "#,
+    )
+    .unwrap();
+
+    let mut backend_options = HashMap::new();
+    backend_options.insert(
+        "detect_npm_dependencies".to_string(),
+        serde_json::json!(true),
+    );
+
+    let options = AnalysisOptions {
+        entry_points: vec![test_file.clone()],
+        project_root: project_root.to_path_buf(),
+        backend_options,
+        ..Default::default()
+    };
+
+    let result = backend.analyze(options).unwrap();
+
+    let unlisted: Vec<_> = result
+        .findings
+        .iter()
+        .filter_map(|f| match f {
+            Finding::UnlistedNpmDependency {
+                package,
+                reason,
+                locations,
+                ..
+            } => Some((package.as_str(), reason.clone(), locations.len())),
+            _ => None,
+        })
+        .collect();
+
+    assert!(unlisted.contains(&("lodash", UnlistedDependencyReason::NotDeclared, 1)));
+    assert!(unlisted.contains(&(
+        "chalk",
+        UnlistedDependencyReason::DevDependencyInProduction,
+        1
+    )));
+    assert_eq!(unlisted.len(), 2);
+    assert_eq!(result.statistics.unlisted_npm_dependencies_count, 2);
+}
+
 #[test]
 fn test_import_pattern_analysis() {
     let backend = JsBackend::new().unwrap();
@@ -515,3 +664,454 @@ fn test_dependency_chain_analysis() {
         "Statistics should track dependency chains"
     );
 }
+
+#[test]
+fn test_barrel_re_export_analysis() {
+    let backend = JsBackend::new().unwrap();
+
+    let temp_dir = TempDir::new().unwrap();
+    let project_root = temp_dir.path();
+    std::fs::create_dir_all(project_root.join("components")).unwrap();
+
+    std::fs::write(
+        project_root.join("app.ts"),
+        "import { Button } from './components';\nconsole.log(Button);",
+    )
+    .unwrap();
+    std::fs::write(
+        project_root.join("components/index.ts"),
+        "export { Button } from './Button';\nexport { Card } from './Card';",
+    )
+    .unwrap();
+    std::fs::write(
+        project_root.join("components/Button.ts"),
+        "export const Button = 'button';",
+    )
+    .unwrap();
+    std::fs::write(
+        project_root.join("components/Card.ts"),
+        "export const Card = 'card';",
+    )
+    .unwrap();
+
+    let mut backend_options = HashMap::new();
+    backend_options.insert("detect_barrels".to_string(), serde_json::json!(true));
+
+    let options = AnalysisOptions {
+        entry_points: vec![project_root.join("app.ts")],
+        project_root: project_root.to_path_buf(),
+        backend_options,
+        ..Default::default()
+    };
+
+    let result = backend.analyze(options).unwrap();
+
+    let unused_re_exports: Vec<_> = result
+        .findings
+        .iter()
+        .filter_map(|f| match f {
+            Finding::UnusedReExport {
+                export_name,
+                origin,
+                ..
+            } => Some((export_name.clone(), origin.clone())),
+            _ => None,
+        })
+        .collect();
+
+    assert_eq!(unused_re_exports.len(), 1);
+    let (export_name, origin) = &unused_re_exports[0];
+    assert_eq!(export_name, "Card");
+
+    // The finding points at the declaration that should be deleted
+    let origin = origin
+        .as_ref()
+        .expect("re-export should resolve to Card.ts");
+    assert!(origin.module.ends_with("components/Card.ts"));
+    assert!(!origin.is_used);
+
+    // The barrel's own unused export finding is replaced
+    assert!(!result.findings.iter().any(|f| matches!(
+        f,
+        Finding::UnusedExport { module, export_name, .. }
+            if module.ends_with("index.ts") && export_name == "Card"
+    )));
+    assert_eq!(result.statistics.unused_re_exports_count, 1);
+}
+
+#[test]
+fn test_project_rules_mark_exports_used() {
+    let backend = JsBackend::new().unwrap();
+
+    let temp_dir = TempDir::new().unwrap();
+    let project_root = temp_dir.path();
+    std::fs::create_dir_all(project_root.join(".danny/rules")).unwrap();
+
+    std::fs::write(
+        project_root.join(".danny/rules/router.toml"),
+        r#"
+[framework]
+name = "Router"
+
+[[rules]]
+name = "route-loaders"
+
+[rules.match]
+export_pattern = "^loader$"
+
+[rules.action]
+mark_used = true
+"#,
+    )
+    .unwrap();
+    std::fs::write(
+        project_root.join("app.ts"),
+        "import { Page } from './route';\nconsole.log(Page);",
+    )
+    .unwrap();
+    std::fs::write(
+        project_root.join("route.ts"),
+        "export const Page = 'page';\nexport function loader() { return {}; }",
+    )
+    .unwrap();
+
+    let options = AnalysisOptions {
+        entry_points: vec![project_root.join("app.ts")],
+        project_root: project_root.to_path_buf(),
+        ..Default::default()
+    };
+
+    let result = backend.analyze(options).unwrap();
+
+    let framework_export = result
+        .findings
+        .iter()
+        .find_map(|f| match f {
+            Finding::FrameworkExport {
+                export_name,
+                framework,
+                rule,
+                ..
+            } if export_name == "loader" => Some((framework.clone(), rule.clone())),
+            _ => None,
+        })
+        .expect("loader should be marked used by the project rule");
+
+    assert_eq!(framework_export.0, "Router");
+    assert_eq!(framework_export.1, "route-loaders");
+    assert!(!result.findings.iter().any(|f| matches!(
+        f,
+        Finding::UnusedExport { export_name, .. } if export_name == "loader"
+    )));
+}
+
+#[test]
+fn test_project_rules_report_matches() {
+    let backend = JsBackend::new().unwrap();
+
+    let temp_dir = TempDir::new().unwrap();
+    let project_root = temp_dir.path();
+    std::fs::create_dir_all(project_root.join(".danny/rules")).unwrap();
+
+    std::fs::write(
+        project_root.join(".danny/rules/policy.toml"),
+        r#"
+[[rules]]
+name = "no-moment"
+
+[rules.match]
+import_from = "moment"
+
+[rules.action]
+severity = "error"
+message = "Use date-fns instead of moment"
+"#,
+    )
+    .unwrap();
+    std::fs::write(
+        project_root.join("app.ts"),
+        "import moment from 'moment';\nconsole.log(moment());",
+    )
+    .unwrap();
+
+    let options = AnalysisOptions {
+        entry_points: vec![project_root.join("app.ts")],
+        project_root: project_root.to_path_buf(),
+        ..Default::default()
+    };
+
+    let result = backend.analyze(options).unwrap();
+
+    let rule_matches: Vec<_> = result
+        .findings
+        .iter()
+        .filter_map(|f| match f {
+            Finding::RuleMatch {
+                rule,
+                message,
+                severity,
+                module,
+                span,
+                ..
+            } => Some((rule, message, *severity, module, span)),
+            _ => None,
+        })
+        .collect();
+
+    assert_eq!(rule_matches.len(), 1);
+    let (rule, message, severity, module, span) = rule_matches[0];
+    assert_eq!(rule, "no-moment");
+    assert_eq!(message, "Use date-fns instead of moment");
+    assert_eq!(severity, RuleSeverity::Error);
+    assert!(module.ends_with("app.ts"));
+    assert!(span.is_some());
+}
+
+#[test]
+fn test_explain_rules_reports_every_rule() {
+    let backend = JsBackend::new().unwrap();
+
+    let temp_dir = TempDir::new().unwrap();
+    let project_root = temp_dir.path();
+    std::fs::create_dir_all(project_root.join(".danny/rules")).unwrap();
+
+    std::fs::write(
+        project_root.join(".danny/rules/routes.toml"),
+        r#"
+[[rules]]
+name = "route-loaders"
+
+[rules.match]
+path_starts_with = ["routes/"]
+export_name = ["loader"]
+
+[rules.action]
+mark_used = true
+"#,
+    )
+    .unwrap();
+    std::fs::write(
+        project_root.join("app.ts"),
+        "import { helper } from './utils';\nconsole.log(helper);",
+    )
+    .unwrap();
+    std::fs::write(
+        project_root.join("utils.ts"),
+        "export const helper = 1;\nexport const loader = 2;",
+    )
+    .unwrap();
+
+    let mut backend_options = HashMap::new();
+    backend_options.insert(
+        "explain_rules".to_string(),
+        serde_json::json!(["utils.ts#loader"]),
+    );
+
+    let options = AnalysisOptions {
+        entry_points: vec![project_root.join("app.ts")],
+        project_root: project_root.to_path_buf(),
+        backend_options,
+        ..Default::default()
+    };
+
+    let result = backend.analyze(options).unwrap();
+
+    assert_eq!(result.rule_explanations.len(), 1);
+    let explanation = &result.rule_explanations[0];
+    assert_eq!(explanation.export_name, "loader");
+    assert_eq!(explanation.outcome, ExportOutcome::Unused);
+
+    let check = explanation
+        .rules
+        .iter()
+        .find(|check| check.rule == "route-loaders")
+        .expect("project rule should be explained");
+    assert!(!check.matched);
+    assert_eq!(check.failed_condition.as_deref(), Some("path_starts_with"));
+    assert_eq!(check.action, "mark used");
+
+    // Built-in rules are listed too
+    assert!(explanation.rules.len() > 1);
+}
+
+#[test]
+fn test_explain_rules_prefers_project_relative_path() {
+    let backend = JsBackend::new().unwrap();
+
+    let temp_dir = TempDir::new().unwrap();
+    let project_root = temp_dir.path();
+    std::fs::create_dir_all(project_root.join("lib")).unwrap();
+
+    std::fs::write(
+        project_root.join("app.ts"),
+        "import { helper } from './utils';\nimport { other } from './lib/utils';\nconsole.log(helper, other);",
+    )
+    .unwrap();
+    std::fs::write(
+        project_root.join("utils.ts"),
+        "export const helper = 1;\nexport const loader = 2;",
+    )
+    .unwrap();
+    std::fs::write(
+        project_root.join("lib/utils.ts"),
+        "export const other = 1;\nexport const loader = 2;",
+    )
+    .unwrap();
+
+    let mut backend_options = HashMap::new();
+    backend_options.insert(
+        "explain_rules".to_string(),
+        serde_json::json!(["utils.ts#loader"]),
+    );
+
+    let options = AnalysisOptions {
+        entry_points: vec![project_root.join("app.ts")],
+        project_root: project_root.to_path_buf(),
+        backend_options,
+        ..Default::default()
+    };
+
+    let result = backend.analyze(options).unwrap();
+
+    assert_eq!(result.rule_explanations.len(), 1);
+    let module = &result.rule_explanations[0].module;
+    assert!(module.ends_with("utils.ts"));
+    assert!(!module.ends_with("lib/utils.ts"));
+}
+
+#[test]
+fn test_explain_rules_rejects_ambiguous_suffix() {
+    let backend = JsBackend::new().unwrap();
+
+    let temp_dir = TempDir::new().unwrap();
+    let project_root = temp_dir.path();
+    std::fs::create_dir_all(project_root.join("a")).unwrap();
+    std::fs::create_dir_all(project_root.join("b")).unwrap();
+
+    std::fs::write(
+        project_root.join("app.ts"),
+        "import { one } from './a/utils';\nimport { two } from './b/utils';\nconsole.log(one, two);",
+    )
+    .unwrap();
+    std::fs::write(project_root.join("a/utils.ts"), "export const one = 1;").unwrap();
+    std::fs::write(project_root.join("b/utils.ts"), "export const two = 2;").unwrap();
+
+    let mut backend_options = HashMap::new();
+    backend_options.insert(
+        "explain_rules".to_string(),
+        serde_json::json!(["utils.ts#one"]),
+    );
+
+    let options = AnalysisOptions {
+        entry_points: vec![project_root.join("app.ts")],
+        project_root: project_root.to_path_buf(),
+        backend_options,
+        ..Default::default()
+    };
+
+    let err = backend.analyze(options).unwrap_err();
+    assert!(err.to_string().contains("Ambiguous path 'utils.ts'"));
+}
+
+#[test]
+fn test_framework_findings_include_rule_file_frameworks() {
+    let backend = JsBackend::new().unwrap();
+
+    let temp_dir = TempDir::new().unwrap();
+    let project_root = temp_dir.path();
+    std::fs::create_dir_all(project_root.join(".danny/rules")).unwrap();
+
+    std::fs::write(
+        project_root.join(".danny/rules/hono.toml"),
+        r#"
+[framework]
+name = "Hono"
+
+[[framework.detection]]
+type = "import"
+pattern = "^hono$"
+weight = 0.6
+
+[[framework.detection]]
+type = "package_dependency"
+pattern = "hono"
+weight = 0.4
+"#,
+    )
+    .unwrap();
+    std::fs::write(
+        project_root.join("package.json"),
+        r#"{ "name": "api", "dependencies": { "hono": "^4.0.0" } }"#,
+    )
+    .unwrap();
+    std::fs::write(
+        project_root.join("app.ts"),
+        "import { Hono } from 'hono';\nexport default new Hono();",
+    )
+    .unwrap();
+
+    let options = AnalysisOptions {
+        entry_points: vec![project_root.join("app.ts")],
+        project_root: project_root.to_path_buf(),
+        ..Default::default()
+    };
+
+    let result = backend.analyze(options).unwrap();
+
+    let (confidence, evidence) = result
+        .findings
+        .iter()
+        .find_map(|f| match f {
+            Finding::Framework {
+                name,
+                confidence,
+                evidence,
+            } if name == "Hono" => Some((*confidence, evidence)),
+            _ => None,
+        })
+        .expect("Hono should be detected from the project rule file");
+
+    assert!((confidence - 1.0).abs() < f32::EPSILON);
+    assert!(evidence.iter().any(|path| path.ends_with("package.json")));
+    assert!(evidence.iter().any(|path| path.ends_with("app.ts")));
+    assert!(result
+        .statistics
+        .frameworks_detected
+        .contains(&"Hono".to_string()));
+}
+
+#[test]
+fn test_invalid_package_json_does_not_fail_analysis() {
+    let backend = JsBackend::new().unwrap();
+
+    let temp_dir = TempDir::new().unwrap();
+    let project_root = temp_dir.path();
+
+    std::fs::write(project_root.join("package.json"), "{ \"name\": \"app\",").unwrap();
+    std::fs::write(
+        project_root.join("index.ts"),
+        "import { used } from './utils';\nconsole.log(used());",
+    )
+    .unwrap();
+    std::fs::write(
+        project_root.join("utils.ts"),
+        "export function used() { return 1; }\nexport function unused() { return 2; }",
+    )
+    .unwrap();
+
+    let options = AnalysisOptions {
+        entry_points: vec![project_root.join("index.ts")],
+        project_root: project_root.to_path_buf(),
+        ..Default::default()
+    };
+
+    // Framework detection skips the malformed package.json instead of
+    // failing the whole analysis
+    let result = backend.analyze(options).unwrap();
+
+    assert!(result.findings.iter().any(|f| matches!(
+        f,
+        Finding::UnusedExport { module, export_name, .. }
+            if module.ends_with("utils.ts") && export_name == "unused"
+    )));
+}
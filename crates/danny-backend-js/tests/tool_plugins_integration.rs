@@ -0,0 +1,217 @@
+//! Integration tests for tool plugins.
+//!
+//! Verifies that files loaded by tools (test runners, Storybook, scripts)
+//! are treated as entry points, that packages referenced by tool configs or
+//! scripts aren't reported as unused, and that code only tests depend on is
+//! reported as used only in tests.
+
+use danny_backend_js::JsBackend;
+use danny_core::{AnalysisOptions, Finding, LanguageBackend};
+use std::collections::HashMap;
+use tempfile::TempDir;
+
+fn unreachable_files(findings: &[Finding]) -> Vec<String> {
+    findings
+        .iter()
+        .filter_map(|f| match f {
+            Finding::UnreachableFile { path, .. } => {
+                Some(path.file_name().unwrap().to_string_lossy().into_owned())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_test_runner_files_are_reachable() {
+    let backend = JsBackend::new().unwrap();
+
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    std::fs::create_dir_all(root.join("src/__tests__")).unwrap();
+    std::fs::create_dir_all(root.join("e2e")).unwrap();
+
+    std::fs::write(
+        root.join("package.json"),
+        r#"{
+          "scripts": { "test": "vitest", "e2e": "playwright test" },
+          "devDependencies": { "vitest": "^3.0.0", "@playwright/test": "^1.0.0" }
+        }"#,
+    )
+    .unwrap();
+    std::fs::write(
+        root.join("src/index.ts"),
+        "export const sum = (a: number, b: number) => a + b;",
+    )
+    .unwrap();
+    std::fs::write(
+        root.join("src/__tests__/index.spec.ts"),
+        "import { sum } from '../index';\nsum(1, 2);",
+    )
+    .unwrap();
+    std::fs::write(root.join("vitest.config.ts"), "export default {};").unwrap();
+    std::fs::write(
+        root.join("playwright.config.ts"),
+        "export default { testDir: './e2e' };",
+    )
+    .unwrap();
+    std::fs::write(root.join("e2e/home.spec.ts"), "export {};").unwrap();
+    std::fs::write(root.join("src/orphan.ts"), "export const orphan = 1;").unwrap();
+
+    let options = AnalysisOptions {
+        entry_points: vec![root.join("src/index.ts")],
+        project_root: root.to_path_buf(),
+        ..Default::default()
+    };
+
+    let result = backend.analyze(options).unwrap();
+    let unreachable = unreachable_files(&result.findings);
+
+    assert!(!unreachable.contains(&"index.spec.ts".to_string()));
+    assert!(!unreachable.contains(&"home.spec.ts".to_string()));
+    assert!(!unreachable.contains(&"vitest.config.ts".to_string()));
+    assert!(!unreachable.contains(&"playwright.config.ts".to_string()));
+    assert!(unreachable.contains(&"orphan.ts".to_string()));
+}
+
+#[test]
+fn test_tool_plugins_can_be_disabled() {
+    let backend = JsBackend::new().unwrap();
+
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+
+    std::fs::write(
+        root.join("package.json"),
+        r#"{ "devDependencies": { "vitest": "^3.0.0" } }"#,
+    )
+    .unwrap();
+    std::fs::write(root.join("index.ts"), "export const a = 1;").unwrap();
+    std::fs::write(root.join("index.test.ts"), "export {};").unwrap();
+
+    let mut backend_options = HashMap::new();
+    backend_options.insert("tool_plugins".to_string(), serde_json::json!(false));
+    // Test globs would classify index.test.ts as a test entry on their own
+    backend_options.insert("test_only".to_string(), serde_json::json!(false));
+
+    let options = AnalysisOptions {
+        entry_points: vec![root.join("index.ts")],
+        project_root: root.to_path_buf(),
+        backend_options,
+        ..Default::default()
+    };
+
+    let result = backend.analyze(options).unwrap();
+
+    assert!(unreachable_files(&result.findings).contains(&"index.test.ts".to_string()));
+}
+
+#[test]
+fn test_script_dependencies_not_reported_unused() {
+    let backend = JsBackend::new().unwrap();
+
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    std::fs::create_dir_all(root.join("scripts")).unwrap();
+
+    std::fs::write(
+        root.join("package.json"),
+        r#"{
+          "scripts": { "build": "tsc -p .", "release": "node scripts/release.js" },
+          "devDependencies": { "typescript": "^5.0.0", "left-pad": "^1.0.0" }
+        }"#,
+    )
+    .unwrap();
+    std::fs::write(root.join("index.ts"), "export const a = 1;").unwrap();
+    std::fs::write(root.join("scripts/release.js"), "console.log('release');").unwrap();
+
+    let mut backend_options = HashMap::new();
+    backend_options.insert(
+        "detect_npm_dependencies".to_string(),
+        serde_json::json!(true),
+    );
+
+    let options = AnalysisOptions {
+        entry_points: vec![root.join("index.ts")],
+        project_root: root.to_path_buf(),
+        backend_options,
+        ..Default::default()
+    };
+
+    let result = backend.analyze(options).unwrap();
+
+    let unused_packages: Vec<_> = result
+        .findings
+        .iter()
+        .filter_map(|f| match f {
+            Finding::UnusedNpmDependency { package, .. } => Some(package.as_str()),
+            _ => None,
+        })
+        .collect();
+
+    assert!(!unused_packages.contains(&"typescript"));
+    assert!(unused_packages.contains(&"left-pad"));
+    assert!(!unreachable_files(&result.findings).contains(&"release.js".to_string()));
+}
+
+#[test]
+fn test_code_used_only_in_tests() {
+    let backend = JsBackend::new().unwrap();
+
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    std::fs::create_dir_all(root.join("src")).unwrap();
+
+    std::fs::write(root.join("package.json"), r#"{ "name": "app" }"#).unwrap();
+    std::fs::write(
+        root.join("src/index.ts"),
+        "import { add } from './math';\nconsole.log(add(1, 2));",
+    )
+    .unwrap();
+    std::fs::write(
+        root.join("src/math.ts"),
+        "export const add = (a: number, b: number) => a + b;\n\
+         export const clamp = (n: number) => Math.max(0, n);",
+    )
+    .unwrap();
+    std::fs::write(
+        root.join("src/fixtures.ts"),
+        "export const numbers = [1, 2, 3];",
+    )
+    .unwrap();
+    std::fs::write(
+        root.join("src/math.test.ts"),
+        "import { add, clamp } from './math';\nimport { numbers } from './fixtures';\n\
+         console.log(add(1, 2), clamp(-1), numbers);",
+    )
+    .unwrap();
+
+    let options = AnalysisOptions {
+        entry_points: vec![root.join("src/index.ts")],
+        project_root: root.to_path_buf(),
+        ..Default::default()
+    };
+
+    let result = backend.analyze(options).unwrap();
+
+    let test_only: Vec<_> = result
+        .findings
+        .iter()
+        .filter_map(|f| match f {
+            Finding::UsedOnlyInTests {
+                module,
+                export_name,
+                ..
+            } => Some((
+                module.file_name().unwrap().to_string_lossy().into_owned(),
+                export_name.clone(),
+            )),
+            _ => None,
+        })
+        .collect();
+
+    assert!(test_only.contains(&("math.ts".to_string(), Some("clamp".to_string()))));
+    assert!(test_only.contains(&("fixtures.ts".to_string(), None)));
+    assert!(!test_only.contains(&("math.ts".to_string(), Some("add".to_string()))));
+    assert!(!unreachable_files(&result.findings).contains(&"math.test.ts".to_string()));
+}
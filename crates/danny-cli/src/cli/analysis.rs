@@ -29,6 +29,7 @@ pub struct AnalysisRunOptions {
     pub no_ignore: bool,
     pub no_gitignore: bool,
     pub ignore_patterns: Vec<String>,
+    pub rules_dirs: Vec<PathBuf>,
     pub verbose: u8,
     pub json: bool,
     pub format: OutputFormat,
@@ -39,7 +40,7 @@ pub fn run_analysis(options: &AnalysisRunOptions) -> Result<()> {
     let working_dir = std::env::current_dir().context("Failed to get current working directory")?;
 
     // Step 1: Detect analysis target (Package or Files mode)
-    let detector = EntryPointDetector::new(working_dir);
+    let detector = EntryPointDetector::new(working_dir).with_rules_dirs(options.rules_dirs.clone());
     let target = detector
         .detect_target(&options.paths, options.force_package_mode)
         .context("Failed to detect analysis target")?;
@@ -201,8 +202,37 @@ pub fn run_analysis(options: &AnalysisRunOptions) -> Result<()> {
             serde_json::Value::Bool(true),
         );
     }
+    if categories.contains(&Category::Types) {
+        backend_options.insert(
+            "detect_type_members".to_string(),
+            serde_json::Value::Bool(true),
+        );
+    }
+    if categories.contains(&Category::Exports) || categories.contains(&Category::Imports) {
+        backend_options.insert("detect_barrels".to_string(), serde_json::Value::Bool(true));
+    }
     // Dead code analysis (Files, Exports, Types, Circular, Framework) is always enabled if requested
 
+    // Verbose runs explain how rules evaluated each reported export
+    if options.verbose > 0 {
+        backend_options.insert("explain_rules".to_string(), serde_json::Value::Bool(true));
+    }
+
+    // Extra rule directories, resolved against the working directory
+    if !options.rules_dirs.is_empty() {
+        let working_dir =
+            std::env::current_dir().context("Failed to get current working directory")?;
+        let rules_dirs = options
+            .rules_dirs
+            .iter()
+            .map(|dir| serde_json::Value::String(working_dir.join(dir).display().to_string()))
+            .collect();
+        backend_options.insert(
+            "rules_dirs".to_string(),
+            serde_json::Value::Array(rules_dirs),
+        );
+    }
+
     let analysis_options = AnalysisOptions {
         entry_points,
         project_root: project_root.clone(),
@@ -112,7 +112,7 @@ mod tests {
         let target = AnalysisTarget::Package(PackageTarget {
             root: PathBuf::from("/project"),
             entry_points: vec![],
-            framework: None,
+            frameworks: vec![],
             has_node_modules: true,
         });
 
@@ -178,7 +178,7 @@ mod tests {
         let target = AnalysisTarget::Package(PackageTarget {
             root: PathBuf::from("/project"),
             entry_points: vec![],
-            framework: None,
+            frameworks: vec![],
             has_node_modules: true,
         });
 
@@ -53,13 +53,21 @@ pub fn filter_findings_with_tracking(
             Finding::UnusedPrivateClassMember { module, .. } => Some(module),
             Finding::UnusedPublicClassMember { module, .. } => Some(module),
             Finding::UnusedEnumMember { module, .. } => Some(module),
+            Finding::UnusedTypeMember { module, .. } => Some(module),
             Finding::UnusedNpmDependency { .. } => None,
+            Finding::UnlistedNpmDependency { locations, .. } => {
+                locations.first().map(|location| &location.file)
+            }
             Finding::SideEffectOnlyImport { module, .. } => Some(module),
             Finding::NamespaceImport { module, .. } => Some(module),
             Finding::TypeOnlyImport { module, .. } => Some(module),
             Finding::DeadCodeModule { path, .. } => Some(path),
+            Finding::UsedOnlyInTests { module, .. } => Some(module),
+            Finding::UnusedReExport { barrel, .. } => Some(barrel),
+            Finding::SideEffectfulBarrel { barrel, .. } => Some(barrel),
             Finding::DependencyChain { chain, .. } => chain.first(),
             Finding::CodeSmell { location, .. } => Some(location),
+            Finding::RuleMatch { module, .. } => Some(module),
         };
 
         match path_to_check {
@@ -1,3 +1,7 @@
 pub mod config;
+pub mod rules;
+pub mod updates;
 
 pub use config::{handle_config_command, ConfigCommand};
+pub use rules::{handle_rules_command, RulesCommand};
+pub use updates::{handle_updates_command, UpdatesArgs};
@@ -0,0 +1,235 @@
+use crate::entry_points::EntryPointDetector;
+use anyhow::{bail, Context, Result};
+use clap::Subcommand;
+use danny_backend_js::JsBackend;
+use danny_core::{AnalysisOptions, ExportOutcome, LanguageBackend};
+use danny_fs::NativeFileSystem;
+use danny_rule_engine::{run_rule_tests, TomlRuleFile};
+use std::path::{Path, PathBuf};
+use tokio::runtime::Runtime;
+
+#[derive(Subcommand, Debug)]
+pub enum RulesCommand {
+    /// Run the [[tests]] fixtures declared in rule files
+    Test {
+        /// Rule files or directories of rule files (default: .danny/rules)
+        paths: Vec<PathBuf>,
+    },
+
+    /// Show how every loaded rule evaluated an export
+    Explain {
+        /// Module containing the export (relative to the project root)
+        module: PathBuf,
+
+        /// Export name
+        export: String,
+
+        /// Entry points or project directory (default: package.json entry points)
+        #[arg(long = "path")]
+        paths: Vec<PathBuf>,
+
+        /// Additional rule files or directories to load
+        #[arg(long = "rules")]
+        rules_dirs: Vec<PathBuf>,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+pub fn handle_rules_command(cmd: RulesCommand) -> Result<()> {
+    match cmd {
+        RulesCommand::Test { paths } => {
+            let runtime = Runtime::new().context("Failed to create tokio runtime")?;
+            runtime.block_on(test_rules(paths))
+        }
+        // The backend drives its own runtime
+        RulesCommand::Explain {
+            module,
+            export,
+            paths,
+            rules_dirs,
+            json,
+        } => explain_export(&module, &export, &paths, &rules_dirs, json),
+    }
+}
+
+fn explain_export(
+    module: &Path,
+    export: &str,
+    paths: &[PathBuf],
+    rules_dirs: &[PathBuf],
+    json: bool,
+) -> Result<()> {
+    let working_dir = std::env::current_dir().context("Failed to get current working directory")?;
+    let target = EntryPointDetector::new(working_dir.clone())
+        .with_rules_dirs(rules_dirs.to_vec())
+        .detect_target(paths, false)
+        .context("Failed to detect analysis target")?;
+
+    let entry_points = match &target {
+        danny_config::AnalysisTarget::Package(pkg) => pkg.entry_points.clone(),
+        danny_config::AnalysisTarget::Files(files) => files.files.clone(),
+    };
+    if entry_points.is_empty() {
+        bail!("No entry points found. Please specify --path or ensure package.json exists.");
+    }
+
+    let mut backend_options = std::collections::HashMap::new();
+    backend_options.insert(
+        "explain_rules".to_string(),
+        serde_json::json!([format!("{}#{}", module.display(), export)]),
+    );
+    if !rules_dirs.is_empty() {
+        backend_options.insert(
+            "rules_dirs".to_string(),
+            rules_dirs
+                .iter()
+                .map(|dir| working_dir.join(dir).display().to_string())
+                .collect(),
+        );
+    }
+
+    let backend = JsBackend::new().context("Failed to create JavaScript backend")?;
+    let result = backend
+        .analyze(AnalysisOptions {
+            entry_points,
+            project_root: target.root_dir().clone(),
+            follow_external: false,
+            max_depth: None,
+            config_path: None,
+            backend_options,
+        })
+        .context("Analysis failed")?;
+
+    let Some(explanation) = result.rule_explanations.first() else {
+        bail!(
+            "Export '{}' not found in {} (is the module reachable from an entry point?)",
+            export,
+            module.display()
+        );
+    };
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(explanation)?);
+        return Ok(());
+    }
+
+    println!(
+        "{}#{}",
+        explanation.module.display(),
+        explanation.export_name
+    );
+    for check in &explanation.rules {
+        if check.matched {
+            println!("  ✓ {}/{} → {}", check.framework, check.rule, check.action);
+        } else {
+            let condition = check.failed_condition.as_deref().unwrap_or("unknown");
+            println!(
+                "  ✗ {}/{} ({} failed)",
+                check.framework, check.rule, condition
+            );
+        }
+    }
+
+    let outcome = match explanation.outcome {
+        ExportOutcome::FrameworkUsed => "used by a framework rule",
+        ExportOutcome::Used => "used",
+        ExportOutcome::Unused => "unused",
+    };
+    println!("\nOutcome: {}", outcome);
+    Ok(())
+}
+
+async fn test_rules(paths: Vec<PathBuf>) -> Result<()> {
+    let paths = if paths.is_empty() {
+        vec![PathBuf::from(".danny/rules")]
+    } else {
+        paths
+    };
+
+    let mut rule_files = Vec::new();
+    for path in &paths {
+        rule_files.extend(collect_rule_files(path)?);
+    }
+    if rule_files.is_empty() {
+        bail!("No rule files found");
+    }
+
+    let mut passed = 0;
+    let mut failed = 0;
+
+    for rule_file in &rule_files {
+        let content = std::fs::read_to_string(rule_file)
+            .with_context(|| format!("Failed to read {}", rule_file.display()))?;
+        let file: TomlRuleFile = toml::from_str(&content)
+            .with_context(|| format!("Failed to parse {}", rule_file.display()))?;
+
+        println!("{}", rule_file.display());
+        if file.tests.is_empty() {
+            println!("  (no tests)");
+            continue;
+        }
+
+        let framework = file
+            .framework
+            .as_ref()
+            .map(|framework| framework.name.clone())
+            .or_else(|| {
+                rule_file
+                    .file_stem()
+                    .map(|stem| stem.to_string_lossy().into_owned())
+            })
+            .unwrap_or_else(|| "Custom".to_string());
+
+        // Fixtures are written to a scratch directory, never the project
+        let fixtures = tempfile::TempDir::new().context("Failed to create fixture directory")?;
+        let fs = NativeFileSystem::new(fixtures.path())?;
+        let results = run_rule_tests(&fs, &framework, &file)
+            .await
+            .with_context(|| format!("Failed to run tests in {}", rule_file.display()))?;
+
+        for result in &results {
+            let rule = result.rule.as_deref().unwrap_or("all rules");
+            if result.passed() {
+                passed += 1;
+                println!("  ✓ {}: {}", rule, result.name);
+            } else {
+                failed += 1;
+                println!("  ✗ {}: {}", rule, result.name);
+                for failure in &result.failures {
+                    println!("      {}", failure);
+                }
+            }
+        }
+    }
+
+    println!("\nRule tests: {} passed, {} failed", passed, failed);
+    if failed > 0 {
+        bail!("{} rule test(s) failed", failed);
+    }
+    Ok(())
+}
+
+/// Rule files at `path`: the file itself, or the `.toml` files in a directory
+fn collect_rule_files(path: &Path) -> Result<Vec<PathBuf>> {
+    if !path.is_dir() {
+        if !path.exists() {
+            bail!("Rule file not found: {}", path.display());
+        }
+        return Ok(vec![path.to_path_buf()]);
+    }
+
+    let mut files = Vec::new();
+    for entry in std::fs::read_dir(path)
+        .with_context(|| format!("Failed to read directory {}", path.display()))?
+    {
+        let file = entry?.path();
+        if file.extension().is_some_and(|ext| ext == "toml") {
+            files.push(file);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
@@ -0,0 +1,178 @@
+use anyhow::{bail, Context, Result};
+use clap::Args;
+use danny_config::{ConfigManager, GlobalSettings};
+use danny_deps::{Ecosystem, UnifiedDependencyManager, UpdateReport, UpdateType};
+use danny_info::{CacheMode, InfoClient, ResponseCache};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::runtime::Runtime;
+
+#[derive(Args, Debug)]
+pub struct UpdatesArgs {
+    /// Manifest to check (default: package.json or Cargo.toml in the current directory)
+    manifest: Option<PathBuf>,
+
+    /// Serve registry and GitHub responses only from the response cache
+    ///
+    /// Packages without a cached response are reported as errors instead of
+    /// being fetched.
+    #[arg(long)]
+    offline: bool,
+
+    /// Response cache directory (default: danny/http in the user cache directory)
+    #[arg(long, value_name = "DIR")]
+    cache_dir: Option<PathBuf>,
+
+    /// Seconds a cached response is used before it is revalidated (default: 3600)
+    #[arg(long, value_name = "SECONDS")]
+    cache_ttl: Option<u64>,
+
+    /// Output as JSON
+    #[arg(long)]
+    json: bool,
+}
+
+pub fn handle_updates_command(args: UpdatesArgs) -> Result<()> {
+    let runtime = Runtime::new().context("Failed to create tokio runtime")?;
+    runtime.block_on(check_updates(args))
+}
+
+async fn check_updates(args: UpdatesArgs) -> Result<()> {
+    let manifest = match &args.manifest {
+        Some(manifest) => manifest.clone(),
+        None => find_manifest()?,
+    };
+    let ecosystem = manifest_ecosystem(&manifest)?;
+
+    // Use ~/.danny/config.toml settings when present
+    let settings = match ConfigManager::load().await {
+        Ok(config) => config.config().settings.clone(),
+        Err(_) => GlobalSettings::default(),
+    };
+
+    let client = InfoClient::new()
+        .context("Failed to create registry client")?
+        .with_cache(response_cache(&args)?);
+    let manager = UnifiedDependencyManager::with_info_client(client)
+        .with_max_concurrent_checks(settings.max_concurrent_checks);
+
+    let report = manager
+        .check_updates(&manifest, ecosystem)
+        .await
+        .with_context(|| format!("Failed to check updates for {}", manifest.display()))?;
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&report_json(&report))?);
+    } else {
+        print_report(&manifest, &report);
+    }
+    Ok(())
+}
+
+fn response_cache(args: &UpdatesArgs) -> Result<ResponseCache> {
+    let cache = match &args.cache_dir {
+        Some(dir) => ResponseCache::new(dir),
+        None => {
+            ResponseCache::in_user_cache_dir().context("Failed to locate the response cache")?
+        }
+    };
+    let cache = match args.cache_ttl {
+        Some(seconds) => cache.with_ttl(Duration::from_secs(seconds)),
+        None => cache,
+    };
+
+    if args.offline {
+        Ok(cache.with_mode(CacheMode::Offline))
+    } else {
+        Ok(cache)
+    }
+}
+
+fn find_manifest() -> Result<PathBuf> {
+    let working_dir = std::env::current_dir().context("Failed to get current working directory")?;
+    ["package.json", "Cargo.toml"]
+        .iter()
+        .map(|name| working_dir.join(name))
+        .find(|path| path.exists())
+        .context("No package.json or Cargo.toml found. Please pass a manifest path.")
+}
+
+fn manifest_ecosystem(manifest: &Path) -> Result<Ecosystem> {
+    match manifest.file_name().and_then(|name| name.to_str()) {
+        Some("package.json") => Ok(Ecosystem::JavaScript),
+        Some("Cargo.toml") => Ok(Ecosystem::Rust),
+        _ => bail!(
+            "Unsupported manifest: {} (expected package.json or Cargo.toml)",
+            manifest.display()
+        ),
+    }
+}
+
+fn update_type_name(update_type: UpdateType) -> &'static str {
+    match update_type {
+        UpdateType::Major => "major",
+        UpdateType::Minor => "minor",
+        UpdateType::Patch => "patch",
+        UpdateType::None => "none",
+    }
+}
+
+fn report_json(report: &UpdateReport) -> serde_json::Value {
+    let updates: Vec<_> = report
+        .recommendations
+        .iter()
+        .filter(|update| update.update_type != UpdateType::None)
+        .map(|update| {
+            serde_json::json!({
+                "package": update.package,
+                "requirement": update.current_req.raw,
+                "installed": update.current_installed,
+                "wanted": update.wanted_version,
+                "latest": update.latest_version,
+                "update_type": update_type_name(update.update_type),
+            })
+        })
+        .collect();
+    let errors: Vec<_> = report
+        .errors
+        .iter()
+        .map(|error| {
+            serde_json::json!({
+                "package": error.package,
+                "error": error.error.to_string(),
+            })
+        })
+        .collect();
+
+    serde_json::json!({ "updates": updates, "errors": errors })
+}
+
+fn print_report(manifest: &Path, report: &UpdateReport) {
+    println!("{}", manifest.display());
+
+    let mut up_to_date = true;
+    for update in &report.recommendations {
+        if update.update_type == UpdateType::None {
+            continue;
+        }
+        up_to_date = false;
+        let installed = update
+            .current_installed
+            .as_deref()
+            .unwrap_or(&update.current_req.raw);
+        println!(
+            "  {}: {} → {} ({})",
+            update.package,
+            installed,
+            update.latest_version,
+            update_type_name(update.update_type)
+        );
+    }
+    if up_to_date && report.errors.is_empty() {
+        println!("  All dependencies are up to date");
+    }
+
+    for error in &report.errors {
+        println!("  ✗ {}: {}", error.package, error.error);
+    }
+}
@@ -5,11 +5,21 @@ use std::path::{Path, PathBuf};
 
 pub struct EntryPointDetector {
     working_dir: PathBuf,
+    rules_dirs: Vec<PathBuf>,
 }
 
 impl EntryPointDetector {
     pub fn new(working_dir: PathBuf) -> Self {
-        Self { working_dir }
+        Self {
+            working_dir,
+            rules_dirs: Vec::new(),
+        }
+    }
+
+    /// Also read framework definitions from these rule directories (`--rules`)
+    pub fn with_rules_dirs(mut self, rules_dirs: Vec<PathBuf>) -> Self {
+        self.rules_dirs = rules_dirs;
+        self
     }
 
     /// Detect analysis target from CLI paths
@@ -73,8 +83,8 @@ impl EntryPointDetector {
         // Parse package.json for entry points
         let entry_points = super::package::extract_entry_points(&package_json)?;
 
-        // Detect framework
-        let framework = super::package::detect_framework(root)?;
+        // Detect frameworks
+        let frameworks = super::package::detect_frameworks(root, &self.rules_dirs)?;
 
         // Check for node_modules
         let has_node_modules = root.join("node_modules").is_dir();
@@ -82,7 +92,7 @@ impl EntryPointDetector {
         Ok(AnalysisTarget::Package(PackageTarget {
             root: root.to_path_buf(),
             entry_points,
-            framework,
+            frameworks,
             has_node_modules,
         }))
     }
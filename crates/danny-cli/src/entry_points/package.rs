@@ -1,8 +1,10 @@
 use anyhow::{Context, Result};
-use danny_config::Framework;
 use danny_core::Error;
+use danny_fs::NativeFileSystem;
+use danny_rule_engine::{ProjectSignals, RuleLoader};
 use std::collections::HashSet;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 /// Extract entry points from package.json with security validation
 pub fn extract_entry_points(package_json: &Path) -> Result<Vec<PathBuf>> {
@@ -46,35 +48,49 @@ pub fn extract_entry_points(package_json: &Path) -> Result<Vec<PathBuf>> {
     Ok(entry_points)
 }
 
-/// Detect framework from package.json dependencies
-pub fn detect_framework(root: &Path) -> Result<Option<Framework>> {
+/// Detect frameworks from package.json dependencies and scripts
+///
+/// Uses the TOML framework definitions: the built-in ones plus `[framework]`
+/// sections in user and project rule files and `rules_dirs`. Results are
+/// ordered by framework priority. If the rule files can't be loaded, only
+/// the built-in frameworks are used; the analysis reports the error.
+pub fn detect_frameworks(root: &Path, rules_dirs: &[PathBuf]) -> Result<Vec<String>> {
     let package_json = root.join("package.json");
 
-    // TOCTOU fix: Try to open file instead of checking existence
-    use std::fs::File;
-    match File::open(&package_json) {
-        Ok(_) => {
-            // File exists, proceed to read
-        }
-        Err(_) => {
-            return Ok(None);
-        }
-    }
+    // TOCTOU fix: Read directly instead of checking existence first
+    let content = match std::fs::read_to_string(&package_json) {
+        Ok(content) => content,
+        Err(_) => return Ok(Vec::new()),
+    };
+    let package: serde_json::Value =
+        serde_json::from_str(&content).context("Failed to parse package.json")?;
+    let signals = ProjectSignals::from_package_json(package_json, &package);
 
-    let content = std::fs::read_to_string(&package_json).context("Failed to read package.json")?;
+    let loaded = load_rule_frameworks(root, rules_dirs).unwrap_or_default();
+    let detector = danny_rule_engine::load_detector_with_overrides(loaded)
+        .or_else(|_| danny_rule_engine::load_detector_with_overrides(Vec::new()))
+        .context("Failed to load framework detection rules")?;
 
-    // Simple detection based on dependencies
-    if content.contains("\"next\"") {
-        Ok(Some(Framework::NextJs))
-    } else if content.contains("\"@vue/") || content.contains("\"vue\"") {
-        Ok(Some(Framework::Vue))
-    } else if content.contains("\"svelte\"") {
-        Ok(Some(Framework::Svelte))
-    } else if content.contains("\"react\"") {
-        Ok(Some(Framework::React))
-    } else {
-        Ok(None)
-    }
+    Ok(detector
+        .detect_project(&signals)
+        .into_iter()
+        .map(|result| result.framework)
+        .collect())
+}
+
+/// Load the `[framework]` sections of the user, project and `rules_dirs` rule files
+fn load_rule_frameworks(
+    root: &Path,
+    rules_dirs: &[PathBuf],
+) -> Result<Vec<danny_rule_engine::FrameworkMetadata>> {
+    let fs = Arc::new(NativeFileSystem::new(root)?);
+    let loader = rules_dirs
+        .iter()
+        .try_fold(RuleLoader::new(fs, root), |loader, dir| {
+            loader.with_shared_rules_dir(dir)
+        })?;
+    let runtime = tokio::runtime::Runtime::new().context("Failed to create tokio runtime")?;
+    Ok(runtime.block_on(loader.load_frameworks())?)
 }
 
 /// Detect framework-specific entry points using TOML patterns
@@ -178,6 +194,92 @@ fn expand_braces(pattern: &str) -> Vec<String> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_detect_frameworks_applies_suppression() {
+        let temp = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("package.json"),
+            r#"{ "dependencies": { "next": "^14.0.0", "react": "^18.0.0" } }"#,
+        )
+        .unwrap();
+
+        let frameworks = detect_frameworks(temp.path(), &[]).unwrap();
+        assert_eq!(frameworks, vec!["Next.js"]);
+    }
+
+    #[test]
+    fn test_detect_frameworks_from_project_rule_files() {
+        let temp = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("package.json"),
+            r#"{ "dependencies": { "hono": "^4.0.0" } }"#,
+        )
+        .unwrap();
+        std::fs::create_dir_all(temp.path().join(".danny/rules")).unwrap();
+        std::fs::write(
+            temp.path().join(".danny/rules/hono.toml"),
+            r#"
+[framework]
+name = "Hono"
+
+[[framework.detection]]
+type = "package_dependency"
+pattern = "hono"
+"#,
+        )
+        .unwrap();
+
+        let frameworks = detect_frameworks(temp.path(), &[]).unwrap();
+        assert_eq!(frameworks, vec!["Hono"]);
+    }
+
+    #[test]
+    fn test_detect_frameworks_from_rules_dir() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let shared = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("package.json"),
+            r#"{ "dependencies": { "hono": "^4.0.0" } }"#,
+        )
+        .unwrap();
+        std::fs::write(
+            shared.path().join("hono.toml"),
+            r#"
+[framework]
+name = "Hono"
+
+[[framework.detection]]
+type = "package_dependency"
+pattern = "hono"
+"#,
+        )
+        .unwrap();
+
+        let frameworks = detect_frameworks(temp.path(), &[shared.path().to_path_buf()]).unwrap();
+        assert_eq!(frameworks, vec!["Hono"]);
+    }
+
+    #[test]
+    fn test_detect_frameworks_ignores_malformed_rule_files() {
+        let temp = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("package.json"),
+            r#"{ "dependencies": { "next": "^14.0.0" } }"#,
+        )
+        .unwrap();
+        std::fs::create_dir_all(temp.path().join(".danny/rules")).unwrap();
+        std::fs::write(temp.path().join(".danny/rules/broken.toml"), "[[rules]\n").unwrap();
+
+        let frameworks = detect_frameworks(temp.path(), &[]).unwrap();
+        assert_eq!(frameworks, vec!["Next.js"]);
+    }
+
+    #[test]
+    fn test_detect_frameworks_without_package_json() {
+        let temp = tempfile::TempDir::new().unwrap();
+        assert!(detect_frameworks(temp.path(), &[]).unwrap().is_empty());
+    }
+
     #[test]
     fn test_expand_braces_simple() {
         assert_eq!(expand_braces("file.{ts,js}"), vec!["file.ts", "file.js"]);
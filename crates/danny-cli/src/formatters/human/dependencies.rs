@@ -7,9 +7,18 @@ use Finding::*;
 pub fn print_dependencies(findings: &[&Finding]) {
     println!("\n📦 Dependencies ({}):", findings.len());
     let mut by_type: HashMap<_, Vec<_>> = HashMap::new();
+    let mut unlisted = Vec::new();
 
     for finding in findings {
-        if let UnusedNpmDependency {
+        if let UnlistedNpmDependency {
+            package,
+            reason,
+            manifest,
+            locations,
+        } = finding
+        {
+            unlisted.push((package, reason, manifest, locations));
+        } else if let UnusedNpmDependency {
             package,
             version,
             dep_type,
@@ -40,4 +49,31 @@ pub fn print_dependencies(findings: &[&Finding]) {
             println!("    ... and {} more", deps.len() - 10);
         }
     }
+
+    if !unlisted.is_empty() {
+        println!("  unlisted ({}):", unlisted.len());
+        for (package, reason, manifest, locations) in unlisted.iter().take(10) {
+            let suggestion = match reason {
+                danny_core::UnlistedDependencyReason::NotDeclared => "Add to dependencies",
+                danny_core::UnlistedDependencyReason::DevDependencyInProduction => {
+                    "Move from devDependencies to dependencies"
+                }
+            };
+            println!(
+                "    ⚠️  {} - Suggestion: {} in {}",
+                package,
+                suggestion,
+                manifest.display()
+            );
+            for location in locations.iter().take(3) {
+                println!("       imported in {}", location.file.display());
+            }
+            if locations.len() > 3 {
+                println!("       ... and {} more", locations.len() - 3);
+            }
+        }
+        if unlisted.len() > 10 {
+            println!("    ... and {} more", unlisted.len() - 10);
+        }
+    }
 }
@@ -7,19 +7,25 @@ use Finding::*;
 pub fn print_exports(findings: &[&Finding]) {
     println!("\n📤 Exports ({}):", findings.len());
     let mut runtime_exports = Vec::new();
+    let mut test_only_exports = Vec::new();
+    let mut unused_re_exports = Vec::new();
 
     for finding in findings {
-        if let UnusedExport {
-            module,
-            export_name,
-            span,
-            is_type_only,
-            ..
-        } = finding
-        {
-            if !*is_type_only {
-                runtime_exports.push((module, export_name, span));
-            }
+        match finding {
+            UsedOnlyInTests {
+                module,
+                export_name: Some(export_name),
+                ..
+            } => test_only_exports.push((module, export_name)),
+            UnusedExport {
+                module,
+                export_name,
+                span,
+                is_type_only: false,
+                ..
+            } => runtime_exports.push((module, export_name, span)),
+            UnusedReExport { .. } => unused_re_exports.push(*finding),
+            _ => {}
         }
     }
 
@@ -37,4 +43,55 @@ pub fn print_exports(findings: &[&Finding]) {
             println!("    ... and {} more modules", by_module.len() - 10);
         }
     }
+
+    if !test_only_exports.is_empty() {
+        println!("\n  Used Only in Tests:");
+        let mut by_module: HashMap<_, Vec<_>> = HashMap::new();
+        for (module, name) in test_only_exports.iter().take(50) {
+            by_module.entry(*module).or_default().push(name.as_str());
+        }
+
+        for (module, exports) in by_module.iter().take(10) {
+            println!("    🧪 {}: {}", module.display(), exports.join(", "));
+        }
+        if by_module.len() > 10 {
+            println!("    ... and {} more modules", by_module.len() - 10);
+        }
+    }
+
+    if !unused_re_exports.is_empty() {
+        println!("\n  Unused Barrel Re-exports:");
+        for finding in unused_re_exports.iter().take(20) {
+            if let UnusedReExport {
+                barrel,
+                export_name,
+                source,
+                origin,
+                ..
+            } = finding
+            {
+                println!(
+                    "    {}: {} from '{}'",
+                    barrel.display(),
+                    export_name,
+                    source
+                );
+                if let Some(origin) = origin {
+                    let suggestion = if origin.is_used {
+                        "remove the re-export"
+                    } else {
+                        "declaration is unused, delete it"
+                    };
+                    println!(
+                        "      ↳ declared in {} ({})",
+                        origin.module.display(),
+                        suggestion
+                    );
+                }
+            }
+        }
+        if unused_re_exports.len() > 20 {
+            println!("    ... and {} more", unused_re_exports.len() - 20);
+        }
+    }
 }
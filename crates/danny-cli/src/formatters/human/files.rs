@@ -9,12 +9,14 @@ pub fn print_files(findings: &[&Finding], result: &AnalysisResult) {
     let mut unreachable_modules = Vec::new();
     let mut unreachable_files = Vec::new();
     let mut dead_code_modules = Vec::new();
+    let mut test_only_files = Vec::new();
 
     for finding in findings {
         match finding {
             UnreachableModule { .. } => unreachable_modules.push(*finding),
             UnreachableFile { .. } => unreachable_files.push(*finding),
             DeadCodeModule { .. } => dead_code_modules.push(*finding),
+            UsedOnlyInTests { .. } => test_only_files.push(*finding),
             _ => {}
         }
     }
@@ -126,4 +128,24 @@ pub fn print_files(findings: &[&Finding], result: &AnalysisResult) {
             println!("    ... and {} more", dead_code_modules.len() - 20);
         }
     }
+
+    // Print files only used by tests
+    if !test_only_files.is_empty() {
+        println!("\n  Used Only in Tests:");
+        for finding in test_only_files.iter().take(20) {
+            if let UsedOnlyInTests {
+                module, used_by, ..
+            } = finding
+            {
+                println!(
+                    "    🧪 {} (used by {} module(s))",
+                    module.display(),
+                    used_by.len()
+                );
+            }
+        }
+        if test_only_files.len() > 20 {
+            println!("    ... and {} more", test_only_files.len() - 20);
+        }
+    }
 }
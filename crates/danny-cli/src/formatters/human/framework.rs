@@ -5,18 +5,37 @@ use Finding::*;
 
 pub fn print_framework(findings: &[&Finding]) {
     println!("\n🎯 Framework ({}):", findings.len());
+    let mut detected = Vec::new();
     let mut framework_exports = Vec::new();
 
     for finding in findings {
-        if let FrameworkExport {
-            module,
-            export_name,
-            framework,
-            rule,
-            explanation: _,
-        } = finding
-        {
-            framework_exports.push((module, export_name, framework, rule));
+        match finding {
+            Framework {
+                name,
+                confidence,
+                evidence,
+            } => detected.push((name, confidence, evidence)),
+            FrameworkExport {
+                module,
+                export_name,
+                framework,
+                rule,
+                explanation: _,
+            } => framework_exports.push((module, export_name, framework, rule)),
+            _ => {}
+        }
+    }
+
+    if !detected.is_empty() {
+        println!("  Detected Frameworks:");
+        for (name, confidence, evidence) in &detected {
+            println!(
+                "    {} ({:.0}% confidence, {} evidence file{})",
+                name,
+                *confidence * 100.0,
+                evidence.len(),
+                if evidence.len() == 1 { "" } else { "s" }
+            );
         }
     }
 
@@ -1,5 +1,6 @@
 //! Imports category formatter.
 
+use super::format_bytes;
 use danny_core::Finding;
 use Finding::*;
 
@@ -10,6 +11,7 @@ pub fn print_imports(findings: &[&Finding]) {
     let mut namespace_imports = Vec::new();
     let mut type_only_imports = Vec::new();
     let mut dependency_chains = Vec::new();
+    let mut side_effectful_barrels = Vec::new();
 
     for finding in findings {
         match finding {
@@ -18,6 +20,7 @@ pub fn print_imports(findings: &[&Finding]) {
             NamespaceImport { .. } => namespace_imports.push(*finding),
             TypeOnlyImport { .. } => type_only_imports.push(*finding),
             DependencyChain { .. } => dependency_chains.push(*finding),
+            SideEffectfulBarrel { .. } => side_effectful_barrels.push(*finding),
             _ => {}
         }
     }
@@ -128,4 +131,26 @@ pub fn print_imports(findings: &[&Finding]) {
             println!("    ... and {} more", dependency_chains.len() - 10);
         }
     }
+
+    if !side_effectful_barrels.is_empty() {
+        println!("\n  Side-Effectful Barrels (Importing them loads every module):");
+        for finding in side_effectful_barrels.iter().take(10) {
+            if let SideEffectfulBarrel {
+                barrel,
+                modules,
+                total_size,
+            } = finding
+            {
+                println!(
+                    "    📦 {} loads {} side-effectful module(s) ({})",
+                    barrel.display(),
+                    modules.len(),
+                    format_bytes(*total_size)
+                );
+            }
+        }
+        if side_effectful_barrels.len() > 10 {
+            println!("    ... and {} more", side_effectful_barrels.len() - 10);
+        }
+    }
 }
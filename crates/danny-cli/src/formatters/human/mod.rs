@@ -7,6 +7,7 @@ mod files;
 mod framework;
 mod imports;
 mod quality;
+mod rules;
 mod symbols;
 mod types;
 
@@ -91,10 +92,25 @@ pub fn print_results(result: &AnalysisResult) {
                     CodeSmellType::TooManyMethods => "Too Many Methods",
                     CodeSmellType::TooManyFields => "Too Many Fields",
                     CodeSmellType::LowCohesion => "Low Cohesion",
+                    CodeSmellType::CognitiveComplexity => "Cognitive Complexity",
+                    CodeSmellType::LowMaintainability => "Low Maintainability",
                 };
                 println!("    {}: {}", type_name, count);
             }
         }
+        if !stats.file_metrics.is_empty() {
+            let mut files: Vec<_> = stats.file_metrics.iter().collect();
+            files.sort_by(|a, b| a.maintainability_index.total_cmp(&b.maintainability_index));
+            println!("  Least maintainable files:");
+            for file in files.iter().take(5) {
+                println!(
+                    "    {} (maintainability {:.1}, max cognitive complexity {})",
+                    file.path.display(),
+                    file.maintainability_index,
+                    file.max_cognitive_complexity
+                );
+            }
+        }
     }
 
     // Print class member statistics if available
@@ -144,6 +160,7 @@ fn print_category_findings(category: Category, findings: &[&Finding], result: &A
         Category::Circular => circular::print_circular(findings),
         Category::Quality => quality::print_quality(findings),
         Category::Framework => framework::print_framework(findings),
+        Category::Rules => rules::print_rules(findings),
     }
 }
 
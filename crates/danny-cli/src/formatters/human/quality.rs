@@ -169,6 +169,8 @@ pub fn print_quality(findings: &[&Finding]) {
                     CodeSmellType::TooManyMethods => "Too Many Methods",
                     CodeSmellType::TooManyFields => "Too Many Fields",
                     CodeSmellType::LowCohesion => "Low Cohesion",
+                    CodeSmellType::CognitiveComplexity => "Cognitive Complexity",
+                    CodeSmellType::LowMaintainability => "Low Maintainability",
                     _ => "Code Smell",
                 };
                 let symbol_display = symbol_name
@@ -0,0 +1,53 @@
+//! Rules category formatter.
+
+use danny_core::{Finding, RuleSeverity};
+use Finding::*;
+
+pub fn print_rules(findings: &[&Finding]) {
+    println!("\n📏 Rules ({}):", findings.len());
+    let mut rule_matches = Vec::new();
+
+    for finding in findings {
+        if let RuleMatch {
+            rule,
+            message,
+            severity,
+            module,
+            export_name,
+            ..
+        } = finding
+        {
+            rule_matches.push((severity, rule, message, module, export_name));
+        }
+    }
+
+    // Errors first, then warnings, then info
+    rule_matches.sort_by_key(|(severity, ..)| match severity {
+        RuleSeverity::Error => 0,
+        RuleSeverity::Warning => 1,
+        RuleSeverity::Info => 2,
+    });
+
+    for (severity, rule, message, module, export_name) in rule_matches.iter().take(50) {
+        let icon = match severity {
+            RuleSeverity::Error => "❌",
+            RuleSeverity::Warning => "⚠️ ",
+            RuleSeverity::Info => "ℹ️ ",
+        };
+        let target = export_name
+            .as_ref()
+            .map(|name| format!("'{}' in ", name))
+            .unwrap_or_default();
+        println!(
+            "    {} {}{} - {} [{}]",
+            icon,
+            target,
+            module.display(),
+            message,
+            rule
+        );
+    }
+    if rule_matches.len() > 50 {
+        println!("    ... and {} more", rule_matches.len() - 50);
+    }
+}
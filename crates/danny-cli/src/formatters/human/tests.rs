@@ -6,7 +6,7 @@ use danny_core::types::{
     ExportKind, NpmDependencyType, SafetyAssessment, SmellSeverity, SourceLocation, Statistics,
     SymbolKind, SymbolSpan, UnreachableModuleMetadata,
 };
-use danny_core::{AnalysisResult, Category, Finding};
+use danny_core::{AnalysisResult, Category, Finding, RuleSeverity};
 use std::collections::HashMap;
 use std::path::PathBuf;
 
@@ -15,6 +15,7 @@ fn create_test_result(findings: Vec<Finding>) -> AnalysisResult {
     AnalysisResult {
         findings,
         ignored_findings: vec![],
+        rule_explanations: vec![],
         statistics: Statistics {
             total_modules: 10,
             total_dependencies: 20,
@@ -301,11 +302,18 @@ fn test_print_results_does_not_panic_with_all_categories() {
                 recommendation: Some("Consider breaking it into smaller functions".to_string()),
                 current_value: Some(150),
                 recommended_threshold: Some(50),
+                score: None,
+                score_threshold: None,
                 metadata: HashMap::new(),
             },
             severity: SmellSeverity::Warning,
         },
         // Framework
+        Finding::Framework {
+            name: "Next.js".to_string(),
+            confidence: 1.0,
+            evidence: vec![PathBuf::from("package.json")],
+        },
         Finding::FrameworkExport {
             module: PathBuf::from("page.tsx"),
             export_name: "default".to_string(),
@@ -313,6 +321,15 @@ fn test_print_results_does_not_panic_with_all_categories() {
             rule: "pages_router".to_string(),
             explanation: None,
         },
+        // Rules
+        Finding::RuleMatch {
+            rule: "no-moment".to_string(),
+            message: "Use date-fns instead of moment".to_string(),
+            severity: RuleSeverity::Error,
+            module: PathBuf::from("date.ts"),
+            export_name: None,
+            span: None,
+        },
     ];
 
     let result = create_test_result(findings);
@@ -7,15 +7,17 @@ use Finding::*;
 pub fn print_types(findings: &[&Finding]) {
     println!("\n📝 Types ({}):", findings.len());
     let mut type_exports = Vec::new();
+    let mut unused_type_members = Vec::new();
 
     for finding in findings {
-        if let UnusedExport {
-            module,
-            export_name,
-            ..
-        } = finding
-        {
-            type_exports.push((module, export_name));
+        match finding {
+            UnusedExport {
+                module,
+                export_name,
+                ..
+            } => type_exports.push((module, export_name)),
+            UnusedTypeMember { .. } => unused_type_members.push(*finding),
+            _ => {}
         }
     }
 
@@ -34,4 +36,34 @@ pub fn print_types(findings: &[&Finding]) {
             println!("    ... and {} more modules", by_module.len() - 10);
         }
     }
+
+    if !unused_type_members.is_empty() {
+        println!("\n  Unused Type Members:");
+        for finding in unused_type_members.iter().take(20) {
+            if let UnusedTypeMember {
+                module,
+                type_name,
+                member_name,
+                member_kind,
+                span,
+            } = finding
+            {
+                let suffix = match member_kind {
+                    danny_core::TypeMemberKind::Method => "()",
+                    danny_core::TypeMemberKind::Property => "",
+                };
+                println!(
+                    "    → {}.{}{} in {}:{}",
+                    type_name,
+                    member_name,
+                    suffix,
+                    module.display(),
+                    span.line
+                );
+            }
+        }
+        if unused_type_members.len() > 20 {
+            println!("    ... and {} more", unused_type_members.len() - 20);
+        }
+    }
 }
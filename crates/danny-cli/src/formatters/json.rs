@@ -7,7 +7,7 @@ pub struct JsonFormatter;
 
 pub fn print_json(result: &AnalysisResult) {
     // Create a JSON structure with category added to each finding
-    let json_result = json!({
+    let mut json_result = json!({
         "findings": result.findings.iter().map(|f| {
             let mut finding_json = serde_json::to_value(f).unwrap();
             if let Some(obj) = finding_json.as_object_mut() {
@@ -20,6 +20,11 @@ pub fn print_json(result: &AnalysisResult) {
         "ignored_findings": result.ignored_findings,
     });
 
+    // Present only in verbose runs
+    if !result.rule_explanations.is_empty() {
+        json_result["rule_explanations"] = json!(result.rule_explanations);
+    }
+
     match serde_json::to_string_pretty(&json_result) {
         Ok(json) => println!("{}", json),
         Err(e) => eprintln!("Error serializing results: {}", e),
@@ -36,6 +36,11 @@ struct Cli {
     /// - Files mode (danny file.ts): symbols, quality, imports, types only
     ///
     /// Use --list-categories to see available categories for your command.
+    ///
+    /// types also reports unused interface and type alias members. A member
+    /// counts as used if any property with its name is accessed anywhere in
+    /// the project; types that are spread or serialized (JSON.stringify,
+    /// Object.keys) are skipped, as their members are read without names.
     #[arg(short, long, value_name = "CATEGORY")]
     category: Vec<String>,
 
@@ -81,6 +86,15 @@ struct Cli {
     #[arg(long = "ignore", value_name = "PATTERN")]
     ignore_patterns: Vec<String>,
 
+    /// Directory of TOML rule files (can be specified multiple times)
+    ///
+    /// Loaded after .danny/rules/ and the user rules directory; a rule
+    /// replaces any rule with the same name from those sources or the
+    /// built-in framework rules. The directory may be outside the project,
+    /// e.g. rules shared across repositories.
+    #[arg(long = "rules", value_name = "DIR")]
+    rules_dirs: Vec<PathBuf>,
+
     /// Verbose output
     #[arg(short = 'v', action = clap::ArgAction::Count)]
     verbose: u8,
@@ -97,6 +111,15 @@ enum Command {
         #[command(subcommand)]
         command: commands::ConfigCommand,
     },
+
+    /// Work with TOML rule files
+    Rules {
+        #[command(subcommand)]
+        command: commands::RulesCommand,
+    },
+
+    /// Check a package.json or Cargo.toml for dependency updates
+    Updates(commands::UpdatesArgs),
 }
 
 #[derive(Debug, Clone, Copy, clap::ValueEnum)]
@@ -111,6 +134,8 @@ fn main() -> Result<()> {
     // Handle subcommands
     match cli.command {
         Some(Command::Config { command }) => commands::handle_config_command(command),
+        Some(Command::Rules { command }) => commands::handle_rules_command(command),
+        Some(Command::Updates(args)) => commands::handle_updates_command(args),
         None => {
             // Parse category strings to Category enums
             let categories: Vec<Category> = cli
@@ -132,6 +157,7 @@ fn main() -> Result<()> {
                 no_ignore: cli.no_ignore,
                 no_gitignore: cli.no_gitignore,
                 ignore_patterns: cli.ignore_patterns.clone(),
+                rules_dirs: cli.rules_dirs.clone(),
                 verbose: cli.verbose,
                 json: cli.json,
                 format: match cli.format {
@@ -7,6 +7,6 @@ pub use security::{
     validate_project_id, validate_project_name, validate_project_path, SecurityError,
 };
 pub use types::{
-    AnalysisTarget, DannyConfig, FilesTarget, Framework, GlobalSettings, PackageSuggestion,
-    PackageTarget, Project, ProjectSettings, WorkspaceMemberConfig,
+    AnalysisTarget, DannyConfig, FilesTarget, GlobalSettings, PackageSuggestion, PackageTarget,
+    Project, ProjectSettings, WorkspaceMemberConfig,
 };
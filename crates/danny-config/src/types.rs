@@ -269,8 +269,8 @@ pub struct PackageTarget {
     pub root: PathBuf,
     /// Entry points detected or configured
     pub entry_points: Vec<PathBuf>,
-    /// Detected framework (if any)
-    pub framework: Option<Framework>,
+    /// Detected frameworks, highest priority first
+    pub frameworks: Vec<String>,
     /// Whether node_modules exists
     pub has_node_modules: bool,
 }
@@ -291,7 +291,7 @@ impl PackageTarget {
         }
 
         // Add Framework if detected
-        if self.framework.is_some() {
+        if !self.frameworks.is_empty() {
             caps.mark_available(Category::Framework);
         } else {
             caps.mark_unavailable(
@@ -339,23 +339,3 @@ pub struct PackageSuggestion {
     pub package_json: PathBuf,
     pub command: String,
 }
-
-/// Detected framework
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum Framework {
-    NextJs,
-    React,
-    Vue,
-    Svelte,
-}
-
-impl Framework {
-    pub fn name(&self) -> &'static str {
-        match self {
-            Framework::NextJs => "Next.js",
-            Framework::React => "React",
-            Framework::Vue => "Vue",
-            Framework::Svelte => "Svelte",
-        }
-    }
-}
@@ -167,6 +167,7 @@ mod tests {
                 statistics: Statistics::default(),
                 errors: vec![],
                 ignored_findings: vec![],
+                rule_explanations: vec![],
             })
         }
     }
@@ -62,9 +62,11 @@ pub mod validation;
 pub use backend::{BackendRegistry, LanguageBackend};
 pub use error::{Error, Result};
 pub use types::{
-    AnalysisCapabilities, AnalysisError, AnalysisMode, AnalysisOptions, AnalysisResult, Category,
-    ClassMemberKind, ClassMemberStats, Dependency, DependencyCoverageStats, EnumStats, EnumValue,
-    ErrorSeverity, ExportKind, Finding, IgnoredFinding, IgnoredFindingsBreakdown, MemberVisibility,
-    NpmDependencyType, PatternType, SafetyAssessment, SourceLocation, Statistics, SymbolSpan,
-    TypeCoverage, UnavailableCategory, UnavailableReason,
+    AnalysisCapabilities, AnalysisError, AnalysisMode, AnalysisOptions, AnalysisResult,
+    BarrelModule, Category, ClassMemberKind, ClassMemberStats, Dependency, DependencyCoverageStats,
+    EnumStats, EnumValue, ErrorSeverity, ExportKind, ExportOutcome, FileMetrics, Finding,
+    HalsteadMetrics, IgnoredFinding, IgnoredFindingsBreakdown, MemberVisibility, NpmDependencyType,
+    PatternType, ReExportOrigin, RuleCheck, RuleExplanation, RuleSeverity, SafetyAssessment,
+    SourceLocation, Statistics, SymbolSpan, TypeCoverage, TypeMemberKind, UnavailableCategory,
+    UnavailableReason, UnlistedDependencyReason,
 };
@@ -29,6 +29,8 @@ pub enum Category {
     Quality,
     /// Framework-detected exports (informational)
     Framework,
+    /// Matches of custom `warn`/`severity` rules
+    Rules,
 }
 
 impl Category {
@@ -44,6 +46,7 @@ impl Category {
             Category::Circular,
             Category::Quality,
             Category::Framework,
+            Category::Rules,
         ]
     }
 
@@ -59,6 +62,7 @@ impl Category {
             Category::Circular => "Circular",
             Category::Quality => "Quality",
             Category::Framework => "Framework",
+            Category::Rules => "Rules",
         }
     }
 
@@ -74,6 +78,7 @@ impl Category {
             Category::Circular => "circular",
             Category::Quality => "quality",
             Category::Framework => "framework",
+            Category::Rules => "rules",
         }
     }
 
@@ -89,6 +94,7 @@ impl Category {
             Category::Circular => "Circular dependency cycles",
             Category::Quality => "Code quality issues",
             Category::Framework => "Framework-specific exports",
+            Category::Rules => "Custom rule matches",
         }
     }
 
@@ -101,6 +107,7 @@ impl Category {
                 | Category::Dependencies
                 | Category::Circular
                 | Category::Framework
+                | Category::Rules
         )
     }
 
@@ -164,6 +171,7 @@ impl AnalysisCapabilities {
                 Category::Imports,
                 Category::Circular,
                 Category::Quality,
+                Category::Rules,
                 // Dependencies and Framework added conditionally via mark_available
             ]),
             unavailable: Vec::new(),
@@ -201,6 +209,10 @@ impl AnalysisCapabilities {
                     category: Category::Framework,
                     reason: UnavailableReason::RequiresPackageJson,
                 },
+                UnavailableCategory {
+                    category: Category::Rules,
+                    reason: UnavailableReason::RequiresFullGraph,
+                },
             ],
             mode: AnalysisMode::Files,
         }
@@ -385,6 +397,21 @@ pub enum Finding {
         explanation: Option<Explanation>,
     },
 
+    /// A file or export that is only reachable from test files.
+    UsedOnlyInTests {
+        /// Module that is only used by tests.
+        module: PathBuf,
+
+        /// Export used only by tests (`None` when the whole file is test-only).
+        export_name: Option<String>,
+
+        /// Source location of the export.
+        span: Option<SourceLocation>,
+
+        /// Modules importing it (test files or other test-only modules).
+        used_by: Vec<PathBuf>,
+    },
+
     /// An unused internal symbol (function, variable, class, etc.).
     UnusedSymbol {
         /// Module containing the symbol.
@@ -471,6 +498,27 @@ pub enum Finding {
         span: SymbolSpan,
     },
 
+    /// Member of an exported interface or object type alias that is never
+    /// read or written
+    ///
+    /// Usage is matched by member name across the whole project, so common
+    /// names (`id`, `name`) are almost never reported. Members read only
+    /// through a spread, `Object.keys`, `JSON.stringify` or a computed key
+    /// are missed; types whose annotated values are used that way are
+    /// skipped, but unannotated values can still produce false positives.
+    UnusedTypeMember {
+        /// Module containing the type
+        module: PathBuf,
+        /// Interface or type alias name
+        type_name: String,
+        /// Member name
+        member_name: String,
+        /// Member kind (property or method)
+        member_kind: TypeMemberKind,
+        /// Source location
+        span: SymbolSpan,
+    },
+
     /// Unused npm dependency (declared but never imported)
     UnusedNpmDependency {
         /// Package name
@@ -481,6 +529,18 @@ pub enum Finding {
         dep_type: NpmDependencyType,
     },
 
+    /// Unlisted npm dependency (imported but not declared in the nearest package.json)
+    UnlistedNpmDependency {
+        /// Package name
+        package: String,
+        /// Why the import isn't covered by package.json
+        reason: UnlistedDependencyReason,
+        /// package.json the import was checked against
+        manifest: PathBuf,
+        /// Import locations referencing the package
+        locations: Vec<SourceLocation>,
+    },
+
     /// Side-effect-only import (cannot be tree-shaken)
     SideEffectOnlyImport {
         /// Module containing the import
@@ -525,6 +585,32 @@ pub enum Finding {
         size: usize,
     },
 
+    /// Barrel re-export that no consumer imports through the barrel
+    UnusedReExport {
+        /// Barrel module containing the re-export
+        barrel: PathBuf,
+        /// Re-exported name (`*` for `export * from`)
+        export_name: String,
+        /// Re-export source specifier
+        source: String,
+        /// Source location of the re-export
+        span: Option<SourceLocation>,
+        /// Original declaration the re-export resolves to (named re-exports only)
+        origin: Option<ReExportOrigin>,
+        /// Modules the re-export passes through, from the barrel to the declaration
+        chain: Vec<PathBuf>,
+    },
+
+    /// Barrel that forces importers to load large modules with side effects
+    SideEffectfulBarrel {
+        /// Barrel module
+        barrel: PathBuf,
+        /// Side-effectful modules loaded by importing the barrel
+        modules: Vec<BarrelModule>,
+        /// Total size of those modules in bytes
+        total_size: usize,
+    },
+
     /// Dependency chain (import path analysis)
     DependencyChain {
         /// Chain of module paths from entry to target
@@ -556,6 +642,27 @@ pub enum Finding {
         /// Detailed information about the smell.
         details: CodeSmellDetails,
     },
+
+    /// A custom rule with a `warn` or `severity` action matched.
+    RuleMatch {
+        /// Rule name.
+        rule: String,
+
+        /// Message from the rule's action.
+        message: String,
+
+        /// Severity from the rule's action.
+        severity: RuleSeverity,
+
+        /// Module the rule matched.
+        module: PathBuf,
+
+        /// Matched export (export-level rules only).
+        export_name: Option<String>,
+
+        /// Location of the matched export or import.
+        span: Option<SourceLocation>,
+    },
 }
 
 impl Finding {
@@ -567,30 +674,40 @@ impl Finding {
         use Finding::*;
         match self {
             // Files category: unused/unreachable files and modules
-            UnreachableFile { .. } | UnreachableModule { .. } | DeadCodeModule { .. } => {
-                Category::Files
-            }
+            UnreachableFile { .. }
+            | UnreachableModule { .. }
+            | DeadCodeModule { .. }
+            | UsedOnlyInTests {
+                export_name: None, ..
+            } => Category::Files,
             // Exports category: unused exports (runtime)
             UnusedExport {
                 is_type_only: false,
                 ..
-            } => Category::Exports,
+            }
+            | UsedOnlyInTests {
+                export_name: Some(_),
+                ..
+            }
+            | UnusedReExport { .. } => Category::Exports,
             // Types category: unused TypeScript types/interfaces
             UnusedExport {
                 is_type_only: true, ..
-            } => Category::Types,
+            }
+            | UnusedTypeMember { .. } => Category::Types,
             // Symbols category: dead code within files
             UnusedSymbol { .. }
             | UnusedPrivateClassMember { .. }
             | UnusedPublicClassMember { .. }
             | UnusedEnumMember { .. } => Category::Symbols,
             // Dependencies category: unused npm packages
-            UnusedNpmDependency { .. } => Category::Dependencies,
+            UnusedNpmDependency { .. } | UnlistedNpmDependency { .. } => Category::Dependencies,
             // Imports category: import patterns & analysis
             SideEffectOnlyImport { .. }
             | NamespaceImport { .. }
             | TypeOnlyImport { .. }
             | DependencyChain { .. }
+            | SideEffectfulBarrel { .. }
             | DynamicImport(_) => Category::Imports,
             // Circular category: circular dependency cycles
             CircularDependency(_) => Category::Circular,
@@ -598,6 +715,8 @@ impl Finding {
             CodeSmell { .. } => Category::Quality,
             // Framework category: framework-detected exports
             FrameworkExport { .. } => Category::Framework,
+            // Rules category: custom policy rule matches
+            RuleMatch { .. } => Category::Rules,
             // These are informational/internal and don't map to user categories
             Module { .. } | Dependency { .. } | Pattern { .. } | Framework { .. } => {
                 Category::Framework
@@ -649,8 +768,14 @@ pub enum CodeSmellType {
     /// Class has too many fields.
     TooManyFields,
 
-    /// Function does too many things (low cohesion).
+    /// Class methods form unrelated groups (low cohesion, LCOM4).
     LowCohesion,
+
+    /// Function is hard to follow (high cognitive complexity).
+    CognitiveComplexity,
+
+    /// Module has a low maintainability index.
+    LowMaintainability,
 }
 
 /// Severity level for code smells.
@@ -667,6 +792,20 @@ pub enum SmellSeverity {
     Error,
 }
 
+/// Severity level for custom rule matches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleSeverity {
+    /// Informational - no action required.
+    Info,
+
+    /// Warning - should be reviewed.
+    Warning,
+
+    /// Error - violates a project policy.
+    Error,
+}
+
 /// Detailed information about a code smell.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct CodeSmellDetails {
@@ -682,6 +821,15 @@ pub struct CodeSmellDetails {
     /// Recommended threshold value.
     pub recommended_threshold: Option<usize>,
 
+    /// Fractional metric value (e.g., maintainability index), for metrics
+    /// that `current_value` can't hold without rounding.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score: Option<f64>,
+
+    /// Threshold the `score` was checked against.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score_threshold: Option<f64>,
+
     /// Additional metadata (JSON value for extensibility).
     #[serde(default)]
     pub metadata: HashMap<String, serde_json::Value>,
@@ -698,6 +846,63 @@ pub struct CodeSmellStats {
 
     /// Breakdown by severity.
     pub by_severity: Vec<(SmellSeverity, usize)>,
+
+    /// Complexity and maintainability metrics of each analyzed file.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub file_metrics: Vec<FileMetrics>,
+}
+
+/// Complexity and maintainability metrics of one file.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct FileMetrics {
+    /// File path.
+    pub path: PathBuf,
+
+    /// Lines containing code (blank and comment-only lines excluded).
+    pub lines_of_code: usize,
+
+    /// Number of top-level functions and methods.
+    pub functions: usize,
+
+    /// Cyclomatic complexity of the whole file (decision points + 1).
+    pub cyclomatic_complexity: usize,
+
+    /// Sum of the cognitive complexity of all functions.
+    pub cognitive_complexity: usize,
+
+    /// Highest cognitive complexity of a single function.
+    pub max_cognitive_complexity: usize,
+
+    /// Halstead measures of the file's tokens.
+    pub halstead: HalsteadMetrics,
+
+    /// Maintainability index, normalized to 0-100 (higher is better).
+    pub maintainability_index: f64,
+}
+
+/// Halstead software science measures.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct HalsteadMetrics {
+    /// Number of distinct operators (n1).
+    pub distinct_operators: usize,
+
+    /// Number of distinct operands (n2).
+    pub distinct_operands: usize,
+
+    /// Total number of operators (N1).
+    pub total_operators: usize,
+
+    /// Total number of operands (N2).
+    pub total_operands: usize,
+
+    /// Program volume: N * log2(n).
+    pub volume: f64,
+
+    /// Difficulty: (n1 / 2) * (N2 / n2).
+    pub difficulty: f64,
+
+    /// Effort: difficulty * volume.
+    pub effort: f64,
 }
 
 /// Types of patterns that can be detected.
@@ -782,6 +987,15 @@ pub enum ClassMemberKind {
     Constructor,
 }
 
+/// Interface or type literal member kind.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TypeMemberKind {
+    /// Property signature (`name: string`)
+    Property,
+    /// Method signature (`load(): void`)
+    Method,
+}
+
 /// Member visibility.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum MemberVisibility {
@@ -817,6 +1031,15 @@ pub enum NpmDependencyType {
     Optional,
 }
 
+/// Why an imported npm package is reported as unlisted.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UnlistedDependencyReason {
+    /// Not declared in any dependency section of package.json
+    NotDeclared,
+    /// Imported by production code but only declared in devDependencies
+    DevDependencyInProduction,
+}
+
 /// Symbol source location with line and column information.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SymbolSpan {
@@ -910,6 +1133,10 @@ pub struct AnalysisResult {
     /// Always populated for transparency in JSON output.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub ignored_findings: Vec<IgnoredFinding>,
+
+    /// Per-rule evaluation of selected exports (only when requested).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rule_explanations: Vec<RuleExplanation>,
 }
 
 /// Summary statistics from analysis.
@@ -971,10 +1198,30 @@ pub struct Statistics {
     #[serde(default)]
     pub unused_enum_members_count: usize,
 
+    /// Number of unused interface and type alias members
+    #[serde(default)]
+    pub unused_type_members_count: usize,
+
     /// Number of unused npm dependencies
     #[serde(default)]
     pub unused_npm_dependencies_count: usize,
 
+    /// Number of unlisted npm dependencies
+    #[serde(default)]
+    pub unlisted_npm_dependencies_count: usize,
+
+    /// Number of files and exports used only by tests
+    #[serde(default)]
+    pub used_only_in_tests_count: usize,
+
+    /// Number of barrel re-exports no consumer imports
+    #[serde(default)]
+    pub unused_re_exports_count: usize,
+
+    /// Number of barrels loading large side-effectful modules
+    #[serde(default)]
+    pub side_effectful_barrels_count: usize,
+
     /// Number of side-effect-only imports
     #[serde(default)]
     pub side_effect_only_imports_count: usize,
@@ -1151,6 +1398,32 @@ pub struct DynamicImportInfo {
     pub creates_chunk: bool,
 }
 
+/// Original declaration behind a barrel re-export.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ReExportOrigin {
+    /// Module declaring the export
+    pub module: PathBuf,
+
+    /// Export name in the declaring module
+    pub export_name: String,
+
+    /// Source location of the declaration
+    pub span: Option<SourceLocation>,
+
+    /// Whether anything imports the declaration without going through the barrel
+    pub is_used: bool,
+}
+
+/// Module loaded as a side effect of importing a barrel.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BarrelModule {
+    /// Module path
+    pub path: PathBuf,
+
+    /// Module size in bytes
+    pub size: usize,
+}
+
 /// Circular dependency detected (Feature 5)
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct CircularDependency {
@@ -1177,6 +1450,54 @@ pub struct IgnoredFinding {
     pub matched_path: PathBuf,
 }
 
+/// How every loaded framework rule evaluated a single export.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RuleExplanation {
+    /// Module containing the export.
+    pub module: PathBuf,
+
+    /// Export name.
+    pub export_name: String,
+
+    /// Final state of the export after analysis.
+    pub outcome: ExportOutcome,
+
+    /// Every loaded rule, in evaluation order.
+    pub rules: Vec<RuleCheck>,
+}
+
+/// Result of evaluating one rule against an export.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RuleCheck {
+    /// Framework (or rule file) the rule belongs to.
+    pub framework: String,
+
+    /// Rule name.
+    pub rule: String,
+
+    /// Whether every matcher condition matched.
+    pub matched: bool,
+
+    /// First matcher condition that failed (e.g., "import_from").
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failed_condition: Option<String>,
+
+    /// Action the rule takes when it matches.
+    pub action: String,
+}
+
+/// Final state of an export after analysis.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportOutcome {
+    /// Marked used by a framework rule.
+    FrameworkUsed,
+    /// Used by an import.
+    Used,
+    /// Reported as unused.
+    Unused,
+}
+
 /// Breakdown of ignored findings by finding type.
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub struct IgnoredFindingsBreakdown {
@@ -1286,6 +1607,118 @@ mod tests {
         assert_eq!(finding, deserialized);
     }
 
+    #[test]
+    fn test_unlisted_npm_dependency_serialization() {
+        let finding = Finding::UnlistedNpmDependency {
+            package: "lodash".to_string(),
+            reason: UnlistedDependencyReason::DevDependencyInProduction,
+            manifest: PathBuf::from("/test/package.json"),
+            locations: vec![SourceLocation {
+                file: PathBuf::from("/test/src/index.ts"),
+                start: 0,
+                end: 27,
+            }],
+        };
+
+        let json = serde_json::to_string(&finding).unwrap();
+        assert!(json.contains("\"type\":\"UnlistedNpmDependency\""));
+        let deserialized: Finding = serde_json::from_str(&json).unwrap();
+        assert_eq!(finding, deserialized);
+        assert_eq!(finding.category(), Category::Dependencies);
+    }
+
+    #[test]
+    fn test_used_only_in_tests_category() {
+        let file = Finding::UsedOnlyInTests {
+            module: PathBuf::from("/test/src/fixtures.ts"),
+            export_name: None,
+            span: None,
+            used_by: vec![PathBuf::from("/test/src/app.test.ts")],
+        };
+        let export = Finding::UsedOnlyInTests {
+            module: PathBuf::from("/test/src/math.ts"),
+            export_name: Some("clamp".to_string()),
+            span: None,
+            used_by: vec![PathBuf::from("/test/src/math.test.ts")],
+        };
+
+        assert_eq!(file.category(), Category::Files);
+        assert_eq!(export.category(), Category::Exports);
+
+        let json = serde_json::to_string(&export).unwrap();
+        let deserialized: Finding = serde_json::from_str(&json).unwrap();
+        assert_eq!(export, deserialized);
+    }
+
+    #[test]
+    fn test_rule_match_category() {
+        let finding = Finding::RuleMatch {
+            rule: "no-moment".to_string(),
+            message: "Use date-fns instead of moment".to_string(),
+            severity: RuleSeverity::Error,
+            module: PathBuf::from("/test/src/date.ts"),
+            export_name: None,
+            span: None,
+        };
+
+        assert_eq!(finding.category(), Category::Rules);
+        assert_eq!(Category::from_cli_name("rules"), Some(Category::Rules));
+
+        let json = serde_json::to_value(&finding).unwrap();
+        assert_eq!(json["type"], "RuleMatch");
+        assert_eq!(json["severity"], "error");
+        let deserialized: Finding = serde_json::from_value(json).unwrap();
+        assert_eq!(finding, deserialized);
+    }
+
+    #[test]
+    fn test_unused_re_export_serialization() {
+        let finding = Finding::UnusedReExport {
+            barrel: PathBuf::from("/test/src/index.ts"),
+            export_name: "Button".to_string(),
+            source: "./components".to_string(),
+            span: None,
+            origin: Some(ReExportOrigin {
+                module: PathBuf::from("/test/src/components/Button.ts"),
+                export_name: "Button".to_string(),
+                span: None,
+                is_used: false,
+            }),
+            chain: vec![
+                PathBuf::from("/test/src/index.ts"),
+                PathBuf::from("/test/src/components/index.ts"),
+                PathBuf::from("/test/src/components/Button.ts"),
+            ],
+        };
+
+        let json = serde_json::to_string(&finding).unwrap();
+        let deserialized: Finding = serde_json::from_str(&json).unwrap();
+        assert_eq!(finding, deserialized);
+        assert_eq!(finding.category(), Category::Exports);
+    }
+
+    #[test]
+    fn test_unused_type_member_serialization() {
+        let finding = Finding::UnusedTypeMember {
+            module: PathBuf::from("/test/src/dto.ts"),
+            type_name: "UserDto".to_string(),
+            member_name: "legacyId".to_string(),
+            member_kind: TypeMemberKind::Property,
+            span: SymbolSpan {
+                file: PathBuf::from("/test/src/dto.ts"),
+                line: 3,
+                column: 4,
+                offset: 42,
+            },
+        };
+
+        let json = serde_json::to_string(&finding).unwrap();
+        assert!(json.contains("\"type\":\"UnusedTypeMember\""));
+        let deserialized: Finding = serde_json::from_str(&json).unwrap();
+        assert_eq!(finding, deserialized);
+        assert_eq!(finding.category(), Category::Types);
+    }
+
     #[test]
     fn test_statistics_defaults() {
         let stats = Statistics::default();
@@ -1388,6 +1821,7 @@ mod tests {
                 matched_pattern: "**/node_modules/**".to_string(),
                 matched_path: PathBuf::from("node_modules/pkg/ignored.js"),
             }],
+            rule_explanations: vec![],
         };
 
         let json = serde_json::to_string(&result).unwrap();
@@ -1406,6 +1840,7 @@ mod tests {
             statistics: Statistics::default(),
             errors: vec![],
             ignored_findings: vec![],
+            rule_explanations: vec![],
         };
 
         let json = serde_json::to_string_pretty(&result).unwrap();
@@ -1416,4 +1851,66 @@ mod tests {
         let deserialized: AnalysisResult = serde_json::from_str(&json).unwrap();
         assert_eq!(deserialized.ignored_findings.len(), 0);
     }
+
+    #[test]
+    fn test_rule_explanation_serialization() {
+        let explanation = RuleExplanation {
+            module: PathBuf::from("src/hooks.ts"),
+            export_name: "useAuth".to_string(),
+            outcome: ExportOutcome::FrameworkUsed,
+            rules: vec![RuleCheck {
+                framework: "React".to_string(),
+                rule: "react-hooks".to_string(),
+                matched: false,
+                failed_condition: Some("import_from".to_string()),
+                action: "mark used".to_string(),
+            }],
+        };
+
+        let json = serde_json::to_value(&explanation).unwrap();
+        assert_eq!(json["outcome"], "framework_used");
+        assert_eq!(json["rules"][0]["failed_condition"], "import_from");
+
+        let deserialized: RuleExplanation = serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized, explanation);
+    }
+
+    #[test]
+    fn test_code_smell_stats_file_metrics_serialization() {
+        let stats = CodeSmellStats {
+            total_smells: 1,
+            by_type: vec![(CodeSmellType::CognitiveComplexity, 1)],
+            by_severity: vec![(SmellSeverity::Warning, 1)],
+            file_metrics: vec![FileMetrics {
+                path: PathBuf::from("src/app.ts"),
+                lines_of_code: 40,
+                functions: 3,
+                cyclomatic_complexity: 7,
+                cognitive_complexity: 18,
+                max_cognitive_complexity: 16,
+                halstead: HalsteadMetrics {
+                    distinct_operators: 12,
+                    distinct_operands: 20,
+                    total_operators: 90,
+                    total_operands: 70,
+                    volume: 800.0,
+                    difficulty: 21.0,
+                    effort: 16800.0,
+                },
+                maintainability_index: 42.5,
+            }],
+        };
+
+        let json = serde_json::to_value(&stats).unwrap();
+        assert_eq!(json["by_type"][0][0], "cognitive_complexity");
+        assert_eq!(json["file_metrics"][0]["maintainability_index"], 42.5);
+        assert_eq!(json["file_metrics"][0]["halstead"]["volume"], 800.0);
+
+        let deserialized: CodeSmellStats = serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized, stats);
+
+        // The table is omitted when metrics weren't collected
+        let json = serde_json::to_value(CodeSmellStats::default()).unwrap();
+        assert!(json.get("file_metrics").is_none());
+    }
 }
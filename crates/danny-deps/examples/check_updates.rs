@@ -2,21 +2,28 @@
 //!
 //! Run with: cargo run --package danny-deps --example check_updates
 
-use danny_deps::{Ecosystem, UnifiedDependencyManager};
+use danny_config::{ConfigManager, GlobalSettings};
+use danny_deps::{Ecosystem, PackageError, UnifiedDependencyManager};
 use std::path::Path;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     println!("=== danny-deps: Check for Updates ===\n");
 
-    let manager = UnifiedDependencyManager::new()?;
+    // Use ~/.danny/config.toml settings when present
+    let settings = match ConfigManager::load().await {
+        Ok(config) => config.config().settings.clone(),
+        Err(_) => GlobalSettings::default(),
+    };
+    let manager = UnifiedDependencyManager::from_settings(&settings)?;
 
     // Check Rust dependencies
     let cargo_toml = Path::new("Cargo.toml");
     if cargo_toml.exists() {
         println!("Checking Rust dependencies in Cargo.toml...\n");
         match manager.check_updates(cargo_toml, Ecosystem::Rust).await {
-            Ok(updates) => {
+            Ok(report) => {
+                let updates = report.recommendations;
                 println!("Found {} potential updates\n", updates.len());
 
                 // Show breaking updates
@@ -67,6 +74,8 @@ async fn main() -> anyhow::Result<()> {
                         );
                     }
                 }
+
+                print_errors(&report.errors);
             }
             Err(e) => println!("Error checking updates: {}\n", e),
         }
@@ -80,7 +89,8 @@ async fn main() -> anyhow::Result<()> {
             .check_updates(package_json, Ecosystem::JavaScript)
             .await
         {
-            Ok(updates) => {
+            Ok(report) => {
+                let updates = report.recommendations;
                 println!("Found {} potential updates\n", updates.len());
 
                 // Show breaking updates
@@ -110,6 +120,8 @@ async fn main() -> anyhow::Result<()> {
                         println!("  ... and {} more", safe.len() - 10);
                     }
                 }
+
+                print_errors(&report.errors);
             }
             Err(e) => println!("Error checking updates: {}\n", e),
         }
@@ -117,3 +129,13 @@ async fn main() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+fn print_errors(errors: &[PackageError]) {
+    if !errors.is_empty() {
+        println!("❌ Failed to Check:");
+        for error in errors {
+            println!("  {}: {}", error.package, error.error);
+        }
+        println!();
+    }
+}
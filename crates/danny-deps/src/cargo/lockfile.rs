@@ -1,6 +1,6 @@
 //! Cargo.lock parser using cargo_lock crate
 
-use crate::{Error, LockedDependencies, LockedPackage, LockfileParser, Result};
+use crate::{Error, LockedDependencies, LockedPackage, LockfileGraph, LockfileParser, Result};
 use cargo_lock::Lockfile;
 use danny_fs::FileSystem;
 use std::path::Path;
@@ -45,6 +45,39 @@ impl LockfileParser for CargoLockfileParser {
         Ok(LockedDependencies { packages })
     }
 
+    async fn parse_graph<F: FileSystem>(&self, _fs: &Arc<F>, path: &Path) -> Result<LockfileGraph> {
+        let lockfile = Lockfile::load(path).map_err(|e| Error::CargoLock(e.to_string()))?;
+
+        let mut graph = LockfileGraph::new();
+        let ids: Vec<_> = lockfile
+            .packages
+            .iter()
+            .map(|pkg| {
+                graph.add_package(LockedPackage {
+                    name: pkg.name.as_str().to_string(),
+                    version: pkg.version.to_string(),
+                    checksum: pkg.checksum.as_ref().map(|c| c.to_string()),
+                    resolved: pkg.source.as_ref().map(|s| s.to_string()),
+                })
+            })
+            .collect();
+
+        for (pkg, &id) in lockfile.packages.iter().zip(&ids) {
+            // Workspace members and path dependencies have no source
+            if pkg.source.is_none() {
+                graph.add_member(id);
+            }
+            for dep in &pkg.dependencies {
+                if let Some(dep_id) = graph.find(dep.name.as_str(), &dep.version.to_string()) {
+                    // Cargo.lock doesn't record the declared requirement
+                    graph.add_dependency(id, dep_id, None);
+                }
+            }
+        }
+
+        Ok(graph)
+    }
+
     async fn verify_integrity<F: FileSystem>(&self, _fs: &Arc<F>, path: &Path) -> Result<()> {
         // cargo_lock crate handles checksum verification automatically
         // Lockfile::load validates checksums during parsing
@@ -92,4 +125,56 @@ version = "0.1.0"
         // Should parse successfully (even if minimal)
         assert!(result.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_parse_graph_keeps_duplicate_versions() {
+        let temp_dir = TempDir::new().unwrap();
+        let lockfile_path = temp_dir.path().join("Cargo.lock");
+        std::fs::write(
+            &lockfile_path,
+            r#"version = 3
+
+[[package]]
+name = "app"
+version = "0.1.0"
+dependencies = [
+ "bitflags 1.3.2",
+ "log",
+]
+
+[[package]]
+name = "bitflags"
+version = "1.3.2"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+
+[[package]]
+name = "bitflags"
+version = "2.4.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+
+[[package]]
+name = "log"
+version = "0.4.20"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+dependencies = [
+ "bitflags 2.4.0",
+]
+"#,
+        )
+        .unwrap();
+
+        let parser = CargoLockfileParser::new();
+        let fs = Arc::new(NativeFileSystem::new(temp_dir.path()).unwrap());
+        let graph = parser.parse_graph(&fs, &lockfile_path).await.unwrap();
+
+        assert_eq!(graph.versions("bitflags").len(), 2);
+        let app = graph.find("app", "0.1.0").unwrap();
+        assert_eq!(graph.roots(), &[app]);
+        assert!(graph.is_member(app));
+
+        let paths = graph.why("bitflags", Some("2.4.0"));
+        assert_eq!(paths.len(), 1);
+        let names: Vec<&str> = paths[0].iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["app", "log", "bitflags"]);
+    }
 }
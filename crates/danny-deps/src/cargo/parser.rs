@@ -56,11 +56,22 @@ impl CargoDependencyManager {
                     .and_then(|v| v.as_bool())
                     .unwrap_or(false);
 
-                let source = table
-                    .get("git")
-                    .or_else(|| table.get("path"))
-                    .and_then(|v| v.as_str())
-                    .map(String::from);
+                let field = |key: &str| table.get(key).and_then(|v| v.as_str());
+                let source = if let Some(git) = field("git") {
+                    Some(format!("git+{}", git))
+                } else if let Some(path) = field("path") {
+                    Some(format!("path+{}", path))
+                } else if let Some(index) = field("registry-index") {
+                    Some(
+                        if index.starts_with("sparse+") || index.starts_with("registry+") {
+                            index.to_string()
+                        } else {
+                            format!("registry+{}", index)
+                        },
+                    )
+                } else {
+                    field("registry").map(String::from)
+                };
 
                 Ok(Dependency {
                     name: name.to_string(),
@@ -318,6 +329,50 @@ serde = { version = "1.0", features = ["derive"] }
         assert_eq!(deps[0].features, vec!["derive"]);
     }
 
+    #[tokio::test]
+    async fn test_parse_dependency_sources() {
+        let temp_dir = TempDir::new().unwrap();
+        let cargo_toml = temp_dir.path().join("Cargo.toml");
+        std::fs::write(
+            &cargo_toml,
+            r#"
+[package]
+name = "test"
+version = "0.1.0"
+
+[dependencies]
+serde = "1.0"
+tokio = { git = "https://github.com/tokio-rs/tokio" }
+local = { path = "../local" }
+acme-core = { version = "1.0", registry = "acme" }
+acme-util = { version = "1.0", registry-index = "sparse+https://index.acme.dev/" }
+"#,
+        )
+        .unwrap();
+
+        let manager = CargoDependencyManager::new();
+        let fs = Arc::new(NativeFileSystem::new(temp_dir.path()).unwrap());
+        let manifest = manager.parse(&fs, &cargo_toml).await.unwrap();
+
+        let source = |name: &str| {
+            manifest
+                .all_dependencies()
+                .find(|dep| dep.name == name)
+                .and_then(|dep| dep.source.clone())
+        };
+        assert_eq!(source("serde"), None);
+        assert_eq!(
+            source("tokio").as_deref(),
+            Some("git+https://github.com/tokio-rs/tokio")
+        );
+        assert_eq!(source("local").as_deref(), Some("path+../local"));
+        assert_eq!(source("acme-core").as_deref(), Some("acme"));
+        assert_eq!(
+            source("acme-util").as_deref(),
+            Some("sparse+https://index.acme.dev/")
+        );
+    }
+
     #[tokio::test]
     async fn test_parse_workspace_root() {
         let temp_dir = TempDir::new().unwrap();
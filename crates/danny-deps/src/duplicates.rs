@@ -0,0 +1,447 @@
+//! Duplicate package versions
+//!
+//! Finds packages a lockfile resolves to more than one version, shows
+//! which dependency chains pull each copy in, and works out whether the
+//! copies could collapse into a single version.
+
+use crate::graph::{LockfileGraph, NodeId};
+use crate::{compare_versions, Ecosystem, ParsedVersionReq};
+use std::collections::{HashMap, HashSet};
+
+/// Every package installed in more than one version
+#[derive(Debug, Clone, Default)]
+pub struct DuplicateReport {
+    /// Duplicated packages, most redundant packages first
+    pub packages: Vec<DuplicatePackage>,
+}
+
+/// A package installed in more than one version
+#[derive(Debug, Clone)]
+pub struct DuplicatePackage {
+    /// Package name
+    pub name: String,
+    /// Installed versions, lowest first
+    pub versions: Vec<DuplicateVersion>,
+    /// Whether one version could replace every copy
+    pub resolution: DedupeResolution,
+    /// Version kept when collapsing: the deduplicated version, or the highest
+    pub target: String,
+    /// Packages that would no longer be installed once every copy is `target`
+    ///
+    /// Lockfiles don't record package sizes, so this package count is the
+    /// estimate of the disk and bundle cost of the duplicates.
+    pub redundant_packages: usize,
+    /// Manifest changes that would remove the other versions
+    ///
+    /// Empty for [`DedupeResolution::Dedupe`], where refreshing the
+    /// lockfile (`npm dedupe`, `yarn dedupe`, `cargo update`) is enough.
+    pub suggestions: Vec<DedupeSuggestion>,
+}
+
+/// One installed version of a duplicated package
+#[derive(Debug, Clone)]
+pub struct DuplicateVersion {
+    /// Locked version
+    pub version: String,
+    /// Dependency chains from the project, root first, as `name@version`
+    pub paths: Vec<Vec<String>>,
+    /// Ranges through which this version is required
+    pub requirements: Vec<VersionRequirement>,
+    /// Packages only installed because of this copy, itself included
+    pub exclusive_packages: usize,
+}
+
+/// A declared range that resolved to a duplicated version
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionRequirement {
+    /// `name@version` of the dependent, or `None` for the project itself
+    pub dependent: Option<String>,
+    /// Declared range, if the lockfile records it
+    pub range: Option<String>,
+}
+
+/// Whether a duplicated package can collapse into one version
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DedupeResolution {
+    /// `version` satisfies every requirement
+    Dedupe {
+        /// Highest locked version satisfying every range
+        version: String,
+    },
+    /// No locked version satisfies every requirement
+    Conflict,
+    /// The known ranges allow one version, but the lockfile doesn't record
+    /// (or danny can't parse) every range (Cargo.lock, pnpm transitive edges)
+    Unknown,
+}
+
+/// A manifest change that removes a duplicated version
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DedupeSuggestion {
+    /// Direct dependency to change in the manifest
+    pub dependency: String,
+    /// Its current range, if recorded
+    pub current_req: Option<String>,
+    /// Range accepting the kept version, when `dependency` is the duplicated
+    /// package itself; `None` means upgrading `dependency` to a release that
+    /// requires the kept version
+    pub suggested_req: Option<String>,
+    /// Duplicated version this change removes
+    pub removes: String,
+}
+
+impl DuplicateReport {
+    /// Find duplicated packages in a lockfile graph
+    pub fn from_graph(graph: &LockfileGraph, ecosystem: Ecosystem) -> Self {
+        let mut by_name: HashMap<&str, Vec<NodeId>> = HashMap::new();
+        for (id, package) in graph.packages() {
+            by_name.entry(package.name.as_str()).or_default().push(id);
+        }
+
+        let mut packages: Vec<_> = by_name
+            .into_iter()
+            .filter(|(_, ids)| ids.len() > 1)
+            .map(|(name, ids)| analyze(graph, ecosystem, name, ids))
+            .collect();
+        packages.sort_by(|a, b| {
+            b.redundant_packages
+                .cmp(&a.redundant_packages)
+                .then_with(|| a.name.cmp(&b.name))
+        });
+
+        Self { packages }
+    }
+
+    /// Whether no package is duplicated
+    pub fn is_empty(&self) -> bool {
+        self.packages.is_empty()
+    }
+
+    /// Packages that would no longer be installed once every duplicate collapses
+    pub fn redundant_packages(&self) -> usize {
+        self.packages.iter().map(|p| p.redundant_packages).sum()
+    }
+}
+
+fn analyze(
+    graph: &LockfileGraph,
+    ecosystem: Ecosystem,
+    name: &str,
+    mut ids: Vec<NodeId>,
+) -> DuplicatePackage {
+    ids.sort_by(|&a, &b| {
+        let (a, b) = (&graph.package(a).version, &graph.package(b).version);
+        compare_versions(a, b, ecosystem).unwrap_or_else(|_| a.cmp(b))
+    });
+
+    let requirements: Vec<Vec<(Option<NodeId>, Option<&str>)>> = ids
+        .iter()
+        .map(|&id| {
+            let dependents = graph
+                .dependents(id)
+                .iter()
+                .map(|&dependent| (Some(dependent), graph.requirement(Some(dependent), id)));
+            let project = graph
+                .roots()
+                .contains(&id)
+                .then(|| (None, graph.requirement(None, id)));
+            dependents.chain(project).collect()
+        })
+        .collect();
+
+    let resolution = resolve(graph, ecosystem, &ids, requirements.iter().flatten());
+    let target = match &resolution {
+        DedupeResolution::Dedupe { version } => graph.find(name, version),
+        _ => None,
+    }
+    .unwrap_or(ids[ids.len() - 1]);
+
+    let installed = reachable(graph, &HashSet::new(), target);
+    let removed: HashSet<NodeId> = ids.iter().copied().filter(|&id| id != target).collect();
+    let redundant_packages = installed.saturating_sub(reachable(graph, &removed, target));
+
+    let versions = ids
+        .iter()
+        .zip(&requirements)
+        .map(|(&id, requirements)| DuplicateVersion {
+            version: graph.package(id).version.clone(),
+            paths: graph
+                .why(name, Some(&graph.package(id).version))
+                .into_iter()
+                .map(|path| {
+                    path.iter()
+                        .map(|p| format!("{}@{}", p.name, p.version))
+                        .collect()
+                })
+                .collect(),
+            requirements: requirements
+                .iter()
+                .map(|&(dependent, range)| VersionRequirement {
+                    dependent: dependent.map(|d| {
+                        let package = graph.package(d);
+                        format!("{}@{}", package.name, package.version)
+                    }),
+                    range: range.map(str::to_string),
+                })
+                .collect(),
+            exclusive_packages: if id == target {
+                0
+            } else {
+                installed.saturating_sub(reachable(graph, &HashSet::from([id]), target))
+            },
+        })
+        .collect();
+
+    let suggestions = match resolution {
+        DedupeResolution::Dedupe { .. } => Vec::new(),
+        _ => suggest(graph, ecosystem, &removed, target),
+    };
+
+    DuplicatePackage {
+        name: name.to_string(),
+        versions,
+        resolution,
+        target: graph.package(target).version.clone(),
+        redundant_packages,
+        suggestions,
+    }
+}
+
+/// Find the highest locked version satisfying every requirement
+fn resolve<'a>(
+    graph: &LockfileGraph,
+    ecosystem: Ecosystem,
+    ids: &[NodeId],
+    requirements: impl Iterator<Item = &'a (Option<NodeId>, Option<&'a str>)>,
+) -> DedupeResolution {
+    let mut complete = true;
+    let mut ranges = Vec::new();
+    for &(_, range) in requirements {
+        match range.and_then(|range| parse_range(range, ecosystem)) {
+            Some(range) => ranges.push(range),
+            None => complete = false,
+        }
+    }
+
+    let satisfying = ids.iter().rev().find(|&&id| {
+        let version = &graph.package(id).version;
+        ranges
+            .iter()
+            .all(|range| range.matches(version).unwrap_or(false))
+    });
+
+    match satisfying {
+        Some(&id) if complete => DedupeResolution::Dedupe {
+            version: graph.package(id).version.clone(),
+        },
+        Some(_) => DedupeResolution::Unknown,
+        None => DedupeResolution::Conflict,
+    }
+}
+
+/// Parse a lockfile range, dropping yarn's `npm:` protocol
+///
+/// Aliases (`npm:other@^1.0.0`), workspace, git and path ranges can't be
+/// checked against a version and yield `None`.
+fn parse_range(range: &str, ecosystem: Ecosystem) -> Option<ParsedVersionReq> {
+    let range = range.strip_prefix("npm:").unwrap_or(range);
+    if range.contains('@') || range.contains(':') {
+        return None;
+    }
+    ParsedVersionReq::parse(range, ecosystem).ok()
+}
+
+/// Count packages installed from the roots when `removed` copies are
+/// replaced by `replacement`
+fn reachable(graph: &LockfileGraph, removed: &HashSet<NodeId>, replacement: NodeId) -> usize {
+    let substitute = |id: NodeId| {
+        if removed.contains(&id) {
+            replacement
+        } else {
+            id
+        }
+    };
+
+    let mut seen = HashSet::new();
+    let mut stack: Vec<NodeId> = graph.roots().iter().map(|&id| substitute(id)).collect();
+    while let Some(id) = stack.pop() {
+        if seen.insert(id) {
+            stack.extend(graph.dependencies(id).iter().map(|&dep| substitute(dep)));
+        }
+    }
+    seen.len()
+}
+
+/// Manifest changes removing each of the `removed` versions
+fn suggest(
+    graph: &LockfileGraph,
+    ecosystem: Ecosystem,
+    removed: &HashSet<NodeId>,
+    target: NodeId,
+) -> Vec<DedupeSuggestion> {
+    let target = graph.package(target);
+    let mut removed: Vec<NodeId> = removed.iter().copied().collect();
+    removed.sort();
+
+    let mut suggestions: Vec<DedupeSuggestion> = Vec::new();
+    for id in removed {
+        let package = graph.package(id);
+        for path in graph.why(&package.name, Some(&package.version)) {
+            let ids: Vec<NodeId> = path
+                .iter()
+                .filter_map(|p| graph.find(&p.name, &p.version))
+                .collect();
+
+            // Members declare their dependencies in a manifest of the project
+            let (dependent, dependency) = match ids.as_slice() {
+                [member, dependency, ..] if graph.is_member(*member) => {
+                    (Some(*member), *dependency)
+                }
+                [member] if graph.is_member(*member) => continue,
+                [dependency, ..] => (None, *dependency),
+                [] => continue,
+            };
+
+            let direct = graph.package(dependency);
+            let suggestion = DedupeSuggestion {
+                dependency: direct.name.clone(),
+                current_req: graph.requirement(dependent, dependency).map(str::to_string),
+                suggested_req: (dependency == id).then(|| match ecosystem {
+                    Ecosystem::Rust => target.version.clone(),
+                    Ecosystem::JavaScript => format!("^{}", target.version),
+                }),
+                removes: package.version.clone(),
+            };
+            if !suggestions.contains(&suggestion) {
+                suggestions.push(suggestion);
+            }
+        }
+    }
+
+    suggestions.sort_by(|a, b| {
+        a.dependency
+            .cmp(&b.dependency)
+            .then_with(|| a.removes.cmp(&b.removes))
+    });
+    suggestions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LockedPackage;
+
+    fn add(graph: &mut LockfileGraph, name: &str, version: &str) -> NodeId {
+        graph.add_package(LockedPackage {
+            name: name.to_string(),
+            version: version.to_string(),
+            checksum: None,
+            resolved: None,
+        })
+    }
+
+    #[test]
+    fn test_duplicates_that_dedupe() {
+        let mut graph = LockfileGraph::new();
+        let ui = add(&mut graph, "ui-kit", "2.0.0");
+        let old = add(&mut graph, "lodash", "4.17.15");
+        let new = add(&mut graph, "lodash", "4.17.21");
+        add(&mut graph, "react", "18.2.0");
+        graph.add_root(ui, Some("^2.0.0"));
+        graph.add_root(new, Some("^4.17.21"));
+        graph.add_dependency(ui, old, Some("^4.17.0"));
+
+        let report = DuplicateReport::from_graph(&graph, Ecosystem::JavaScript);
+        assert_eq!(report.packages.len(), 1);
+
+        let lodash = &report.packages[0];
+        assert_eq!(lodash.name, "lodash");
+        assert_eq!(
+            lodash.resolution,
+            DedupeResolution::Dedupe {
+                version: "4.17.21".to_string()
+            }
+        );
+        assert_eq!(lodash.redundant_packages, 1);
+        assert!(lodash.suggestions.is_empty());
+        assert_eq!(lodash.versions[0].version, "4.17.15");
+        assert_eq!(
+            lodash.versions[0].paths,
+            vec![vec![
+                "ui-kit@2.0.0".to_string(),
+                "lodash@4.17.15".to_string()
+            ]]
+        );
+        assert_eq!(
+            lodash.versions[0].requirements,
+            vec![VersionRequirement {
+                dependent: Some("ui-kit@2.0.0".to_string()),
+                range: Some("^4.17.0".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_conflicting_duplicates_suggest_manifest_bumps() {
+        let mut graph = LockfileGraph::new();
+        let app = add(&mut graph, "app", "1.0.0");
+        let legacy = add(&mut graph, "legacy-table", "1.0.0");
+        let old = add(&mut graph, "react", "17.0.2");
+        let new = add(&mut graph, "react", "18.2.0");
+        let old_scheduler = add(&mut graph, "scheduler", "0.20.2");
+        let scheduler = add(&mut graph, "scheduler", "0.23.0");
+        graph.add_member(app);
+        graph.add_dependency(app, old, Some("^17.0.0"));
+        graph.add_dependency(app, legacy, Some("^1.0.0"));
+        graph.add_dependency(legacy, new, Some("^18.0.0"));
+        graph.add_dependency(old, old_scheduler, Some("^0.20.2"));
+        graph.add_dependency(new, scheduler, Some("^0.23.0"));
+
+        let report = DuplicateReport::from_graph(&graph, Ecosystem::JavaScript);
+        let react = report.packages.iter().find(|p| p.name == "react").unwrap();
+
+        assert_eq!(react.resolution, DedupeResolution::Conflict);
+        assert_eq!(react.target, "18.2.0");
+        // react@17.0.2 and the scheduler only it needs
+        assert_eq!(react.redundant_packages, 2);
+        assert_eq!(react.versions[0].exclusive_packages, 2);
+        assert_eq!(react.versions[1].exclusive_packages, 0);
+        assert_eq!(
+            react.suggestions,
+            vec![DedupeSuggestion {
+                dependency: "react".to_string(),
+                current_req: Some("^17.0.0".to_string()),
+                suggested_req: Some("^18.2.0".to_string()),
+                removes: "17.0.2".to_string(),
+            }]
+        );
+        assert_eq!(report.packages[0].name, "react");
+    }
+
+    #[test]
+    fn test_unrecorded_ranges_are_unknown() {
+        let mut graph = LockfileGraph::new();
+        let app = add(&mut graph, "app", "0.1.0");
+        let log = add(&mut graph, "log", "0.4.20");
+        let old = add(&mut graph, "bitflags", "1.3.2");
+        let new = add(&mut graph, "bitflags", "2.4.0");
+        graph.add_member(app);
+        graph.add_dependency(app, old, None);
+        graph.add_dependency(app, log, None);
+        graph.add_dependency(log, new, None);
+
+        let report = DuplicateReport::from_graph(&graph, Ecosystem::Rust);
+        let bitflags = &report.packages[0];
+
+        assert_eq!(bitflags.resolution, DedupeResolution::Unknown);
+        assert_eq!(
+            bitflags.suggestions,
+            vec![DedupeSuggestion {
+                dependency: "bitflags".to_string(),
+                current_req: None,
+                suggested_req: Some("2.4.0".to_string()),
+                removes: "1.3.2".to_string(),
+            }]
+        );
+    }
+}
@@ -57,6 +57,10 @@ pub enum Error {
     #[error("Workspace detection error: {0}")]
     WorkspaceError(String),
 
+    /// Registry configuration error
+    #[error("Registry configuration error: {0}")]
+    RegistryError(String),
+
     /// Generic error with context
     #[error("{0}")]
     Other(String),
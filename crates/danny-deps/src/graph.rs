@@ -0,0 +1,291 @@
+//! Lockfile dependency graph
+//!
+//! [`LockedDependencies`](crate::LockedDependencies) keeps one version per
+//! package name. [`LockfileGraph`] keeps every `name@version` a lockfile
+//! resolves, plus the dependency edges between them, so callers can find
+//! duplicate versions and explain why a package is installed.
+
+use crate::LockedPackage;
+use std::collections::{HashMap, HashSet};
+
+/// Maximum number of paths returned by [`LockfileGraph::why`]
+///
+/// Large lockfiles can reach a package through thousands of paths; the
+/// first few are enough to explain why it is installed.
+pub const MAX_WHY_PATHS: usize = 100;
+
+/// Index of a package in a [`LockfileGraph`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(usize);
+
+/// Every package@version in a lockfile with its dependency edges
+#[derive(Debug, Clone, Default)]
+pub struct LockfileGraph {
+    nodes: Vec<LockedPackage>,
+    dependencies: Vec<Vec<NodeId>>,
+    dependents: Vec<Vec<NodeId>>,
+    roots: Vec<NodeId>,
+    members: HashSet<NodeId>,
+    /// Declared ranges keyed by (dependent, dependency); `None` is the project
+    requirements: HashMap<(Option<NodeId>, NodeId), String>,
+    index: HashMap<(String, String), NodeId>,
+}
+
+impl LockfileGraph {
+    /// Create an empty graph
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a package, returning the existing node for a known name@version
+    pub fn add_package(&mut self, package: LockedPackage) -> NodeId {
+        let key = (package.name.clone(), package.version.clone());
+        if let Some(id) = self.index.get(&key) {
+            return *id;
+        }
+
+        let id = NodeId(self.nodes.len());
+        self.nodes.push(package);
+        self.dependencies.push(Vec::new());
+        self.dependents.push(Vec::new());
+        self.index.insert(key, id);
+        id
+    }
+
+    /// Record that `from` depends on `to`, through `requirement` if the
+    /// lockfile records the declared range
+    pub fn add_dependency(&mut self, from: NodeId, to: NodeId, requirement: Option<&str>) {
+        if from == to {
+            return;
+        }
+        if !self.dependencies[from.0].contains(&to) {
+            self.dependencies[from.0].push(to);
+            self.dependents[to.0].push(from);
+        }
+        if let Some(requirement) = requirement {
+            self.requirements
+                .insert((Some(from), to), requirement.to_string());
+        }
+    }
+
+    /// Mark a package as required directly by the project, through
+    /// `requirement` if the lockfile records the declared range
+    ///
+    /// Lockfiles that record workspace members (Cargo, Yarn Berry) add
+    /// those with [`add_member`](Self::add_member) instead.
+    pub fn add_root(&mut self, id: NodeId, requirement: Option<&str>) {
+        if !self.roots.contains(&id) {
+            self.roots.push(id);
+        }
+        if let Some(requirement) = requirement {
+            self.requirements
+                .insert((None, id), requirement.to_string());
+        }
+    }
+
+    /// Mark a package as a workspace member of the project
+    ///
+    /// Members are roots whose own dependencies are declared in a
+    /// manifest of the project.
+    pub fn add_member(&mut self, id: NodeId) {
+        self.add_root(id, None);
+        self.members.insert(id);
+    }
+
+    /// Package stored at `id`
+    pub fn package(&self, id: NodeId) -> &LockedPackage {
+        &self.nodes[id.0]
+    }
+
+    /// All packages with their ids
+    pub fn packages(&self) -> impl Iterator<Item = (NodeId, &LockedPackage)> {
+        self.nodes
+            .iter()
+            .enumerate()
+            .map(|(index, package)| (NodeId(index), package))
+    }
+
+    /// Number of package@version nodes
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Whether the graph has no packages
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Node for an exact name@version
+    pub fn find(&self, name: &str, version: &str) -> Option<NodeId> {
+        self.index
+            .get(&(name.to_string(), version.to_string()))
+            .copied()
+    }
+
+    /// Every locked version of `name`, in insertion order
+    pub fn versions(&self, name: &str) -> Vec<NodeId> {
+        self.packages()
+            .filter(|(_, package)| package.name == name)
+            .map(|(id, _)| id)
+            .collect()
+    }
+
+    /// Packages `id` depends on
+    pub fn dependencies(&self, id: NodeId) -> &[NodeId] {
+        &self.dependencies[id.0]
+    }
+
+    /// Packages depending on `id`
+    pub fn dependents(&self, id: NodeId) -> &[NodeId] {
+        &self.dependents[id.0]
+    }
+
+    /// Packages required directly by the project
+    pub fn roots(&self) -> &[NodeId] {
+        &self.roots
+    }
+
+    /// Whether `id` is a workspace member
+    pub fn is_member(&self, id: NodeId) -> bool {
+        self.members.contains(&id)
+    }
+
+    /// Range through which `dependent` (or the project, for `None`)
+    /// requires `id`, if the lockfile records it
+    pub fn requirement(&self, dependent: Option<NodeId>, id: NodeId) -> Option<&str> {
+        self.requirements.get(&(dependent, id)).map(String::as_str)
+    }
+
+    /// Mark every package nothing depends on as a root
+    ///
+    /// Used for lockfiles that don't record the project's own
+    /// dependencies (Yarn classic).
+    pub(crate) fn infer_roots(&mut self) {
+        for index in 0..self.nodes.len() {
+            if self.dependents[index].is_empty() {
+                self.add_root(NodeId(index), None);
+            }
+        }
+    }
+
+    /// Explain why `name` (optionally an exact `version`) is installed
+    ///
+    /// Returns dependency paths from a root to the package, each listed
+    /// root first. At most [`MAX_WHY_PATHS`] paths are returned; cycles
+    /// are not followed.
+    pub fn why(&self, name: &str, version: Option<&str>) -> Vec<Vec<&LockedPackage>> {
+        let targets = match version {
+            Some(version) => self.find(name, version).into_iter().collect(),
+            None => self.versions(name),
+        };
+
+        let roots: HashSet<NodeId> = self.roots.iter().copied().collect();
+        let mut paths = Vec::new();
+        for target in targets {
+            let mut path = vec![target];
+            self.collect_paths(&roots, &mut path, &mut paths);
+        }
+
+        paths
+            .into_iter()
+            .map(|path| path.into_iter().rev().map(|id| self.package(id)).collect())
+            .collect()
+    }
+
+    /// Walk dependents from the last node of `path` up to the roots
+    fn collect_paths(
+        &self,
+        roots: &HashSet<NodeId>,
+        path: &mut Vec<NodeId>,
+        paths: &mut Vec<Vec<NodeId>>,
+    ) {
+        if paths.len() >= MAX_WHY_PATHS {
+            return;
+        }
+
+        let current = *path.last().expect("path is never empty");
+        if roots.contains(&current) {
+            paths.push(path.clone());
+        }
+
+        for &dependent in self.dependents(current) {
+            if !path.contains(&dependent) {
+                path.push(dependent);
+                self.collect_paths(roots, path, paths);
+                path.pop();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn package(name: &str, version: &str) -> LockedPackage {
+        LockedPackage {
+            name: name.to_string(),
+            version: version.to_string(),
+            checksum: None,
+            resolved: None,
+        }
+    }
+
+    #[test]
+    fn test_graph_keeps_every_version() {
+        let mut graph = LockfileGraph::new();
+        let old = graph.add_package(package("lodash", "3.10.1"));
+        let new = graph.add_package(package("lodash", "4.17.21"));
+
+        assert_ne!(old, new);
+        assert_eq!(graph.add_package(package("lodash", "4.17.21")), new);
+        assert_eq!(graph.versions("lodash"), vec![old, new]);
+        assert_eq!(graph.len(), 2);
+    }
+
+    #[test]
+    fn test_why_lists_paths_from_roots() {
+        let mut graph = LockfileGraph::new();
+        let app = graph.add_package(package("app-utils", "1.0.0"));
+        let cli = graph.add_package(package("cli", "2.0.0"));
+        let lodash = graph.add_package(package("lodash", "4.17.21"));
+        let chalk = graph.add_package(package("chalk", "5.0.0"));
+        graph.add_root(app, Some("^1.0.0"));
+        graph.add_root(cli, None);
+        graph.add_dependency(app, lodash, Some("^4.17.0"));
+        graph.add_dependency(cli, chalk, None);
+        graph.add_dependency(chalk, lodash, None);
+        // Cycles are not followed
+        graph.add_dependency(lodash, chalk, None);
+
+        let mut paths: Vec<Vec<String>> = graph
+            .why("lodash", Some("4.17.21"))
+            .into_iter()
+            .map(|path| path.iter().map(|p| p.name.clone()).collect())
+            .collect();
+        paths.sort();
+
+        assert_eq!(
+            paths,
+            vec![
+                vec!["app-utils".to_string(), "lodash".to_string()],
+                vec!["cli".to_string(), "chalk".to_string(), "lodash".to_string()],
+            ]
+        );
+        assert!(graph.why("lodash", Some("1.0.0")).is_empty());
+        assert_eq!(graph.requirement(Some(app), lodash), Some("^4.17.0"));
+        assert_eq!(graph.requirement(None, app), Some("^1.0.0"));
+        assert_eq!(graph.requirement(Some(chalk), lodash), None);
+    }
+
+    #[test]
+    fn test_infer_roots() {
+        let mut graph = LockfileGraph::new();
+        let a = graph.add_package(package("a", "1.0.0"));
+        let b = graph.add_package(package("b", "1.0.0"));
+        graph.add_dependency(a, b, None);
+        graph.infer_roots();
+
+        assert_eq!(graph.roots(), &[a]);
+    }
+}
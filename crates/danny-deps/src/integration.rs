@@ -1,11 +1,25 @@
 //! Integration with danny-info for unified dependency management
 
-use crate::{DependencyManager, Ecosystem, Error, Result, UpdateType, VersionReq};
+use crate::cargo::lockfile::CargoLockfileParser;
+use crate::cargo::workspace::CargoWorkspace;
+use crate::npm::lockfile::{NpmLockfileParser, PnpmLockfileParser, YarnLockfileParser};
+use crate::npm::workspace::NpmWorkspace;
+use crate::{
+    compare_versions, CargoConfig, Dependency, DependencyManager, DependencySource, Ecosystem,
+    Error, LockfileGraph, LockfileParser, NodeId, NpmConfig, ParsedVersionReq, Result, UpdateType,
+    VersionReq,
+};
+use danny_config::GlobalSettings;
 use danny_fs::NativeFileSystem;
 use danny_info::{InfoClient, Registry};
-use std::path::Path;
+use futures::stream::{self, StreamExt};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+/// Lockfile names checked next to a manifest, in order of preference
+const CARGO_LOCKFILES: &[&str] = &["Cargo.lock"];
+const NPM_LOCKFILES: &[&str] = &["package-lock.json", "pnpm-lock.yaml", "yarn.lock"];
+
 /// Update recommendation combining local and remote data
 #[derive(Debug, Clone)]
 pub struct UpdateRecommendation {
@@ -15,9 +29,14 @@ pub struct UpdateRecommendation {
     pub current_req: VersionReq,
     /// Current installed version (from lockfile, if available)
     pub current_installed: Option<String>,
+    /// Highest published version satisfying the current requirement
+    pub wanted_version: Option<String>,
     /// Latest available version
     pub latest_version: String,
-    /// Update type (major, minor, patch)
+    /// Update type (major, minor, patch) from the installed version to latest
+    ///
+    /// Without a lockfile, the lowest version the requirement names stands
+    /// in for the installed version.
     pub update_type: UpdateType,
     /// Whether update satisfies current requirement
     pub satisfies_requirement: bool,
@@ -29,9 +48,32 @@ pub struct UpdateRecommendation {
     pub releases: Vec<danny_info::Release>,
 }
 
+/// A package whose update check failed
+#[derive(Debug)]
+pub struct PackageError {
+    /// Package name
+    pub package: String,
+    /// Why the check failed
+    pub error: Error,
+}
+
+/// Outcome of [`UnifiedDependencyManager::check_updates`]
+#[derive(Debug, Default)]
+pub struct UpdateReport {
+    /// Recommendations for every package that could be checked
+    pub recommendations: Vec<UpdateRecommendation>,
+    /// Packages that couldn't be checked
+    pub errors: Vec<PackageError>,
+}
+
+/// Packages checked at once unless configured otherwise, matching the
+/// default of `max_concurrent_checks` in Danny's global settings
+pub const DEFAULT_MAX_CONCURRENT_CHECKS: usize = 5;
+
 /// Unified dependency manager combining local parsing and remote fetching
 pub struct UnifiedDependencyManager {
     info_client: InfoClient,
+    max_concurrent_checks: usize,
 }
 
 impl UnifiedDependencyManager {
@@ -40,13 +82,75 @@ impl UnifiedDependencyManager {
         Ok(Self {
             info_client: InfoClient::new()
                 .map_err(|e| Error::Other(format!("Failed to create InfoClient: {}", e)))?,
+            max_concurrent_checks: DEFAULT_MAX_CONCURRENT_CHECKS,
         })
     }
 
+    /// Create a manager using a configured client, e.g. one with an on-disk
+    /// response cache or in offline mode
+    ///
+    /// ```rust,no_run
+    /// use danny_deps::UnifiedDependencyManager;
+    /// use danny_info::{CacheMode, InfoClient, ResponseCache};
+    ///
+    /// # fn example() -> anyhow::Result<()> {
+    /// let cache = ResponseCache::in_user_cache_dir()?.with_mode(CacheMode::Offline);
+    /// let manager = UnifiedDependencyManager::with_info_client(InfoClient::new()?.with_cache(cache));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_info_client(info_client: InfoClient) -> Self {
+        Self {
+            info_client,
+            max_concurrent_checks: DEFAULT_MAX_CONCURRENT_CHECKS,
+        }
+    }
+
+    /// Create a manager configured from Danny's global settings
+    ///
+    /// ```rust,no_run
+    /// use danny_config::ConfigManager;
+    /// use danny_deps::UnifiedDependencyManager;
+    ///
+    /// # async fn example() -> anyhow::Result<()> {
+    /// let config = ConfigManager::load().await?;
+    /// let manager = UnifiedDependencyManager::from_settings(&config.config().settings)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_settings(settings: &GlobalSettings) -> Result<Self> {
+        Ok(Self::new()?.with_max_concurrent_checks(settings.max_concurrent_checks))
+    }
+
+    /// Set how many packages are checked at once
+    ///
+    /// Requests to each registry still respect its rate limit.
+    pub fn with_max_concurrent_checks(mut self, max_concurrent_checks: usize) -> Self {
+        self.max_concurrent_checks = max_concurrent_checks.max(1);
+        self
+    }
+
     /// Check for updates in a dependency file
     ///
     /// This combines local dependency parsing with remote package data fetching
-    /// to provide comprehensive update recommendations.
+    /// to provide comprehensive update recommendations. Installed versions come
+    /// from the nearest lockfile (Cargo.lock, package-lock.json, pnpm-lock.yaml
+    /// or yarn.lock) in the manifest directory or one of its parents.
+    ///
+    /// Lookups follow the project's registry configuration: scoped registries
+    /// and tokens from `.npmrc`, and `[registries]` from `.cargo/config.toml`
+    /// (see [`NpmConfig`] and [`CargoConfig`]). Git, path and other
+    /// non-registry dependencies are skipped.
+    ///
+    /// Up to [`with_max_concurrent_checks`](Self::with_max_concurrent_checks)
+    /// packages are checked at once, within each registry's rate limit, and
+    /// transient failures are retried by the [`InfoClient`]. A package that
+    /// still can't be checked is reported in [`UpdateReport::errors`] rather
+    /// than failing the whole check.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only if the manifest can't be read or parsed.
     ///
     /// # Arguments
     /// * `manifest_path` - Path to dependency file (Cargo.toml or package.json)
@@ -59,11 +163,14 @@ impl UnifiedDependencyManager {
     ///
     /// # async fn example() -> anyhow::Result<()> {
     /// let manager = UnifiedDependencyManager::new()?;
-    /// let updates = manager.check_updates(Path::new("Cargo.toml"), Ecosystem::Rust).await?;
+    /// let report = manager.check_updates(Path::new("Cargo.toml"), Ecosystem::Rust).await?;
     ///
-    /// for update in updates {
+    /// for update in &report.recommendations {
     ///     println!("{}: {} -> {}", update.package, update.current_req.raw, update.latest_version);
     /// }
+    /// for failed in &report.errors {
+    ///     eprintln!("{}: {}", failed.package, failed.error);
+    /// }
     /// # Ok(())
     /// # }
     /// ```
@@ -71,7 +178,7 @@ impl UnifiedDependencyManager {
         &self,
         manifest_path: &Path,
         ecosystem: Ecosystem,
-    ) -> Result<Vec<UpdateRecommendation>> {
+    ) -> Result<UpdateReport> {
         // Create FileSystem scoped to manifest directory
         let manifest_dir = manifest_path.parent().unwrap_or_else(|| Path::new("."));
         let fs = Arc::new(
@@ -95,90 +202,151 @@ impl UnifiedDependencyManager {
             }
         };
 
-        let mut recommendations = Vec::new();
+        // An unreadable lockfile only loses the installed versions
+        let lockfile = match find_lockfile(manifest_dir, ecosystem).await {
+            Some(path) => load_lockfile_graph(&path, ecosystem).await.ok(),
+            None => None,
+        };
 
-        // Check each dependency
-        for dep in manifest.all_dependencies() {
-            // Skip workspace dependencies
-            if dep.workspace {
-                continue;
-            }
+        let registries = match ecosystem {
+            Ecosystem::Rust => Registries::Cargo(CargoConfig::load(manifest_dir).await),
+            Ecosystem::JavaScript => Registries::Npm(NpmConfig::load(manifest_dir).await),
+        };
 
-            // Fetch latest version from registry
-            let latest_info = match ecosystem {
-                Ecosystem::Rust => self
-                    .info_client
-                    .fetch_crates_io(&dep.name)
-                    .await
-                    .map_err(|e| Error::Other(format!("Failed to fetch {}: {}", dep.name, e)))?,
-                Ecosystem::JavaScript => self
-                    .info_client
-                    .fetch_npm(&dep.name)
-                    .await
-                    .map_err(|e| Error::Other(format!("Failed to fetch {}: {}", dep.name, e)))?,
-            };
-
-            // Parse versions
-            let latest_version = latest_info.version.clone();
-
-            // Determine update type
-            let update_type = if let Some(current) = &dep
-                .version_req
-                .raw
-                .strip_prefix("^")
-                .or_else(|| dep.version_req.raw.strip_prefix("~"))
-                .or_else(|| dep.version_req.raw.strip_prefix(">="))
-                .or(Some(dep.version_req.raw.as_str()))
-            {
-                crate::version::update_type(current, &latest_version, ecosystem)
-                    .unwrap_or(UpdateType::None)
-            } else {
-                UpdateType::None
-            };
-
-            // Check if latest satisfies requirement
-            let parsed_req =
-                crate::version::ParsedVersionReq::parse(&dep.version_req.raw, ecosystem).ok();
-            let satisfies_requirement = parsed_req
-                .as_ref()
-                .and_then(|req| req.matches(&latest_version).ok())
-                .unwrap_or(false);
-
-            // Fetch changelog if repository is available
-            let changelog_entries = if let Some(repo) = &latest_info.repository {
-                self.info_client
-                    .fetch_parsed_changelog(repo)
+        let manifest_name = manifest.name.as_str();
+        let registries = &registries;
+        let lockfile = lockfile.as_ref();
+        let checks = manifest
+            .all_dependencies()
+            // Workspace dependencies are checked where they're declared
+            .filter(|dep| !dep.workspace)
+            .filter_map(|dep| {
+                // Git, path and other non-registry dependencies have nothing to look up
+                let source = DependencySource::of(dep);
+                source.is_registry().then_some((dep, source))
+            })
+            .map(|(dep, source)| async move {
+                self.check_dependency(manifest_name, dep, &source, registries, lockfile, ecosystem)
                     .await
-                    .map(|parsed| parsed.entries)
-                    .unwrap_or_default()
-            } else {
-                vec![]
-            };
-
-            // Fetch releases if repository is available
-            let releases = if let Some(repo) = &latest_info.repository {
-                self.info_client
-                    .fetch_releases(repo)
-                    .await
-                    .unwrap_or_default()
-            } else {
-                vec![]
-            };
-
-            recommendations.push(UpdateRecommendation {
-                package: dep.name.clone(),
-                current_req: dep.version_req.clone(),
-                current_installed: None, // TODO: Parse from lockfile
-                latest_version,
-                update_type,
-                satisfies_requirement,
-                registry: latest_info.registry,
-                changelog_entries,
-                releases,
+                    .map_err(|error| PackageError {
+                        package: dep.name.clone(),
+                        error,
+                    })
             });
+
+        let results: Vec<_> = stream::iter(checks)
+            .buffered(self.max_concurrent_checks)
+            .collect()
+            .await;
+
+        let mut report = UpdateReport::default();
+        for result in results {
+            match result {
+                Ok(recommendation) => report.recommendations.push(recommendation),
+                Err(error) => report.errors.push(error),
+            }
         }
 
-        Ok(recommendations)
+        Ok(report)
+    }
+
+    /// Build the recommendation for one dependency
+    async fn check_dependency(
+        &self,
+        project_name: &str,
+        dep: &Dependency,
+        source: &DependencySource,
+        registries: &Registries,
+        lockfile: Option<&LockfileGraph>,
+        ecosystem: Ecosystem,
+    ) -> Result<UpdateRecommendation> {
+        // Fetch latest version from registry
+        let latest_info = self.fetch_latest(registries, dep, source).await?;
+
+        // Parse versions
+        let latest_version = latest_info.version.clone();
+        let parsed_req = ParsedVersionReq::parse(&dep.version_req.raw, ecosystem).ok();
+        let current_installed = lockfile.and_then(|graph| {
+            installed_version(graph, project_name, dep, parsed_req.as_ref(), ecosystem)
+        });
+        let wanted_version = parsed_req
+            .as_ref()
+            .and_then(|req| wanted_version(&latest_info.versions, req, ecosystem));
+
+        // Determine update type
+        let current = current_installed.as_deref().unwrap_or_else(|| {
+            let raw = dep.version_req.raw.as_str();
+            raw.strip_prefix("^")
+                .or_else(|| raw.strip_prefix("~"))
+                .or_else(|| raw.strip_prefix(">="))
+                .unwrap_or(raw)
+        });
+        let update_type = crate::version::update_type(current, &latest_version, ecosystem)
+            .unwrap_or(UpdateType::None);
+
+        // Check if latest satisfies requirement
+        let satisfies_requirement = parsed_req
+            .as_ref()
+            .and_then(|req| req.matches(&latest_version).ok())
+            .unwrap_or(false);
+
+        // Changelogs and releases are extras: failing to fetch them (e.g. when
+        // GitHub's rate limit is used up) doesn't fail the package
+        let changelog_entries = if let Some(repo) = &latest_info.repository {
+            self.info_client
+                .fetch_parsed_changelog(repo)
+                .await
+                .map(|parsed| parsed.entries)
+                .unwrap_or_default()
+        } else {
+            vec![]
+        };
+
+        let releases = if let Some(repo) = &latest_info.repository {
+            self.info_client
+                .fetch_releases(repo)
+                .await
+                .unwrap_or_default()
+        } else {
+            vec![]
+        };
+
+        Ok(UpdateRecommendation {
+            package: dep.name.clone(),
+            current_req: dep.version_req.clone(),
+            current_installed,
+            wanted_version,
+            latest_version,
+            update_type,
+            satisfies_requirement,
+            registry: latest_info.registry,
+            changelog_entries,
+            releases,
+        })
+    }
+
+    /// Fetch package information from the registry `dep` is published on
+    async fn fetch_latest(
+        &self,
+        registries: &Registries,
+        dep: &Dependency,
+        source: &DependencySource,
+    ) -> Result<danny_info::PackageInfo> {
+        let info = match registries {
+            Registries::Cargo(config) => match config.registry_for(source)? {
+                Some(registry) => {
+                    self.info_client
+                        .fetch_cargo_index(&registry, &dep.name)
+                        .await
+                }
+                None => self.info_client.fetch_crates_io(&dep.name).await,
+            },
+            Registries::Npm(config) => match config.registry_for(&dep.name) {
+                Some(registry) => self.info_client.fetch_npm_from(&registry, &dep.name).await,
+                None => self.info_client.fetch_npm(&dep.name).await,
+            },
+        };
+        info.map_err(|e| Error::Other(format!("Failed to fetch {}: {}", dep.name, e)))
     }
 
     /// Get update recommendations filtered by update type
@@ -214,6 +382,128 @@ impl Default for UnifiedDependencyManager {
     }
 }
 
+/// Registry configuration for the ecosystem being checked
+enum Registries {
+    Cargo(CargoConfig),
+    Npm(NpmConfig),
+}
+
+/// Find the lockfile for a manifest in `manifest_dir` or its parents
+///
+/// Workspace members share the lockfile at the workspace root. The search
+/// stops at the workspace root or the repository root (a directory
+/// containing `.git`), so lockfiles of unrelated enclosing projects are
+/// never used.
+async fn find_lockfile(manifest_dir: &Path, ecosystem: Ecosystem) -> Option<PathBuf> {
+    let (names, workspace_root) = match ecosystem {
+        Ecosystem::Rust => (CARGO_LOCKFILES, CargoWorkspace::find_root(manifest_dir)),
+        Ecosystem::JavaScript => (NPM_LOCKFILES, NpmWorkspace::find_root(manifest_dir)),
+    };
+    let workspace_root = workspace_root.ok().flatten();
+
+    for dir in manifest_dir.ancestors() {
+        for name in names {
+            let candidate = dir.join(name);
+            if tokio::fs::try_exists(&candidate).await.unwrap_or(false) {
+                return Some(candidate);
+            }
+        }
+
+        let is_repository_root = tokio::fs::try_exists(dir.join(".git"))
+            .await
+            .unwrap_or(false);
+        if workspace_root.as_deref() == Some(dir) || is_repository_root {
+            break;
+        }
+    }
+    None
+}
+
+/// Parse a lockfile found by [`find_lockfile`]
+async fn load_lockfile_graph(path: &Path, ecosystem: Ecosystem) -> Result<LockfileGraph> {
+    let dir = path.parent().unwrap_or_else(|| Path::new("."));
+    let fs = Arc::new(
+        NativeFileSystem::new(dir)
+            .map_err(|e| Error::Other(format!("Failed to create filesystem: {}", e)))?,
+    );
+
+    let file_name = path.file_name().and_then(|name| name.to_str());
+    match (ecosystem, file_name) {
+        (Ecosystem::Rust, _) => CargoLockfileParser::new().parse_graph(&fs, path).await,
+        (Ecosystem::JavaScript, Some("pnpm-lock.yaml")) => {
+            PnpmLockfileParser::new().parse_graph(&fs, path).await
+        }
+        (Ecosystem::JavaScript, Some("yarn.lock")) => {
+            YarnLockfileParser::new().parse_graph(&fs, path).await
+        }
+        (Ecosystem::JavaScript, _) => NpmLockfileParser::new().parse_graph(&fs, path).await,
+    }
+}
+
+/// Locked version of `dep` as required by the manifest package `project`
+///
+/// Looks at the dependencies of the workspace member named `project`, then
+/// at the project's direct dependencies, then at every locked version. When
+/// several versions are left, the highest one satisfying the requirement wins.
+fn installed_version(
+    graph: &LockfileGraph,
+    project: &str,
+    dep: &Dependency,
+    req: Option<&ParsedVersionReq>,
+    ecosystem: Ecosystem,
+) -> Option<String> {
+    let named = |ids: &[NodeId]| -> Vec<NodeId> {
+        ids.iter()
+            .copied()
+            .filter(|&id| graph.package(id).name == dep.name)
+            .collect()
+    };
+
+    let member_deps: Vec<NodeId> = graph
+        .roots()
+        .iter()
+        .filter(|&&id| graph.is_member(id) && graph.package(id).name == project)
+        .flat_map(|&id| named(graph.dependencies(id)))
+        .collect();
+    let candidates = [member_deps, named(graph.roots()), graph.versions(&dep.name)]
+        .into_iter()
+        .find(|ids| !ids.is_empty())?;
+
+    let versions: Vec<&str> = candidates
+        .iter()
+        .map(|&id| graph.package(id).version.as_str())
+        .collect();
+    let satisfying: Vec<&str> = versions
+        .iter()
+        .copied()
+        .filter(|v| req.is_some_and(|req| req.matches(v).unwrap_or(false)))
+        .collect();
+
+    let pool = if satisfying.is_empty() {
+        &versions
+    } else {
+        &satisfying
+    };
+    highest(pool.iter().copied(), ecosystem).map(str::to_string)
+}
+
+/// Highest published version satisfying `req`
+fn wanted_version(
+    versions: &[String],
+    req: &ParsedVersionReq,
+    ecosystem: Ecosystem,
+) -> Option<String> {
+    let satisfying = versions
+        .iter()
+        .map(String::as_str)
+        .filter(|v| req.matches(v).unwrap_or(false));
+    highest(satisfying, ecosystem).map(str::to_string)
+}
+
+fn highest<'a>(versions: impl Iterator<Item = &'a str>, ecosystem: Ecosystem) -> Option<&'a str> {
+    versions.max_by(|a, b| compare_versions(a, b, ecosystem).unwrap_or_else(|_| a.cmp(b)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -228,6 +518,7 @@ mod tests {
                     ecosystem: Ecosystem::Rust,
                 },
                 current_installed: None,
+                wanted_version: None,
                 latest_version: "2.0.0".to_string(),
                 update_type: UpdateType::Major,
                 satisfies_requirement: false,
@@ -242,6 +533,7 @@ mod tests {
                     ecosystem: Ecosystem::Rust,
                 },
                 current_installed: None,
+                wanted_version: None,
                 latest_version: "1.1.0".to_string(),
                 update_type: UpdateType::Minor,
                 satisfies_requirement: true,
@@ -262,4 +554,207 @@ mod tests {
         assert_eq!(safe.len(), 1);
         assert_eq!(safe[0].package, "test2");
     }
+
+    fn locked(graph: &mut LockfileGraph, name: &str, version: &str) -> NodeId {
+        graph.add_package(crate::LockedPackage {
+            name: name.to_string(),
+            version: version.to_string(),
+            checksum: None,
+            resolved: None,
+        })
+    }
+
+    fn dependency(name: &str, raw: &str, ecosystem: Ecosystem) -> Dependency {
+        Dependency {
+            name: name.to_string(),
+            version_req: VersionReq {
+                raw: raw.to_string(),
+                ecosystem,
+            },
+            dep_type: crate::DependencyType::Runtime,
+            features: vec![],
+            workspace: false,
+            source: None,
+        }
+    }
+
+    #[test]
+    fn test_installed_version_prefers_member_dependencies() {
+        let mut graph = LockfileGraph::new();
+        let app = locked(&mut graph, "app", "0.1.0");
+        let tool = locked(&mut graph, "tool", "0.1.0");
+        let old = locked(&mut graph, "serde", "0.9.15");
+        let new = locked(&mut graph, "serde", "1.0.195");
+        graph.add_member(app);
+        graph.add_member(tool);
+        graph.add_dependency(app, new, None);
+        graph.add_dependency(tool, old, None);
+
+        let dep = dependency("serde", "1.0", Ecosystem::Rust);
+        let req = ParsedVersionReq::parse("1.0", Ecosystem::Rust).unwrap();
+        let installed =
+            |project| installed_version(&graph, project, &dep, Some(&req), Ecosystem::Rust);
+
+        assert_eq!(installed("app").as_deref(), Some("1.0.195"));
+        assert_eq!(installed("tool").as_deref(), Some("0.9.15"));
+        // Unknown project: the highest locked version satisfying the requirement
+        assert_eq!(installed("other").as_deref(), Some("1.0.195"));
+    }
+
+    #[test]
+    fn test_installed_version_from_direct_dependencies() {
+        let mut graph = LockfileGraph::new();
+        let react = locked(&mut graph, "react", "18.2.0");
+        let nested = locked(&mut graph, "react", "17.0.2");
+        let legacy = locked(&mut graph, "legacy", "1.0.0");
+        graph.add_root(react, Some("^18.0.0"));
+        graph.add_root(legacy, Some("^1.0.0"));
+        graph.add_dependency(legacy, nested, Some("^17.0.0"));
+
+        let dep = dependency("react", "^17.0.0", Ecosystem::JavaScript);
+        let req = ParsedVersionReq::parse("^17.0.0", Ecosystem::JavaScript).unwrap();
+
+        // The project's own copy, even though a nested copy matches the range
+        assert_eq!(
+            installed_version(&graph, "app", &dep, Some(&req), Ecosystem::JavaScript).as_deref(),
+            Some("18.2.0")
+        );
+        let missing = dependency("vue", "^3.0.0", Ecosystem::JavaScript);
+        assert!(installed_version(&graph, "app", &missing, None, Ecosystem::JavaScript).is_none());
+    }
+
+    #[test]
+    fn test_wanted_version() {
+        let versions: Vec<String> = ["1.2.0", "1.9.0", "1.10.0-beta.1", "2.0.0"]
+            .iter()
+            .map(|v| v.to_string())
+            .collect();
+
+        let req = ParsedVersionReq::parse("^1.2.0", Ecosystem::JavaScript).unwrap();
+        assert_eq!(
+            wanted_version(&versions, &req, Ecosystem::JavaScript).as_deref(),
+            Some("1.9.0")
+        );
+
+        let req = ParsedVersionReq::parse("^3.0.0", Ecosystem::JavaScript).unwrap();
+        assert!(wanted_version(&versions, &req, Ecosystem::JavaScript).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_find_lockfile_in_parent_directory() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let member = temp_dir.path().join("packages").join("ui");
+        std::fs::create_dir_all(&member).unwrap();
+        std::fs::write(
+            temp_dir.path().join("pnpm-lock.yaml"),
+            "lockfileVersion: '9.0'\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            find_lockfile(&member, Ecosystem::JavaScript).await,
+            Some(temp_dir.path().join("pnpm-lock.yaml"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_find_lockfile_stops_at_workspace_root() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let root = temp_dir.path().join("app");
+        let member = root.join("packages").join("ui");
+        std::fs::create_dir_all(&member).unwrap();
+        std::fs::write(
+            root.join("package.json"),
+            r#"{ "workspaces": ["packages/*"] }"#,
+        )
+        .unwrap();
+        // Lockfile of an unrelated enclosing project
+        std::fs::write(temp_dir.path().join("yarn.lock"), "").unwrap();
+
+        assert_eq!(find_lockfile(&member, Ecosystem::JavaScript).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_find_lockfile_stops_at_repository_root() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let repo = temp_dir.path().join("repo");
+        std::fs::create_dir_all(repo.join(".git")).unwrap();
+        std::fs::write(temp_dir.path().join("Cargo.lock"), "version = 4\n").unwrap();
+
+        assert_eq!(find_lockfile(&repo, Ecosystem::Rust).await, None);
+    }
+
+    #[test]
+    fn test_from_settings_applies_max_concurrent_checks() {
+        let settings = GlobalSettings {
+            max_concurrent_checks: 12,
+            ..Default::default()
+        };
+        let manager = UnifiedDependencyManager::from_settings(&settings).unwrap();
+        assert_eq!(manager.max_concurrent_checks, 12);
+
+        let settings = GlobalSettings {
+            max_concurrent_checks: 0,
+            ..Default::default()
+        };
+        let manager = UnifiedDependencyManager::from_settings(&settings).unwrap();
+        assert_eq!(manager.max_concurrent_checks, 1);
+    }
+
+    #[tokio::test]
+    async fn test_failed_package_is_reported_alongside_recommendations() {
+        use std::io::{Read, Write};
+
+        // Serve `left-pad` and 404 everything else
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        std::thread::spawn(move || {
+            for mut socket in listener.incoming().flatten() {
+                let mut request = [0; 4096];
+                let read = socket.read(&mut request).unwrap_or(0);
+                let request = String::from_utf8_lossy(&request[..read]);
+                let response = if request.starts_with("GET /left-pad ") {
+                    let body = r#"{"name":"left-pad","dist-tags":{"latest":"1.3.0"},"versions":{"1.0.0":{},"1.3.0":{}}}"#;
+                    format!(
+                        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                        body.len(),
+                        body
+                    )
+                } else {
+                    "HTTP/1.1 404 Not Found\r\ncontent-length: 0\r\nconnection: close\r\n\r\n"
+                        .to_string()
+                };
+                let _ = socket.write_all(response.as_bytes());
+            }
+        });
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("package.json"),
+            r#"{"name":"app","version":"1.0.0","dependencies":{"left-pad":"^1.0.0","no-such-package":"^1.0.0"}}"#,
+        )
+        .unwrap();
+        std::fs::write(
+            temp_dir.path().join(".npmrc"),
+            format!("registry=http://{}/\n", addr),
+        )
+        .unwrap();
+
+        let client = InfoClient::builder()
+            .without_rate_limiting()
+            .retries(0)
+            .build()
+            .unwrap();
+        let manager = UnifiedDependencyManager::with_info_client(client);
+        let report = manager
+            .check_updates(&temp_dir.path().join("package.json"), Ecosystem::JavaScript)
+            .await
+            .unwrap();
+
+        assert_eq!(report.recommendations.len(), 1);
+        assert_eq!(report.recommendations[0].package, "left-pad");
+        assert_eq!(report.recommendations[0].latest_version, "1.3.0");
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].package, "no-such-package");
+    }
 }
@@ -9,6 +9,8 @@
 //! - Safely update dependency files while preserving formatting and comments
 //! - Support monorepo/workspace scenarios (Cargo workspaces, pnpm/npm workspaces)
 //! - Verify lockfile integrity (checksums)
+//! - Report packages locked in more than one version, with dedupe suggestions
+//! - Route registry lookups using `.npmrc` and `.cargo/config.toml`
 //!
 //! ## Architecture
 //!
@@ -42,16 +44,25 @@
 
 pub mod cargo;
 pub mod checksum;
+pub mod duplicates;
 pub mod error;
+pub mod graph;
 pub mod integration;
 pub mod npm;
+pub mod registry;
 pub mod traits;
 pub mod types;
 pub mod update;
 pub mod version;
 
 // Re-export main types and traits
+pub use duplicates::{
+    DedupeResolution, DedupeSuggestion, DuplicatePackage, DuplicateReport, DuplicateVersion,
+    VersionRequirement,
+};
 pub use error::{Error, Result};
+pub use graph::{LockfileGraph, NodeId};
+pub use registry::{CargoConfig, DependencySource, NpmConfig};
 pub use traits::{DependencyManager, LockedDependencies, LockedPackage, LockfileParser};
 pub use types::{
     AppliedUpdate, Dependency, DependencyFile, DependencyType, DependencyUpdate, Ecosystem,
@@ -66,4 +77,7 @@ pub use npm::NpmDependencyManager;
 pub use version::{compare_versions, update_type, ParsedVersionReq, UpdateType};
 
 // Re-export integration types
-pub use integration::{UnifiedDependencyManager, UpdateRecommendation};
+pub use integration::{
+    PackageError, UnifiedDependencyManager, UpdateRecommendation, UpdateReport,
+    DEFAULT_MAX_CONCURRENT_CHECKS,
+};
@@ -1,9 +1,15 @@
 //! npm/pnpm/yarn lockfile parsers
 
-use crate::{Error, LockedDependencies, LockedPackage, LockfileParser, Result};
-use danny_fs::FileSystem;
+use crate::checksum::{ChecksumAlgorithm, ChecksumVerifier};
+use crate::{
+    compare_versions, Ecosystem, Error, LockedDependencies, LockedPackage, LockfileGraph,
+    LockfileParser, Result,
+};
+use base64::{engine::general_purpose, Engine as _};
+use danny_fs::{DiscoveryOptions, FileSystem};
 use serde::Deserialize;
-use std::collections::HashMap;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
 use std::sync::Arc;
 
@@ -25,10 +31,70 @@ struct PackageLock {
 }
 
 #[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
 struct PackageLockEntry {
-    version: String,
+    name: Option<String>,
+    version: Option<String>,
     resolved: Option<String>,
     integrity: Option<String>,
+    /// Workspace symlink; `resolved` holds the target key
+    #[serde(default)]
+    link: bool,
+    #[serde(default)]
+    dependencies: BTreeMap<String, String>,
+    #[serde(default)]
+    dev_dependencies: BTreeMap<String, String>,
+    #[serde(default)]
+    optional_dependencies: BTreeMap<String, String>,
+    #[serde(default)]
+    peer_dependencies: BTreeMap<String, String>,
+}
+
+impl PackageLockEntry {
+    /// Names and ranges this entry depends on; devDependencies are only
+    /// recorded for the root and workspace packages
+    fn dependency_ranges(&self) -> impl Iterator<Item = (&String, &String)> {
+        self.dependencies
+            .iter()
+            .chain(&self.dev_dependencies)
+            .chain(&self.optional_dependencies)
+            .chain(&self.peer_dependencies)
+    }
+}
+
+/// Package name for a `packages` key such as `node_modules/a/node_modules/@scope/b`
+fn npm_package_name(key: &str, entry: &PackageLockEntry) -> Option<String> {
+    if let Some(name) = &entry.name {
+        return Some(name.clone());
+    }
+    key.rsplit_once("node_modules/")
+        .map(|(_, name)| name.to_string())
+}
+
+/// Resolve `name` required from `key` the way Node does: the nearest
+/// `node_modules/<name>` walking up from the requiring package
+fn npm_resolve<'a>(
+    packages: &'a HashMap<String, PackageLockEntry>,
+    key: &str,
+    name: &str,
+) -> Option<&'a str> {
+    let mut base = key;
+    loop {
+        let candidate = if base.is_empty() {
+            format!("node_modules/{}", name)
+        } else {
+            format!("{}/node_modules/{}", base, name)
+        };
+        if let Some((found, _)) = packages.get_key_value(&candidate) {
+            return Some(found);
+        }
+        if base.is_empty() {
+            return None;
+        }
+        base = base
+            .rfind("/node_modules/")
+            .map_or("", |index| &base[..index]);
+    }
 }
 
 #[async_trait::async_trait]
@@ -61,7 +127,8 @@ impl LockfileParser for NpmLockfileParser {
                     name.clone(),
                     LockedPackage {
                         name,
-                        version: entry.version.clone(),
+                        // Workspace links carry no version
+                        version: entry.version.clone()?,
                         checksum: entry.integrity.clone(),
                         resolved: entry.resolved.clone(),
                     },
@@ -72,6 +139,73 @@ impl LockfileParser for NpmLockfileParser {
         Ok(LockedDependencies { packages })
     }
 
+    async fn parse_graph<F: FileSystem>(&self, fs: &Arc<F>, path: &Path) -> Result<LockfileGraph> {
+        let content = fs.read_to_string(path).await?;
+        let lock: PackageLock = serde_json::from_str(&content).map_err(Error::Json)?;
+
+        let mut graph = LockfileGraph::new();
+        let mut ids = HashMap::new();
+        for (key, entry) in &lock.packages {
+            if key.is_empty() || entry.link {
+                continue;
+            }
+            let (Some(name), Some(version)) = (npm_package_name(key, entry), &entry.version) else {
+                continue;
+            };
+            let id = graph.add_package(LockedPackage {
+                name,
+                version: version.clone(),
+                checksum: entry.integrity.clone(),
+                resolved: entry.resolved.clone(),
+            });
+            ids.insert(key.as_str(), id);
+        }
+
+        // Links point at the workspace package they install
+        let node_for = |key: &str| {
+            let entry = &lock.packages[key];
+            match (entry.link, entry.resolved.as_deref()) {
+                (true, Some(target)) => ids.get(target).copied(),
+                _ => ids.get(key).copied(),
+            }
+        };
+
+        let mut edges = Vec::new();
+        let mut roots = Vec::new();
+        let mut members = Vec::new();
+        for (key, entry) in &lock.packages {
+            let from = node_for(key);
+            let is_project = key.is_empty() || !key.contains("node_modules/");
+            if !key.is_empty() && is_project {
+                // Workspace packages are part of the project
+                members.extend(from);
+            }
+
+            for (name, range) in entry.dependency_ranges() {
+                let Some(to) = npm_resolve(&lock.packages, key, name).and_then(node_for) else {
+                    continue;
+                };
+                match from {
+                    Some(from) if !key.is_empty() => edges.push((from, to, range)),
+                    _ if is_project => roots.push((to, range)),
+                    _ => {}
+                }
+            }
+        }
+
+        for (from, to, range) in edges {
+            graph.add_dependency(from, to, Some(range));
+        }
+        for (root, range) in roots {
+            graph.add_root(root, Some(range));
+        }
+        for member in members {
+            graph.add_member(member);
+        }
+
+        Ok(graph)
+    }
+
     async fn verify_integrity<F: FileSystem>(&self, fs: &Arc<F>, path: &Path) -> Result<()> {
         // For now, just parse the file
         // Full integrity verification would require fetching packages
@@ -97,23 +231,130 @@ impl PnpmLockfileParser {
 }
 
 #[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
 struct PnpmLock {
+    /// `5.4` (a number) in v5, `'6.0'`/`'9.0'` afterwards
     #[serde(rename = "lockfileVersion")]
-    _lockfile_version: String,
+    _lockfile_version: serde_yaml::Value,
+    /// Projects of the workspace (v6+)
+    #[serde(default)]
+    importers: BTreeMap<String, PnpmImporter>,
+    /// Root project of single-project v5 lockfiles
+    #[serde(flatten)]
+    root: PnpmImporter,
+    #[serde(default)]
     packages: HashMap<String, PnpmLockEntry>,
+    /// Dependency edges of each package (v9)
+    #[serde(default)]
+    snapshots: HashMap<String, PnpmDependencies>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PnpmImporter {
+    #[serde(default)]
+    dependencies: BTreeMap<String, PnpmImporterDependency>,
+    #[serde(default)]
+    dev_dependencies: BTreeMap<String, PnpmImporterDependency>,
+    #[serde(default)]
+    optional_dependencies: BTreeMap<String, PnpmImporterDependency>,
+}
+
+/// `react: 18.2.0` (v5) or `react: { specifier: ^18.2.0, version: 18.2.0 }`
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum PnpmImporterDependency {
+    Version(String),
+    Specified { specifier: String, version: String },
+}
+
+impl PnpmImporter {
+    /// Name, reference and (v6+) declared specifier of each dependency
+    fn references(&self) -> impl Iterator<Item = (&String, &str, Option<&str>)> {
+        self.dependencies
+            .iter()
+            .chain(&self.dev_dependencies)
+            .chain(&self.optional_dependencies)
+            .map(|(name, dependency)| match dependency {
+                PnpmImporterDependency::Version(version) => (name, version.as_str(), None),
+                PnpmImporterDependency::Specified { specifier, version } => {
+                    (name, version.as_str(), Some(specifier.as_str()))
+                }
+            })
+    }
 }
 
 #[derive(Debug, Deserialize)]
 struct PnpmLockEntry {
     resolution: PnpmResolution,
+    #[serde(flatten)]
+    dependencies: PnpmDependencies,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PnpmDependencies {
+    #[serde(default)]
+    dependencies: BTreeMap<String, String>,
+    #[serde(default)]
+    optional_dependencies: BTreeMap<String, String>,
+}
+
+impl PnpmDependencies {
+    fn references(&self) -> impl Iterator<Item = (&String, &String)> {
+        self.dependencies.iter().chain(&self.optional_dependencies)
+    }
 }
 
 #[derive(Debug, Deserialize)]
 struct PnpmResolution {
-    integrity: String,
+    integrity: Option<String>,
     tarball: Option<String>,
 }
 
+/// Strip peer suffixes: `1.0.0(react@18.2.0)` (v6+) or `1.0.0_react@18.2.0` (v5)
+fn strip_pnpm_peers(version: &str) -> &str {
+    let version = version.split('(').next().unwrap_or(version);
+    version.split('_').next().unwrap_or(version)
+}
+
+/// Name and version of a `packages` key
+///
+/// Handles `/foo/1.0.0` (v5), `/foo@1.0.0` (v6) and `foo@1.0.0` (v9),
+/// scoped names, and peer suffixes.
+fn parse_pnpm_key(key: &str) -> Option<(String, String)> {
+    let key = key.trim_start_matches('/');
+    let key = key.split('(').next().unwrap_or(key);
+
+    // v6+: name@version, where a name has at most one '/' (for its scope)
+    if let Some(at) = key.get(1..).and_then(|rest| rest.find('@')) {
+        let (name, version) = (&key[..at + 1], &key[at + 2..]);
+        let slashes = name.matches('/').count();
+        if slashes == 0 || (slashes == 1 && name.starts_with('@')) {
+            return Some((name.to_string(), version.to_string()));
+        }
+    }
+
+    // v5: name/version
+    let (name, version) = strip_pnpm_peers(key).rsplit_once('/')?;
+    Some((name.to_string(), version.to_string()))
+}
+
+/// Package a dependency reference points to
+///
+/// References are usually a version, but aliases and v5/v6 lockfiles may
+/// use a full key; `link:`/`file:` references point outside the store.
+fn pnpm_reference(name: &str, reference: &str) -> Option<(String, String)> {
+    if reference.starts_with("link:") || reference.starts_with("file:") {
+        return None;
+    }
+    let version = strip_pnpm_peers(reference);
+    if reference.starts_with('/') || version.contains('@') {
+        return parse_pnpm_key(reference);
+    }
+    Some((name.to_string(), version.to_string()))
+}
+
 #[async_trait::async_trait]
 impl LockfileParser for PnpmLockfileParser {
     async fn parse_lockfile<F: FileSystem>(
@@ -124,25 +365,18 @@ impl LockfileParser for PnpmLockfileParser {
         let content = fs.read_to_string(path).await?;
         let lock: PnpmLock = serde_yaml::from_str(&content).map_err(Error::Yaml)?;
 
-        // pnpm uses format: "/foo/1.0.0" as key
         let packages = lock
             .packages
             .iter()
             .filter_map(|(key, entry)| {
-                let parts: Vec<&str> = key.trim_start_matches('/').split('/').collect();
-                if parts.len() < 2 {
-                    return None;
-                }
-
-                let name = parts[0].to_string();
-                let version = parts[1].to_string();
+                let (name, version) = parse_pnpm_key(key)?;
 
                 Some((
                     name.clone(),
                     LockedPackage {
                         name,
                         version,
-                        checksum: Some(entry.resolution.integrity.clone()),
+                        checksum: entry.resolution.integrity.clone(),
                         resolved: entry.resolution.tarball.clone(),
                     },
                 ))
@@ -152,6 +386,63 @@ impl LockfileParser for PnpmLockfileParser {
         Ok(LockedDependencies { packages })
     }
 
+    async fn parse_graph<F: FileSystem>(&self, fs: &Arc<F>, path: &Path) -> Result<LockfileGraph> {
+        let content = fs.read_to_string(path).await?;
+        let lock: PnpmLock = serde_yaml::from_str(&content).map_err(Error::Yaml)?;
+
+        let mut graph = LockfileGraph::new();
+        for (key, entry) in &lock.packages {
+            if let Some((name, version)) = parse_pnpm_key(key) {
+                graph.add_package(LockedPackage {
+                    name,
+                    version,
+                    checksum: entry.resolution.integrity.clone(),
+                    resolved: entry.resolution.tarball.clone(),
+                });
+            }
+        }
+
+        let find = |graph: &LockfileGraph, name: &str, reference: &str| {
+            let (name, version) = pnpm_reference(name, reference)?;
+            graph.find(&name, &version)
+        };
+
+        // v9 moved edges from `packages` to `snapshots`
+        let edges = lock
+            .packages
+            .iter()
+            .map(|(key, entry)| (key, &entry.dependencies))
+            .chain(&lock.snapshots);
+        let mut resolved = Vec::new();
+        for (key, dependencies) in edges {
+            let Some(from) = parse_pnpm_key(key).and_then(|(n, v)| graph.find(&n, &v)) else {
+                continue;
+            };
+            for (name, reference) in dependencies.references() {
+                resolved.extend(find(&graph, name, reference).map(|to| (from, to)));
+            }
+        }
+        // Only importers record ranges; package edges are exact versions
+        for (from, to) in resolved {
+            graph.add_dependency(from, to, None);
+        }
+
+        let roots: Vec<_> = lock
+            .importers
+            .values()
+            .chain(std::iter::once(&lock.root))
+            .flat_map(PnpmImporter::references)
+            .filter_map(|(name, reference, specifier)| {
+                find(&graph, name, reference).map(|id| (id, specifier))
+            })
+            .collect();
+        for (root, specifier) in roots {
+            graph.add_root(root, specifier);
+        }
+
+        Ok(graph)
+    }
+
     async fn verify_integrity<F: FileSystem>(&self, fs: &Arc<F>, path: &Path) -> Result<()> {
         self.parse_lockfile(fs, path).await?;
         Ok(())
@@ -164,36 +455,316 @@ impl Default for PnpmLockfileParser {
     }
 }
 
-/// yarn.lock parser (basic support)
+/// yarn.lock parser (Yarn classic v1 and Yarn Berry)
 ///
-/// Note: yarn.lock uses a custom format that's harder to parse.
-/// This is a simplified parser that extracts basic information.
+/// Classic lockfiles use Yarn's own indentation-based format; Berry
+/// lockfiles are YAML with a `__metadata` entry. Both map one or more
+/// `name@range` specifiers to a single resolved package.
 pub struct YarnLockfileParser;
 
+/// yarn.lock flavor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum YarnLockFormat {
+    /// Yarn 1 (`# yarn lockfile v1`)
+    Classic,
+    /// Yarn 2+ (YAML with `__metadata`)
+    Berry,
+}
+
+/// Parsed yarn.lock
+#[derive(Debug, Clone)]
+pub struct YarnLockfile {
+    /// Lockfile flavor
+    pub format: YarnLockFormat,
+    /// Resolved packages in lockfile order
+    pub entries: Vec<YarnLockEntry>,
+}
+
+/// A single resolved package in yarn.lock
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct YarnLockEntry {
+    /// Package name (e.g. `@babel/core`)
+    pub name: String,
+    /// Ranges resolved to this entry, without the name (e.g. `^7.0.0`, `npm:^7.0.0`)
+    pub specifiers: Vec<String>,
+    /// Resolved version
+    pub version: String,
+    /// Range protocol: `npm`, `workspace`, `patch`, `file`, `link`, `git+https`, ...
+    pub protocol: String,
+    /// Tarball URL (classic) or locator (Berry `resolution`)
+    pub resolved: Option<String>,
+    /// Subresource integrity (classic) or hex SHA-512 checksum (Berry)
+    pub integrity: Option<String>,
+    /// Dependencies and optional dependencies with their ranges
+    pub dependencies: BTreeMap<String, String>,
+}
+
+impl YarnLockEntry {
+    /// Whether this entry is a local workspace rather than an installed package
+    pub fn is_workspace(&self) -> bool {
+        self.protocol == "workspace"
+    }
+
+    /// Verify a package archive against this entry's hash
+    ///
+    /// Classic entries are checked against their `sha512-` integrity,
+    /// Berry entries against the hex `checksum` of the cache archive.
+    /// Entries without a SHA-512 hash (e.g. legacy `sha1-` integrity)
+    /// are not verified.
+    pub fn verify(&self, data: &[u8]) -> Result<()> {
+        let Some(expected) = self.sha512() else {
+            return Ok(());
+        };
+
+        ChecksumVerifier::new(ChecksumAlgorithm::Sha512)
+            .verify_from_string(data, &expected)
+            .map_err(|e| match e {
+                Error::ChecksumMismatch(_, expected, computed) => {
+                    Error::ChecksumMismatch(self.locator(), expected, computed)
+                }
+                other => other,
+            })
+    }
+
+    /// `name@version` for error messages
+    fn locator(&self) -> String {
+        format!("{}@{}", self.name, self.version)
+    }
+
+    /// Berry checksum without its cache key prefix (`10c0/<hex>`)
+    fn berry_checksum(&self) -> Option<&str> {
+        let checksum = self.integrity.as_deref()?;
+        Some(checksum.rsplit('/').next().unwrap_or(checksum))
+    }
+
+    /// The SHA-512 hash as npm-style `sha512-<base64>`
+    fn sha512(&self) -> Option<String> {
+        let integrity = self.integrity.as_deref()?;
+        if integrity.contains('-') {
+            return integrity
+                .split_whitespace()
+                .find(|hash| hash.starts_with("sha512-"))
+                .map(str::to_string);
+        }
+
+        let bytes = hex::decode(self.berry_checksum()?).ok()?;
+        Some(format!(
+            "sha512-{}",
+            general_purpose::STANDARD.encode(bytes)
+        ))
+    }
+
+    /// Check the hash is well-formed without needing the package archive
+    fn validate_integrity(&self) -> std::result::Result<(), String> {
+        let Some(integrity) = self.integrity.as_deref() else {
+            return Ok(());
+        };
+
+        if integrity.contains('-') {
+            for hash in integrity.split_whitespace() {
+                let (algorithm, digest) = hash
+                    .split_once('-')
+                    .ok_or_else(|| format!("malformed integrity '{}'", hash))?;
+                let bytes = general_purpose::STANDARD
+                    .decode(digest)
+                    .map_err(|e| format!("malformed {} integrity: {}", algorithm, e))?;
+                if algorithm == "sha512" && bytes.len() != 64 {
+                    return Err(format!("sha512 integrity has {} bytes", bytes.len()));
+                }
+            }
+            return Ok(());
+        }
+
+        let checksum = self.berry_checksum().unwrap_or(integrity);
+        match hex::decode(checksum) {
+            Ok(bytes) if bytes.len() == 64 => Ok(()),
+            Ok(bytes) => Err(format!("checksum has {} bytes, expected 64", bytes.len())),
+            Err(e) => Err(format!("malformed checksum: {}", e)),
+        }
+    }
+}
+
 impl YarnLockfileParser {
     /// Create a new yarn lockfile parser
     pub fn new() -> Self {
         Self
     }
+
+    /// Parse yarn.lock content, detecting classic or Berry format
+    pub fn parse_content(&self, path: &Path, content: &str) -> Result<YarnLockfile> {
+        let is_berry = content.lines().any(|line| line.trim_end() == "__metadata:");
+
+        let entries = if is_berry {
+            parse_berry(content)
+        } else {
+            parse_classic(content)
+        }
+        .map_err(|message| Error::InvalidFormat(path.to_path_buf(), message))?;
+
+        Ok(YarnLockfile {
+            format: if is_berry {
+                YarnLockFormat::Berry
+            } else {
+                YarnLockFormat::Classic
+            },
+            entries,
+        })
+    }
+
+    /// Parse yarn.lock at `path`
+    pub async fn parse_yarn_lock<F: FileSystem>(
+        &self,
+        fs: &Arc<F>,
+        path: &Path,
+    ) -> Result<YarnLockfile> {
+        let content = fs.read_to_string(path).await?;
+        self.parse_content(path, &content)
+    }
 }
 
 #[async_trait::async_trait]
 impl LockfileParser for YarnLockfileParser {
     async fn parse_lockfile<F: FileSystem>(
         &self,
-        _fs: &Arc<F>,
-        _path: &Path,
+        fs: &Arc<F>,
+        path: &Path,
     ) -> Result<LockedDependencies> {
-        // Yarn lockfile format is complex and uses a custom parser
-        // For now, return empty dependencies
-        // TODO: Implement proper yarn.lock parsing
-        Ok(LockedDependencies {
-            packages: HashMap::new(),
-        })
+        let lockfile = self.parse_yarn_lock(fs, path).await?;
+
+        // yarn.lock can hold several versions of one package; report the
+        // highest, preferring the patched entry when versions tie
+        let mut packages: HashMap<String, LockedPackage> = HashMap::new();
+        for entry in lockfile.entries.iter().filter(|e| !e.is_workspace()) {
+            let replace = packages.get(&entry.name).is_none_or(|current| {
+                let ordering =
+                    compare_versions(&entry.version, &current.version, Ecosystem::JavaScript)
+                        .unwrap_or_else(|_| entry.version.cmp(&current.version));
+                match ordering {
+                    Ordering::Greater => true,
+                    Ordering::Equal => entry.protocol == "patch",
+                    Ordering::Less => false,
+                }
+            });
+            if replace {
+                packages.insert(
+                    entry.name.clone(),
+                    LockedPackage {
+                        name: entry.name.clone(),
+                        version: entry.version.clone(),
+                        checksum: entry.integrity.clone(),
+                        resolved: entry.resolved.clone(),
+                    },
+                );
+            }
+        }
+
+        Ok(LockedDependencies { packages })
+    }
+
+    async fn parse_graph<F: FileSystem>(&self, fs: &Arc<F>, path: &Path) -> Result<LockfileGraph> {
+        let lockfile = self.parse_yarn_lock(fs, path).await?;
+
+        let mut graph = LockfileGraph::new();
+        let mut by_specifier = HashMap::new();
+        let mut ids = Vec::with_capacity(lockfile.entries.len());
+        for entry in &lockfile.entries {
+            let id = graph.add_package(LockedPackage {
+                name: entry.name.clone(),
+                version: entry.version.clone(),
+                checksum: entry.integrity.clone(),
+                resolved: entry.resolved.clone(),
+            });
+            for specifier in &entry.specifiers {
+                by_specifier.insert((entry.name.as_str(), specifier.as_str()), id);
+            }
+            ids.push(id);
+        }
+
+        for (entry, &from) in lockfile.entries.iter().zip(&ids) {
+            for (name, range) in &entry.dependencies {
+                // Berry writes `npm:` ranges for dependencies but keys
+                // some entries by the bare range, and vice versa
+                let to = by_specifier
+                    .get(&(name.as_str(), range.as_str()))
+                    .copied()
+                    .or_else(|| {
+                        let alternate = match range.strip_prefix("npm:") {
+                            Some(bare) => bare.to_string(),
+                            None => format!("npm:{}", range),
+                        };
+                        by_specifier
+                            .get(&(name.as_str(), alternate.as_str()))
+                            .copied()
+                    });
+                if let Some(to) = to {
+                    graph.add_dependency(from, to, Some(range));
+                }
+            }
+        }
+
+        // Classic lockfiles don't record the project's own dependencies
+        match lockfile.format {
+            YarnLockFormat::Berry => {
+                for (entry, &id) in lockfile.entries.iter().zip(&ids) {
+                    if entry.is_workspace() {
+                        graph.add_member(id);
+                    }
+                }
+            }
+            YarnLockFormat::Classic => graph.infer_roots(),
+        }
+
+        Ok(graph)
     }
 
-    async fn verify_integrity<F: FileSystem>(&self, _fs: &Arc<F>, _path: &Path) -> Result<()> {
-        // TODO: Implement yarn.lock integrity verification
+    async fn verify_integrity<F: FileSystem>(&self, fs: &Arc<F>, path: &Path) -> Result<()> {
+        let lockfile = self.parse_yarn_lock(fs, path).await?;
+
+        for entry in &lockfile.entries {
+            entry.validate_integrity().map_err(|message| {
+                Error::InvalidFormat(
+                    path.to_path_buf(),
+                    format!("{}: {}", entry.locator(), message),
+                )
+            })?;
+        }
+
+        // Berry keeps package archives in .yarn/cache, named with the
+        // first 10 hex digits of their checksum
+        let cache_dir = path
+            .parent()
+            .unwrap_or_else(|| Path::new("."))
+            .join(".yarn")
+            .join("cache");
+        if lockfile.format != YarnLockFormat::Berry || !fs.exists(&cache_dir).await? {
+            return Ok(());
+        }
+
+        let options = DiscoveryOptions {
+            max_depth: 1,
+            include_hidden: true,
+            respect_gitignore: false,
+            ..Default::default()
+        };
+        let archives = fs
+            .discover_files(&cache_dir, &[".zip"], &[], &options)
+            .await?;
+
+        for entry in &lockfile.entries {
+            let Some(prefix) = entry.berry_checksum().and_then(|c| c.get(..10)) else {
+                continue;
+            };
+            let suffix = format!("-{}.zip", prefix);
+            let archive = archives.iter().find(|archive| {
+                archive
+                    .file_name()
+                    .is_some_and(|name| name.to_string_lossy().ends_with(&suffix))
+            });
+            if let Some(archive) = archive {
+                entry.verify(&fs.read(archive).await?)?;
+            }
+        }
+
         Ok(())
     }
 }
@@ -204,6 +775,163 @@ impl Default for YarnLockfileParser {
     }
 }
 
+/// Split `name@range`, keeping the `@` of scoped names
+fn split_specifier(specifier: &str) -> Option<(&str, &str)> {
+    let at = specifier.get(1..)?.find('@')? + 1;
+    Some((&specifier[..at], &specifier[at + 1..]))
+}
+
+/// Protocol of a range: `npm:^1.0.0` -> `npm`, `^1.0.0` -> `npm`
+fn range_protocol(range: &str) -> String {
+    match range.split_once(':') {
+        Some((protocol, _))
+            if !protocol.is_empty()
+                && protocol
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '+') =>
+        {
+            protocol.to_string()
+        }
+        _ => "npm".to_string(),
+    }
+}
+
+/// Build an entry from its `name@range` key specifiers
+fn entry_from_key(key: &str) -> std::result::Result<YarnLockEntry, String> {
+    let mut name = None;
+    let mut specifiers = Vec::new();
+    for specifier in key.split(',').map(|s| unquote(s.trim())) {
+        let (spec_name, range) = split_specifier(specifier)
+            .ok_or_else(|| format!("invalid package specifier '{}'", specifier))?;
+        name.get_or_insert(spec_name);
+        specifiers.push(range.to_string());
+    }
+
+    let name = name.ok_or_else(|| format!("empty entry key '{}'", key))?;
+    Ok(YarnLockEntry {
+        name: name.to_string(),
+        protocol: range_protocol(&specifiers[0]),
+        specifiers,
+        version: String::new(),
+        resolved: None,
+        integrity: None,
+        dependencies: BTreeMap::new(),
+    })
+}
+
+fn unquote(value: &str) -> &str {
+    value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .unwrap_or(value)
+}
+
+/// Parse Yarn classic's indentation-based format
+fn parse_classic(content: &str) -> std::result::Result<Vec<YarnLockEntry>, String> {
+    let mut entries = Vec::new();
+    let mut current: Option<YarnLockEntry> = None;
+    let mut in_dependencies = false;
+
+    for (index, line) in content.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        let indent = line.len() - line.trim_start().len();
+
+        match indent {
+            0 => {
+                let key = trimmed.strip_suffix(':').ok_or_else(|| {
+                    format!("line {}: expected an entry key ending in ':'", index + 1)
+                })?;
+                entries.extend(current.take());
+                current = Some(entry_from_key(key)?);
+                in_dependencies = false;
+            }
+            2 => {
+                let entry = current
+                    .as_mut()
+                    .ok_or_else(|| format!("line {}: field outside of an entry", index + 1))?;
+                if let Some(section) = trimmed.strip_suffix(':') {
+                    in_dependencies = matches!(section, "dependencies" | "optionalDependencies");
+                    continue;
+                }
+                in_dependencies = false;
+
+                let (field, value) = split_field(trimmed);
+                match field {
+                    "version" => entry.version = value.to_string(),
+                    "resolved" => entry.resolved = Some(value.to_string()),
+                    "integrity" => entry.integrity = Some(value.to_string()),
+                    _ => {}
+                }
+            }
+            _ => {
+                if let (true, Some(entry)) = (in_dependencies, current.as_mut()) {
+                    let (name, range) = split_field(trimmed);
+                    entry
+                        .dependencies
+                        .insert(name.to_string(), range.to_string());
+                }
+            }
+        }
+    }
+    entries.extend(current);
+
+    match entries.iter().find(|entry| entry.version.is_empty()) {
+        Some(entry) => Err(format!("entry '{}' has no version", entry.name)),
+        None => Ok(entries),
+    }
+}
+
+/// Split a classic `key "value"` line
+fn split_field(line: &str) -> (&str, &str) {
+    match line.split_once(char::is_whitespace) {
+        Some((key, value)) => (unquote(key), unquote(value.trim())),
+        None => (unquote(line), ""),
+    }
+}
+
+/// Parse Yarn Berry's YAML format
+fn parse_berry(content: &str) -> std::result::Result<Vec<YarnLockEntry>, String> {
+    let document: BTreeMap<String, serde_yaml::Value> =
+        serde_yaml::from_str(content).map_err(|e| e.to_string())?;
+
+    document
+        .iter()
+        .filter(|(key, _)| key.as_str() != "__metadata")
+        .map(|(key, value)| {
+            let mut entry = entry_from_key(key)?;
+            entry.version = value
+                .get("version")
+                .and_then(yaml_scalar)
+                .ok_or_else(|| format!("entry '{}' has no version", key))?;
+            entry.resolved = value.get("resolution").and_then(yaml_scalar);
+            entry.integrity = value.get("checksum").and_then(yaml_scalar);
+            for section in ["dependencies", "optionalDependencies"] {
+                if let Some(serde_yaml::Value::Mapping(deps)) = value.get(section) {
+                    entry
+                        .dependencies
+                        .extend(deps.iter().filter_map(|(name, range)| {
+                            Some((yaml_scalar(name)?, yaml_scalar(range)?))
+                        }));
+                }
+            }
+            Ok(entry)
+        })
+        .collect()
+}
+
+/// YAML scalars as strings (`version: 1.0` parses as a number)
+fn yaml_scalar(value: &serde_yaml::Value) -> Option<String> {
+    match value {
+        serde_yaml::Value::String(s) => Some(s.clone()),
+        serde_yaml::Value::Number(n) => Some(n.to_string()),
+        serde_yaml::Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -243,4 +971,372 @@ mod tests {
         assert!(result.packages.contains_key("react"));
         assert_eq!(result.packages["react"].version, "18.0.0");
     }
+
+    fn names(paths: Vec<Vec<&LockedPackage>>) -> Vec<Vec<String>> {
+        let mut names: Vec<Vec<String>> = paths
+            .into_iter()
+            .map(|path| {
+                path.iter()
+                    .map(|p| format!("{}@{}", p.name, p.version))
+                    .collect()
+            })
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[tokio::test]
+    async fn test_npm_graph_resolves_nested_node_modules() {
+        let temp_dir = TempDir::new().unwrap();
+        let lockfile = temp_dir.path().join("package-lock.json");
+        std::fs::write(
+            &lockfile,
+            r#"
+{
+  "lockfileVersion": 3,
+  "packages": {
+    "": {
+      "name": "app",
+      "workspaces": ["packages/ui"],
+      "dependencies": { "express": "^4.0.0", "ui": "*" },
+      "devDependencies": { "debug": "^4.0.0" }
+    },
+    "node_modules/debug": { "version": "4.3.4", "dependencies": { "ms": "2.1.2" } },
+    "node_modules/express": { "version": "4.18.2", "dependencies": { "debug": "2.6.9" } },
+    "node_modules/express/node_modules/debug": {
+      "version": "2.6.9",
+      "dependencies": { "ms": "2.0.0" }
+    },
+    "node_modules/express/node_modules/ms": { "version": "2.0.0" },
+    "node_modules/ms": { "version": "2.1.2" },
+    "node_modules/ui": { "resolved": "packages/ui", "link": true },
+    "packages/ui": { "name": "ui", "version": "1.0.0", "dependencies": { "ms": "^2.1.0" } }
+  }
+}
+"#,
+        )
+        .unwrap();
+
+        let parser = NpmLockfileParser::new();
+        let fs = Arc::new(NativeFileSystem::new(temp_dir.path()).unwrap());
+        let graph = parser.parse_graph(&fs, &lockfile).await.unwrap();
+
+        assert_eq!(graph.versions("debug").len(), 2);
+        assert_eq!(graph.versions("ms").len(), 2);
+        let express = graph.find("express", "4.18.2").unwrap();
+        let old_debug = graph.find("debug", "2.6.9").unwrap();
+        assert_eq!(graph.requirement(None, express), Some("^4.0.0"));
+        assert_eq!(graph.requirement(Some(express), old_debug), Some("2.6.9"));
+        assert!(graph.is_member(graph.find("ui", "1.0.0").unwrap()));
+        assert_eq!(
+            names(graph.why("ms", Some("2.0.0"))),
+            vec![vec!["express@4.18.2", "debug@2.6.9", "ms@2.0.0"]]
+        );
+        assert_eq!(
+            names(graph.why("ms", Some("2.1.2"))),
+            vec![
+                vec!["debug@4.3.4", "ms@2.1.2"],
+                vec!["ui@1.0.0", "ms@2.1.2"]
+            ]
+        );
+
+        // Locked dependencies still report the hoisted version
+        let locked = parser.parse_lockfile(&fs, &lockfile).await.unwrap();
+        assert_eq!(locked.packages["debug"].version, "4.3.4");
+        assert!(!locked.packages.contains_key("ui"));
+    }
+
+    #[test]
+    fn test_parse_pnpm_key() {
+        let parse = |key| parse_pnpm_key(key).unwrap();
+        let expected = |name: &str, version: &str| (name.to_string(), version.to_string());
+
+        assert_eq!(parse("/lodash/4.17.21"), expected("lodash", "4.17.21"));
+        assert_eq!(
+            parse("/@babel/core/7.0.0_react@18.2.0"),
+            expected("@babel/core", "7.0.0")
+        );
+        assert_eq!(parse("/lodash@4.17.21"), expected("lodash", "4.17.21"));
+        assert_eq!(
+            parse("@tanstack/react-query@5.0.0(react@18.2.0)"),
+            expected("@tanstack/react-query", "5.0.0")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pnpm_graph() {
+        let temp_dir = TempDir::new().unwrap();
+        let lockfile = temp_dir.path().join("pnpm-lock.yaml");
+        std::fs::write(
+            &lockfile,
+            r#"
+lockfileVersion: '9.0'
+
+importers:
+  .:
+    dependencies:
+      '@scope/ui':
+        specifier: ^1.0.0
+        version: 1.0.0(react@18.2.0)
+      react:
+        specifier: ^18.0.0
+        version: 18.2.0
+  packages/legacy:
+    dependencies:
+      react:
+        specifier: ^17.0.0
+        version: 17.0.2
+
+packages:
+  '@scope/ui@1.0.0':
+    resolution: {integrity: sha512-ui}
+  loose-envify@1.4.0:
+    resolution: {integrity: sha512-le}
+  react@17.0.2:
+    resolution: {integrity: sha512-r17}
+  react@18.2.0:
+    resolution: {integrity: sha512-r18}
+
+snapshots:
+  '@scope/ui@1.0.0(react@18.2.0)':
+    dependencies:
+      react: 18.2.0
+  loose-envify@1.4.0: {}
+  react@17.0.2:
+    dependencies:
+      loose-envify: 1.4.0
+  react@18.2.0:
+    dependencies:
+      loose-envify: 1.4.0
+"#,
+        )
+        .unwrap();
+
+        let parser = PnpmLockfileParser::new();
+        let fs = Arc::new(NativeFileSystem::new(temp_dir.path()).unwrap());
+        let graph = parser.parse_graph(&fs, &lockfile).await.unwrap();
+
+        assert_eq!(graph.len(), 4);
+        assert_eq!(graph.versions("react").len(), 2);
+        assert_eq!(
+            names(graph.why("react", Some("18.2.0"))),
+            vec![
+                vec!["@scope/ui@1.0.0", "react@18.2.0"],
+                vec!["react@18.2.0"]
+            ]
+        );
+        assert_eq!(graph.why("loose-envify", None).len(), 3);
+        let react = graph.find("react", "18.2.0").unwrap();
+        assert_eq!(graph.requirement(None, react), Some("^18.0.0"));
+    }
+
+    const YARN_CLASSIC: &str = r#"# THIS IS AN AUTOGENERATED FILE. DO NOT EDIT THIS FILE DIRECTLY.
+# yarn lockfile v1
+
+
+"@babel/code-frame@^7.0.0", "@babel/code-frame@^7.10.4":
+  version "7.12.13"
+  resolved "https://registry.yarnpkg.com/@babel/code-frame/-/code-frame-7.12.13.tgz#dcfc826b"
+  integrity sha512-HV1Cm0Q3ZrpCR93tkWOYiuYIgLxZXZFVG2VgK+MBWjUqZTundupbfx2aXarXuw5Ko5aMcjtJgbSs4vUGBS5v6g==
+  dependencies:
+    "@babel/highlight" "^7.12.13"
+
+lodash@^3.0.0:
+  version "3.10.1"
+  resolved "https://registry.yarnpkg.com/lodash/-/lodash-3.10.1.tgz"
+
+lodash@^4.17.21:
+  version "4.17.21"
+  resolved "https://registry.yarnpkg.com/lodash/-/lodash-4.17.21.tgz"
+
+local-lib@file:../local-lib:
+  version "1.0.0"
+"#;
+
+    fn berry_lockfile(checksum: &str) -> String {
+        format!(
+            r#"# This file is generated by running "yarn install" inside your project.
+
+__metadata:
+  version: 8
+  cacheKey: 10c0
+
+"my-app@workspace:.":
+  version: 0.0.0-use.local
+  resolution: "my-app@workspace:."
+  dependencies:
+    left-pad: "npm:^1.3.0"
+  languageName: unknown
+  linkType: soft
+
+"left-pad@npm:^1.3.0":
+  version: 1.3.0
+  resolution: "left-pad@npm:1.3.0"
+  checksum: 10c0/{checksum}
+  languageName: node
+  linkType: hard
+
+"resolve@npm:^1.20.0":
+  version: 1.22.1
+  resolution: "resolve@npm:1.22.1"
+  languageName: node
+  linkType: hard
+
+"resolve@patch:resolve@npm%3A^1.20.0#optional!builtin<compat/resolve>":
+  version: 1.22.1
+  resolution: "resolve@patch:resolve@npm%3A1.22.1#optional!builtin<compat/resolve>::version=1.22.1&hash=c3c19d"
+  languageName: node
+  linkType: hard
+"#
+        )
+    }
+
+    #[test]
+    fn test_parse_yarn_classic() {
+        let lockfile = YarnLockfileParser::new()
+            .parse_content(Path::new("yarn.lock"), YARN_CLASSIC)
+            .unwrap();
+
+        assert_eq!(lockfile.format, YarnLockFormat::Classic);
+        assert_eq!(lockfile.entries.len(), 4);
+
+        let code_frame = &lockfile.entries[0];
+        assert_eq!(code_frame.name, "@babel/code-frame");
+        assert_eq!(code_frame.specifiers, vec!["^7.0.0", "^7.10.4"]);
+        assert_eq!(code_frame.version, "7.12.13");
+        assert_eq!(code_frame.protocol, "npm");
+        assert!(code_frame
+            .integrity
+            .as_deref()
+            .unwrap()
+            .starts_with("sha512-"));
+        assert_eq!(code_frame.dependencies["@babel/highlight"], "^7.12.13");
+
+        assert_eq!(lockfile.entries[3].protocol, "file");
+    }
+
+    #[test]
+    fn test_parse_yarn_berry() {
+        let checksum = "ab".repeat(64);
+        let lockfile = YarnLockfileParser::new()
+            .parse_content(Path::new("yarn.lock"), &berry_lockfile(&checksum))
+            .unwrap();
+
+        assert_eq!(lockfile.format, YarnLockFormat::Berry);
+        let workspace = lockfile
+            .entries
+            .iter()
+            .find(|e| e.name == "my-app")
+            .unwrap();
+        assert!(workspace.is_workspace());
+        assert_eq!(workspace.dependencies["left-pad"], "npm:^1.3.0");
+
+        let left_pad = lockfile
+            .entries
+            .iter()
+            .find(|e| e.name == "left-pad")
+            .unwrap();
+        assert_eq!(left_pad.specifiers, vec!["npm:^1.3.0"]);
+        assert_eq!(left_pad.resolved.as_deref(), Some("left-pad@npm:1.3.0"));
+        assert!(left_pad.validate_integrity().is_ok());
+
+        assert!(lockfile
+            .entries
+            .iter()
+            .any(|e| e.name == "resolve" && e.protocol == "patch"));
+    }
+
+    #[test]
+    fn test_parse_yarn_lock_rejects_malformed_entries() {
+        let parser = YarnLockfileParser::new();
+        let missing_version = "lodash@^4.0.0:\n  resolved \"https://example.com\"\n";
+        assert!(matches!(
+            parser.parse_content(Path::new("yarn.lock"), missing_version),
+            Err(Error::InvalidFormat(..))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_yarn_locked_dependencies() {
+        let temp_dir = TempDir::new().unwrap();
+        let lockfile = temp_dir.path().join("yarn.lock");
+        std::fs::write(&lockfile, YARN_CLASSIC).unwrap();
+
+        let parser = YarnLockfileParser::new();
+        let fs = Arc::new(NativeFileSystem::new(temp_dir.path()).unwrap());
+        let result = parser.parse_lockfile(&fs, &lockfile).await.unwrap();
+
+        // Highest of the locked versions wins
+        assert_eq!(result.packages["lodash"].version, "4.17.21");
+        assert_eq!(result.packages["@babel/code-frame"].version, "7.12.13");
+
+        std::fs::write(&lockfile, berry_lockfile(&"ab".repeat(64))).unwrap();
+        let result = parser.parse_lockfile(&fs, &lockfile).await.unwrap();
+        assert!(!result.packages.contains_key("my-app"));
+        assert!(result.packages["resolve"]
+            .resolved
+            .as_deref()
+            .unwrap()
+            .starts_with("resolve@patch:"));
+    }
+
+    #[tokio::test]
+    async fn test_yarn_verify_integrity_checks_berry_cache() {
+        let temp_dir = TempDir::new().unwrap();
+        let archive = b"left-pad archive";
+        let checksum = ChecksumVerifier::new(ChecksumAlgorithm::Sha512).compute(archive);
+        let checksum = hex::encode(
+            general_purpose::STANDARD
+                .decode(checksum.strip_prefix("sha512-").unwrap())
+                .unwrap(),
+        );
+
+        let lockfile = temp_dir.path().join("yarn.lock");
+        std::fs::write(&lockfile, berry_lockfile(&checksum)).unwrap();
+        let cache = temp_dir.path().join(".yarn/cache");
+        std::fs::create_dir_all(&cache).unwrap();
+        let archive_path = cache.join(format!(
+            "left-pad-npm-1.3.0-a1b2c3d4e5-{}.zip",
+            &checksum[..10]
+        ));
+        std::fs::write(&archive_path, archive).unwrap();
+
+        let parser = YarnLockfileParser::new();
+        let fs = Arc::new(NativeFileSystem::new(temp_dir.path()).unwrap());
+        parser.verify_integrity(&fs, &lockfile).await.unwrap();
+
+        std::fs::write(&archive_path, b"tampered").unwrap();
+        let err = parser.verify_integrity(&fs, &lockfile).await.unwrap_err();
+        assert!(matches!(err, Error::ChecksumMismatch(ref name, ..) if name == "left-pad@1.3.0"));
+    }
+
+    #[tokio::test]
+    async fn test_yarn_graph() {
+        let temp_dir = TempDir::new().unwrap();
+        let lockfile = temp_dir.path().join("yarn.lock");
+        let parser = YarnLockfileParser::new();
+        let fs = Arc::new(NativeFileSystem::new(temp_dir.path()).unwrap());
+
+        std::fs::write(&lockfile, YARN_CLASSIC).unwrap();
+        let graph = parser.parse_graph(&fs, &lockfile).await.unwrap();
+        assert_eq!(graph.versions("lodash").len(), 2);
+        assert_eq!(
+            names(graph.why("lodash", Some("3.10.1"))),
+            vec![vec!["lodash@3.10.1"]]
+        );
+
+        std::fs::write(&lockfile, berry_lockfile(&"ab".repeat(64))).unwrap();
+        let graph = parser.parse_graph(&fs, &lockfile).await.unwrap();
+        assert_eq!(
+            names(graph.why("left-pad", None)),
+            vec![vec!["my-app@0.0.0-use.local", "left-pad@1.3.0"]]
+        );
+        let app = graph.find("my-app", "0.0.0-use.local").unwrap();
+        let left_pad = graph.find("left-pad", "1.3.0").unwrap();
+        assert!(graph.is_member(app));
+        assert_eq!(graph.requirement(Some(app), left_pad), Some("npm:^1.3.0"));
+        // The patched resolve shares the node of the version it patches
+        assert_eq!(graph.versions("resolve").len(), 1);
+        assert!(graph.why("resolve", None).is_empty());
+    }
 }
@@ -0,0 +1,670 @@
+//! Registry routing for private packages
+//!
+//! Reads the registry configuration npm and Cargo use themselves, so that
+//! lookups for private packages go to the registry they're published on:
+//! - `.npmrc`: `registry=`, scoped `@scope:registry=` and `_authToken` lines
+//! - `.cargo/config.toml`: `[registries]` and `[registry] default`, plus
+//!   tokens from `$CARGO_HOME/credentials.toml`
+//!
+//! Git, path and other non-registry dependencies are recognized so that
+//! lookups can skip them.
+
+use crate::{Dependency, Ecosystem, Error, Result};
+use danny_info::RegistryConfig;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use toml_edit::{DocumentMut, TableLike};
+
+/// Name Cargo uses for crates.io in `registry` fields and config
+const CRATES_IO: &str = "crates-io";
+
+/// Index URLs that mean crates.io
+const CRATES_IO_INDEXES: &[&str] = &[
+    "https://github.com/rust-lang/crates.io-index",
+    "https://index.crates.io",
+];
+
+/// Version specifier prefixes npm resolves without a registry
+const NPM_GIT_PREFIXES: &[&str] = &[
+    "git+",
+    "git:",
+    "git@",
+    "github:",
+    "gitlab:",
+    "bitbucket:",
+    "gist:",
+];
+const NPM_LOCAL_PREFIXES: &[&str] = &[
+    "file:",
+    "link:",
+    "workspace:",
+    "portal:",
+    "patch:",
+    "http:",
+    "https:",
+    "./",
+    "../",
+    "/",
+    "~/",
+];
+
+/// Where a dependency is fetched from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DependencySource {
+    /// The ecosystem's default registry (or, for npm, the scope's registry)
+    Registry,
+    /// A Cargo registry named in `.cargo/config.toml`
+    NamedRegistry(String),
+    /// A Cargo registry given by its index URL, with its `registry+` or
+    /// `sparse+` prefix
+    RegistryIndex(String),
+    /// A git repository
+    Git(String),
+    /// A local path, workspace package, tarball URL or other source that
+    /// isn't a registry
+    Local(String),
+}
+
+impl DependencySource {
+    /// Classify where `dep` comes from
+    ///
+    /// Cargo dependencies are classified by [`Dependency::source`]; npm
+    /// dependencies by their version specifier (`git+https://…`,
+    /// `github:owner/repo`, `file:../pkg`, `workspace:*`, …).
+    pub fn of(dep: &Dependency) -> Self {
+        match dep.version_req.ecosystem {
+            Ecosystem::Rust => Self::from_cargo_source(dep.source.as_deref()),
+            Ecosystem::JavaScript => match dep.source.as_deref() {
+                Some(source) => Self::Local(source.to_string()),
+                None => Self::from_npm_specifier(&dep.version_req.raw),
+            },
+        }
+    }
+
+    /// Whether the dependency is looked up in a registry
+    pub fn is_registry(&self) -> bool {
+        matches!(
+            self,
+            Self::Registry | Self::NamedRegistry(_) | Self::RegistryIndex(_)
+        )
+    }
+
+    fn from_cargo_source(source: Option<&str>) -> Self {
+        let Some(source) = source else {
+            return Self::Registry;
+        };
+        if let Some(url) = source.strip_prefix("git+") {
+            Self::Git(url.to_string())
+        } else if let Some(path) = source.strip_prefix("path+") {
+            Self::Local(path.to_string())
+        } else if source.starts_with("registry+") || source.starts_with("sparse+") {
+            Self::RegistryIndex(source.to_string())
+        } else if source == CRATES_IO {
+            Self::Registry
+        } else {
+            Self::NamedRegistry(source.to_string())
+        }
+    }
+
+    fn from_npm_specifier(specifier: &str) -> Self {
+        let specifier = specifier.trim();
+        if NPM_GIT_PREFIXES.iter().any(|p| specifier.starts_with(p)) {
+            Self::Git(specifier.to_string())
+        } else if NPM_LOCAL_PREFIXES.iter().any(|p| specifier.starts_with(p)) {
+            Self::Local(specifier.to_string())
+        } else if !specifier.starts_with('@')
+            && !specifier.starts_with("npm:")
+            && specifier.contains('/')
+            && !specifier.contains(char::is_whitespace)
+        {
+            // GitHub shorthand: `owner/repo` or `owner/repo#ref`
+            Self::Git(specifier.to_string())
+        } else {
+            Self::Registry
+        }
+    }
+}
+
+/// Registry settings from `.npmrc` files
+///
+/// Files are applied user config first, then every `.npmrc` from the
+/// outermost ancestor of the project down to the project directory, so the
+/// nearest file wins. `${VAR}` references are expanded from the environment.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NpmConfig {
+    registry: Option<String>,
+    /// Registry URL per scope, e.g. `@acme` -> `https://npm.acme.dev/`
+    scopes: HashMap<String, String>,
+    /// Tokens per registry prefix, e.g. `//npm.acme.dev/` -> token
+    auth_tokens: HashMap<String, String>,
+    /// Unprefixed `_authToken`, used for the default registry
+    auth_token: Option<String>,
+}
+
+impl NpmConfig {
+    /// Load the configuration that applies to packages in `project_dir`
+    ///
+    /// Missing or unreadable files are skipped.
+    pub async fn load(project_dir: &Path) -> Self {
+        let mut paths: Vec<PathBuf> = std::env::var_os("NPM_CONFIG_USERCONFIG")
+            .map(PathBuf::from)
+            .or_else(|| dirs::home_dir().map(|home| home.join(".npmrc")))
+            .into_iter()
+            .collect();
+        paths.extend(ancestor_files(project_dir, ".npmrc"));
+
+        let mut config = Self::default();
+        for path in paths {
+            if let Ok(content) = tokio::fs::read_to_string(&path).await {
+                config.merge(&content, &process_env);
+            }
+        }
+        config
+    }
+
+    /// Parse the contents of one `.npmrc` file
+    pub fn parse(content: &str) -> Self {
+        Self::parse_with_env(content, process_env)
+    }
+
+    /// Parse the contents of one `.npmrc` file, expanding `${VAR}` with `env`
+    /// instead of the process environment
+    pub fn parse_with_env(content: &str, env: impl Fn(&str) -> Option<String>) -> Self {
+        let mut config = Self::default();
+        config.merge(content, &env);
+        config
+    }
+
+    /// Apply the settings in `content` on top of the current ones
+    fn merge(&mut self, content: &str, env: &dyn Fn(&str) -> Option<String>) {
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let key = key.trim();
+            let value = expand_env(value.trim().trim_matches('"'), env);
+
+            if key == "registry" {
+                self.registry = Some(value);
+            } else if key == "_authToken" {
+                self.auth_token = Some(value);
+            } else if let Some(scope) = key.strip_suffix(":registry") {
+                if scope.starts_with('@') {
+                    self.scopes.insert(scope.to_string(), value);
+                }
+            } else if let Some(prefix) = key.strip_suffix(":_authToken") {
+                if prefix.starts_with("//") {
+                    self.auth_tokens.insert(with_slash(prefix), value);
+                }
+            }
+        }
+    }
+
+    /// Registry `package` is published on
+    ///
+    /// Returns `None` when nothing in `.npmrc` applies, meaning the client's
+    /// default npm registry should be used.
+    pub fn registry_for(&self, package: &str) -> Option<RegistryConfig> {
+        let scope = package
+            .split_once('/')
+            .map(|(scope, _)| scope)
+            .filter(|scope| scope.starts_with('@'));
+        let scope_url = scope.and_then(|scope| self.scopes.get(scope));
+
+        let public = RegistryConfig::npm();
+        let config = match scope_url.or(self.registry.as_ref()) {
+            Some(url) if RegistryConfig::new(url.as_str()).base_url() != public.base_url() => {
+                RegistryConfig::new(url.as_str())
+            }
+            _ => public.clone(),
+        };
+
+        // A bare `_authToken` belongs to the default registry
+        let token = self
+            .token_for(config.base_url())
+            .or_else(|| scope_url.map_or(self.auth_token.as_deref(), |_| None))
+            .filter(|token| !token.is_empty());
+        match token {
+            Some(token) => Some(config.with_token(token)),
+            None if config == public => None,
+            None => Some(config),
+        }
+    }
+
+    /// Token for `url`, matched like npm does: by the longest
+    /// `//host/path/` prefix
+    fn token_for(&self, url: &str) -> Option<&str> {
+        let nerfed = with_slash(url.split_once(':').map_or(url, |(_, rest)| rest));
+        self.auth_tokens
+            .iter()
+            .filter(|(prefix, _)| nerfed.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(_, token)| token.as_str())
+    }
+}
+
+/// A registry from `.cargo/config.toml`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct CargoRegistry {
+    index: Option<String>,
+    token: Option<String>,
+}
+
+/// Registry settings from Cargo configuration
+///
+/// Files are applied like Cargo does: `$CARGO_HOME/config.toml` and
+/// `credentials.toml` first, then every `.cargo/config.toml` from the
+/// outermost ancestor of the project down to the project directory.
+/// `CARGO_REGISTRIES_<NAME>_INDEX`, `CARGO_REGISTRIES_<NAME>_TOKEN` and
+/// `CARGO_REGISTRY_DEFAULT` take precedence over files.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CargoConfig {
+    default: Option<String>,
+    registries: HashMap<String, CargoRegistry>,
+}
+
+impl CargoConfig {
+    /// Load the configuration that applies to crates in `project_dir`
+    ///
+    /// Missing, unreadable or invalid files are skipped.
+    pub async fn load(project_dir: &Path) -> Self {
+        let mut paths = Vec::new();
+        let cargo_home = std::env::var_os("CARGO_HOME")
+            .map(PathBuf::from)
+            .or_else(|| dirs::home_dir().map(|home| home.join(".cargo")));
+        if let Some(home) = cargo_home {
+            for name in ["config", "config.toml", "credentials", "credentials.toml"] {
+                paths.push(home.join(name));
+            }
+        }
+        for dir in ancestor_files(project_dir, ".cargo") {
+            paths.push(dir.join("config"));
+            paths.push(dir.join("config.toml"));
+        }
+
+        let mut config = Self::default();
+        for path in paths {
+            if let Ok(content) = tokio::fs::read_to_string(&path).await {
+                let _ = config.merge(&content);
+            }
+        }
+        config
+    }
+
+    /// Parse the contents of one Cargo config file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `content` isn't valid TOML.
+    pub fn parse(content: &str) -> Result<Self> {
+        let mut config = Self::default();
+        config.merge(content)?;
+        Ok(config)
+    }
+
+    /// Apply the settings in `content` on top of the current ones
+    fn merge(&mut self, content: &str) -> Result<()> {
+        let doc: DocumentMut = content.parse()?;
+
+        if let Some(default) = doc
+            .get("registry")
+            .and_then(|item| item.as_table_like())
+            .and_then(|table| string(table, "default"))
+        {
+            self.default = Some(default);
+        }
+
+        if let Some(registries) = doc.get("registries").and_then(|item| item.as_table_like()) {
+            for (name, item) in registries.iter() {
+                let Some(table) = item.as_table_like() else {
+                    continue;
+                };
+                let registry = self.registries.entry(name.to_string()).or_default();
+                if let Some(index) = string(table, "index") {
+                    registry.index = Some(index);
+                }
+                if let Some(token) = string(table, "token") {
+                    registry.token = Some(token);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Registry a dependency from `source` is looked up in
+    ///
+    /// Returns `None` for crates.io (and for git and local sources, which
+    /// aren't looked up at all). Other registries are queried through their
+    /// sparse index, so the config points at the index URL.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a named registry isn't configured, or its index
+    /// is a git index, which can't be queried over HTTP.
+    pub fn registry_for(&self, source: &DependencySource) -> Result<Option<RegistryConfig>> {
+        let name = match source {
+            DependencySource::Registry => match self.default_registry() {
+                Some(name) if name != CRATES_IO => name,
+                _ => return Ok(None),
+            },
+            DependencySource::NamedRegistry(name) => name.clone(),
+            DependencySource::RegistryIndex(index) => {
+                let name = self
+                    .registries
+                    .keys()
+                    .find(|name| self.index(name).as_deref() == Some(index.as_str()));
+                let token = name.and_then(|name| self.token(name));
+                return index_registry(index, token);
+            }
+            DependencySource::Git(_) | DependencySource::Local(_) => return Ok(None),
+        };
+
+        if name == CRATES_IO {
+            return Ok(None);
+        }
+        let index = self.index(&name).ok_or_else(|| {
+            Error::RegistryError(format!(
+                "Registry `{}` is not configured in .cargo/config.toml",
+                name
+            ))
+        })?;
+        index_registry(&index, self.token(&name))
+    }
+
+    fn default_registry(&self) -> Option<String> {
+        std::env::var("CARGO_REGISTRY_DEFAULT")
+            .ok()
+            .or_else(|| self.default.clone())
+    }
+
+    fn index(&self, name: &str) -> Option<String> {
+        std::env::var(format!("CARGO_REGISTRIES_{}_INDEX", env_name(name)))
+            .ok()
+            .or_else(|| self.registries.get(name)?.index.clone())
+    }
+
+    fn token(&self, name: &str) -> Option<String> {
+        std::env::var(format!("CARGO_REGISTRIES_{}_TOKEN", env_name(name)))
+            .ok()
+            .or_else(|| self.registries.get(name)?.token.clone())
+    }
+}
+
+/// Lookup config for the registry with `index`
+fn index_registry(index: &str, token: Option<String>) -> Result<Option<RegistryConfig>> {
+    let (url, sparse) = match index.strip_prefix("sparse+") {
+        Some(url) => (url, true),
+        None => (index.strip_prefix("registry+").unwrap_or(index), false),
+    };
+    let url = url.trim_end_matches('/');
+    if CRATES_IO_INDEXES.contains(&url) {
+        return Ok(None);
+    }
+    if !sparse {
+        return Err(Error::RegistryError(format!(
+            "Registry index {} is a git index; only sparse indexes can be queried",
+            url
+        )));
+    }
+
+    let registry = RegistryConfig::new(url);
+    Ok(Some(match token {
+        Some(token) => registry.with_authorization(token),
+        None => registry,
+    }))
+}
+
+/// `name` in every ancestor of `dir`, outermost first
+fn ancestor_files(dir: &Path, name: &str) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = dir.ancestors().map(|dir| dir.join(name)).collect();
+    paths.reverse();
+    paths
+}
+
+fn string(table: &dyn TableLike, key: &str) -> Option<String> {
+    table.get(key)?.as_str().map(String::from)
+}
+
+/// Registry name as it appears in environment variables
+fn env_name(name: &str) -> String {
+    name.to_uppercase().replace('-', "_")
+}
+
+fn with_slash(url: &str) -> String {
+    if url.ends_with('/') {
+        url.to_string()
+    } else {
+        format!("{}/", url)
+    }
+}
+
+fn process_env(name: &str) -> Option<String> {
+    std::env::var(name).ok()
+}
+
+/// Expand `${VAR}` references; unset variables expand to nothing, as in npm
+fn expand_env(value: &str, env: &dyn Fn(&str) -> Option<String>) -> String {
+    let mut expanded = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find("${") {
+        let Some(len) = rest[start + 2..].find('}') else {
+            break;
+        };
+        expanded.push_str(&rest[..start]);
+        expanded.push_str(&env(&rest[start + 2..start + 2 + len]).unwrap_or_default());
+        rest = &rest[start + 3 + len..];
+    }
+    expanded.push_str(rest);
+    expanded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DependencyType, VersionReq};
+    use tempfile::TempDir;
+
+    fn dependency(raw: &str, source: Option<&str>, ecosystem: Ecosystem) -> Dependency {
+        Dependency {
+            name: "pkg".to_string(),
+            version_req: VersionReq {
+                raw: raw.to_string(),
+                ecosystem,
+            },
+            dep_type: DependencyType::Runtime,
+            features: vec![],
+            workspace: false,
+            source: source.map(String::from),
+        }
+    }
+
+    #[test]
+    fn test_npm_dependency_sources() {
+        let source = |raw| DependencySource::of(&dependency(raw, None, Ecosystem::JavaScript));
+
+        assert_eq!(source("^1.2.0"), DependencySource::Registry);
+        assert_eq!(source("npm:react@^18.0.0"), DependencySource::Registry);
+        assert_eq!(source("latest"), DependencySource::Registry);
+        assert!(matches!(
+            source("git+https://github.com/acme/ui.git"),
+            DependencySource::Git(_)
+        ));
+        assert!(matches!(source("github:acme/ui"), DependencySource::Git(_)));
+        assert!(matches!(source("acme/ui#v2"), DependencySource::Git(_)));
+        assert!(matches!(source("file:../ui"), DependencySource::Local(_)));
+        assert!(matches!(source("workspace:*"), DependencySource::Local(_)));
+        assert!(matches!(
+            source("https://example.com/ui-1.0.0.tgz"),
+            DependencySource::Local(_)
+        ));
+    }
+
+    #[test]
+    fn test_cargo_dependency_sources() {
+        let source = |source| DependencySource::of(&dependency("1.0", source, Ecosystem::Rust));
+
+        assert_eq!(source(None), DependencySource::Registry);
+        assert_eq!(source(Some("crates-io")), DependencySource::Registry);
+        assert_eq!(
+            source(Some("git+https://github.com/tokio-rs/tokio")),
+            DependencySource::Git("https://github.com/tokio-rs/tokio".to_string())
+        );
+        assert_eq!(
+            source(Some("path+../local")),
+            DependencySource::Local("../local".to_string())
+        );
+        assert_eq!(
+            source(Some("acme")),
+            DependencySource::NamedRegistry("acme".to_string())
+        );
+        assert_eq!(
+            source(Some("sparse+https://index.acme.dev/")),
+            DependencySource::RegistryIndex("sparse+https://index.acme.dev/".to_string())
+        );
+    }
+
+    #[test]
+    fn test_npmrc_scopes_and_tokens() {
+        let config = NpmConfig::parse(
+            r#"
+# company registry
+@acme:registry=https://npm.acme.dev/
+//npm.acme.dev/:_authToken="acme-token"
+//registry.npmjs.org/:_authToken=public-token
+"#,
+        );
+
+        let acme = config.registry_for("@acme/ui").unwrap();
+        assert_eq!(acme.base_url(), "https://npm.acme.dev");
+        assert_eq!(acme.authorization(), Some("Bearer acme-token"));
+        assert_eq!(acme.requests_per_second(), None);
+
+        let public = config.registry_for("react").unwrap();
+        assert_eq!(public.base_url(), "https://registry.npmjs.org");
+        assert_eq!(public.authorization(), Some("Bearer public-token"));
+        assert_eq!(public.requests_per_second(), Some(1));
+
+        assert!(NpmConfig::default().registry_for("react").is_none());
+        assert!(NpmConfig::default().registry_for("@acme/ui").is_none());
+    }
+
+    #[test]
+    fn test_npmrc_default_registry_and_bare_token() {
+        let env = HashMap::from([("NPM_TOKEN".to_string(), "from-env".to_string())]);
+        let config = NpmConfig::parse_with_env(
+            "registry=https://mirror.example.com/npm/\n_authToken=${NPM_TOKEN}\n",
+            |name| env.get(name).cloned(),
+        );
+
+        let registry = config.registry_for("left-pad").unwrap();
+        assert_eq!(registry.base_url(), "https://mirror.example.com/npm");
+        assert_eq!(registry.authorization(), Some("Bearer from-env"));
+    }
+
+    #[tokio::test]
+    async fn test_nearest_npmrc_wins() {
+        let temp_dir = TempDir::new().unwrap();
+        let member = temp_dir.path().join("packages/app");
+        std::fs::create_dir_all(&member).unwrap();
+        std::fs::write(
+            temp_dir.path().join(".npmrc"),
+            "@acme:registry=https://npm.acme.dev/\n@tools:registry=https://tools.acme.dev/\n",
+        )
+        .unwrap();
+        std::fs::write(
+            member.join(".npmrc"),
+            "@acme:registry=https://staging.acme.dev/\n",
+        )
+        .unwrap();
+
+        let config = NpmConfig::load(&member).await;
+        let registry = |name| config.registry_for(name).unwrap().base_url().to_string();
+        assert_eq!(registry("@acme/ui"), "https://staging.acme.dev");
+        assert_eq!(registry("@tools/cli"), "https://tools.acme.dev");
+    }
+
+    #[tokio::test]
+    async fn test_cargo_registries() {
+        let temp_dir = TempDir::new().unwrap();
+        let project = temp_dir.path().join("app");
+        std::fs::create_dir_all(temp_dir.path().join(".cargo")).unwrap();
+        std::fs::create_dir_all(&project).unwrap();
+        std::fs::write(
+            temp_dir.path().join(".cargo/config.toml"),
+            r#"
+[registries.acme]
+index = "sparse+https://index.acme.dev/"
+token = "acme-token"
+
+[registries]
+legacy = { index = "https://git.acme.dev/index.git" }
+"#,
+        )
+        .unwrap();
+
+        let config = CargoConfig::load(&project).await;
+
+        let acme = config
+            .registry_for(&DependencySource::NamedRegistry("acme".to_string()))
+            .unwrap()
+            .unwrap();
+        assert_eq!(acme.base_url(), "https://index.acme.dev");
+        assert_eq!(acme.authorization(), Some("acme-token"));
+
+        let by_index = config
+            .registry_for(&DependencySource::RegistryIndex(
+                "sparse+https://index.acme.dev/".to_string(),
+            ))
+            .unwrap()
+            .unwrap();
+        assert_eq!(by_index, acme);
+
+        assert!(config
+            .registry_for(&DependencySource::Registry)
+            .unwrap()
+            .is_none());
+        assert!(matches!(
+            config.registry_for(&DependencySource::NamedRegistry("legacy".to_string())),
+            Err(Error::RegistryError(_))
+        ));
+        assert!(matches!(
+            config.registry_for(&DependencySource::NamedRegistry("missing".to_string())),
+            Err(Error::RegistryError(_))
+        ));
+    }
+
+    #[test]
+    fn test_cargo_default_registry() {
+        let config = CargoConfig::parse(
+            r#"
+[registry]
+default = "acme"
+
+[registries.acme]
+index = "sparse+https://index.acme.dev/"
+"#,
+        )
+        .unwrap();
+
+        let registry = config
+            .registry_for(&DependencySource::Registry)
+            .unwrap()
+            .unwrap();
+        assert_eq!(registry.base_url(), "https://index.acme.dev");
+        assert_eq!(registry.authorization(), None);
+
+        let crates_io = CargoConfig::parse(
+            "[registries.mirror]\nindex = \"sparse+https://index.crates.io/\"\n",
+        )
+        .unwrap();
+        assert!(crates_io
+            .registry_for(&DependencySource::NamedRegistry("mirror".to_string()))
+            .unwrap()
+            .is_none());
+    }
+}
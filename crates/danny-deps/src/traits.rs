@@ -1,5 +1,6 @@
 //! Core traits for dependency management
 
+use crate::graph::LockfileGraph;
 use crate::types::{DependencyFile, DependencyUpdate, UpdateResult};
 use crate::Result;
 use danny_fs::FileSystem;
@@ -70,6 +71,12 @@ pub trait LockfileParser: Send + Sync {
         path: &Path,
     ) -> Result<LockedDependencies>;
 
+    /// Parse a lockfile into a graph of every package@version and its dependencies
+    ///
+    /// # Errors
+    /// Returns an error if the lockfile cannot be read or parsed
+    async fn parse_graph<F: FileSystem>(&self, fs: &Arc<F>, path: &Path) -> Result<LockfileGraph>;
+
     /// Verify lockfile integrity (checksums)
     ///
     /// # Errors
@@ -78,6 +85,9 @@ pub trait LockfileParser: Send + Sync {
 }
 
 /// Represents the locked/installed versions from a lockfile
+///
+/// Holds one version per package name; use
+/// [`LockfileParser::parse_graph`] when a lockfile may contain several.
 #[derive(Debug, Clone)]
 pub struct LockedDependencies {
     /// Map of package name to installed version
@@ -52,7 +52,11 @@ pub struct Dependency {
     pub features: Vec<String>,
     /// Whether this is a workspace dependency
     pub workspace: bool,
-    /// Optional source (git, path, registry)
+    /// Optional source: `git+<url>`, `path+<path>`, `registry+<index>` or
+    /// `sparse+<index>`, or the name of a registry from `.cargo/config.toml`
+    ///
+    /// `None` means the ecosystem's default registry. See
+    /// [`DependencySource`](crate::registry::DependencySource).
     pub source: Option<String>,
 }
 
@@ -147,9 +147,15 @@ serde = "1.0"
 
     // Check for updates (this will make network requests)
     match manager.check_updates(&cargo_toml, Ecosystem::Rust).await {
-        Ok(updates) => {
+        Ok(report) => {
+            // A failed fetch is reported per package rather than failing the check
+            if let Some(error) = report.errors.iter().find(|e| e.package == "serde") {
+                eprintln!("Network error (acceptable in tests): {}", error.error);
+                return;
+            }
+
             // Should find serde
-            let serde_update = updates.iter().find(|u| u.package == "serde");
+            let serde_update = report.recommendations.iter().find(|u| u.package == "serde");
             assert!(serde_update.is_some(), "Should find serde update");
 
             if let Some(update) = serde_update {
@@ -0,0 +1,390 @@
+//! Configurable construction of [`InfoClient`]
+//!
+//! Every registry gets its own base URL, credentials and rate limit, so the
+//! client can be pointed at a private npm registry, a GitHub Enterprise
+//! instance or a local mock server.
+
+use crate::cache::ResponseCache;
+use crate::client::{
+    HttpClient, DEFAULT_MAX_RETRIES, DEFAULT_RETRY_DELAY, DEFAULT_TIMEOUT, DEFAULT_USER_AGENT,
+};
+use crate::crates_io::CRATES_IO_API_URL;
+use crate::error::Result;
+use crate::github::{GITHUB_API_URL, GITHUB_RAW_URL};
+use crate::jsr::JSR_API_URL;
+use crate::npm::NPM_REGISTRY_URL;
+use crate::InfoClient;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Connection settings for one registry
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RegistryConfig {
+    base_url: String,
+    authorization: Option<String>,
+    requests_per_second: Option<u32>,
+}
+
+impl RegistryConfig {
+    /// Create a config for the registry at `base_url`, without a token or
+    /// rate limit
+    pub fn new(base_url: impl Into<String>) -> Self {
+        let mut base_url = base_url.into();
+        while base_url.ends_with('/') {
+            base_url.pop();
+        }
+        Self {
+            base_url,
+            authorization: None,
+            requests_per_second: None,
+        }
+    }
+
+    /// The public npm registry, limited to 1 request/second
+    pub fn npm() -> Self {
+        Self::new(NPM_REGISTRY_URL).with_rate_limit(1)
+    }
+
+    /// The crates.io API, limited to 1 request/second as crates.io requires
+    pub fn crates_io() -> Self {
+        Self::new(CRATES_IO_API_URL).with_rate_limit(1)
+    }
+
+    /// The JSR registry, limited to 1 request/second
+    pub fn jsr() -> Self {
+        Self::new(JSR_API_URL).with_rate_limit(1)
+    }
+
+    /// The GitHub API, authenticated with `GITHUB_TOKEN` if it is set
+    ///
+    /// GitHub enforces its own rate limits (60 requests/hour unauthenticated,
+    /// 5000 requests/hour authenticated), so none is applied client-side.
+    pub fn github() -> Self {
+        let config = Self::new(GITHUB_API_URL);
+        match std::env::var("GITHUB_TOKEN") {
+            Ok(token) if !token.is_empty() => config.with_token(token),
+            _ => config,
+        }
+    }
+
+    /// Send `token` as a bearer token with every request
+    pub fn with_token(mut self, token: impl Into<String>) -> Self {
+        self.authorization = Some(format!("Bearer {}", token.into()));
+        self
+    }
+
+    /// Send `value` verbatim as the Authorization header with every request
+    ///
+    /// Cargo registries expect the bare token rather than a bearer token.
+    pub fn with_authorization(mut self, value: impl Into<String>) -> Self {
+        self.authorization = Some(value.into());
+        self
+    }
+
+    /// Send at most `requests_per_second` requests per second
+    pub fn with_rate_limit(mut self, requests_per_second: u32) -> Self {
+        self.requests_per_second = Some(requests_per_second);
+        self
+    }
+
+    /// Disable client-side rate limiting
+    pub fn without_rate_limit(mut self) -> Self {
+        self.requests_per_second = None;
+        self
+    }
+
+    /// Base URL requests are made against, without a trailing slash
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Authorization header sent with every request
+    pub fn authorization(&self) -> Option<&str> {
+        self.authorization.as_deref()
+    }
+
+    /// Client-side rate limit in requests per second
+    pub fn requests_per_second(&self) -> Option<u32> {
+        self.requests_per_second
+    }
+}
+
+/// Builder for [`InfoClient`]
+///
+/// Created with [`InfoClient::builder`]. Registries that aren't configured
+/// use their public defaults (see [`RegistryConfig::npm`] and friends).
+///
+/// # Example
+///
+/// ```no_run
+/// # use danny_info::{InfoClient, RegistryConfig};
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = InfoClient::builder()
+///     .npm(RegistryConfig::new("https://npm.example.com").with_token("secret"))
+///     .github(RegistryConfig::new("https://github.example.com/api/v3"))
+///     .github_raw("https://github.example.com/raw")
+///     .user_agent("my-tool/1.0")
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct InfoClientBuilder {
+    npm: RegistryConfig,
+    crates_io: RegistryConfig,
+    jsr: RegistryConfig,
+    github: RegistryConfig,
+    github_raw_url: String,
+    user_agent: String,
+    timeout: Duration,
+    max_retries: u32,
+    cache: Option<ResponseCache>,
+}
+
+impl InfoClientBuilder {
+    pub(crate) fn new() -> Self {
+        Self {
+            npm: RegistryConfig::npm(),
+            crates_io: RegistryConfig::crates_io(),
+            jsr: RegistryConfig::jsr(),
+            github: RegistryConfig::github(),
+            github_raw_url: GITHUB_RAW_URL.to_string(),
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            timeout: DEFAULT_TIMEOUT,
+            max_retries: DEFAULT_MAX_RETRIES,
+            cache: None,
+        }
+    }
+
+    /// Configure the npm registry
+    pub fn npm(mut self, config: RegistryConfig) -> Self {
+        self.npm = config;
+        self
+    }
+
+    /// Configure the crates.io API
+    pub fn crates_io(mut self, config: RegistryConfig) -> Self {
+        self.crates_io = config;
+        self
+    }
+
+    /// Configure the JSR registry
+    pub fn jsr(mut self, config: RegistryConfig) -> Self {
+        self.jsr = config;
+        self
+    }
+
+    /// Configure the GitHub API
+    ///
+    /// The token is only sent to the API, never to the raw file host.
+    pub fn github(mut self, config: RegistryConfig) -> Self {
+        self.github = config;
+        self
+    }
+
+    /// Set the host serving raw repository files (changelogs)
+    ///
+    /// Defaults to `https://raw.githubusercontent.com`.
+    pub fn github_raw(mut self, base_url: impl Into<String>) -> Self {
+        self.github_raw_url = RegistryConfig::new(base_url).base_url;
+        self
+    }
+
+    /// Set the User-Agent header sent to every registry
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    /// Set the timeout for each request (30 seconds by default)
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Set how often a request is retried after a transient failure
+    ///
+    /// Connection errors, timeouts, `429`s and `5xx`s are retried with
+    /// exponential backoff, honoring `Retry-After` and GitHub's
+    /// `x-ratelimit-reset`. Defaults to 2; `0` disables retries.
+    pub fn retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Disable client-side rate limiting for every registry
+    ///
+    /// Note: crates.io requires 1 req/sec maximum.
+    pub fn without_rate_limiting(mut self) -> Self {
+        for config in [
+            &mut self.npm,
+            &mut self.crates_io,
+            &mut self.jsr,
+            &mut self.github,
+        ] {
+            config.requests_per_second = None;
+        }
+        self
+    }
+
+    /// Serve and store responses through an on-disk cache
+    ///
+    /// See [`InfoClient::with_cache`].
+    pub fn cache(mut self, cache: ResponseCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Build the client
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP clients cannot be initialized, a token
+    /// isn't a valid header value or a rate limit is zero.
+    pub fn build(self) -> Result<InfoClient> {
+        let cache = self.cache.map(Arc::new);
+        let client = |config: &RegistryConfig| -> Result<HttpClient> {
+            let client = HttpClient::with_config(
+                &self.user_agent,
+                self.timeout,
+                config.authorization(),
+                config.requests_per_second(),
+            )?
+            .with_retries(self.max_retries, DEFAULT_RETRY_DELAY);
+            Ok(match &cache {
+                Some(cache) => client.with_cache(cache.clone()),
+                None => client,
+            })
+        };
+
+        Ok(InfoClient {
+            npm_client: client(&self.npm)?,
+            crates_io_client: client(&self.crates_io)?,
+            jsr_client: client(&self.jsr)?,
+            github_client: client(&self.github)?,
+            github_raw_client: client(&RegistryConfig::new(self.github_raw_url.as_str()))?,
+            npm_url: self.npm.base_url,
+            crates_io_url: self.crates_io.base_url,
+            jsr_url: self.jsr.base_url,
+            github_url: self.github.base_url,
+            github_raw_url: self.github_raw_url,
+            user_agent: self.user_agent,
+            timeout: self.timeout,
+            max_retries: self.max_retries,
+            cache,
+            registries: Mutex::default(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Error, RepositoryUrl};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    #[test]
+    fn test_registry_config() {
+        let config = RegistryConfig::new("https://npm.example.com/")
+            .with_token("secret")
+            .with_rate_limit(5);
+        assert_eq!(config.base_url(), "https://npm.example.com");
+        assert_eq!(config.authorization(), Some("Bearer secret"));
+        assert_eq!(config.requests_per_second(), Some(5));
+        assert_eq!(config.without_rate_limit().requests_per_second(), None);
+
+        assert_eq!(RegistryConfig::crates_io().requests_per_second(), Some(1));
+    }
+
+    #[test]
+    fn test_zero_rate_limit_is_an_error() {
+        let result = InfoClient::builder()
+            .jsr(RegistryConfig::jsr().with_rate_limit(0))
+            .build();
+        assert!(matches!(result, Err(Error::Other(_))));
+    }
+
+    #[tokio::test]
+    async fn test_custom_registry_url_token_and_user_agent() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}/npm/", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = vec![0; 4096];
+            let read = socket.read(&mut request).await.unwrap();
+            let body =
+                r#"{"name":"@acme/ui","dist-tags":{"latest":"2.0.0"},"versions":{"2.0.0":{}}}"#;
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            socket.write_all(response.as_bytes()).await.unwrap();
+            String::from_utf8_lossy(&request[..read]).to_lowercase()
+        });
+
+        let client = InfoClient::builder()
+            .npm(RegistryConfig::new(base_url).with_token("s3cret"))
+            .user_agent("acme-bot/1.0")
+            .build()
+            .unwrap();
+        let info = client.fetch_npm("@acme/ui").await.unwrap();
+        assert_eq!(info.version, "2.0.0");
+
+        let request = server.await.unwrap();
+        assert!(request.starts_with("get /npm/@acme%2fui "));
+        assert!(request.contains("authorization: bearer s3cret"));
+        assert!(request.contains("user-agent: acme-bot/1.0"));
+    }
+
+    #[test]
+    fn test_registries_on_one_host_share_a_rate_limiter() {
+        let client = InfoClient::new().unwrap();
+        let npm_limiter = client.npm_client.rate_limiter().unwrap();
+
+        let authenticated = client
+            .registry_client(&RegistryConfig::npm().with_token("s3cret"))
+            .unwrap();
+        assert!(Arc::ptr_eq(
+            authenticated.rate_limiter().unwrap(),
+            npm_limiter
+        ));
+
+        let other = client
+            .registry_client(&RegistryConfig::new("https://npm.acme.dev").with_rate_limit(1))
+            .unwrap();
+        assert!(!Arc::ptr_eq(other.rate_limiter().unwrap(), npm_limiter));
+    }
+
+    #[tokio::test]
+    async fn test_github_token_is_not_sent_to_raw_host() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let raw_url = format!("http://{}", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = vec![0; 4096];
+            let read = socket.read(&mut request).await.unwrap();
+            let body = "# Changelog";
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-length: {}\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            socket.write_all(response.as_bytes()).await.unwrap();
+            String::from_utf8_lossy(&request[..read]).to_lowercase()
+        });
+
+        let client = InfoClient::builder()
+            .github(RegistryConfig::github().with_token("s3cret"))
+            .github_raw(raw_url)
+            .build()
+            .unwrap();
+        let repo = RepositoryUrl::new("acme", "ui", "https://github.com/acme/ui");
+        assert_eq!(client.fetch_changelog(&repo).await.unwrap(), "# Changelog");
+
+        let request = server.await.unwrap();
+        assert!(request.starts_with("get /acme/ui/head/"));
+        assert!(!request.contains("authorization"));
+    }
+}
@@ -0,0 +1,303 @@
+//! On-disk HTTP response cache
+//!
+//! Responses are stored as one JSON file per request under the cache
+//! directory. Fresh entries are served without touching the network (or the
+//! rate limiter); stale entries are revalidated with `If-None-Match` /
+//! `If-Modified-Since`, so an unchanged package costs a `304` instead of a
+//! full download.
+//!
+//! Responses fetched with credentials are keyed by a hash of the
+//! `Authorization` header, so they are never served to a client using other
+//! credentials or none.
+//!
+//! Caching is opt-in via [`InfoClient::with_cache`](crate::InfoClient::with_cache).
+//! `danny updates` always uses it and serves only cached responses with
+//! `--offline`.
+
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Default time a cached response is served without revalidation
+pub const DEFAULT_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// How a [`ResponseCache`] is consulted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CacheMode {
+    /// Serve fresh entries, revalidate stale ones
+    #[default]
+    Normal,
+    /// Serve only from the cache, whatever the age of the entry, and never
+    /// touch the network
+    Offline,
+}
+
+/// Persistent cache for registry and GitHub responses
+#[derive(Debug, Clone)]
+pub struct ResponseCache {
+    dir: PathBuf,
+    ttl: Duration,
+    mode: CacheMode,
+}
+
+/// A cached response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CachedResponse {
+    /// Requested URL, checked on lookup to guard against key collisions
+    pub url: String,
+    /// HTTP status; `404`s are cached so missing files aren't probed again
+    pub status: u16,
+    /// Unix time the response was stored or last revalidated
+    pub stored_at: u64,
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+    pub body: String,
+}
+
+impl CachedResponse {
+    pub(crate) fn new(
+        url: &str,
+        status: u16,
+        etag: Option<String>,
+        last_modified: Option<String>,
+        body: String,
+    ) -> Self {
+        Self {
+            url: url.to_string(),
+            status,
+            stored_at: now(),
+            etag,
+            last_modified,
+            body,
+        }
+    }
+
+    /// Mark the entry as just revalidated
+    pub(crate) fn touch(&mut self) {
+        self.stored_at = now();
+    }
+}
+
+impl ResponseCache {
+    /// Create a cache stored in `dir`
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            ttl: DEFAULT_TTL,
+            mode: CacheMode::Normal,
+        }
+    }
+
+    /// Create a cache in the user cache directory (e.g. `~/.cache/danny/http`)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the platform has no user cache directory.
+    pub fn in_user_cache_dir() -> Result<Self> {
+        let dir = dirs::cache_dir()
+            .ok_or_else(|| Error::other("Could not determine the user cache directory"))?;
+        Ok(Self::new(dir.join("danny").join("http")))
+    }
+
+    /// Set how long responses are served without revalidation
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Set how the cache is consulted
+    pub fn with_mode(mut self, mode: CacheMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Directory holding cached responses
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Time responses are served without revalidation
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// How the cache is consulted
+    pub fn mode(&self) -> CacheMode {
+        self.mode
+    }
+
+    /// Delete every cached response
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache directory exists but can't be removed.
+    pub async fn clear(&self) -> Result<()> {
+        match tokio::fs::remove_dir_all(&self.dir).await {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+
+    /// Whether `entry` can be served without revalidation
+    pub(crate) fn is_fresh(&self, entry: &CachedResponse) -> bool {
+        now().saturating_sub(entry.stored_at) < self.ttl.as_secs()
+    }
+
+    /// Look up the response stored under `key`
+    ///
+    /// Unreadable or corrupt entries are treated as missing.
+    pub(crate) async fn get(&self, key: &str) -> Option<CachedResponse> {
+        let content = tokio::fs::read(self.path(key)).await.ok()?;
+        let entry: CachedResponse = serde_json::from_slice(&content).ok()?;
+        (entry.url == key_url(key)).then_some(entry)
+    }
+
+    /// Store a response under `key`
+    ///
+    /// Failing to write the cache never fails the request, so errors are
+    /// only logged.
+    pub(crate) async fn put(&self, key: &str, entry: &CachedResponse) {
+        if let Err(e) = self.write(key, entry).await {
+            tracing::debug!("Failed to cache response for {}: {}", entry.url, e);
+        }
+    }
+
+    async fn write(&self, key: &str, entry: &CachedResponse) -> Result<()> {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+        tokio::fs::create_dir_all(&self.dir).await?;
+        let path = self.path(key);
+        // Write then rename so concurrent readers never see partial entries
+        let tmp = path.with_extension(format!(
+            "{}-{}.tmp",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        tokio::fs::write(&tmp, serde_json::to_vec(entry)?).await?;
+        if let Err(e) = tokio::fs::rename(&tmp, &path).await {
+            let _ = tokio::fs::remove_file(&tmp).await;
+            return Err(e.into());
+        }
+        Ok(())
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir
+            .join(format!("{:016x}.json", fnv1a(key.as_bytes())))
+    }
+}
+
+/// Cache key for a request: the URL plus the headers that change the body
+///
+/// `credentials` is the [`credentials_id`] of the request's `Authorization`
+/// header, if any.
+pub(crate) fn cache_key(url: &str, accept: Option<&str>, credentials: Option<u64>) -> String {
+    let mut key = url.to_string();
+    if let Some(accept) = accept {
+        key.push('\n');
+        key.push_str(accept);
+    }
+    if let Some(credentials) = credentials {
+        key.push_str(&format!("\nauth:{:016x}", credentials));
+    }
+    key
+}
+
+/// Identifies an `Authorization` header value without keeping the secret
+pub(crate) fn credentials_id(authorization: &str) -> u64 {
+    fnv1a(authorization.as_bytes())
+}
+
+fn key_url(key: &str) -> &str {
+    key.split('\n').next().unwrap_or(key)
+}
+
+/// FNV-1a, stable across Rust versions unlike `DefaultHasher`
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_round_trip_and_freshness() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let cache = ResponseCache::new(temp_dir.path());
+        let key = cache_key("https://registry.npmjs.org/react", None, None);
+
+        assert!(cache.get(&key).await.is_none());
+
+        let entry = CachedResponse::new(
+            "https://registry.npmjs.org/react",
+            200,
+            Some("\"abc\"".to_string()),
+            None,
+            "{}".to_string(),
+        );
+        cache.put(&key, &entry).await;
+
+        let cached = cache.get(&key).await.unwrap();
+        assert_eq!(cached.body, "{}");
+        assert_eq!(cached.etag.as_deref(), Some("\"abc\""));
+        assert!(cache.is_fresh(&cached));
+
+        let stale = ResponseCache::new(temp_dir.path()).with_ttl(Duration::ZERO);
+        assert!(!stale.is_fresh(&cached));
+
+        cache.clear().await.unwrap();
+        assert!(cache.get(&key).await.is_none());
+        cache.clear().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_accept_header_is_part_of_the_key() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let cache = ResponseCache::new(temp_dir.path());
+        let url = "https://api.github.com/repos/facebook/react/releases";
+        let json = cache_key(url, Some("application/vnd.github+json"), None);
+
+        cache
+            .put(
+                &json,
+                &CachedResponse::new(url, 200, None, None, "[]".to_string()),
+            )
+            .await;
+
+        assert!(cache.get(&json).await.is_some());
+        assert!(cache.get(&cache_key(url, None, None)).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_credentials_are_part_of_the_key() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let cache = ResponseCache::new(temp_dir.path());
+        let url = "https://npm.example.com/@acme/private";
+        let token = cache_key(url, None, Some(credentials_id("Bearer secret")));
+
+        cache
+            .put(
+                &token,
+                &CachedResponse::new(url, 200, None, None, "{}".to_string()),
+            )
+            .await;
+
+        assert!(cache.get(&token).await.is_some());
+        assert!(cache.get(&cache_key(url, None, None)).await.is_none());
+        let other = cache_key(url, None, Some(credentials_id("Bearer other")));
+        assert!(cache.get(&other).await.is_none());
+        assert!(!token.contains("secret"));
+    }
+}
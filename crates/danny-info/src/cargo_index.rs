@@ -0,0 +1,121 @@
+//! Sparse index client for alternative Cargo registries
+//!
+//! Private registries don't necessarily serve the crates.io web API, but
+//! every registry Cargo can use over HTTP serves a sparse index: one file per
+//! crate with a JSON object per published version.
+
+use crate::client::HttpClient;
+use crate::error::{Error, Result};
+use crate::types::{PackageInfo, Registry};
+use serde::Deserialize;
+
+/// One line of an index file
+#[derive(Debug, Deserialize)]
+struct IndexEntry {
+    name: String,
+    vers: String,
+    #[serde(default)]
+    yanked: bool,
+}
+
+/// Fetch package information from a sparse registry index
+///
+/// `index_url` is the index URL without the `sparse+` prefix.
+pub async fn fetch_index_package(
+    client: &HttpClient,
+    index_url: &str,
+    crate_name: &str,
+) -> Result<PackageInfo> {
+    if crate_name.is_empty() {
+        return Err(Error::InvalidPackageName(
+            "Crate name cannot be empty".to_string(),
+        ));
+    }
+
+    let url = format!("{}/{}", index_url, index_path(crate_name));
+    let content = client.get_text(&url).await.map_err(|e| {
+        if e.to_string().contains("404") {
+            Error::PackageNotFound(crate_name.to_string(), index_url.to_string())
+        } else {
+            e
+        }
+    })?;
+
+    parse_index(crate_name, index_url, &content)
+}
+
+/// Path of a crate's file within the index
+fn index_path(crate_name: &str) -> String {
+    let name = crate_name.to_lowercase();
+    match name.len() {
+        1 => format!("1/{}", name),
+        2 => format!("2/{}", name),
+        3 => format!("3/{}/{}", &name[..1], name),
+        _ => format!("{}/{}/{}", &name[..2], &name[2..4], name),
+    }
+}
+
+fn parse_index(crate_name: &str, index_url: &str, content: &str) -> Result<PackageInfo> {
+    let mut name = crate_name.to_string();
+    let mut versions = Vec::new();
+    for line in content.lines().filter(|line| !line.trim().is_empty()) {
+        let entry: IndexEntry = serde_json::from_str(line)?;
+        name = entry.name;
+        if !entry.yanked {
+            versions.push(entry.vers);
+        }
+    }
+
+    // Like crates.io's max_version: the highest stable release, falling
+    // back to prereleases only if there is nothing else
+    let parsed: Vec<semver::Version> = versions
+        .iter()
+        .filter_map(|v| semver::Version::parse(v).ok())
+        .collect();
+    let latest = parsed
+        .iter()
+        .filter(|v| v.pre.is_empty())
+        .max()
+        .or_else(|| parsed.iter().max())
+        .ok_or_else(|| Error::PackageNotFound(crate_name.to_string(), index_url.to_string()))?;
+
+    Ok(PackageInfo {
+        registry: Registry::Cargo,
+        name,
+        version: latest.to_string(),
+        versions,
+        description: None,
+        repository: None,
+        homepage: None,
+        license: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_index_path() {
+        assert_eq!(index_path("a"), "1/a");
+        assert_eq!(index_path("ab"), "2/ab");
+        assert_eq!(index_path("abc"), "3/a/abc");
+        assert_eq!(index_path("Serde_JSON"), "se/rd/serde_json");
+    }
+
+    #[test]
+    fn test_parse_index() {
+        let content = r#"{"name":"acme-core","vers":"1.0.0","deps":[],"cksum":"00","features":{},"yanked":false}
+{"name":"acme-core","vers":"1.2.0","deps":[],"cksum":"00","features":{},"yanked":false}
+{"name":"acme-core","vers":"1.3.0","deps":[],"cksum":"00","features":{},"yanked":true}
+{"name":"acme-core","vers":"2.0.0-beta.1","deps":[],"cksum":"00","features":{},"yanked":false}
+"#;
+        let info = parse_index("acme-core", "https://index.example.com", content).unwrap();
+        assert_eq!(info.registry, Registry::Cargo);
+        assert_eq!(info.version, "1.2.0");
+        assert_eq!(info.versions, ["1.0.0", "1.2.0", "2.0.0-beta.1"]);
+
+        let result = parse_index("acme-core", "https://index.example.com", "");
+        assert!(matches!(result, Err(Error::PackageNotFound(_, _))));
+    }
+}
@@ -1,10 +1,32 @@
-//! HTTP client wrapper with rate limiting
+//! HTTP client wrapper with rate limiting, retries and response caching
 
-use crate::error::Result;
+use crate::cache::{cache_key, credentials_id, CacheMode, CachedResponse, ResponseCache};
+use crate::error::{Error, Result};
 use governor::{Quota, RateLimiter};
+use reqwest::header::{
+    HeaderMap, HeaderValue, ACCEPT, AUTHORIZATION, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH,
+    LAST_MODIFIED, RETRY_AFTER,
+};
+use reqwest::StatusCode;
+use std::collections::HashMap;
 use std::num::NonZeroU32;
-use std::sync::Arc;
-use std::time::Duration;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// User agent sent unless configured otherwise
+pub const DEFAULT_USER_AGENT: &str = concat!("fob-info/", env!("CARGO_PKG_VERSION"));
+
+/// Request timeout used unless configured otherwise
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Retries after a transient failure unless configured otherwise
+pub const DEFAULT_MAX_RETRIES: u32 = 2;
+
+/// Delay before the first retry; doubled for each further retry
+pub const DEFAULT_RETRY_DELAY: Duration = Duration::from_millis(500);
+
+/// Longest a request waits for a rate limit to reset before giving up
+const MAX_RETRY_WAIT: Duration = Duration::from_secs(60);
 
 /// Rate limiter for a specific registry
 pub type RegistryRateLimiter = Arc<
@@ -20,42 +42,104 @@ pub type RegistryRateLimiter = Arc<
 pub struct HttpClient {
     client: reqwest::Client,
     rate_limiter: Option<RegistryRateLimiter>,
+    cache: Option<Arc<ResponseCache>>,
+    /// [`credentials_id`] of the default `Authorization` header, keeping
+    /// authenticated responses apart in the cache
+    credentials: Option<u64>,
+    max_retries: u32,
+    retry_delay: Duration,
+    /// When each host's server-side rate limit resets, from the
+    /// `x-ratelimit-*` headers GitHub sends
+    rate_limit_resets: Arc<Mutex<HashMap<String, SystemTime>>>,
 }
 
 impl HttpClient {
     /// Create a new HTTP client with default configuration (no rate limiting)
     pub fn new() -> Result<Self> {
-        let client = reqwest::Client::builder()
-            .user_agent(format!("fob-info/{}", env!("CARGO_PKG_VERSION")))
-            .timeout(Duration::from_secs(30))
-            .build()?;
-
-        Ok(Self {
-            client,
-            rate_limiter: None,
-        })
+        Self::with_config(DEFAULT_USER_AGENT, DEFAULT_TIMEOUT, None, None)
     }
 
-    /// Create a new HTTP client with rate limiting
+    /// Create a new HTTP client
     ///
     /// # Arguments
     ///
-    /// * `requests_per_second` - Maximum requests per second (e.g., 1 for crates.io)
-    pub fn with_rate_limit(requests_per_second: u32) -> Result<Self> {
+    /// * `user_agent` - User-Agent header sent with every request
+    /// * `timeout` - Timeout for each request
+    /// * `authorization` - Authorization header sent with every request
+    /// * `requests_per_second` - Client-side rate limit, if any
+    pub fn with_config(
+        user_agent: &str,
+        timeout: Duration,
+        authorization: Option<&str>,
+        requests_per_second: Option<u32>,
+    ) -> Result<Self> {
+        let mut headers = HeaderMap::new();
+        if let Some(authorization) = authorization {
+            let mut value = HeaderValue::from_str(authorization)
+                .map_err(|_| Error::other("Invalid token format"))?;
+            value.set_sensitive(true);
+            headers.insert(AUTHORIZATION, value);
+        }
+
         let client = reqwest::Client::builder()
-            .user_agent(format!("fob-info/{}", env!("CARGO_PKG_VERSION")))
-            .timeout(Duration::from_secs(30))
+            .user_agent(user_agent)
+            .timeout(timeout)
+            .default_headers(headers)
             .build()?;
 
-        let quota = Quota::per_second(NonZeroU32::new(requests_per_second).unwrap());
-        let rate_limiter = Arc::new(RateLimiter::direct(quota));
+        let rate_limiter = match requests_per_second {
+            Some(requests_per_second) => {
+                let requests_per_second = NonZeroU32::new(requests_per_second)
+                    .ok_or_else(|| Error::other("Rate limit must be at least 1 request/second"))?;
+                Some(Arc::new(RateLimiter::direct(Quota::per_second(
+                    requests_per_second,
+                ))))
+            }
+            None => None,
+        };
 
         Ok(Self {
             client,
-            rate_limiter: Some(rate_limiter),
+            rate_limiter,
+            cache: None,
+            credentials: authorization.map(credentials_id),
+            max_retries: DEFAULT_MAX_RETRIES,
+            retry_delay: DEFAULT_RETRY_DELAY,
+            rate_limit_resets: Arc::default(),
         })
     }
 
+    /// Retry transient failures (connection errors, timeouts, 429 and 5xx
+    /// responses) up to `max_retries` times
+    ///
+    /// Retries wait for `Retry-After` or the rate limit reset when the
+    /// server sends one, and back off exponentially from `delay` otherwise.
+    pub fn with_retries(mut self, max_retries: u32, delay: Duration) -> Self {
+        self.max_retries = max_retries;
+        self.retry_delay = delay;
+        self
+    }
+
+    /// Share `rate_limiter` instead of this client's own limit
+    ///
+    /// Clients for the same host should share one limiter so that, e.g.,
+    /// authenticated and anonymous lookups draw from the same budget.
+    pub fn with_rate_limiter(mut self, rate_limiter: RegistryRateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// The client-side rate limiter, if any
+    pub fn rate_limiter(&self) -> Option<&RegistryRateLimiter> {
+        self.rate_limiter.as_ref()
+    }
+
+    /// Serve and store responses through `cache`
+    pub fn with_cache(mut self, cache: Arc<ResponseCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
     /// Wait for rate limiter if enabled
     async fn wait_for_rate_limit(&self) {
         if let Some(limiter) = &self.rate_limiter {
@@ -65,77 +149,263 @@ impl HttpClient {
 
     /// Make a GET request and deserialize JSON response
     pub async fn get_json<T: serde::de::DeserializeOwned>(&self, url: &str) -> Result<T> {
-        self.wait_for_rate_limit().await;
+        let body = self.get(url, HeaderMap::new()).await?;
+        Ok(serde_json::from_str(&body)?)
+    }
+
+    /// Make a GET request with custom headers and deserialize JSON response
+    pub async fn get_json_with_headers<T: serde::de::DeserializeOwned>(
+        &self,
+        url: &str,
+        headers: HeaderMap,
+    ) -> Result<T> {
+        let body = self.get(url, headers).await?;
+        Ok(serde_json::from_str(&body)?)
+    }
 
-        let response = self.client.get(url).send().await?;
+    /// Make a GET request and return the response text
+    pub async fn get_text(&self, url: &str) -> Result<String> {
+        self.get(url, HeaderMap::new()).await
+    }
+
+    /// Make a GET request through the cache, if any
+    ///
+    /// Fresh cache entries are returned without a request. Stale entries are
+    /// revalidated, and served as-is when the registry can't be reached.
+    async fn get(&self, url: &str, headers: HeaderMap) -> Result<String> {
+        let Some(cache) = &self.cache else {
+            let (status, response) = self.send(url, headers, None).await?;
+            check_status(status, url)?;
+            return Ok(response.text().await?);
+        };
+
+        let header = |name| {
+            headers
+                .get(name)
+                .and_then(|v: &HeaderValue| v.to_str().ok())
+        };
+        let credentials = header(AUTHORIZATION)
+            .map(credentials_id)
+            .or(self.credentials);
+        let key = cache_key(url, header(ACCEPT), credentials);
+        let cached = cache.get(&key).await;
 
-        // Handle rate limiting (HTTP 429)
-        if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
-            return Err(crate::error::Error::RateLimitExceeded(url.to_string()));
+        match (&cached, cache.mode()) {
+            (Some(entry), CacheMode::Offline) => return cached_body(entry, url),
+            (None, CacheMode::Offline) => return Err(Error::Offline(url.to_string())),
+            (Some(entry), CacheMode::Normal) if cache.is_fresh(entry) => {
+                return cached_body(entry, url);
+            }
+            _ => {}
         }
 
-        // Check if the request was successful
-        if !response.status().is_success() {
-            return Err(crate::error::Error::other(format!(
-                "HTTP request failed with status {}: {}",
-                response.status(),
-                url
-            )));
+        let (status, response) = match self.send(url, headers, cached.as_ref()).await {
+            Ok(sent) => sent,
+            Err(Error::Http(e)) if e.is_connect() || e.is_timeout() => {
+                return match &cached {
+                    Some(entry) => cached_body(entry, url),
+                    None => Err(Error::Http(e)),
+                };
+            }
+            Err(e) => return Err(e),
+        };
+
+        if status == StatusCode::NOT_MODIFIED {
+            if let Some(mut entry) = cached {
+                entry.touch();
+                cache.put(&key, &entry).await;
+                return cached_body(&entry, url);
+            }
         }
 
-        let json = response.json().await?;
-        Ok(json)
+        // Cache 404s too: changelog lookups probe many missing files
+        if status.is_success() || status == StatusCode::NOT_FOUND {
+            let header = |name| {
+                response
+                    .headers()
+                    .get(name)
+                    .and_then(|v: &HeaderValue| v.to_str().ok())
+                    .map(String::from)
+            };
+            let etag = header(ETAG);
+            let last_modified = header(LAST_MODIFIED);
+            let body = response.text().await?;
+            let entry = CachedResponse::new(url, status.as_u16(), etag, last_modified, body);
+            cache.put(&key, &entry).await;
+            return cached_body(&entry, url);
+        }
+
+        check_status(status, url)?;
+        Ok(response.text().await?)
     }
 
-    /// Make a GET request with custom headers and deserialize JSON response
-    pub async fn get_json_with_headers<T: serde::de::DeserializeOwned>(
+    /// Send a GET request, conditional on `cached` when revalidating
+    async fn send(
         &self,
         url: &str,
-        headers: reqwest::header::HeaderMap,
-    ) -> Result<T> {
-        self.wait_for_rate_limit().await;
+        headers: HeaderMap,
+        cached: Option<&CachedResponse>,
+    ) -> Result<(StatusCode, reqwest::Response)> {
+        let host = reqwest::Url::parse(url)
+            .ok()
+            .and_then(|url| url.host_str().map(String::from));
+
+        let mut attempt = 0;
+        loop {
+            if let Some(host) = &host {
+                self.wait_for_reset(host, url).await?;
+            }
+            self.wait_for_rate_limit().await;
 
-        let response = self.client.get(url).headers(headers).send().await?;
+            let mut request = self.client.get(url).headers(headers.clone());
+            if let Some(entry) = cached {
+                if let Some(etag) = &entry.etag {
+                    request = request.header(IF_NONE_MATCH, etag);
+                }
+                if let Some(last_modified) = &entry.last_modified {
+                    request = request.header(IF_MODIFIED_SINCE, last_modified);
+                }
+            }
 
-        // Handle rate limiting (HTTP 429)
-        if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
-            return Err(crate::error::Error::RateLimitExceeded(url.to_string()));
+            let result = request.send().await;
+            if let (Ok(response), Some(host)) = (&result, &host) {
+                self.record_rate_limit(host, response.headers());
+            }
+
+            let transient = match &result {
+                Ok(response) => is_transient(response.status(), response.headers()),
+                Err(e) => e.is_connect() || e.is_timeout(),
+            };
+            if transient && attempt < self.max_retries {
+                let delay = result
+                    .as_ref()
+                    .ok()
+                    .and_then(|response| requested_delay(response.headers()))
+                    .unwrap_or(self.retry_delay * 2u32.pow(attempt));
+                if delay <= MAX_RETRY_WAIT {
+                    tracing::debug!("Retrying {} in {:?}", url, delay);
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                    continue;
+                }
+            }
+
+            let response = result?;
+            let status = response.status();
+            if is_rate_limited(status, response.headers()) {
+                return Err(Error::RateLimitExceeded(url.to_string()));
+            }
+            return Ok((status, response));
         }
+    }
 
-        if !response.status().is_success() {
-            return Err(crate::error::Error::other(format!(
-                "HTTP request failed with status {}: {}",
-                response.status(),
-                url
-            )));
+    /// Wait until `host`'s rate limit resets, or fail right away if that is
+    /// too far off
+    async fn wait_for_reset(&self, host: &str, url: &str) -> Result<()> {
+        let wait = {
+            let mut resets = self
+                .rate_limit_resets
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            let Some(reset) = resets.get(host) else {
+                return Ok(());
+            };
+            match reset.duration_since(SystemTime::now()) {
+                Ok(wait) => wait,
+                Err(_) => {
+                    resets.remove(host);
+                    return Ok(());
+                }
+            }
+        };
+
+        if wait > MAX_RETRY_WAIT {
+            return Err(Error::RateLimitExceeded(url.to_string()));
         }
+        tokio::time::sleep(wait).await;
+        Ok(())
+    }
 
-        let json = response.json().await?;
-        Ok(json)
+    /// Remember when `host`'s rate limit resets once it is used up
+    fn record_rate_limit(&self, host: &str, headers: &HeaderMap) {
+        let Some(remaining) = header_u64(headers, "x-ratelimit-remaining") else {
+            return;
+        };
+        let mut resets = self
+            .rate_limit_resets
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        match header_u64(headers, "x-ratelimit-reset") {
+            Some(reset) if remaining == 0 => {
+                resets.insert(host.to_string(), UNIX_EPOCH + Duration::from_secs(reset));
+            }
+            _ => {
+                resets.remove(host);
+            }
+        }
     }
+}
 
-    /// Make a GET request and return the response text
-    pub async fn get_text(&self, url: &str) -> Result<String> {
-        self.wait_for_rate_limit().await;
+/// Whether a response is worth retrying
+fn is_transient(status: StatusCode, headers: &HeaderMap) -> bool {
+    (status.is_server_error() && status != StatusCode::NOT_IMPLEMENTED)
+        || is_rate_limited(status, headers)
+}
 
-        let response = self.client.get(url).send().await?;
+/// Whether a response rejects the request for exceeding a rate limit
+///
+/// GitHub answers `403` rather than `429` when the limit is used up.
+fn is_rate_limited(status: StatusCode, headers: &HeaderMap) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS
+        || (status == StatusCode::FORBIDDEN
+            && (headers.contains_key(RETRY_AFTER)
+                || header_u64(headers, "x-ratelimit-remaining") == Some(0)))
+}
 
-        // Handle rate limiting (HTTP 429)
-        if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
-            return Err(crate::error::Error::RateLimitExceeded(url.to_string()));
-        }
+/// Delay the server asked for, via `Retry-After` (in seconds) or the time
+/// until an exhausted rate limit resets
+fn requested_delay(headers: &HeaderMap) -> Option<Duration> {
+    if let Some(seconds) = header_u64(headers, RETRY_AFTER.as_str()) {
+        return Some(Duration::from_secs(seconds));
+    }
+    if header_u64(headers, "x-ratelimit-remaining") == Some(0) {
+        let reset = UNIX_EPOCH + Duration::from_secs(header_u64(headers, "x-ratelimit-reset")?);
+        return Some(
+            reset
+                .duration_since(SystemTime::now())
+                .unwrap_or(Duration::ZERO),
+        );
+    }
+    None
+}
 
-        if !response.status().is_success() {
-            return Err(crate::error::Error::other(format!(
-                "HTTP request failed with status {}: {}",
-                response.status(),
-                url
-            )));
-        }
+fn header_u64(headers: &HeaderMap, name: &str) -> Option<u64> {
+    headers.get(name)?.to_str().ok()?.trim().parse().ok()
+}
+
+/// Map error statuses to errors
+fn check_status(status: StatusCode, url: &str) -> Result<()> {
+    // Handle rate limiting (HTTP 429)
+    if status == StatusCode::TOO_MANY_REQUESTS {
+        return Err(Error::RateLimitExceeded(url.to_string()));
+    }
 
-        let text = response.text().await?;
-        Ok(text)
+    // Check if the request was successful
+    if !status.is_success() {
+        return Err(Error::other(format!(
+            "HTTP request failed with status {}: {}",
+            status, url
+        )));
     }
+
+    Ok(())
+}
+
+/// Body of a cached response, or the error its status maps to
+fn cached_body(entry: &CachedResponse, url: &str) -> Result<String> {
+    let status = StatusCode::from_u16(entry.status).unwrap_or(StatusCode::OK);
+    check_status(status, url)?;
+    Ok(entry.body.clone())
 }
 
 impl Default for HttpClient {
@@ -143,3 +413,196 @@ impl Default for HttpClient {
         Self::new().expect("Failed to create default HTTP client")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Serve one request with `response`, returning the request head
+    async fn serve_once(response: &'static str) -> (String, tokio::task::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/react", listener.local_addr().unwrap());
+        let handle = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = vec![0; 4096];
+            let read = socket.read(&mut request).await.unwrap();
+            socket.write_all(response.as_bytes()).await.unwrap();
+            String::from_utf8_lossy(&request[..read]).to_lowercase()
+        });
+        (url, handle)
+    }
+
+    /// Serve one connection per response, in order, returning how many
+    /// requests were answered
+    async fn serve_each(
+        responses: &'static [&'static str],
+    ) -> (String, tokio::task::JoinHandle<usize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!(
+            "http://{}/repos/facebook/react/releases",
+            listener.local_addr().unwrap()
+        );
+        let handle = tokio::spawn(async move {
+            for response in responses {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut request = vec![0; 4096];
+                socket.read(&mut request).await.unwrap();
+                socket.write_all(response.as_bytes()).await.unwrap();
+            }
+            responses.len()
+        });
+        (url, handle)
+    }
+
+    fn cached_client(cache: ResponseCache) -> HttpClient {
+        HttpClient::new().unwrap().with_cache(Arc::new(cache))
+    }
+
+    #[tokio::test]
+    async fn test_offline_serves_only_cached_responses() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let cache = ResponseCache::new(temp_dir.path()).with_mode(CacheMode::Offline);
+        // Nothing listens here; offline mode must not try
+        let url = "http://127.0.0.1:9/react";
+        cache
+            .put(
+                &cache_key(url, None, None),
+                &CachedResponse::new(url, 200, None, None, r#"{"v":1}"#.to_string()),
+            )
+            .await;
+
+        let client = cached_client(cache);
+        let json: serde_json::Value = client.get_json(url).await.unwrap();
+        assert_eq!(json["v"], 1);
+
+        let missing = client.get_text("http://127.0.0.1:9/vue").await;
+        assert!(matches!(missing, Err(Error::Offline(_))));
+    }
+
+    #[tokio::test]
+    async fn test_authenticated_requests_skip_anonymous_cache_entries() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let cache = Arc::new(ResponseCache::new(temp_dir.path()).with_mode(CacheMode::Offline));
+        let url = "http://127.0.0.1:9/@acme/private";
+        cache
+            .put(
+                &cache_key(url, None, None),
+                &CachedResponse::new(url, 200, None, None, "{}".to_string()),
+            )
+            .await;
+
+        let client = HttpClient::with_config("test", DEFAULT_TIMEOUT, Some("Bearer secret"), None)
+            .unwrap()
+            .with_cache(cache);
+        let result = client.get_text(url).await;
+        assert!(matches!(result, Err(Error::Offline(_))));
+    }
+
+    #[tokio::test]
+    async fn test_stale_entries_are_revalidated() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let (url, server) =
+            serve_once("HTTP/1.1 304 Not Modified\r\nContent-Length: 0\r\n\r\n").await;
+        let cache = ResponseCache::new(temp_dir.path()).with_ttl(Duration::ZERO);
+        cache
+            .put(
+                &cache_key(&url, None, None),
+                &CachedResponse::new(
+                    &url,
+                    200,
+                    Some("\"v1\"".to_string()),
+                    None,
+                    "cached".to_string(),
+                ),
+            )
+            .await;
+
+        let client = cached_client(cache);
+        assert_eq!(client.get_text(&url).await.unwrap(), "cached");
+        assert!(server.await.unwrap().contains("if-none-match: \"v1\""));
+    }
+
+    #[tokio::test]
+    async fn test_responses_are_cached_with_validators() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let (url, server) = serve_once(
+            "HTTP/1.1 200 OK\r\nETag: \"v2\"\r\nContent-Length: 5\r\nConnection: close\r\n\r\nfresh",
+        )
+        .await;
+
+        let client = cached_client(ResponseCache::new(temp_dir.path()));
+        assert_eq!(client.get_text(&url).await.unwrap(), "fresh");
+        server.await.unwrap();
+
+        // The server is gone: a fresh entry is served without a request
+        assert_eq!(client.get_text(&url).await.unwrap(), "fresh");
+        let cached = ResponseCache::new(temp_dir.path())
+            .get(&cache_key(&url, None, None))
+            .await
+            .unwrap();
+        assert_eq!(cached.etag.as_deref(), Some("\"v2\""));
+    }
+
+    #[tokio::test]
+    async fn test_stale_entries_are_served_when_unreachable() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let url = "http://127.0.0.1:9/react";
+        let cache = ResponseCache::new(temp_dir.path()).with_ttl(Duration::ZERO);
+        cache
+            .put(
+                &cache_key(url, None, None),
+                &CachedResponse::new(url, 200, None, None, "stale".to_string()),
+            )
+            .await;
+
+        let client = cached_client(cache);
+        assert_eq!(client.get_text(url).await.unwrap(), "stale");
+    }
+
+    #[tokio::test]
+    async fn test_transient_errors_are_retried() {
+        let (url, server) = serve_each(&[
+            "HTTP/1.1 503 Service Unavailable\r\nconnection: close\r\ncontent-length: 0\r\n\r\n",
+            "HTTP/1.1 429 Too Many Requests\r\nretry-after: 0\r\nconnection: close\r\ncontent-length: 0\r\n\r\n",
+            "HTTP/1.1 200 OK\r\nconnection: close\r\ncontent-length: 2\r\n\r\nok",
+        ])
+        .await;
+
+        let client = HttpClient::new()
+            .unwrap()
+            .with_retries(2, Duration::from_millis(1));
+        assert_eq!(client.get_text(&url).await.unwrap(), "ok");
+        assert_eq!(server.await.unwrap(), 3);
+
+        let (url, server) = serve_each(&[
+            "HTTP/1.1 502 Bad Gateway\r\nconnection: close\r\ncontent-length: 0\r\n\r\n",
+        ])
+        .await;
+        let client = HttpClient::new().unwrap().with_retries(0, Duration::ZERO);
+        assert!(client.get_text(&url).await.is_err());
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_exhausted_github_rate_limit_fails_fast() {
+        // Resets in an hour: too long to wait for
+        let (url, server) = serve_each(&[
+            "HTTP/1.1 403 Forbidden\r\nx-ratelimit-remaining: 0\r\nx-ratelimit-reset: 9999999999\r\nconnection: close\r\ncontent-length: 0\r\n\r\n",
+        ])
+        .await;
+
+        let client = HttpClient::new()
+            .unwrap()
+            .with_retries(2, Duration::from_millis(1));
+        let result = client.get_text(&url).await;
+        assert!(matches!(result, Err(Error::RateLimitExceeded(_))));
+        assert_eq!(server.await.unwrap(), 1);
+
+        // The server is gone; the recorded reset stops further requests
+        let result = client.get_text(&url).await;
+        assert!(matches!(result, Err(Error::RateLimitExceeded(_))));
+    }
+}
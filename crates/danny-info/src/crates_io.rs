@@ -6,13 +6,22 @@ use crate::repository::parse_repository_url;
 use crate::types::{PackageInfo, Registry};
 use serde::Deserialize;
 
-const CRATES_IO_API_URL: &str = "https://crates.io/api/v1";
+pub(crate) const CRATES_IO_API_URL: &str = "https://crates.io/api/v1";
 
 /// crates.io API response structure
 #[derive(Debug, Deserialize)]
 struct CratesIoResponse {
     #[serde(rename = "crate")]
     crate_info: CrateInfo,
+    #[serde(default)]
+    versions: Vec<CrateVersion>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CrateVersion {
+    num: String,
+    #[serde(default)]
+    yanked: bool,
 }
 
 #[derive(Debug, Deserialize)]
@@ -27,7 +36,11 @@ struct CrateInfo {
 }
 
 /// Fetch package information from crates.io registry
-pub async fn fetch_crates_io_package(client: &HttpClient, crate_name: &str) -> Result<PackageInfo> {
+pub async fn fetch_crates_io_package(
+    client: &HttpClient,
+    base_url: &str,
+    crate_name: &str,
+) -> Result<PackageInfo> {
     // Validate crate name
     if crate_name.is_empty() {
         return Err(Error::InvalidPackageName(
@@ -35,7 +48,7 @@ pub async fn fetch_crates_io_package(client: &HttpClient, crate_name: &str) -> R
         ));
     }
 
-    let url = format!("{}/crates/{}", CRATES_IO_API_URL, crate_name);
+    let url = format!("{}/crates/{}", base_url, crate_name);
 
     // Fetch crate metadata
     let response: CratesIoResponse = client.get_json(&url).await.map_err(|e| {
@@ -57,6 +70,12 @@ pub async fn fetch_crates_io_package(client: &HttpClient, crate_name: &str) -> R
         registry: Registry::CratesIo,
         name: response.crate_info.name,
         version: response.crate_info.max_version,
+        versions: response
+            .versions
+            .into_iter()
+            .filter(|v| !v.yanked)
+            .map(|v| v.num)
+            .collect(),
         description: response.crate_info.description,
         repository,
         homepage: response.crate_info.homepage,
@@ -72,7 +91,9 @@ mod tests {
     #[ignore] // Requires network access
     async fn test_fetch_crates_io_package() {
         let client = HttpClient::new().unwrap();
-        let info = fetch_crates_io_package(&client, "serde").await.unwrap();
+        let info = fetch_crates_io_package(&client, CRATES_IO_API_URL, "serde")
+            .await
+            .unwrap();
 
         assert_eq!(info.registry, Registry::CratesIo);
         assert_eq!(info.name, "serde");
@@ -84,7 +105,7 @@ mod tests {
     #[tokio::test]
     async fn test_invalid_crate_name() {
         let client = HttpClient::new().unwrap();
-        let result = fetch_crates_io_package(&client, "").await;
+        let result = fetch_crates_io_package(&client, CRATES_IO_API_URL, "").await;
         assert!(matches!(result, Err(Error::InvalidPackageName(_))));
     }
 
@@ -92,8 +113,12 @@ mod tests {
     #[ignore] // Requires network access
     async fn test_nonexistent_crate() {
         let client = HttpClient::new().unwrap();
-        let result =
-            fetch_crates_io_package(&client, "this-crate-definitely-does-not-exist-12345").await;
+        let result = fetch_crates_io_package(
+            &client,
+            CRATES_IO_API_URL,
+            "this-crate-definitely-does-not-exist-12345",
+        )
+        .await;
         assert!(matches!(result, Err(Error::PackageNotFound(_, _))));
     }
 }
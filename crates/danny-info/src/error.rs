@@ -48,6 +48,14 @@ pub enum Error {
     #[error("Unsupported repository host: {0} (only GitHub is supported)")]
     UnsupportedRepositoryHost(String),
 
+    /// Response not cached while offline
+    #[error("Offline and no cached response for URL: {0}")]
+    Offline(String),
+
+    /// Cache I/O failed
+    #[error("Cache I/O failed: {0}")]
+    Io(#[from] std::io::Error),
+
     /// Rate limit exceeded (HTTP 429)
     #[error("Rate limit exceeded for URL: {0}")]
     RateLimitExceeded(String),
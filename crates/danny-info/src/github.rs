@@ -3,9 +3,11 @@
 use crate::client::HttpClient;
 use crate::error::{Error, Result};
 use crate::types::{Release, RepositoryUrl};
-use std::env;
 
-const GITHUB_API_URL: &str = "https://api.github.com";
+pub(crate) const GITHUB_API_URL: &str = "https://api.github.com";
+
+/// Host serving raw files from the default branch
+pub(crate) const GITHUB_RAW_URL: &str = "https://raw.githubusercontent.com";
 
 /// Common changelog file names to search for
 const CHANGELOG_FILES: &[&str] = &[
@@ -24,42 +26,22 @@ const CHANGELOG_FILES: &[&str] = &[
 /// Fetch releases from a GitHub repository
 ///
 /// Returns up to 100 releases (GitHub API default per_page limit).
-/// Set GITHUB_TOKEN environment variable for higher rate limits.
-pub async fn fetch_releases(client: &HttpClient, repo: &RepositoryUrl) -> Result<Vec<Release>> {
-    let url = format!(
-        "{}/repos/{}/{}/releases",
-        GITHUB_API_URL, repo.owner, repo.repo
+/// Authentication, if any, is configured on `client`.
+pub async fn fetch_releases(
+    client: &HttpClient,
+    api_url: &str,
+    repo: &RepositoryUrl,
+) -> Result<Vec<Release>> {
+    let url = format!("{}/repos/{}/{}/releases", api_url, repo.owner, repo.repo);
+
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert(
+        reqwest::header::ACCEPT,
+        "application/vnd.github+json"
+            .parse()
+            .map_err(|_| Error::other("Invalid Accept header"))?,
     );
-
-    // Add GitHub token if available for higher rate limits
-    let releases: Vec<Release> = if let Ok(token) = env::var("GITHUB_TOKEN") {
-        let mut headers = reqwest::header::HeaderMap::new();
-        headers.insert(
-            reqwest::header::AUTHORIZATION,
-            format!("Bearer {}", token)
-                .parse()
-                .map_err(|_| Error::other("Invalid GitHub token format"))?,
-        );
-        headers.insert(
-            reqwest::header::ACCEPT,
-            "application/vnd.github+json"
-                .parse()
-                .map_err(|_| Error::other("Invalid Accept header"))?,
-        );
-
-        client.get_json_with_headers(&url, headers).await?
-    } else {
-        // Without token - lower rate limit
-        let mut headers = reqwest::header::HeaderMap::new();
-        headers.insert(
-            reqwest::header::ACCEPT,
-            "application/vnd.github+json"
-                .parse()
-                .map_err(|_| Error::other("Invalid Accept header"))?,
-        );
-
-        client.get_json_with_headers(&url, headers).await?
-    };
+    let releases: Vec<Release> = client.get_json_with_headers(&url, headers).await?;
 
     if releases.is_empty() {
         return Err(Error::GitHubApi(format!(
@@ -75,10 +57,14 @@ pub async fn fetch_releases(client: &HttpClient, repo: &RepositoryUrl) -> Result
 ///
 /// Tries common changelog file names in order.
 /// Returns the raw markdown content of the first found changelog.
-pub async fn fetch_changelog(client: &HttpClient, repo: &RepositoryUrl) -> Result<String> {
+pub async fn fetch_changelog(
+    client: &HttpClient,
+    raw_url: &str,
+    repo: &RepositoryUrl,
+) -> Result<String> {
     // Try each common changelog filename
     for filename in CHANGELOG_FILES {
-        match try_fetch_file(client, repo, filename).await {
+        match try_fetch_file(client, raw_url, repo, filename).await {
             Ok(content) => return Ok(content),
             Err(_) => continue, // Try next filename
         }
@@ -93,14 +79,12 @@ pub async fn fetch_changelog(client: &HttpClient, repo: &RepositoryUrl) -> Resul
 /// Try to fetch a specific file from the repository's default branch
 async fn try_fetch_file(
     client: &HttpClient,
+    raw_url: &str,
     repo: &RepositoryUrl,
     filename: &str,
 ) -> Result<String> {
-    // Use raw.githubusercontent.com for direct file access
-    let url = format!(
-        "https://raw.githubusercontent.com/{}/{}/HEAD/{}",
-        repo.owner, repo.repo, filename
-    );
+    // Use raw.githubusercontent.com (or the configured equivalent) for direct file access
+    let url = format!("{}/{}/{}/HEAD/{}", raw_url, repo.owner, repo.repo, filename);
 
     client.get_text(&url).await
 }
@@ -115,7 +99,9 @@ mod tests {
         let client = HttpClient::new().unwrap();
         let repo = RepositoryUrl::new("facebook", "react", "https://github.com/facebook/react");
 
-        let releases = fetch_releases(&client, &repo).await.unwrap();
+        let releases = fetch_releases(&client, GITHUB_API_URL, &repo)
+            .await
+            .unwrap();
         assert!(!releases.is_empty());
         assert!(releases[0].tag_name.starts_with('v'));
     }
@@ -126,7 +112,9 @@ mod tests {
         let client = HttpClient::new().unwrap();
         let repo = RepositoryUrl::new("facebook", "react", "https://github.com/facebook/react");
 
-        let changelog = fetch_changelog(&client, &repo).await.unwrap();
+        let changelog = fetch_changelog(&client, GITHUB_RAW_URL, &repo)
+            .await
+            .unwrap();
         assert!(!changelog.is_empty());
         assert!(changelog.contains("##") || changelog.contains("###"));
     }
@@ -142,7 +130,7 @@ mod tests {
             "https://github.com/octocat/Hello-World",
         );
 
-        let result = fetch_changelog(&client, &repo).await;
+        let result = fetch_changelog(&client, GITHUB_RAW_URL, &repo).await;
         assert!(matches!(result, Err(Error::ChangelogNotFound(_, _))));
     }
 }
@@ -5,7 +5,7 @@ use crate::error::{Error, Result};
 use crate::types::{PackageInfo, Registry};
 use serde::Deserialize;
 
-const JSR_API_URL: &str = "https://jsr.io";
+pub(crate) const JSR_API_URL: &str = "https://jsr.io";
 
 /// JSR package metadata response
 #[derive(Debug, Deserialize)]
@@ -15,10 +15,18 @@ struct JsrPackageMetadata {
     #[serde(default)]
     description: Option<String>,
     latest: Option<String>,
+    #[serde(default)]
+    versions: std::collections::BTreeMap<String, JsrVersion>,
     #[serde(rename = "githubRepository")]
     github_repository: Option<GithubRepository>,
 }
 
+#[derive(Debug, Deserialize)]
+struct JsrVersion {
+    #[serde(default)]
+    yanked: bool,
+}
+
 #[derive(Debug, Deserialize)]
 struct GithubRepository {
     owner: String,
@@ -28,7 +36,11 @@ struct GithubRepository {
 /// Fetch package information from JSR registry
 ///
 /// Package name should be in the format "@scope/package" (e.g., "@std/path")
-pub async fn fetch_jsr_package(client: &HttpClient, package_name: &str) -> Result<PackageInfo> {
+pub async fn fetch_jsr_package(
+    client: &HttpClient,
+    base_url: &str,
+    package_name: &str,
+) -> Result<PackageInfo> {
     // Validate package name format
     if !package_name.starts_with('@') || !package_name.contains('/') {
         return Err(Error::InvalidPackageName(format!(
@@ -49,7 +61,7 @@ pub async fn fetch_jsr_package(client: &HttpClient, package_name: &str) -> Resul
     let scope = parts[0];
     let name = parts[1];
 
-    let url = format!("{}/@{}/{}/meta.json", JSR_API_URL, scope, name);
+    let url = format!("{}/@{}/{}/meta.json", base_url, scope, name);
 
     // Fetch package metadata
     let response: JsrPackageMetadata = client.get_json(&url).await.map_err(|e| {
@@ -73,6 +85,12 @@ pub async fn fetch_jsr_package(client: &HttpClient, package_name: &str) -> Resul
         registry: Registry::Jsr,
         name: format!("@{}/{}", response.scope, response.name),
         version,
+        versions: response
+            .versions
+            .into_iter()
+            .filter(|(_, v)| !v.yanked)
+            .map(|(num, _)| num)
+            .collect(),
         description: response.description,
         repository,
         homepage: None,
@@ -88,7 +106,9 @@ mod tests {
     #[ignore] // Requires network access
     async fn test_fetch_jsr_package() {
         let client = HttpClient::new().unwrap();
-        let info = fetch_jsr_package(&client, "@std/path").await.unwrap();
+        let info = fetch_jsr_package(&client, JSR_API_URL, "@std/path")
+            .await
+            .unwrap();
 
         assert_eq!(info.registry, Registry::Jsr);
         assert!(info.name.starts_with("@std/"));
@@ -100,11 +120,11 @@ mod tests {
         let client = HttpClient::new().unwrap();
 
         // Missing @ prefix
-        let result = fetch_jsr_package(&client, "std/path").await;
+        let result = fetch_jsr_package(&client, JSR_API_URL, "std/path").await;
         assert!(matches!(result, Err(Error::InvalidPackageName(_))));
 
         // Missing scope separator
-        let result = fetch_jsr_package(&client, "@stdpath").await;
+        let result = fetch_jsr_package(&client, JSR_API_URL, "@stdpath").await;
         assert!(matches!(result, Err(Error::InvalidPackageName(_))));
     }
 }
@@ -30,6 +30,9 @@
 //! }
 //! ```
 
+mod builder;
+mod cache;
+mod cargo_index;
 mod changelog_parser;
 mod client;
 mod crates_io;
@@ -40,10 +43,15 @@ mod npm;
 mod repository;
 mod types;
 
+pub use builder::{InfoClientBuilder, RegistryConfig};
+pub use cache::{CacheMode, ResponseCache, DEFAULT_TTL};
 pub use error::{Error, Result};
 pub use types::{ChangelogEntry, PackageInfo, ParsedChangelog, Registry, Release, RepositoryUrl};
 
 use client::HttpClient;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 /// Main client for fetching package information
 ///
@@ -59,6 +67,19 @@ pub struct InfoClient {
     crates_io_client: HttpClient,
     jsr_client: HttpClient,
     github_client: HttpClient,
+    /// Client for the raw file host; never sends the GitHub token
+    github_raw_client: HttpClient,
+    npm_url: String,
+    crates_io_url: String,
+    jsr_url: String,
+    github_url: String,
+    github_raw_url: String,
+    user_agent: String,
+    timeout: Duration,
+    max_retries: u32,
+    cache: Option<Arc<ResponseCache>>,
+    /// Clients for registries only known at lookup time, e.g. from `.npmrc`
+    registries: Mutex<HashMap<RegistryConfig, HttpClient>>,
 }
 
 impl InfoClient {
@@ -72,12 +93,14 @@ impl InfoClient {
     ///
     /// Returns an error if the HTTP clients cannot be initialized.
     pub fn new() -> Result<Self> {
-        Ok(Self {
-            npm_client: HttpClient::with_rate_limit(1)?,
-            crates_io_client: HttpClient::with_rate_limit(1)?,
-            jsr_client: HttpClient::with_rate_limit(1)?,
-            github_client: HttpClient::new()?, // GitHub enforces its own rate limits
-        })
+        Self::builder().build()
+    }
+
+    /// Configure registries, credentials, user agent and timeouts
+    ///
+    /// See [`InfoClientBuilder`].
+    pub fn builder() -> InfoClientBuilder {
+        InfoClientBuilder::new()
     }
 
     /// Create a new InfoClient without rate limiting (use with caution!)
@@ -94,12 +117,7 @@ impl InfoClient {
     ///
     /// Returns an error if the HTTP clients cannot be initialized.
     pub fn without_rate_limiting() -> Result<Self> {
-        Ok(Self {
-            npm_client: HttpClient::new()?,
-            crates_io_client: HttpClient::new()?,
-            jsr_client: HttpClient::new()?,
-            github_client: HttpClient::new()?,
-        })
+        Self::builder().without_rate_limiting().build()
     }
 
     /// Create a new InfoClient with a custom user agent and rate limiting
@@ -107,16 +125,39 @@ impl InfoClient {
     /// # Errors
     ///
     /// Returns an error if the HTTP clients cannot be initialized.
-    pub fn with_user_agent(_user_agent: impl Into<String>) -> Result<Self> {
-        // Note: HttpClient::with_rate_limit currently doesn't support custom user agent
-        // This is a limitation we can address if needed
-        // For now, we use the default user agent with rate limiting
-        Ok(Self {
-            npm_client: HttpClient::with_rate_limit(1)?,
-            crates_io_client: HttpClient::with_rate_limit(1)?,
-            jsr_client: HttpClient::with_rate_limit(1)?,
-            github_client: HttpClient::new()?,
-        })
+    pub fn with_user_agent(user_agent: impl Into<String>) -> Result<Self> {
+        Self::builder().user_agent(user_agent).build()
+    }
+
+    /// Serve and store responses through an on-disk cache
+    ///
+    /// Fresh responses skip the network and the rate limiters entirely; stale
+    /// ones are revalidated with ETag/Last-Modified. With
+    /// [`CacheMode::Offline`] only cached responses are served.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use danny_info::{CacheMode, InfoClient, ResponseCache};
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let cache = ResponseCache::in_user_cache_dir()?.with_mode(CacheMode::Offline);
+    /// let client = InfoClient::new()?.with_cache(cache);
+    /// let info = client.fetch_npm("react").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_cache(self, cache: ResponseCache) -> Self {
+        let cache = Arc::new(cache);
+        Self {
+            npm_client: self.npm_client.with_cache(cache.clone()),
+            crates_io_client: self.crates_io_client.with_cache(cache.clone()),
+            jsr_client: self.jsr_client.with_cache(cache.clone()),
+            github_client: self.github_client.with_cache(cache.clone()),
+            github_raw_client: self.github_raw_client.with_cache(cache.clone()),
+            cache: Some(cache),
+            registries: Mutex::default(),
+            ..self
+        }
     }
 
     /// Fetch package information from npm registry
@@ -137,7 +178,7 @@ impl InfoClient {
     /// # }
     /// ```
     pub async fn fetch_npm(&self, name: &str) -> Result<PackageInfo> {
-        npm::fetch_npm_package(&self.npm_client, name).await
+        npm::fetch_npm_package(&self.npm_client, &self.npm_url, name).await
     }
 
     /// Fetch package information from crates.io registry
@@ -158,7 +199,7 @@ impl InfoClient {
     /// # }
     /// ```
     pub async fn fetch_crates_io(&self, name: &str) -> Result<PackageInfo> {
-        crates_io::fetch_crates_io_package(&self.crates_io_client, name).await
+        crates_io::fetch_crates_io_package(&self.crates_io_client, &self.crates_io_url, name).await
     }
 
     /// Fetch package information from JSR (JavaScript Registry)
@@ -179,13 +220,54 @@ impl InfoClient {
     /// # }
     /// ```
     pub async fn fetch_jsr(&self, name: &str) -> Result<PackageInfo> {
-        jsr::fetch_jsr_package(&self.jsr_client, name).await
+        jsr::fetch_jsr_package(&self.jsr_client, &self.jsr_url, name).await
+    }
+
+    /// Fetch package information from an npm-compatible registry
+    ///
+    /// Used for private registries and scopes configured outside the client,
+    /// e.g. in `.npmrc`. The client's user agent, timeout and cache apply.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use danny_info::{InfoClient, RegistryConfig};
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = InfoClient::new()?;
+    /// let registry = RegistryConfig::new("https://npm.example.com").with_token("secret");
+    /// let info = client.fetch_npm_from(&registry, "@acme/ui").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_npm_from(
+        &self,
+        registry: &RegistryConfig,
+        name: &str,
+    ) -> Result<PackageInfo> {
+        let client = self.registry_client(registry)?;
+        npm::fetch_npm_package(&client, registry.base_url(), name).await
+    }
+
+    /// Fetch crate information from an alternative Cargo registry
+    ///
+    /// `registry` points at the registry's sparse index (without the
+    /// `sparse+` prefix). Cargo registries expect the token verbatim, so set
+    /// it with [`RegistryConfig::with_authorization`]. The index has no
+    /// descriptions or repository links, so those are left empty.
+    pub async fn fetch_cargo_index(
+        &self,
+        registry: &RegistryConfig,
+        name: &str,
+    ) -> Result<PackageInfo> {
+        let client = self.registry_client(registry)?;
+        cargo_index::fetch_index_package(&client, registry.base_url(), name).await
     }
 
     /// Fetch releases from a GitHub repository
     ///
     /// Returns up to 100 releases (GitHub API default limit).
-    /// Set GITHUB_TOKEN environment variable for higher rate limits.
+    /// Set GITHUB_TOKEN environment variable (or configure a token with
+    /// [`InfoClient::builder`]) for higher rate limits.
     ///
     /// # Arguments
     ///
@@ -204,7 +286,7 @@ impl InfoClient {
     /// # }
     /// ```
     pub async fn fetch_releases(&self, repo: &RepositoryUrl) -> Result<Vec<Release>> {
-        github::fetch_releases(&self.github_client, repo).await
+        github::fetch_releases(&self.github_client, &self.github_url, repo).await
     }
 
     /// Fetch changelog content from a GitHub repository
@@ -229,7 +311,7 @@ impl InfoClient {
     /// # }
     /// ```
     pub async fn fetch_changelog(&self, repo: &RepositoryUrl) -> Result<String> {
-        github::fetch_changelog(&self.github_client, repo).await
+        github::fetch_changelog(&self.github_raw_client, &self.github_raw_url, repo).await
     }
 
     /// Fetch and parse a changelog with date extraction
@@ -264,10 +346,59 @@ impl InfoClient {
     /// # }
     /// ```
     pub async fn fetch_parsed_changelog(&self, repo: &RepositoryUrl) -> Result<ParsedChangelog> {
-        let markdown = github::fetch_changelog(&self.github_client, repo).await?;
+        let markdown =
+            github::fetch_changelog(&self.github_raw_client, &self.github_raw_url, repo).await?;
         Ok(changelog_parser::parse_changelog(&markdown))
     }
 
+    /// Client for `registry`, created on first use so its rate limiter is
+    /// shared by every lookup
+    ///
+    /// A rate-limited registry on a host that already has a limiter (e.g.
+    /// the public npm registry with a token from `.npmrc`) shares it.
+    fn registry_client(&self, registry: &RegistryConfig) -> Result<HttpClient> {
+        let mut registries = self
+            .registries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Some(client) = registries.get(registry) {
+            return Ok(client.clone());
+        }
+
+        let host = url_host(registry.base_url());
+        let shared_limiter = [
+            (self.npm_url.as_str(), &self.npm_client),
+            (self.crates_io_url.as_str(), &self.crates_io_client),
+            (self.jsr_url.as_str(), &self.jsr_client),
+            (self.github_url.as_str(), &self.github_client),
+        ]
+        .into_iter()
+        .chain(
+            registries
+                .iter()
+                .map(|(config, client)| (config.base_url(), client)),
+        )
+        .filter(|(url, _)| host.is_some() && url_host(url) == host)
+        .find_map(|(_, client)| client.rate_limiter().cloned())
+        .filter(|_| registry.requests_per_second().is_some());
+
+        let mut client = HttpClient::with_config(
+            &self.user_agent,
+            self.timeout,
+            registry.authorization(),
+            registry.requests_per_second(),
+        )?
+        .with_retries(self.max_retries, client::DEFAULT_RETRY_DELAY);
+        if let Some(limiter) = shared_limiter {
+            client = client.with_rate_limiter(limiter);
+        }
+        if let Some(cache) = &self.cache {
+            client = client.with_cache(cache.clone());
+        }
+        registries.insert(registry.clone(), client.clone());
+        Ok(client)
+    }
+
     /// Parse a repository URL string into a RepositoryUrl
     ///
     /// Supports various GitHub URL formats:
@@ -294,3 +425,8 @@ impl Default for InfoClient {
         Self::new().expect("Failed to create default InfoClient")
     }
 }
+
+/// Host of `url`; clients for the same host share a rate limiter
+fn url_host(url: &str) -> Option<String> {
+    reqwest::Url::parse(url).ok()?.host_str().map(String::from)
+}
@@ -4,9 +4,11 @@ use crate::client::HttpClient;
 use crate::error::{Error, Result};
 use crate::repository::{extract_repo_from_json, parse_repository_url};
 use crate::types::{PackageInfo, Registry};
+use serde::de::IgnoredAny;
 use serde::Deserialize;
+use std::collections::BTreeMap;
 
-const NPM_REGISTRY_URL: &str = "https://registry.npmjs.org";
+pub(crate) const NPM_REGISTRY_URL: &str = "https://registry.npmjs.org";
 
 /// npm registry API response structure
 #[derive(Debug, Deserialize)]
@@ -15,6 +17,8 @@ struct NpmPackageResponse {
     description: Option<String>,
     #[serde(rename = "dist-tags")]
     dist_tags: DistTags,
+    #[serde(default)]
+    versions: BTreeMap<String, IgnoredAny>,
     repository: Option<serde_json::Value>,
     homepage: Option<String>,
     license: Option<serde_json::Value>,
@@ -26,7 +30,11 @@ struct DistTags {
 }
 
 /// Fetch package information from npm registry
-pub async fn fetch_npm_package(client: &HttpClient, package_name: &str) -> Result<PackageInfo> {
+pub async fn fetch_npm_package(
+    client: &HttpClient,
+    base_url: &str,
+    package_name: &str,
+) -> Result<PackageInfo> {
     // Validate package name (basic validation)
     if package_name.is_empty() {
         return Err(Error::InvalidPackageName(
@@ -41,7 +49,7 @@ pub async fn fetch_npm_package(client: &HttpClient, package_name: &str) -> Resul
         package_name.to_string()
     };
 
-    let url = format!("{}/{}", NPM_REGISTRY_URL, encoded_name);
+    let url = format!("{}/{}", base_url, encoded_name);
 
     // Fetch package metadata
     let response: NpmPackageResponse = client.get_json(&url).await.map_err(|e| {
@@ -72,6 +80,7 @@ pub async fn fetch_npm_package(client: &HttpClient, package_name: &str) -> Resul
         registry: Registry::Npm,
         name: response.name,
         version: response.dist_tags.latest,
+        versions: response.versions.into_keys().collect(),
         description: response.description,
         repository,
         homepage: response.homepage,
@@ -87,7 +96,9 @@ mod tests {
     #[ignore] // Requires network access
     async fn test_fetch_npm_package() {
         let client = HttpClient::new().unwrap();
-        let info = fetch_npm_package(&client, "react").await.unwrap();
+        let info = fetch_npm_package(&client, NPM_REGISTRY_URL, "react")
+            .await
+            .unwrap();
 
         assert_eq!(info.registry, Registry::Npm);
         assert_eq!(info.name, "react");
@@ -100,7 +111,9 @@ mod tests {
     #[ignore] // Requires network access
     async fn test_fetch_scoped_npm_package() {
         let client = HttpClient::new().unwrap();
-        let info = fetch_npm_package(&client, "@types/node").await.unwrap();
+        let info = fetch_npm_package(&client, NPM_REGISTRY_URL, "@types/node")
+            .await
+            .unwrap();
 
         assert_eq!(info.registry, Registry::Npm);
         assert_eq!(info.name, "@types/node");
@@ -110,7 +123,7 @@ mod tests {
     #[tokio::test]
     async fn test_invalid_package_name() {
         let client = HttpClient::new().unwrap();
-        let result = fetch_npm_package(&client, "").await;
+        let result = fetch_npm_package(&client, NPM_REGISTRY_URL, "").await;
         assert!(matches!(result, Err(Error::InvalidPackageName(_))));
     }
 }
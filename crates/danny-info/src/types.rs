@@ -12,6 +12,8 @@ pub enum Registry {
     CratesIo,
     /// JSR (JavaScript Registry)
     Jsr,
+    /// An alternative Cargo registry, read through its sparse index
+    Cargo,
 }
 
 impl Registry {
@@ -21,6 +23,7 @@ impl Registry {
             Registry::Npm => "npm",
             Registry::CratesIo => "crates.io",
             Registry::Jsr => "jsr",
+            Registry::Cargo => "cargo",
         }
     }
 }
@@ -40,6 +43,9 @@ pub struct PackageInfo {
     pub name: String,
     /// Latest version
     pub version: String,
+    /// Every published version that isn't yanked (unordered)
+    #[serde(default)]
+    pub versions: Vec<String>,
     /// Package description
     pub description: Option<String>,
     /// Repository URL information
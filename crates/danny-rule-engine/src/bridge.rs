@@ -36,6 +36,16 @@ impl TomlFrameworkRule {
         })
     }
 
+    /// Framework name (e.g. "React", or the `[framework]` name of a rule file)
+    pub fn framework_name(&self) -> &str {
+        &self.name
+    }
+
+    /// The compiled rules, for attributing framework-used exports
+    pub fn engine(&self) -> &RuleEngine {
+        &self.engine
+    }
+
     /// Create a new TOML framework rule from a TOML string
     pub fn from_toml_str(name: String, toml_content: &str) -> crate::Result<Self> {
         let rule_file: TomlRuleFile = toml::from_str(toml_content)?;
@@ -131,13 +141,9 @@ async fn apply_action_to_graph(
         RuleAction::Skip => {
             // Skip means don't analyze - no action needed on graph
         }
-        RuleAction::Warn { message: _ } => {
-            // Warning actions are logged but don't mutate the graph
-            // The caller can handle warnings as needed
-        }
-        RuleAction::SetSeverity { level: _ } => {
-            // Severity override actions are handled by the caller
-            // They don't mutate the graph directly
+        RuleAction::Warn { .. } | RuleAction::SetSeverity { .. } => {
+            // Reporting actions don't mutate the graph; the caller collects
+            // them with `RuleEngine::rule_matches`
         }
     }
     Ok(())
@@ -1,10 +1,14 @@
 //! Built-in framework rules embedded in the binary
 //!
 //! This module provides the default framework detection rules for popular
-//! frameworks like React, Next.js, Vue, and Svelte. These rules are embedded
+//! frameworks like React, Next.js, Vue, Nuxt, Svelte, SvelteKit, Remix, Astro,
+//! Angular and Express. These rules are embedded
 //! at compile time via `include_str!()` for zero-config defaults.
 
-use crate::{EntryPointPattern, Result, TomlFrameworkRule};
+use crate::{
+    EntryPointPattern, FrameworkDetector, FrameworkMetadata, Result, TomlFrameworkRule, TomlRule,
+    TomlRuleFile,
+};
 use fob::graph::FrameworkRule;
 
 /// React framework rules (hooks, components)
@@ -19,9 +23,27 @@ pub const VUE_RULES: &str = include_str!("built_in/vue.toml");
 /// Svelte framework rules (stores, reactive patterns)
 pub const SVELTE_RULES: &str = include_str!("built_in/svelte.toml");
 
+/// Nuxt framework rules (pages, auto-imported composables, server handlers)
+pub const NUXT_RULES: &str = include_str!("built_in/nuxt.toml");
+
+/// SvelteKit framework rules (load functions, actions, endpoints, hooks)
+pub const SVELTEKIT_RULES: &str = include_str!("built_in/sveltekit.toml");
+
+/// Remix / React Router framework rules (route module exports)
+pub const REMIX_RULES: &str = include_str!("built_in/remix.toml");
+
+/// Astro framework rules (getStaticPaths, endpoints, middleware)
+pub const ASTRO_RULES: &str = include_str!("built_in/astro.toml");
+
+/// Angular framework rules (decorated classes, app config)
+pub const ANGULAR_RULES: &str = include_str!("built_in/angular.toml");
+
+/// Express framework rules (app exports)
+pub const EXPRESS_RULES: &str = include_str!("built_in/express.toml");
+
 /// Load all built-in framework rules
 ///
-/// This function loads the 4 embedded framework rule sets and returns them
+/// This function loads the 10 embedded framework rule sets and returns them
 /// as trait objects that can be passed to Fob's analysis pipeline.
 ///
 /// # Example
@@ -30,25 +52,87 @@ pub const SVELTE_RULES: &str = include_str!("built_in/svelte.toml");
 /// use danny_rule_engine::load_built_in_rules;
 ///
 /// let rules = load_built_in_rules().expect("Failed to load built-in rules");
-/// assert_eq!(rules.len(), 4); // React, Next.js, Vue, Svelte
+/// assert_eq!(rules.len(), 10);
 /// ```
 pub fn load_built_in_rules() -> Result<Vec<Box<dyn FrameworkRule>>> {
-    let frameworks = [
-        ("React", REACT_RULES),
-        ("Next.js", NEXTJS_RULES),
-        ("Vue", VUE_RULES),
-        ("Svelte", SVELTE_RULES),
-    ];
-
-    frameworks
-        .iter()
+    load_built_in_toml_files()
+        .into_iter()
         .map(|(name, toml_str)| {
-            TomlFrameworkRule::from_toml_str((*name).to_string(), toml_str)
+            TomlFrameworkRule::from_toml_str(name, toml_str)
                 .map(|rule| Box::new(rule) as Box<dyn FrameworkRule>)
         })
         .collect()
 }
 
+/// Combine built-in framework rules with rules loaded from disk
+///
+/// `loaded` usually comes from [`RuleLoader::load_all`](crate::RuleLoader::load_all).
+/// A loaded rule replaces the built-in rule with the same name. Loaded rules
+/// are grouped by their file's `[framework]` name (`"Custom"` when missing)
+/// and returned ahead of the built-in frameworks, in the loader's priority
+/// order.
+pub fn load_rules_with_overrides(loaded: Vec<TomlRule>) -> Result<Vec<TomlFrameworkRule>> {
+    let mut groups: Vec<(String, Vec<TomlRule>)> = Vec::new();
+    for rule in loaded {
+        let framework = rule
+            .framework
+            .clone()
+            .unwrap_or_else(|| "Custom".to_string());
+        match groups.iter_mut().find(|(name, _)| *name == framework) {
+            Some((_, rules)) => rules.push(rule),
+            None => groups.push((framework, vec![rule])),
+        }
+    }
+
+    let overridden: Vec<&str> = groups
+        .iter()
+        .flat_map(|(_, rules)| rules.iter().map(|rule| rule.name.as_str()))
+        .collect();
+
+    let mut built_in = Vec::new();
+    for (name, toml_str) in load_built_in_toml_files() {
+        let file: TomlRuleFile = toml::from_str(toml_str)?;
+        let description = file
+            .framework
+            .and_then(|f| f.description)
+            .unwrap_or_default();
+        let rules = file
+            .rules
+            .into_iter()
+            .filter(|rule| !overridden.contains(&rule.name.as_str()))
+            .collect();
+        built_in.push(TomlFrameworkRule::new(name, description, rules)?);
+    }
+
+    let mut framework_rules = groups
+        .into_iter()
+        .map(|(name, rules)| TomlFrameworkRule::new(name, String::new(), rules))
+        .collect::<Result<Vec<_>>>()?;
+    framework_rules.extend(built_in);
+
+    Ok(framework_rules)
+}
+
+/// Build a framework detector from the built-in frameworks and loaded ones
+///
+/// `loaded` usually comes from [`RuleLoader::load_frameworks`](crate::RuleLoader::load_frameworks).
+/// A loaded framework replaces the built-in framework with the same name;
+/// new names add frameworks without any code changes.
+pub fn load_detector_with_overrides(loaded: Vec<FrameworkMetadata>) -> Result<FrameworkDetector> {
+    let mut frameworks = Vec::new();
+    for (_, toml_str) in load_built_in_toml_files() {
+        let file: TomlRuleFile = toml::from_str(toml_str)?;
+        if let Some(framework) = file.framework {
+            if !loaded.iter().any(|f| f.name == framework.name) {
+                frameworks.push(framework);
+            }
+        }
+    }
+    frameworks.extend(loaded);
+
+    FrameworkDetector::from_frameworks(frameworks)
+}
+
 /// Load all built-in entry point patterns from TOML files
 ///
 /// This function extracts entry point patterns from all embedded framework TOML files.
@@ -63,11 +147,9 @@ pub fn load_built_in_rules() -> Result<Vec<Box<dyn FrameworkRule>>> {
 /// // Use entry_points to discover files using glob patterns
 /// ```
 pub fn load_built_in_entry_points() -> Result<Vec<EntryPointPattern>> {
-    let frameworks = [REACT_RULES, NEXTJS_RULES, VUE_RULES, SVELTE_RULES];
-
     let mut all_entry_points = Vec::new();
 
-    for toml_str in frameworks.iter() {
+    for (_, toml_str) in load_built_in_toml_files() {
         match crate::extract_entry_points(toml_str) {
             Ok(mut entry_points) => {
                 all_entry_points.append(&mut entry_points);
@@ -100,6 +182,12 @@ pub fn load_built_in_toml_files() -> Vec<(String, &'static str)> {
         ("Next.js".to_string(), NEXTJS_RULES),
         ("Vue".to_string(), VUE_RULES),
         ("Svelte".to_string(), SVELTE_RULES),
+        ("Nuxt".to_string(), NUXT_RULES),
+        ("SvelteKit".to_string(), SVELTEKIT_RULES),
+        ("Remix".to_string(), REMIX_RULES),
+        ("Astro".to_string(), ASTRO_RULES),
+        ("Angular".to_string(), ANGULAR_RULES),
+        ("Express".to_string(), EXPRESS_RULES),
     ]
 }
 
@@ -110,13 +198,68 @@ mod tests {
     #[test]
     fn test_load_built_in_rules() {
         let rules = load_built_in_rules().expect("Failed to load built-in rules");
-        assert_eq!(rules.len(), 4, "Should load all 4 framework rules");
+        assert_eq!(rules.len(), 10, "Should load all 10 framework rules");
 
         let names: Vec<&str> = rules.iter().map(|r| r.name()).collect();
         assert!(names.contains(&"React"), "Should include React rules");
         assert!(names.contains(&"Next.js"), "Should include Next.js rules");
         assert!(names.contains(&"Vue"), "Should include Vue rules");
         assert!(names.contains(&"Svelte"), "Should include Svelte rules");
+        assert!(names.contains(&"Nuxt"), "Should include Nuxt rules");
+        assert!(
+            names.contains(&"SvelteKit"),
+            "Should include SvelteKit rules"
+        );
+        assert!(names.contains(&"Remix"), "Should include Remix rules");
+        assert!(names.contains(&"Astro"), "Should include Astro rules");
+        assert!(names.contains(&"Angular"), "Should include Angular rules");
+        assert!(names.contains(&"Express"), "Should include Express rules");
+    }
+
+    #[test]
+    fn test_loaded_rules_override_built_in_rules() {
+        let react: TomlRuleFile = toml::from_str(REACT_RULES).unwrap();
+        let built_in_name = react.rules[0].name.clone();
+
+        let custom = r#"
+[framework]
+name = "Acme Router"
+
+[[rules]]
+name = "routes"
+[rules.match]
+export_pattern = "^route"
+[rules.action]
+mark_used = true
+
+[[rules]]
+name = "REPLACED"
+[rules.match]
+export_pattern = "^never$"
+[rules.action]
+mark_used = true
+"#
+        .replace("REPLACED", &built_in_name);
+        let mut loaded = toml::from_str::<TomlRuleFile>(&custom).unwrap().rules;
+        for rule in &mut loaded {
+            rule.framework = Some("Acme Router".to_string());
+        }
+
+        let rules = load_rules_with_overrides(loaded).unwrap();
+        assert_eq!(rules.len(), 11);
+        assert_eq!(rules[0].framework_name(), "Acme Router");
+        assert_eq!(rules[0].engine().rules.len(), 2);
+
+        let react_rules = rules
+            .iter()
+            .find(|rule| rule.framework_name() == "React")
+            .unwrap();
+        assert_eq!(react_rules.engine().rules.len(), react.rules.len() - 1);
+        assert!(react_rules
+            .engine()
+            .rules
+            .iter()
+            .all(|rule| rule.name != built_in_name));
     }
 
     #[test]
@@ -149,6 +292,206 @@ mod tests {
         assert_eq!(rule.name(), "Svelte");
     }
 
+    #[test]
+    fn test_framework_packs_mark_convention_exports() {
+        use fob::graph::{Export, ExportKind, Module, ModuleId, SourceSpan, SourceType};
+        use std::path::PathBuf;
+
+        let marked_by = |framework: &str, toml_str: &str, path: &str, export: &str| {
+            let rule = TomlFrameworkRule::from_toml_str(framework.to_string(), toml_str).unwrap();
+            let path = PathBuf::from(path);
+            let module = Module::builder(
+                ModuleId::new(&path).unwrap(),
+                path.clone(),
+                SourceType::TypeScript,
+            )
+            .build();
+            let export = Export {
+                name: export.to_string(),
+                kind: ExportKind::Named,
+                span: SourceSpan {
+                    file: path,
+                    start: 0,
+                    end: 0,
+                },
+                is_type_only: false,
+                is_framework_used: false,
+                is_used: false,
+                re_exported_from: None,
+                came_from_commonjs: false,
+                usage_count: None,
+            };
+            rule.engine()
+                .matching_rule(&module, &export)
+                .map(|(name, _)| name.to_string())
+        };
+
+        let cases = [
+            (
+                "Remix",
+                REMIX_RULES,
+                "/app/routes/posts.$id.tsx",
+                "loader",
+                true,
+            ),
+            ("Remix", REMIX_RULES, "/app/root.tsx", "ErrorBoundary", true),
+            (
+                "Remix",
+                REMIX_RULES,
+                "/app/utils/format.ts",
+                "loader",
+                false,
+            ),
+            (
+                "SvelteKit",
+                SVELTEKIT_RULES,
+                "/src/routes/blog/+page.ts",
+                "load",
+                true,
+            ),
+            (
+                "SvelteKit",
+                SVELTEKIT_RULES,
+                "/src/routes/+page.server.ts",
+                "actions",
+                true,
+            ),
+            (
+                "SvelteKit",
+                SVELTEKIT_RULES,
+                "/src/lib/api.ts",
+                "load",
+                false,
+            ),
+            (
+                "Nuxt",
+                NUXT_RULES,
+                "/site/composables/useCart.ts",
+                "useCart",
+                true,
+            ),
+            (
+                "Nuxt",
+                NUXT_RULES,
+                "/site/server/api/users.get.ts",
+                "default",
+                true,
+            ),
+            (
+                "Astro",
+                ASTRO_RULES,
+                "/src/pages/blog/[slug].astro",
+                "getStaticPaths",
+                true,
+            ),
+            (
+                "Astro",
+                ASTRO_RULES,
+                "/src/middleware.ts",
+                "onRequest",
+                true,
+            ),
+            (
+                "Angular",
+                ANGULAR_RULES,
+                "/src/app/app.routes.ts",
+                "routes",
+                true,
+            ),
+        ];
+
+        for (framework, toml_str, path, export, expected) in cases {
+            assert_eq!(
+                marked_by(framework, toml_str, path, export).is_some(),
+                expected,
+                "{} rules for {}#{}",
+                framework,
+                path,
+                export
+            );
+        }
+    }
+
+    #[test]
+    fn test_react_rules_mark_components_without_literal_jsx() {
+        use fob::graph::{
+            Export, ExportKind, Import, ImportKind, Module, ModuleId, SourceSpan, SourceType,
+        };
+        use std::path::PathBuf;
+
+        let rule = TomlFrameworkRule::from_toml_str("React".to_string(), REACT_RULES).unwrap();
+        let span = |file: &PathBuf| SourceSpan {
+            file: file.clone(),
+            start: 0,
+            end: 0,
+        };
+        let marked_by = |path: &str, imports: &[&str], source: &str, export: &str| {
+            let path = PathBuf::from(path);
+            let imports = imports
+                .iter()
+                .map(|source| Import {
+                    source: source.to_string(),
+                    specifiers: vec![],
+                    kind: ImportKind::Static,
+                    resolved_to: None,
+                    span: span(&path),
+                })
+                .collect();
+            let mut module = Module::builder(
+                ModuleId::new(&path).unwrap(),
+                path.clone(),
+                SourceType::TypeScript,
+            )
+            .imports(imports)
+            .build();
+            module.source = source.into();
+            let export = Export {
+                name: export.to_string(),
+                kind: ExportKind::Named,
+                span: span(&path),
+                is_type_only: false,
+                is_framework_used: false,
+                is_used: false,
+                re_exported_from: None,
+                came_from_commonjs: false,
+                usage_count: None,
+            };
+            rule.engine()
+                .matching_rule(&module, &export)
+                .map(|(name, _)| name.to_string())
+        };
+
+        let wrapped = r#"
+            export class Profile extends React.Component {}
+            function Inner() { return createElement("div"); }
+            export const Memoized = memo(Inner);
+            export const Connected = connect(mapState)(Inner);
+            export const Config = defineConfig({ strict: true });
+        "#;
+        let cases = [
+            ("/src/Profile.tsx", "Profile", true),
+            ("/src/Profile.tsx", "Memoized", true),
+            ("/src/Profile.tsx", "Connected", true),
+            ("/src/Profile.tsx", "Config", false),
+        ];
+        for (path, export, expected) in cases {
+            assert_eq!(
+                marked_by(path, &[], wrapped, export).is_some(),
+                expected,
+                "{}#{}",
+                path,
+                export
+            );
+        }
+
+        // PascalCase exports of React modules count without parsing the source
+        let legacy = "export function Legacy() { return React.createElement('div'); }";
+        assert_eq!(
+            marked_by("/src/Legacy.jsx", &["react"], legacy, "Legacy").as_deref(),
+            Some("react-components")
+        );
+    }
+
     #[test]
     fn test_all_rules_have_descriptions() {
         let rules = load_built_in_rules().expect("Failed to load rules");
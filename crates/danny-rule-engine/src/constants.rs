@@ -47,3 +47,10 @@ pub const REGEX_DFA_SIZE_LIMIT: usize = 2_000_000; // 2MB
 /// loading rules from nested directories. Most real projects don't
 /// need more than 10 levels of nesting.
 pub const MAX_DIRECTORY_DEPTH: usize = 10;
+
+/// Maximum nesting depth of `any`/`all`/`not` matcher groups (8 levels)
+///
+/// Rationale: Groups compile recursively. Real rules rarely nest more
+/// than two or three levels, and a hard cap keeps hostile rule files
+/// from exhausting the stack.
+pub const MAX_MATCHER_DEPTH: usize = 8;
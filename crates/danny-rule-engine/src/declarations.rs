@@ -0,0 +1,490 @@
+//! Export declaration kinds parsed from module source
+//!
+//! Fob's `Export` only records names and flags, so `export_type` matching
+//! parses the module with oxc to learn what each export actually declares:
+//! a function, an arrow-function const, a class, an enum, a type alias, an
+//! interface, or a component (a function returning JSX, a class extending
+//! `Component`, or a wrapped component such as `memo(Inner)`). Results are
+//! cached per module path so every rule evaluated against a module shares
+//! one parse.
+
+use fob::graph::Module;
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{
+    ArrowFunctionExpression, Class, Declaration, ExportDefaultDeclarationKind, Expression,
+    Function, FunctionBody, Statement, VariableDeclarationKind,
+};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// What a top-level export declares
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeclarationKind {
+    /// Function declaration, or a variable bound to an arrow/function expression
+    Function,
+    Class,
+    Enum,
+    TypeAlias,
+    Interface,
+    /// Any other variable binding
+    Variable,
+}
+
+/// Keyword a variable-bound export was declared with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VariableKind {
+    Const,
+    Let,
+    Var,
+}
+
+impl VariableKind {
+    fn from_oxc(kind: VariableDeclarationKind) -> Option<Self> {
+        match kind {
+            VariableDeclarationKind::Const => Some(Self::Const),
+            VariableDeclarationKind::Let => Some(Self::Let),
+            VariableDeclarationKind::Var => Some(Self::Var),
+            _ => None,
+        }
+    }
+}
+
+/// Parsed shape of a single export
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExportDeclaration {
+    pub kind: DeclarationKind,
+    /// `const`/`let`/`var` keyword for variable-bound exports
+    pub binding: Option<VariableKind>,
+    pub is_async: bool,
+    /// The function (or the function wrapped by `memo`/`forwardRef`) returns JSX
+    pub returns_jsx: bool,
+    /// Class extending `Component`/`PureComponent` (or `React.Component`)
+    pub extends_component: bool,
+    /// Call wrapping a PascalCase identifier: `memo(Inner)`, `connect(...)(Inner)`
+    pub wraps_component: bool,
+}
+
+impl ExportDeclaration {
+    fn new(kind: DeclarationKind) -> Self {
+        Self {
+            kind,
+            binding: None,
+            is_async: false,
+            returns_jsx: false,
+            extends_component: false,
+            wraps_component: false,
+        }
+    }
+
+    /// Whether this declares a React component
+    pub fn is_component(&self) -> bool {
+        self.returns_jsx || self.extends_component || self.wraps_component
+    }
+
+    fn class(class: &Class<'_>) -> Self {
+        let extends_component = match &class.super_class {
+            Some(Expression::Identifier(ident)) => is_component_base(&ident.name),
+            Some(Expression::StaticMemberExpression(member)) => {
+                is_component_base(&member.property.name)
+            }
+            _ => false,
+        };
+
+        Self {
+            extends_component,
+            ..Self::new(DeclarationKind::Class)
+        }
+    }
+
+    fn function(function: &Function<'_>) -> Self {
+        Self {
+            is_async: function.r#async,
+            returns_jsx: function
+                .body
+                .as_ref()
+                .is_some_and(|body| body_returns_jsx(body)),
+            ..Self::new(DeclarationKind::Function)
+        }
+    }
+
+    fn arrow(arrow: &ArrowFunctionExpression<'_>) -> Self {
+        let returns_jsx = if arrow.expression {
+            matches!(
+                arrow.body.statements.first(),
+                Some(Statement::ExpressionStatement(statement)) if is_jsx(&statement.expression)
+            )
+        } else {
+            body_returns_jsx(&arrow.body)
+        };
+
+        Self {
+            is_async: arrow.r#async,
+            returns_jsx,
+            ..Self::new(DeclarationKind::Function)
+        }
+    }
+
+    /// Classify an initializer or default-exported expression
+    fn expression(expression: &Expression<'_>) -> Self {
+        match expression {
+            Expression::ArrowFunctionExpression(arrow) => Self::arrow(arrow),
+            Expression::FunctionExpression(function) => Self::function(function),
+            Expression::ClassExpression(class) => Self::class(class),
+            Expression::ParenthesizedExpression(inner) => Self::expression(&inner.expression),
+            // `memo(() => <div />)`, `forwardRef(function Input() { ... })`,
+            // `memo(Inner)`, `connect(mapState)(Inner)`
+            Expression::CallExpression(call) => {
+                let arguments = || call.arguments.iter().filter_map(|a| a.as_expression());
+                Self {
+                    returns_jsx: arguments().any(|argument| {
+                        matches!(
+                            argument,
+                            Expression::ArrowFunctionExpression(_)
+                                | Expression::FunctionExpression(_)
+                        ) && Self::expression(argument).returns_jsx
+                    }),
+                    wraps_component: arguments().any(|argument| match argument {
+                        Expression::Identifier(ident) => is_pascal_case(&ident.name),
+                        _ => false,
+                    }),
+                    ..Self::new(DeclarationKind::Variable)
+                }
+            }
+            _ => Self::new(DeclarationKind::Variable),
+        }
+    }
+}
+
+/// Export declarations of one module, keyed by exported name
+#[derive(Debug, Clone, Default)]
+pub struct ExportDeclarations {
+    by_name: HashMap<String, ExportDeclaration>,
+}
+
+impl ExportDeclarations {
+    /// Parse `source` and classify its exports
+    ///
+    /// Returns `None` for files oxc can't parse as JavaScript/TypeScript
+    /// (unknown extensions, `.vue`/`.svelte` components) or when the parser
+    /// gives up. Re-exports from other modules are not resolved.
+    pub fn parse(path: &Path, source: &str) -> Option<Self> {
+        let mut source_type = SourceType::from_path(path).ok()?;
+        // `.js`/`.mjs`/`.cjs` files commonly contain JSX
+        if source_type.is_javascript() {
+            source_type = source_type.with_jsx(true);
+        }
+        let allocator = Allocator::default();
+        let parsed = Parser::new(&allocator, source, source_type).parse();
+
+        if parsed.panicked {
+            return None;
+        }
+
+        let mut locals = HashMap::new();
+        let mut declarations = Self::default();
+        let mut specifiers = Vec::new();
+
+        for statement in &parsed.program.body {
+            match statement {
+                Statement::ExportNamedDeclaration(export) => {
+                    if let Some(declaration) = &export.declaration {
+                        for (name, shape) in declared(declaration) {
+                            locals.insert(name.clone(), shape);
+                            declarations.by_name.insert(name, shape);
+                        }
+                    }
+                    if export.source.is_none() {
+                        specifiers.extend(export.specifiers.iter().map(|specifier| {
+                            (
+                                specifier.local.name().to_string(),
+                                specifier.exported.name().to_string(),
+                            )
+                        }));
+                    }
+                }
+                Statement::ExportDefaultDeclaration(export) => {
+                    let shape = match &export.declaration {
+                        ExportDefaultDeclarationKind::FunctionDeclaration(function) => {
+                            Some(ExportDeclaration::function(function))
+                        }
+                        ExportDefaultDeclarationKind::ClassDeclaration(class) => {
+                            Some(ExportDeclaration::class(class))
+                        }
+                        ExportDefaultDeclarationKind::TSInterfaceDeclaration(_) => {
+                            Some(ExportDeclaration::new(DeclarationKind::Interface))
+                        }
+                        kind => match kind.as_expression() {
+                            // Resolved against local declarations below
+                            Some(Expression::Identifier(ident)) => {
+                                specifiers.push((ident.name.to_string(), "default".to_string()));
+                                None
+                            }
+                            Some(expression) => Some(ExportDeclaration::expression(expression)),
+                            None => None,
+                        },
+                    };
+                    if let Some(shape) = shape {
+                        declarations.by_name.insert("default".to_string(), shape);
+                    }
+                }
+                _ => {
+                    if let Some(declaration) = statement.as_declaration() {
+                        locals.extend(declared(declaration));
+                    }
+                }
+            }
+        }
+
+        for (local, exported) in specifiers {
+            if let Some(shape) = locals.get(&local) {
+                declarations.by_name.insert(exported, *shape);
+            }
+        }
+
+        Some(declarations)
+    }
+
+    /// Declaration behind an exported name
+    pub fn get(&self, name: &str) -> Option<&ExportDeclaration> {
+        self.by_name.get(name)
+    }
+}
+
+/// Parsed declarations shared by every matcher compiled from one rule set
+#[derive(Debug, Clone, Default)]
+pub(crate) struct DeclarationCache {
+    modules: Arc<Mutex<HashMap<PathBuf, Option<Arc<ExportDeclarations>>>>>,
+}
+
+impl DeclarationCache {
+    /// Declarations of `module`, parsing its source on first use
+    ///
+    /// Returns `None` when the source can't be parsed.
+    pub(crate) fn get(&self, module: &Module) -> Option<Arc<ExportDeclarations>> {
+        let mut modules = self
+            .modules
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        modules
+            .entry(module.path.clone())
+            .or_insert_with(|| {
+                ExportDeclarations::parse(&module.path, &module.source).map(Arc::new)
+            })
+            .clone()
+    }
+}
+
+/// Names and shapes bound by a declaration
+fn declared(declaration: &Declaration<'_>) -> Vec<(String, ExportDeclaration)> {
+    let named = |name: &str, shape| vec![(name.to_string(), shape)];
+
+    match declaration {
+        Declaration::FunctionDeclaration(function) => function
+            .id
+            .as_ref()
+            .map(|id| named(id.name.as_str(), ExportDeclaration::function(function)))
+            .unwrap_or_default(),
+        Declaration::ClassDeclaration(class) => class
+            .id
+            .as_ref()
+            .map(|id| named(id.name.as_str(), ExportDeclaration::class(class)))
+            .unwrap_or_default(),
+        Declaration::TSEnumDeclaration(it) => named(
+            it.id.name.as_str(),
+            ExportDeclaration::new(DeclarationKind::Enum),
+        ),
+        Declaration::TSTypeAliasDeclaration(it) => named(
+            it.id.name.as_str(),
+            ExportDeclaration::new(DeclarationKind::TypeAlias),
+        ),
+        Declaration::TSInterfaceDeclaration(it) => named(
+            it.id.name.as_str(),
+            ExportDeclaration::new(DeclarationKind::Interface),
+        ),
+        Declaration::VariableDeclaration(variables) => variables
+            .declarations
+            .iter()
+            .filter_map(|declarator| {
+                let name = declarator.id.get_identifier_name()?;
+                let shape = declarator
+                    .init
+                    .as_ref()
+                    .map(ExportDeclaration::expression)
+                    .unwrap_or_else(|| ExportDeclaration::new(DeclarationKind::Variable));
+                Some((
+                    name.to_string(),
+                    ExportDeclaration {
+                        binding: VariableKind::from_oxc(variables.kind),
+                        ..shape
+                    },
+                ))
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Whether any `return` in a function body (outside nested functions) yields JSX
+fn body_returns_jsx(body: &FunctionBody<'_>) -> bool {
+    body.statements.iter().any(statement_returns_jsx)
+}
+
+fn statement_returns_jsx(statement: &Statement<'_>) -> bool {
+    match statement {
+        Statement::ReturnStatement(it) => it.argument.as_ref().is_some_and(is_jsx),
+        Statement::BlockStatement(it) => it.body.iter().any(statement_returns_jsx),
+        Statement::IfStatement(it) => {
+            statement_returns_jsx(&it.consequent)
+                || it.alternate.as_ref().is_some_and(statement_returns_jsx)
+        }
+        Statement::SwitchStatement(it) => it
+            .cases
+            .iter()
+            .any(|case| case.consequent.iter().any(statement_returns_jsx)),
+        Statement::TryStatement(it) => {
+            it.block.body.iter().any(statement_returns_jsx)
+                || it
+                    .handler
+                    .as_ref()
+                    .is_some_and(|handler| handler.body.body.iter().any(statement_returns_jsx))
+                || it
+                    .finalizer
+                    .as_ref()
+                    .is_some_and(|block| block.body.iter().any(statement_returns_jsx))
+        }
+        Statement::ForStatement(it) => statement_returns_jsx(&it.body),
+        Statement::ForInStatement(it) => statement_returns_jsx(&it.body),
+        Statement::ForOfStatement(it) => statement_returns_jsx(&it.body),
+        Statement::WhileStatement(it) => statement_returns_jsx(&it.body),
+        Statement::DoWhileStatement(it) => statement_returns_jsx(&it.body),
+        Statement::LabeledStatement(it) => statement_returns_jsx(&it.body),
+        _ => false,
+    }
+}
+
+fn is_component_base(name: &str) -> bool {
+    matches!(name, "Component" | "PureComponent")
+}
+
+fn is_pascal_case(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase())
+}
+
+fn is_jsx(expression: &Expression<'_>) -> bool {
+    match expression {
+        Expression::JSXElement(_) | Expression::JSXFragment(_) => true,
+        Expression::ParenthesizedExpression(it) => is_jsx(&it.expression),
+        Expression::ConditionalExpression(it) => is_jsx(&it.consequent) || is_jsx(&it.alternate),
+        Expression::LogicalExpression(it) => is_jsx(&it.left) || is_jsx(&it.right),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(path: &str, source: &str) -> ExportDeclarations {
+        ExportDeclarations::parse(Path::new(path), source).unwrap()
+    }
+
+    #[test]
+    fn test_parse_declaration_kinds() {
+        let declarations = parse(
+            "src/lib.ts",
+            r#"
+            export function load() {}
+            export async function fetchData() {}
+            export const handler = async () => {};
+            export const LIMIT = 10;
+            export let counter = 0;
+            export class Store {}
+            export enum Color { Red }
+            export type Id = string;
+            export interface Props { id: Id }
+            "#,
+        );
+
+        let kind = |name| declarations.get(name).unwrap().kind;
+        assert_eq!(kind("load"), DeclarationKind::Function);
+        assert!(declarations.get("fetchData").unwrap().is_async);
+        assert_eq!(kind("handler"), DeclarationKind::Function);
+        assert!(declarations.get("handler").unwrap().is_async);
+        assert_eq!(kind("LIMIT"), DeclarationKind::Variable);
+        assert_eq!(
+            declarations.get("counter").unwrap().binding,
+            Some(VariableKind::Let)
+        );
+        assert_eq!(kind("Store"), DeclarationKind::Class);
+        assert_eq!(kind("Color"), DeclarationKind::Enum);
+        assert_eq!(kind("Id"), DeclarationKind::TypeAlias);
+        assert_eq!(kind("Props"), DeclarationKind::Interface);
+    }
+
+    #[test]
+    fn test_parse_components_returning_jsx() {
+        let declarations = parse(
+            "src/Button.tsx",
+            r#"
+            export function Button() { if (!ok) { return null; } return <button />; }
+            export const Card = () => (<div />);
+            export const Input = forwardRef((props, ref) => <input ref={ref} />);
+            export function useToggle() { const render = () => <span />; return [on, render]; }
+            function Page() { return <main />; }
+            export { Page as Home };
+            export default Page;
+            "#,
+        );
+
+        assert!(declarations.get("Button").unwrap().returns_jsx);
+        assert!(declarations.get("Card").unwrap().returns_jsx);
+        assert!(declarations.get("Input").unwrap().returns_jsx);
+        assert!(!declarations.get("useToggle").unwrap().returns_jsx);
+        assert!(declarations.get("Home").unwrap().returns_jsx);
+        assert!(declarations.get("default").unwrap().returns_jsx);
+    }
+
+    #[test]
+    fn test_parse_class_and_wrapped_components() {
+        let declarations = parse(
+            "src/Profile.tsx",
+            r#"
+            export class Profile extends React.Component {}
+            export class Avatar extends PureComponent {}
+            export class Store extends EventEmitter {}
+            function Inner() { return createElement("div"); }
+            export const Memoized = memo(Inner);
+            export const Connected = connect(mapState)(Inner);
+            export const Config = defineConfig({ strict: true });
+            "#,
+        );
+
+        let is_component = |name| declarations.get(name).unwrap().is_component();
+        assert!(is_component("Profile"));
+        assert!(is_component("Avatar"));
+        assert!(!is_component("Store"));
+        assert!(is_component("Memoized"));
+        assert!(is_component("Connected"));
+        assert!(!is_component("Config"));
+    }
+
+    #[test]
+    fn test_parse_jsx_in_plain_javascript() {
+        let declarations = parse(
+            "src/Button.js",
+            "export const Button = () => <button />;\nexport function Link() { return <a />; }",
+        );
+
+        assert!(declarations.get("Button").unwrap().returns_jsx);
+        assert!(declarations.get("Link").unwrap().returns_jsx);
+    }
+
+    #[test]
+    fn test_parse_unsupported_extension() {
+        assert!(ExportDeclarations::parse(Path::new("App.vue"), "<template />").is_none());
+    }
+}
@@ -3,11 +3,11 @@
 //! This module provides TOML-driven framework detection, replacing hardcoded
 //! detection logic with declarative rules.
 
-use crate::{DetectionRule, DetectionType, Result, RuleError, TomlRuleFile};
+use crate::{DetectionRule, DetectionType, FrameworkMetadata, Result, RuleError, TomlRuleFile};
 use globset::{Glob, GlobMatcher};
 use regex::Regex;
-use std::collections::HashMap;
-use std::path::Path;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 
 /// Maximum regex pattern length (to prevent ReDoS attacks)
 const MAX_REGEX_LEN: usize = 500;
@@ -81,6 +81,8 @@ pub struct DetectionEvidence {
     pub confidence: f32,
     /// Additional context
     pub context: Option<String>,
+    /// File the evidence was found in (module or package.json)
+    pub file: Option<PathBuf>,
 }
 
 /// Detection result for a single framework
@@ -94,6 +96,70 @@ pub struct DetectionResult {
     pub evidence: Vec<DetectionEvidence>,
 }
 
+impl DetectionResult {
+    /// Files the evidence was found in, without duplicates
+    pub fn evidence_files(&self) -> Vec<PathBuf> {
+        let mut files: Vec<PathBuf> = Vec::new();
+        for file in self.evidence.iter().filter_map(|e| e.file.as_ref()) {
+            if !files.contains(file) {
+                files.push(file.clone());
+            }
+        }
+        files
+    }
+}
+
+/// Project-wide signals for [`FrameworkDetector::detect_project`]
+#[derive(Debug, Clone, Default)]
+pub struct ProjectSignals {
+    /// Path of package.json (recorded as evidence for package matches)
+    pub package_json: Option<PathBuf>,
+    /// Dependency names and versions from every package.json dependency section
+    pub dependencies: HashMap<String, String>,
+    /// package.json scripts
+    pub scripts: HashMap<String, String>,
+    /// Module paths with the import sources they contain
+    pub modules: Vec<(PathBuf, Vec<String>)>,
+}
+
+/// package.json sections whose entries count as dependencies
+const DEPENDENCY_SECTIONS: [&str; 4] = [
+    "dependencies",
+    "devDependencies",
+    "peerDependencies",
+    "optionalDependencies",
+];
+
+impl ProjectSignals {
+    /// Signals from a parsed package.json at `path`: its scripts and the
+    /// dependencies from every dependency section
+    pub fn from_package_json(path: impl Into<PathBuf>, package: &serde_json::Value) -> Self {
+        Self {
+            package_json: Some(path.into()),
+            dependencies: DEPENDENCY_SECTIONS
+                .iter()
+                .flat_map(|section| string_map(package, section))
+                .collect(),
+            scripts: string_map(package, "scripts"),
+            modules: Vec::new(),
+        }
+    }
+}
+
+/// Read a `{ "name": "value" }` object from package.json
+fn string_map(package: &serde_json::Value, key: &str) -> HashMap<String, String> {
+    package
+        .get(key)
+        .and_then(|v| v.as_object())
+        .map(|entries| {
+            entries
+                .iter()
+                .map(|(name, value)| (name.clone(), value.as_str().unwrap_or_default().to_string()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 /// Framework detector using TOML-based rules
 pub struct FrameworkDetector {
     /// Compiled detection rules by framework name
@@ -107,42 +173,52 @@ pub struct FrameworkDetector {
 impl FrameworkDetector {
     /// Create a new detector from TOML rule files
     pub fn from_toml_files(toml_files: Vec<(String, &str)>) -> Result<Self> {
+        let mut metadata = Vec::new();
+        for (_name, toml_content) in toml_files {
+            let file: TomlRuleFile = toml::from_str(toml_content).map_err(RuleError::TomlError)?;
+            metadata.extend(file.framework);
+        }
+
+        Self::from_frameworks(metadata)
+    }
+
+    /// Create a new detector from `[framework]` sections
+    ///
+    /// Frameworks are usually built-in ones combined with those from user
+    /// and project rule files (see [`load_detector_with_overrides`](crate::load_detector_with_overrides)).
+    pub fn from_frameworks(frameworks_metadata: Vec<FrameworkMetadata>) -> Result<Self> {
         let mut frameworks = HashMap::new();
         let mut priorities = HashMap::new();
         let mut suppresses = HashMap::new();
 
-        for (_name, toml_content) in toml_files {
-            let file: TomlRuleFile = toml::from_str(toml_content).map_err(RuleError::TomlError)?;
-
-            if let Some(metadata) = file.framework {
-                let framework_name = metadata.name.clone();
-                let priority = metadata.priority.unwrap_or(50);
-                priorities.insert(framework_name.clone(), priority);
+        for metadata in frameworks_metadata {
+            let framework_name = metadata.name.clone();
+            let priority = metadata.priority.unwrap_or(50);
+            priorities.insert(framework_name.clone(), priority);
 
-                if !metadata.suppresses.is_empty() {
-                    suppresses.insert(framework_name.clone(), metadata.suppresses);
-                }
+            if !metadata.suppresses.is_empty() {
+                suppresses.insert(framework_name.clone(), metadata.suppresses);
+            }
 
-                // Compile detection rules
-                let mut compiled_rules = Vec::new();
-                for rule in metadata.detection {
-                    // Validate weight if present
-                    if let Some(weight) = rule.weight {
-                        if !weight.is_finite() || weight < 0.0 {
-                            return Err(RuleError::InvalidPattern(format!(
-                                "Invalid weight {}: must be finite and non-negative",
-                                weight
-                            )));
-                        }
+            // Compile detection rules
+            let mut compiled_rules = Vec::new();
+            for rule in metadata.detection {
+                // Validate weight if present
+                if let Some(weight) = rule.weight {
+                    if !weight.is_finite() || weight < 0.0 {
+                        return Err(RuleError::InvalidPattern(format!(
+                            "Invalid weight {}: must be finite and non-negative",
+                            weight
+                        )));
                     }
-
-                    let compiled = CompiledDetectionRule::compile(rule)?;
-                    compiled_rules.push(compiled);
                 }
 
-                if !compiled_rules.is_empty() {
-                    frameworks.insert(framework_name, compiled_rules);
-                }
+                let compiled = CompiledDetectionRule::compile(rule)?;
+                compiled_rules.push(compiled);
+            }
+
+            if !compiled_rules.is_empty() {
+                frameworks.insert(framework_name, compiled_rules);
             }
         }
 
@@ -153,10 +229,59 @@ impl FrameworkDetector {
         })
     }
 
+    /// Detect frameworks across a whole project
+    ///
+    /// Evidence from package.json, every module's imports and every module
+    /// path is combined before suppression, and each piece records the file
+    /// it came from.
+    pub fn detect_project(&self, signals: &ProjectSignals) -> Vec<DetectionResult> {
+        let mut results = HashMap::new();
+
+        self.collect_from_package_json(
+            &signals.dependencies,
+            &signals.scripts,
+            signals.package_json.as_deref(),
+            &mut results,
+        );
+        for (path, imports) in &signals.modules {
+            self.collect_from_imports(imports, Some(path), &mut results);
+            self.collect_from_path(path, &mut results);
+        }
+
+        self.finalize_results(results)
+    }
+
     /// Detect frameworks from module imports
     pub fn detect_from_imports(&self, imports: &[String]) -> Vec<DetectionResult> {
-        let mut results: HashMap<String, DetectionResult> = HashMap::new();
+        let mut results = HashMap::new();
+        self.collect_from_imports(imports, None, &mut results);
+        self.finalize_results(results)
+    }
 
+    /// Detect frameworks from file paths
+    pub fn detect_from_path(&self, path: &Path) -> Vec<DetectionResult> {
+        let mut results = HashMap::new();
+        self.collect_from_path(path, &mut results);
+        self.finalize_results(results)
+    }
+
+    /// Detect frameworks from package.json data
+    pub fn detect_from_package_json(
+        &self,
+        dependencies: &HashMap<String, String>,
+        scripts: &HashMap<String, String>,
+    ) -> Vec<DetectionResult> {
+        let mut results = HashMap::new();
+        self.collect_from_package_json(dependencies, scripts, None, &mut results);
+        self.finalize_results(results)
+    }
+
+    fn collect_from_imports(
+        &self,
+        imports: &[String],
+        file: Option<&Path>,
+        results: &mut HashMap<String, DetectionResult>,
+    ) {
         for (framework_name, rules) in &self.frameworks {
             for rule in rules {
                 if rule.rule.rule_type != DetectionType::Import {
@@ -182,28 +307,17 @@ impl FrameworkDetector {
                             rule: format!("import:{}", rule.rule.pattern),
                             confidence,
                             context: Some(format!("import: {}", import)),
+                            file: file.map(Path::to_path_buf),
                         };
 
-                        results
-                            .entry(framework_name.clone())
-                            .or_insert_with(|| DetectionResult {
-                                framework: framework_name.clone(),
-                                confidence: 0.0,
-                                evidence: Vec::new(),
-                            })
-                            .evidence
-                            .push(evidence);
+                        add_evidence(results, framework_name, evidence);
                     }
                 }
             }
         }
-
-        self.finalize_results(results)
     }
 
-    /// Detect frameworks from file paths
-    pub fn detect_from_path(&self, path: &Path) -> Vec<DetectionResult> {
-        let mut results: HashMap<String, DetectionResult> = HashMap::new();
+    fn collect_from_path(&self, path: &Path, results: &mut HashMap<String, DetectionResult>) {
         let path_str = path.to_string_lossy();
 
         for (framework_name, rules) in &self.frameworks {
@@ -236,32 +350,22 @@ impl FrameworkDetector {
                         ),
                         confidence: weight,
                         context: Some(format!("path: {}", path_str)),
+                        file: Some(path.to_path_buf()),
                     };
 
-                    results
-                        .entry(framework_name.clone())
-                        .or_insert_with(|| DetectionResult {
-                            framework: framework_name.clone(),
-                            confidence: 0.0,
-                            evidence: Vec::new(),
-                        })
-                        .evidence
-                        .push(evidence);
+                    add_evidence(results, framework_name, evidence);
                 }
             }
         }
-
-        self.finalize_results(results)
     }
 
-    /// Detect frameworks from package.json data
-    pub fn detect_from_package_json(
+    fn collect_from_package_json(
         &self,
         dependencies: &HashMap<String, String>,
         scripts: &HashMap<String, String>,
-    ) -> Vec<DetectionResult> {
-        let mut results: HashMap<String, DetectionResult> = HashMap::new();
-
+        file: Option<&Path>,
+        results: &mut HashMap<String, DetectionResult>,
+    ) {
         for (framework_name, rules) in &self.frameworks {
             for rule in rules {
                 let weight = rule.rule.weight.unwrap_or(1.0);
@@ -285,22 +389,13 @@ impl FrameworkDetector {
                         ),
                         confidence: weight,
                         context: Some(format!("package.json: {}", rule.rule.pattern)),
+                        file: file.map(Path::to_path_buf),
                     };
 
-                    results
-                        .entry(framework_name.clone())
-                        .or_insert_with(|| DetectionResult {
-                            framework: framework_name.clone(),
-                            confidence: 0.0,
-                            evidence: Vec::new(),
-                        })
-                        .evidence
-                        .push(evidence);
+                    add_evidence(results, framework_name, evidence);
                 }
             }
         }
-
-        self.finalize_results(results)
     }
 
     /// Finalize detection results: calculate confidence, apply suppression, and sort
@@ -308,11 +403,14 @@ impl FrameworkDetector {
         &self,
         mut results: HashMap<String, DetectionResult>,
     ) -> Vec<DetectionResult> {
-        // Calculate total confidence scores
+        // Calculate total confidence scores: each rule counts once, however
+        // many imports or files it matched
         for result in results.values_mut() {
+            let mut seen = HashSet::new();
             result.confidence = result
                 .evidence
                 .iter()
+                .filter(|e| seen.insert(e.rule.as_str()))
                 .map(|e| e.confidence)
                 .sum::<f32>()
                 .min(1.0);
@@ -336,8 +434,6 @@ impl FrameworkDetector {
 
     /// Apply suppression rules (e.g., Next.js suppresses React)
     fn apply_suppression(&self, results: &mut Vec<DetectionResult>) {
-        use std::collections::HashSet;
-
         // Build set of frameworks that should be suppressed
         let suppressed_frameworks: HashSet<&str> = results
             .iter()
@@ -351,6 +447,23 @@ impl FrameworkDetector {
     }
 }
 
+/// Adds `evidence` to the result for `framework`
+fn add_evidence(
+    results: &mut HashMap<String, DetectionResult>,
+    framework: &str,
+    evidence: DetectionEvidence,
+) {
+    results
+        .entry(framework.to_string())
+        .or_insert_with(|| DetectionResult {
+            framework: framework.to_string(),
+            confidence: 0.0,
+            evidence: Vec::new(),
+        })
+        .evidence
+        .push(evidence);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -379,6 +492,73 @@ mod tests {
         assert!(results[0].confidence > 0.0);
     }
 
+    #[test]
+    fn test_detect_project_records_evidence_files() {
+        let toml = r#"
+            [framework]
+            name = "React"
+            priority = 50
+
+            [[framework.detection]]
+            type = "import"
+            pattern = "^react$"
+            weight = 0.6
+
+            [[framework.detection]]
+            type = "package_dependency"
+            pattern = "react"
+            weight = 0.3
+        "#;
+
+        let detector =
+            FrameworkDetector::from_toml_files(vec![("React".to_string(), toml)]).unwrap();
+
+        let signals = ProjectSignals {
+            package_json: Some(PathBuf::from("package.json")),
+            dependencies: HashMap::from([("react".to_string(), "^18.0.0".to_string())]),
+            scripts: HashMap::new(),
+            modules: vec![
+                (PathBuf::from("src/a.tsx"), vec!["react".to_string()]),
+                (PathBuf::from("src/b.tsx"), vec!["react".to_string()]),
+                (PathBuf::from("src/c.ts"), vec!["lodash".to_string()]),
+            ],
+        };
+        let results = detector.detect_project(&signals);
+
+        assert_eq!(results.len(), 1);
+        // Each rule counts once, however many modules it matched
+        assert!((results[0].confidence - 0.9).abs() < f32::EPSILON);
+        assert_eq!(
+            results[0].evidence_files(),
+            vec![
+                PathBuf::from("package.json"),
+                PathBuf::from("src/a.tsx"),
+                PathBuf::from("src/b.tsx"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_project_signals_from_package_json() {
+        let package = serde_json::json!({
+            "name": "app",
+            "scripts": { "dev": "next dev" },
+            "dependencies": { "react": "^18.0.0" },
+            "devDependencies": { "typescript": "^5.0.0" },
+            "peerDependencies": { "react-dom": "^18.0.0" },
+            "optionalDependencies": { "fsevents": "^2.0.0" },
+            "keywords": ["not-a-dependency"]
+        });
+
+        let signals = ProjectSignals::from_package_json("package.json", &package);
+
+        assert_eq!(signals.package_json, Some(PathBuf::from("package.json")));
+        assert_eq!(signals.scripts["dev"], "next dev");
+        assert_eq!(signals.dependencies.len(), 4);
+        assert_eq!(signals.dependencies["typescript"], "^5.0.0");
+        assert!(signals.modules.is_empty());
+    }
+
     #[test]
     fn test_suppression() {
         let nextjs_toml = r#"
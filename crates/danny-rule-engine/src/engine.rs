@@ -3,8 +3,10 @@
 //! This is the main execution engine that takes TOML rules and applies them
 //! to the module graph.
 
-use crate::{CompiledMatcher, Result, RuleAction, TomlRule};
-use fob::graph::{Module, ModuleGraph};
+use crate::declarations::DeclarationCache;
+use crate::{CompiledMatcher, Result, RuleAction, Severity, TomlRule};
+use fob::graph::{Module, ModuleGraph, SourceSpan};
+use std::path::PathBuf;
 
 /// The rule engine executes compiled rules against modules
 #[derive(Clone)]
@@ -22,11 +24,16 @@ pub(crate) struct CompiledRule {
 
 impl RuleEngine {
     /// Create a new rule engine from TOML rules
+    ///
+    /// All rules share one declaration cache, so each module is parsed at
+    /// most once for `export_type` matching.
     pub fn new(toml_rules: Vec<TomlRule>) -> Result<Self> {
+        let declarations = DeclarationCache::default();
         let rules = toml_rules
             .into_iter()
             .map(|toml_rule| {
-                let matcher = CompiledMatcher::from_toml(&toml_rule.matcher)?;
+                let matcher =
+                    CompiledMatcher::with_declarations(&toml_rule.matcher, declarations.clone())?;
                 let action = toml_rule.action.to_action();
 
                 Ok(CompiledRule {
@@ -40,6 +47,96 @@ impl RuleEngine {
         Ok(Self { rules })
     }
 
+    /// Find the first mark-used rule matching an export
+    ///
+    /// Returns the rule name and its `reason`, so callers can report which
+    /// rule kept an export alive.
+    pub fn matching_rule(
+        &self,
+        module: &Module,
+        export: &fob::graph::Export,
+    ) -> Option<(&str, Option<&str>)> {
+        self.rules.iter().find_map(|rule| match &rule.action {
+            RuleAction::MarkUsed { reason }
+                if !rule.matcher.is_file_only() && rule.matcher.matches(module, export) =>
+            {
+                Some((rule.name.as_str(), reason.as_deref()))
+            }
+            _ => None,
+        })
+    }
+
+    /// Evaluate every rule against an export
+    ///
+    /// Unlike rule application, nothing short-circuits: each rule reports
+    /// whether it matched and, if not, the first condition that failed.
+    /// File-level rules are evaluated against the module alone.
+    pub fn explain(&self, module: &Module, export: &fob::graph::Export) -> Vec<RuleEvaluation> {
+        let placeholder = placeholder_export();
+
+        self.rules
+            .iter()
+            .map(|rule| {
+                let export = if rule.matcher.is_file_only() {
+                    &placeholder
+                } else {
+                    export
+                };
+                let failed_condition = rule.matcher.failed_condition(module, export);
+
+                RuleEvaluation {
+                    rule: rule.name.clone(),
+                    matched: failed_condition.is_none(),
+                    failed_condition,
+                    action: rule.action.clone(),
+                }
+            })
+            .collect()
+    }
+
+    /// Collect matches of rules with `warn` or `severity` actions
+    ///
+    /// These rules don't change analysis; each match is reported so the
+    /// caller can surface it as a finding. Export-level rules report every
+    /// matching export, file-level rules report the module once, located
+    /// at the matching import when the rule has an import condition.
+    pub fn rule_matches(&self, modules: &[Module]) -> Vec<RuleMatch> {
+        let mut matches = Vec::new();
+
+        for module in modules {
+            for rule in &self.rules {
+                let Some((severity, message)) = rule.action.report() else {
+                    continue;
+                };
+
+                let report =
+                    |export: Option<&fob::graph::Export>, span: Option<&SourceSpan>| RuleMatch {
+                        rule: rule.name.clone(),
+                        message: message.to_string(),
+                        severity,
+                        module: module.path.clone(),
+                        export_name: export.map(|export| export.name.clone()),
+                        span: span.cloned(),
+                    };
+
+                if rule.matcher.is_file_only() {
+                    if rule.matcher.matches(module, &placeholder_export()) {
+                        let import = rule.matcher.matching_import(module);
+                        matches.push(report(None, import.map(|import| &import.span)));
+                    }
+                } else {
+                    for export in &module.exports {
+                        if rule.matcher.matches(module, export) {
+                            matches.push(report(Some(export), Some(&export.span)));
+                        }
+                    }
+                }
+            }
+        }
+
+        matches
+    }
+
     /// Apply all rules to the module graph
     ///
     /// This mutates the module graph by marking exports as framework-used
@@ -100,6 +197,12 @@ impl RuleEngine {
 
         for module in modules {
             for rule in &self.rules {
+                // Reporting rules are collected by `rule_matches` and must not
+                // stop later rules from applying
+                if rule.action.report().is_some() {
+                    continue;
+                }
+
                 if rule.matcher.is_file_only() {
                     // File-level rule (e.g., skip entire file)
                     if rule.matcher.matches(module, &placeholder_export()) {
@@ -168,13 +271,8 @@ impl RuleEngine {
             RuleAction::Skip => {
                 stats.files_skipped += 1;
             }
-            RuleAction::Warn { message: _ } => {
-                // Warning actions are logged but don't affect stats
-                // The caller can handle warnings as needed
-            }
-            RuleAction::SetSeverity { level: _ } => {
-                // Severity override actions are handled by the caller
-                // They don't affect stats directly
+            RuleAction::Warn { .. } | RuleAction::SetSeverity { .. } => {
+                // Reporting actions don't affect stats; see `rule_matches`
             }
         }
 
@@ -182,6 +280,36 @@ impl RuleEngine {
     }
 }
 
+/// How one rule evaluated against an export (see [`RuleEngine::explain`])
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuleEvaluation {
+    /// Rule name
+    pub rule: String,
+    /// Whether every condition matched
+    pub matched: bool,
+    /// First condition that failed, named after its TOML field
+    pub failed_condition: Option<String>,
+    /// Action the rule takes when it matches
+    pub action: RuleAction,
+}
+
+/// A match of a rule with a `warn` or `severity` action
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuleMatch {
+    /// Rule name
+    pub rule: String,
+    /// Message from the rule's action
+    pub message: String,
+    /// Severity from the rule's action
+    pub severity: Severity,
+    /// Module the rule matched
+    pub module: PathBuf,
+    /// Matched export (export-level rules only)
+    pub export_name: Option<String>,
+    /// Location of the matched export or import
+    pub span: Option<SourceSpan>,
+}
+
 /// Statistics from rule application
 #[derive(Debug, Default)]
 pub struct RuleStats {
@@ -192,11 +320,10 @@ pub struct RuleStats {
 
 /// Create a placeholder export for file-only matching
 fn placeholder_export() -> fob::graph::Export {
-    use std::path::PathBuf;
     fob::graph::Export {
         name: String::new(),
         kind: fob::graph::ExportKind::Named,
-        span: fob::graph::SourceSpan {
+        span: SourceSpan {
             file: PathBuf::new(),
             start: 0,
             end: 0,
@@ -213,6 +340,55 @@ fn placeholder_export() -> fob::graph::Export {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use fob::graph::{Export, ExportKind, Import, ImportKind, ModuleId, SourceType};
+
+    fn span(path: &str, start: u32, end: u32) -> SourceSpan {
+        SourceSpan {
+            file: PathBuf::from(path),
+            start,
+            end,
+        }
+    }
+
+    fn create_module(path: &str, imports: Vec<&str>, exports: Vec<&str>) -> Module {
+        let path_buf = PathBuf::from(path);
+        let module_id = ModuleId::new(&path_buf).unwrap();
+
+        let imports = imports
+            .into_iter()
+            .enumerate()
+            .map(|(i, source)| Import {
+                source: source.to_string(),
+                specifiers: vec![],
+                kind: ImportKind::Static,
+                resolved_to: None,
+                span: span(path, i as u32 * 20, i as u32 * 20 + 18),
+            })
+            .collect();
+        let exports = exports
+            .into_iter()
+            .map(|name| Export {
+                name: name.to_string(),
+                kind: ExportKind::Named,
+                span: span(path, 100, 120),
+                is_type_only: false,
+                is_framework_used: false,
+                is_used: false,
+                re_exported_from: None,
+                came_from_commonjs: false,
+                usage_count: None,
+            })
+            .collect();
+
+        Module::builder(module_id, path_buf, SourceType::JavaScript)
+            .imports(imports)
+            .exports(exports)
+            .build()
+    }
+
+    fn parse_rules(toml: &str) -> Vec<TomlRule> {
+        toml::from_str::<crate::TomlRuleFile>(toml).unwrap().rules
+    }
 
     #[test]
     fn test_create_engine() {
@@ -220,4 +396,143 @@ mod tests {
         let engine = RuleEngine::new(rules).unwrap();
         assert_eq!(engine.rules.len(), 0);
     }
+
+    #[test]
+    fn test_rule_matches_reports_exports_and_imports() {
+        let engine = RuleEngine::new(parse_rules(
+            r#"
+[[rules]]
+name = "no-legacy-exports"
+[rules.match]
+path_starts_with = ["legacy/"]
+export_pattern = ".*"
+[rules.action]
+warn = true
+message = "Exports from legacy/ are deprecated"
+
+[[rules]]
+name = "no-moment"
+[rules.match]
+import_from = "moment"
+[rules.action]
+severity = "error"
+message = "Use date-fns instead of moment"
+
+[[rules]]
+name = "keep-handlers"
+[rules.match]
+export_pattern = "^handler$"
+[rules.action]
+mark_used = true
+"#,
+        ))
+        .unwrap();
+
+        let modules = vec![
+            create_module("legacy/date.ts", vec!["react", "moment"], vec!["format"]),
+            create_module("src/api.ts", vec![], vec!["handler"]),
+        ];
+
+        let matches = engine.rule_matches(&modules);
+        assert_eq!(matches.len(), 2);
+
+        assert_eq!(matches[0].rule, "no-legacy-exports");
+        assert_eq!(matches[0].severity, Severity::Warn);
+        assert_eq!(matches[0].export_name.as_deref(), Some("format"));
+        assert_eq!(matches[0].span, Some(span("legacy/date.ts", 100, 120)));
+
+        assert_eq!(matches[1].rule, "no-moment");
+        assert_eq!(matches[1].message, "Use date-fns instead of moment");
+        assert_eq!(matches[1].severity, Severity::Error);
+        assert_eq!(matches[1].export_name, None);
+        // Located at the `moment` import, not the first import
+        assert_eq!(matches[1].span, Some(span("legacy/date.ts", 20, 38)));
+    }
+
+    #[test]
+    fn test_explain_reports_every_rule() {
+        let engine = RuleEngine::new(parse_rules(
+            r#"
+[[rules]]
+name = "react-hooks"
+[rules.match]
+import_from = "react"
+export_pattern = "^use[A-Z]"
+[rules.action]
+mark_used = true
+reason = "React hook"
+
+[[rules]]
+name = "pages"
+[rules.match]
+path_starts_with = ["pages/"]
+[rules.action]
+skip = true
+
+[[rules]]
+name = "legacy"
+[rules.match]
+all = [{ path_pattern = "^src/" }, { not_export_name = ["useAuth"] }]
+[rules.action]
+warn = true
+message = "Legacy export"
+"#,
+        ))
+        .unwrap();
+
+        let module = create_module("src/auth.ts", vec!["react"], vec!["useAuth"]);
+        let evaluations = engine.explain(&module, &module.exports[0]);
+
+        assert_eq!(evaluations.len(), 3);
+        assert!(evaluations[0].matched);
+        assert_eq!(evaluations[0].failed_condition, None);
+        assert_eq!(evaluations[0].action.to_string(), "mark used (React hook)");
+
+        assert!(!evaluations[1].matched);
+        assert_eq!(
+            evaluations[1].failed_condition.as_deref(),
+            Some("path_starts_with")
+        );
+        assert_eq!(evaluations[1].action.to_string(), "skip");
+
+        assert_eq!(
+            evaluations[2].failed_condition.as_deref(),
+            Some("all[1].not_export_name")
+        );
+        assert_eq!(evaluations[2].action.to_string(), "warn: Legacy export");
+    }
+
+    #[tokio::test]
+    async fn test_reporting_rules_do_not_stop_later_rules() {
+        let engine = RuleEngine::new(parse_rules(
+            r#"
+[[rules]]
+name = "no-moment"
+[rules.match]
+import_from = "moment"
+[rules.action]
+warn = true
+
+[[rules]]
+name = "skip-dates"
+[rules.match]
+path_ends_with = ["date.ts"]
+[rules.action]
+skip = true
+"#,
+        ))
+        .unwrap();
+
+        let modules = vec![create_module("date.ts", vec!["moment"], vec![])];
+        let mut actions = Vec::new();
+        engine
+            .apply_with_callback(&modules, |_, _, action| {
+                actions.push(action.clone());
+                Ok(())
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(actions, vec![RuleAction::Skip]);
+    }
 }
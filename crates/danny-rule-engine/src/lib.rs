@@ -18,7 +18,7 @@
 //! [rules.match]
 //! import_from = "react"
 //! export_pattern = "^use[A-Z]\\w+"
-//! export_type = "function"
+//! export_type = "hook"
 //! [rules.action]
 //! mark_used = true
 //! reason = "React hook pattern"
@@ -27,25 +27,32 @@
 pub mod bridge;
 pub mod built_in;
 pub mod constants;
+pub mod declarations;
 pub mod detection;
 pub mod engine;
 pub mod entry_points;
 pub mod loader;
 pub mod matcher;
+pub mod testing;
 pub mod toml_rule;
 
 // Re-export core types
 pub use bridge::TomlFrameworkRule;
-pub use built_in::{load_built_in_entry_points, load_built_in_rules};
+pub use built_in::{
+    load_built_in_entry_points, load_built_in_rules, load_detector_with_overrides,
+    load_rules_with_overrides,
+};
 pub use constants::*;
-pub use detection::{DetectionEvidence, DetectionResult, FrameworkDetector};
-pub use engine::RuleEngine;
+pub use declarations::{DeclarationKind, ExportDeclaration, ExportDeclarations, VariableKind};
+pub use detection::{DetectionEvidence, DetectionResult, FrameworkDetector, ProjectSignals};
+pub use engine::{RuleEngine, RuleEvaluation, RuleMatch};
 pub use entry_points::extract_entry_points;
 pub use loader::RuleLoader;
 pub use matcher::CompiledMatcher;
+pub use testing::{run_rule_tests, RuleTestFailure, RuleTestResult};
 pub use toml_rule::{
     DetectionRule, DetectionType, EntryPointPattern, ExportType, FrameworkMetadata, RuleAction,
-    RuleMatcher, Severity, TomlRule, TomlRuleFile,
+    RuleMatcher, RuleTest, Severity, TomlRule, TomlRuleFile,
 };
 
 /// Result type for rule operations
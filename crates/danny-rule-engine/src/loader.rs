@@ -6,7 +6,7 @@ use crate::constants::{
     MAX_DIRECTORY_DEPTH, MAX_REGEX_LENGTH, MAX_TOML_FILE_SIZE, REGEX_DFA_SIZE_LIMIT,
     REGEX_SIZE_LIMIT,
 };
-use crate::{Result, RuleError, TomlRule, TomlRuleFile};
+use crate::{FrameworkMetadata, Result, RuleError, TomlRule, TomlRuleFile};
 use danny_fs::{DiscoveryOptions as FsDiscoveryOptions, FileSystem};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -24,6 +24,10 @@ pub struct RuleLoader<F: FileSystem> {
 
     /// Project rules (.danny/rules/)
     project_path: Option<PathBuf>,
+
+    /// Extra rule directories (e.g. `--rules <dir>`), loaded last, with the
+    /// filesystem to read them through (the project's if `None`)
+    extra_paths: Vec<(PathBuf, Option<Arc<dyn FileSystem>>)>,
 }
 
 impl<F: FileSystem> RuleLoader<F> {
@@ -34,20 +38,100 @@ impl<F: FileSystem> RuleLoader<F> {
             builtin_path: Self::find_builtin_rules(),
             user_path: Self::find_user_rules(),
             project_path: Some(project_root.join(".danny/rules")),
+            extra_paths: Vec::new(),
         }
     }
 
+    /// Add a rule directory with higher priority than project rules
+    ///
+    /// Relative paths are resolved against the project root. Unlike the
+    /// default sources, a missing directory is an error.
+    pub fn with_rules_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.extra_paths.push((dir.into(), None));
+        self
+    }
+
+    /// Add a rule directory read through its own filesystem
+    ///
+    /// Use this for directories outside the project, e.g. rules shared
+    /// across repositories. `dir` must be inside `fs`'s root.
+    pub fn with_rules_dir_in(mut self, fs: Arc<dyn FileSystem>, dir: impl Into<PathBuf>) -> Self {
+        self.extra_paths.push((dir.into(), Some(fs)));
+        self
+    }
+
+    /// Add a rule directory that may live outside the project
+    ///
+    /// Relative paths are resolved against the current directory, and the
+    /// directory is read through a filesystem rooted at it.
+    #[cfg(feature = "native-fs")]
+    pub fn with_shared_rules_dir(self, dir: impl AsRef<Path>) -> Result<Self> {
+        let dir = std::path::absolute(dir.as_ref()).map_err(RuleError::IoError)?;
+        let fs = danny_fs::NativeFileSystem::new(&dir).map_err(|e| RuleError::LoadError {
+            path: dir.display().to_string(),
+            source: Box::new(e),
+        })?;
+        let root = fs.project_root().to_path_buf();
+        Ok(self.with_rules_dir_in(Arc::new(fs), root))
+    }
+
     /// Load all rules from all sources
     ///
     /// Priority order (higher overrides lower):
     /// 1. Built-in rules (lowest priority)
     /// 2. User rules
-    /// 3. Project rules (highest priority)
+    /// 3. Project rules
+    /// 4. Directories added with [`with_rules_dir`](Self::with_rules_dir) (highest priority)
+    ///
+    /// A rule overrides a lower-priority rule with the same name. The result
+    /// is sorted by each rule's `priority` field (highest first).
     ///
     /// Note: Built-in and user rules are only loaded if they're within the FileSystem's
     /// project root. This ensures WASM compatibility where only project-local rules are available.
     pub async fn load_all(&self) -> Result<Vec<TomlRule>> {
         let mut all_rules = Vec::new();
+        for (fs, dir) in self.rule_directories().await? {
+            for (path, file) in self.load_files_from_directory(fs, &dir).await? {
+                override_rules(&mut all_rules, rules_from_file(file, &path)?);
+            }
+        }
+
+        // Sort by priority (higher priority first), then by name for stable ordering
+        // Using sort_by instead of sort_by_key ensures deterministic behavior
+        all_rules.sort_by(|a, b| {
+            b.priority
+                .unwrap_or(0)
+                .cmp(&a.priority.unwrap_or(0))
+                .then_with(|| a.name.cmp(&b.name))
+        });
+
+        Ok(all_rules)
+    }
+
+    /// Load the `[framework]` sections of all rule files
+    ///
+    /// Sources are read in the same order as [`load_all`](Self::load_all);
+    /// a framework replaces a lower-priority framework with the same name.
+    /// Rule files without a `[framework]` section are skipped.
+    pub async fn load_frameworks(&self) -> Result<Vec<FrameworkMetadata>> {
+        let mut frameworks: Vec<FrameworkMetadata> = Vec::new();
+        for (fs, dir) in self.rule_directories().await? {
+            for (_, file) in self.load_files_from_directory(fs, &dir).await? {
+                if let Some(framework) = file.framework {
+                    frameworks.retain(|f| f.name != framework.name);
+                    frameworks.push(framework);
+                }
+            }
+        }
+
+        Ok(frameworks)
+    }
+
+    /// Existing rule directories and the filesystem each is read through,
+    /// lowest priority first
+    async fn rule_directories(&self) -> Result<Vec<(&dyn FileSystem, PathBuf)>> {
+        let project_fs: &dyn FileSystem = self.fs.as_ref();
+        let mut directories = Vec::new();
         let project_root = self.fs.project_root();
 
         // Load built-in rules (only if within project root)
@@ -62,7 +146,7 @@ impl<F: FileSystem> RuleLoader<F> {
                             .await
                             .map_err(RuleError::IoError)?
                     {
-                        all_rules.extend(self.load_from_directory(&normalized).await?);
+                        directories.push((project_fs, normalized));
                     }
                 }
                 Err(_) => {
@@ -83,7 +167,7 @@ impl<F: FileSystem> RuleLoader<F> {
                             .await
                             .map_err(RuleError::IoError)?
                     {
-                        all_rules.extend(self.load_from_directory(&normalized).await?);
+                        directories.push((project_fs, normalized));
                     }
                 }
                 Err(_) => {
@@ -95,35 +179,48 @@ impl<F: FileSystem> RuleLoader<F> {
         // Load project rules (always within project root)
         if let Some(ref path) = self.project_path {
             if self.fs.exists(path).await.map_err(RuleError::IoError)? {
-                all_rules.extend(self.load_from_directory(path).await?);
+                directories.push((project_fs, path.clone()));
             }
         }
 
-        // Sort by priority (higher priority first), then by name for stable ordering
-        // Using sort_by instead of sort_by_key ensures deterministic behavior
-        all_rules.sort_by(|a, b| {
-            b.priority
-                .unwrap_or(0)
-                .cmp(&a.priority.unwrap_or(0))
-                .then_with(|| a.name.cmp(&b.name))
-        });
+        // Load explicitly requested directories (must exist)
+        for (path, fs) in &self.extra_paths {
+            let fs = fs.as_deref().unwrap_or(project_fs);
+            let exists = fs.exists(path).await.map_err(|e| RuleError::LoadError {
+                path: path.display().to_string(),
+                source: Box::new(e),
+            })?;
+            if !exists {
+                return Err(RuleError::LoadError {
+                    path: path.display().to_string(),
+                    source: Box::new(std::io::Error::new(
+                        std::io::ErrorKind::NotFound,
+                        "Rules directory not found",
+                    )),
+                });
+            }
+            directories.push((fs, path.clone()));
+        }
 
-        Ok(all_rules)
+        Ok(directories)
     }
 
-    /// Load rules from a specific directory
-    async fn load_from_directory(&self, dir: &Path) -> Result<Vec<TomlRule>> {
-        let mut rules = Vec::new();
+    /// Load and parse the rule files in a specific directory
+    async fn load_files_from_directory(
+        &self,
+        fs: &dyn FileSystem,
+        dir: &Path,
+    ) -> Result<Vec<(PathBuf, TomlRuleFile)>> {
+        let mut files = Vec::new();
 
         // Normalize the directory path for security checks
-        let normalized_dir =
-            self.fs
-                .normalize_path(dir)
-                .await
-                .map_err(|e| RuleError::LoadError {
-                    path: dir.display().to_string(),
-                    source: Box::new(e),
-                })?;
+        let normalized_dir = fs
+            .normalize_path(dir)
+            .await
+            .map_err(|e| RuleError::LoadError {
+                path: dir.display().to_string(),
+                source: Box::new(e),
+            })?;
 
         // Discover .toml files in the directory
         // Note: We set a very large max_file_size here to discover all files,
@@ -141,8 +238,7 @@ impl<F: FileSystem> RuleLoader<F> {
 
         // Use ".toml" extension (loader's filesystem helper expects the dot-prefixed extension)
         // Use original dir path for discovery - normalization happens per-file for security checks
-        let discovered_files = self
-            .fs
+        let discovered_files = fs
             .discover_files(dir, &[".toml"], &[], &discovery_options)
             .await
             .map_err(|e| RuleError::LoadError {
@@ -155,8 +251,7 @@ impl<F: FileSystem> RuleLoader<F> {
             // Security: Verify path is within the expected directory
             // (FileSystem already validates this, but double-check for safety)
             let normalized_path =
-                self.fs
-                    .normalize_path(&path)
+                fs.normalize_path(&path)
                     .await
                     .map_err(|e| RuleError::LoadError {
                         path: path.display().to_string(),
@@ -168,14 +263,10 @@ impl<F: FileSystem> RuleLoader<F> {
             }
 
             // Security: Check file size before reading
-            let metadata = self
-                .fs
-                .metadata(&path)
-                .await
-                .map_err(|e| RuleError::LoadError {
-                    path: path.display().to_string(),
-                    source: Box::new(e),
-                })?;
+            let metadata = fs.metadata(&path).await.map_err(|e| RuleError::LoadError {
+                path: path.display().to_string(),
+                source: Box::new(e),
+            })?;
 
             if metadata.size > MAX_TOML_FILE_SIZE {
                 return Err(RuleError::LoadError {
@@ -191,17 +282,22 @@ impl<F: FileSystem> RuleLoader<F> {
             }
 
             // Load and parse the file
-            let file_rules = self.load_from_file(&path).await?;
-            rules.extend(file_rules);
+            let file = Self::read_rule_file(fs, &path).await?;
+            files.push((path, file));
         }
 
-        Ok(rules)
+        Ok(files)
     }
 
     /// Load rules from a single TOML file
+    #[cfg(test)]
     async fn load_from_file(&self, path: &Path) -> Result<Vec<TomlRule>> {
-        let contents = self
-            .fs
+        rules_from_file(Self::read_rule_file(self.fs.as_ref(), path).await?, path)
+    }
+
+    /// Read and parse a single TOML rule file
+    async fn read_rule_file(fs: &dyn FileSystem, path: &Path) -> Result<TomlRuleFile> {
+        let contents = fs
             .read_to_string(path)
             .await
             .map_err(|e| RuleError::LoadError {
@@ -209,22 +305,10 @@ impl<F: FileSystem> RuleLoader<F> {
                 source: Box::new(e),
             })?;
 
-        let file: TomlRuleFile = toml::from_str(&contents).map_err(|e| RuleError::LoadError {
+        toml::from_str(&contents).map_err(|e| RuleError::LoadError {
             path: path.display().to_string(),
             source: Box::new(e),
-        })?;
-
-        // Security: Validate regex patterns to prevent ReDoS
-        for rule in &file.rules {
-            if let Some(ref pattern) = rule.matcher.export_pattern {
-                validate_regex_pattern(pattern, path)?;
-            }
-            if let Some(ref pattern) = rule.matcher.path_pattern {
-                validate_regex_pattern(pattern, path)?;
-            }
-        }
-
-        Ok(file.rules)
+        })
     }
 
     /// Find built-in rules directory
@@ -310,6 +394,33 @@ impl<F: FileSystem> RuleLoader<F> {
     }
 }
 
+/// Takes the rules out of a parsed rule file, tagged with its framework name
+fn rules_from_file(file: TomlRuleFile, path: &Path) -> Result<Vec<TomlRule>> {
+    let framework = file.framework.as_ref().map(|f| f.name.clone());
+    let mut rules = file.rules;
+
+    // Security: Validate regex patterns to prevent ReDoS
+    for rule in &mut rules {
+        rule.framework = framework.clone();
+        if let Some(ref pattern) = rule.matcher.export_pattern {
+            validate_regex_pattern(pattern, path)?;
+        }
+        if let Some(ref pattern) = rule.matcher.path_pattern {
+            validate_regex_pattern(pattern, path)?;
+        }
+    }
+
+    Ok(rules)
+}
+
+/// Adds `rules` to `existing`, replacing rules with the same name
+fn override_rules(existing: &mut Vec<TomlRule>, rules: Vec<TomlRule>) {
+    for rule in rules {
+        existing.retain(|r| r.name != rule.name);
+        existing.push(rule);
+    }
+}
+
 /// Validates regex patterns to prevent ReDoS attacks
 ///
 /// Note: This function performs basic validation during rule loading.
@@ -375,10 +486,97 @@ mark_used = true
             builtin_path: None,
             user_path: None,
             project_path: Some(temp_dir.path().to_path_buf()),
+            extra_paths: Vec::new(),
         };
 
         let rules = loader.load_from_file(&rule_file).await.unwrap();
         assert_eq!(rules.len(), 1);
         assert_eq!(rules[0].name, "test-rule");
+        assert_eq!(rules[0].framework, None);
+    }
+
+    fn write_rule(dir: &Path, file: &str, framework: &str, name: &str, pattern: &str) {
+        std::fs::create_dir_all(dir).unwrap();
+        std::fs::write(
+            dir.join(file),
+            format!(
+                r#"
+[framework]
+name = "{framework}"
+
+[[rules]]
+name = "{name}"
+
+[rules.match]
+export_pattern = "{pattern}"
+
+[rules.action]
+mark_used = true
+"#
+            ),
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_rules_dir_overrides_project_rules() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_rules = temp_dir.path().join(".danny/rules");
+        let extra_rules = temp_dir.path().join("tools/rules");
+        write_rule(&project_rules, "routes.toml", "Router", "routes", "^route");
+        write_rule(&project_rules, "di.toml", "DI", "providers", "Provider$");
+        write_rule(&extra_rules, "routes.toml", "Router v2", "routes", "^page");
+
+        let fs = Arc::new(NativeFileSystem::new(temp_dir.path()).unwrap());
+        let loader = RuleLoader {
+            fs,
+            builtin_path: None,
+            user_path: None,
+            project_path: Some(project_rules),
+            extra_paths: Vec::new(),
+        }
+        .with_rules_dir(&extra_rules);
+
+        let rules = loader.load_all().await.unwrap();
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[0].name, "providers");
+        assert_eq!(rules[0].framework.as_deref(), Some("DI"));
+        assert_eq!(rules[1].name, "routes");
+        assert_eq!(rules[1].framework.as_deref(), Some("Router v2"));
+        assert_eq!(rules[1].matcher.export_pattern.as_deref(), Some("^page"));
+    }
+
+    #[tokio::test]
+    async fn test_shared_rules_dir_outside_project() {
+        let project = TempDir::new().unwrap();
+        let shared = TempDir::new().unwrap();
+        write_rule(shared.path(), "di.toml", "DI", "providers", "Provider$");
+
+        let fs = Arc::new(NativeFileSystem::new(project.path()).unwrap());
+        let loader = RuleLoader {
+            fs,
+            builtin_path: None,
+            user_path: None,
+            project_path: None,
+            extra_paths: Vec::new(),
+        }
+        .with_shared_rules_dir(shared.path())
+        .unwrap();
+
+        let rules = loader.load_all().await.unwrap();
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].name, "providers");
+        assert_eq!(loader.load_frameworks().await.unwrap()[0].name, "DI");
+    }
+
+    #[tokio::test]
+    async fn test_missing_rules_dir_is_an_error() {
+        let temp_dir = TempDir::new().unwrap();
+        let fs = Arc::new(NativeFileSystem::new(temp_dir.path()).unwrap());
+        let loader =
+            RuleLoader::new(fs, temp_dir.path()).with_rules_dir(temp_dir.path().join("nope"));
+
+        let err = loader.load_all().await.unwrap_err();
+        assert!(err.to_string().contains("Rules directory not found"));
     }
 }
@@ -4,8 +4,9 @@
 //! Regex patterns are compiled once and cached to avoid runtime overhead.
 
 use crate::constants::{
-    MAX_CONTENT_SIZE, MAX_REGEX_LENGTH, REGEX_DFA_SIZE_LIMIT, REGEX_SIZE_LIMIT,
+    MAX_CONTENT_SIZE, MAX_MATCHER_DEPTH, MAX_REGEX_LENGTH, REGEX_DFA_SIZE_LIMIT, REGEX_SIZE_LIMIT,
 };
+use crate::declarations::DeclarationCache;
 use crate::{Result, RuleError, RuleMatcher};
 use fob::graph::{Export, Module};
 use regex::{Regex, RegexBuilder};
@@ -100,6 +101,19 @@ pub struct CompiledMatcher {
 
     /// Maximum usage count threshold
     max_usage_count: Option<usize>,
+
+    // Boolean groups
+    /// At least one must match (empty = no condition)
+    any: Vec<CompiledMatcher>,
+
+    /// All must match
+    all: Vec<CompiledMatcher>,
+
+    /// Must not match
+    not: Option<Box<CompiledMatcher>>,
+
+    /// Parsed export declarations for `export_type`, shared with sub-matchers
+    declarations: DeclarationCache,
 }
 
 impl Clone for CompiledMatcher {
@@ -147,13 +161,43 @@ impl Clone for CompiledMatcher {
             content_regex_pattern: self.content_regex_pattern.clone(),
             min_usage_count: self.min_usage_count,
             max_usage_count: self.max_usage_count,
+            any: self.any.clone(),
+            all: self.all.clone(),
+            not: self.not.clone(),
+            declarations: self.declarations.clone(),
         }
     }
 }
 
 impl CompiledMatcher {
     /// Compile a RuleMatcher into an efficient executable form
+    ///
+    /// `any`/`all`/`not` groups compile recursively with the same regex
+    /// limits, up to [`MAX_MATCHER_DEPTH`] levels deep.
     pub fn from_toml(matcher: &RuleMatcher) -> Result<Self> {
+        Self::with_declarations(matcher, DeclarationCache::default())
+    }
+
+    /// Compile a matcher that reuses already-parsed export declarations
+    pub(crate) fn with_declarations(
+        matcher: &RuleMatcher,
+        declarations: DeclarationCache,
+    ) -> Result<Self> {
+        Self::compile(matcher, 0, &declarations)
+    }
+
+    fn compile(
+        matcher: &RuleMatcher,
+        depth: usize,
+        declarations: &DeclarationCache,
+    ) -> Result<Self> {
+        if depth > MAX_MATCHER_DEPTH {
+            return Err(RuleError::InvalidPattern(format!(
+                "Matcher groups exceed maximum nesting depth of {}",
+                MAX_MATCHER_DEPTH
+            )));
+        }
+
         // Validate usage count range (min must be <= max)
         if let (Some(min), Some(max)) = (matcher.min_usage_count, matcher.max_usage_count) {
             if min > max {
@@ -224,6 +268,22 @@ impl CompiledMatcher {
             .map(|pattern| compile_regex_safe(pattern))
             .transpose()?;
 
+        // Compile boolean groups
+        let compile_group = |matchers: &Option<Vec<RuleMatcher>>| -> Result<Vec<Self>> {
+            matchers
+                .iter()
+                .flatten()
+                .map(|sub| Self::compile(sub, depth + 1, declarations))
+                .collect()
+        };
+        let any = compile_group(&matcher.any)?;
+        let all = compile_group(&matcher.all)?;
+        let not = matcher
+            .not
+            .as_ref()
+            .map(|sub| Self::compile(sub, depth + 1, declarations).map(Box::new))
+            .transpose()?;
+
         Ok(Self {
             export_regex,
             export_regex_pattern: matcher.export_pattern.clone(),
@@ -249,6 +309,10 @@ impl CompiledMatcher {
             content_regex_pattern: matcher.content_pattern.clone(),
             min_usage_count: matcher.min_usage_count,
             max_usage_count: matcher.max_usage_count,
+            any,
+            all,
+            not,
+            declarations: declarations.clone(),
         })
     }
 
@@ -256,18 +320,36 @@ impl CompiledMatcher {
     ///
     /// Returns `true` if ALL conditions match (AND logic).
     /// Short-circuits on first non-match for performance.
-    /// Negation checks are performed after positive checks.
+    /// Negation checks are performed after positive checks, and boolean
+    /// groups last.
     pub fn matches(&self, module: &Module, export: &Export) -> bool {
+        self.check_import_conditions(module).is_none()
+            && self.check_export_conditions(module, export).is_none()
+            && self.check_path_conditions(module).is_none()
+            && self.check_content_conditions(module).is_none()
+            && self.check_negation_conditions(module, export).is_none()
+            && self.check_usage_count_conditions(export).is_none()
+            && self.check_group_conditions(module, export)
+    }
+
+    /// Name the first condition that rejects a module and export
+    ///
+    /// Conditions are named after their TOML fields (e.g. `export_pattern`),
+    /// with group members as `all[1].path_pattern`. Returns `None` when the
+    /// matcher matches.
+    pub fn failed_condition(&self, module: &Module, export: &Export) -> Option<String> {
         self.check_import_conditions(module)
-            && self.check_export_conditions(export)
-            && self.check_path_conditions(module)
-            && self.check_content_conditions(module)
-            && self.check_negation_conditions(module, export)
-            && self.check_usage_count_conditions(export)
+            .or_else(|| self.check_export_conditions(module, export))
+            .or_else(|| self.check_path_conditions(module))
+            .or_else(|| self.check_content_conditions(module))
+            .or_else(|| self.check_negation_conditions(module, export))
+            .or_else(|| self.check_usage_count_conditions(export))
+            .map(str::to_string)
+            .or_else(|| self.failed_group_condition(module, export))
     }
 
     /// Check all import-related conditions
-    fn check_import_conditions(&self, module: &Module) -> bool {
+    fn check_import_conditions(&self, module: &Module) -> Option<&'static str> {
         // Check import_from (requires module inspection)
         // Support multiple sources: match if ANY source matches
         if let Some(ref required_imports) = self.import_from {
@@ -275,7 +357,7 @@ impl CompiledMatcher {
                 .iter()
                 .any(|source| module_imports_from(module, source))
             {
-                return false;
+                return Some("import_from");
             }
         }
 
@@ -286,7 +368,7 @@ impl CompiledMatcher {
                 .iter()
                 .any(|import| regex.is_match(&import.source))
             {
-                return false;
+                return Some("import_from_pattern");
             }
         }
 
@@ -297,10 +379,21 @@ impl CompiledMatcher {
             || self.import_namespace.is_some())
             && !self.check_import_specifier_conditions(module)
         {
-            return false;
+            return Some(self.import_specifier_condition());
         }
 
-        true
+        None
+    }
+
+    /// Name the specifier condition reported when no import satisfies them
+    fn import_specifier_condition(&self) -> &'static str {
+        if self.import_specifiers.is_some() {
+            "import_specifiers"
+        } else if self.import_default.is_some() {
+            "import_default"
+        } else {
+            "import_namespace"
+        }
     }
 
     /// Check if any import matches source requirements AND specifier requirements
@@ -371,61 +464,63 @@ impl CompiledMatcher {
     }
 
     /// Check all export-related conditions
-    fn check_export_conditions(&self, export: &Export) -> bool {
+    fn check_export_conditions(&self, module: &Module, export: &Export) -> Option<&'static str> {
         // Check export name (hash lookup: O(1))
         if let Some(ref names) = self.export_names {
             if !names.contains(&export.name) {
-                return false;
+                return Some("export_name");
             }
         }
 
         // Check export pattern (compiled regex)
         if let Some(ref regex) = self.export_regex {
             if !regex.is_match(&export.name) {
-                return false;
+                return Some("export_pattern");
             }
         }
 
-        // Check export type
+        // Check export type against the parsed declaration (cached per module)
         if let Some(ref typ) = self.export_type {
-            if !typ.matches_fob_export(export) {
-                return false;
+            let declarations = self.declarations.get(module);
+            let declaration = declarations.as_ref().and_then(|d| d.get(&export.name));
+            if !typ.matches_declaration(export, declaration) {
+                return Some("export_type");
             }
         }
 
-        true
+        None
     }
 
     /// Check all path-related conditions
-    fn check_path_conditions(&self, module: &Module) -> bool {
+    fn check_path_conditions(&self, module: &Module) -> Option<&'static str> {
         let path_str = module.path.to_string_lossy();
 
         if let Some(ref prefixes) = self.path_starts_with {
             if !prefixes.iter().any(|prefix| path_str.starts_with(prefix)) {
-                return false;
+                return Some("path_starts_with");
             }
         }
 
         if let Some(ref suffixes) = self.path_ends_with {
             if !suffixes.iter().any(|suffix| path_str.ends_with(suffix)) {
-                return false;
+                return Some("path_ends_with");
             }
         }
 
         if let Some(ref regex) = self.path_regex {
             if !regex.is_match(&path_str) {
-                return false;
+                return Some("path_pattern");
             }
         }
 
-        true
+        None
     }
 
     /// Check file content patterns (expensive I/O operation)
     ///
     /// Note: Content regex matching is not supported in WASM builds
     /// due to filesystem limitations. On WASM, this check is skipped.
-    fn check_content_conditions(&self, module: &Module) -> bool {
+    fn check_content_conditions(&self, module: &Module) -> Option<&'static str> {
         #[cfg(not(target_arch = "wasm32"))]
         {
             if let Some(ref regex) = self.content_regex {
@@ -434,22 +529,22 @@ impl CompiledMatcher {
                     if metadata.len() > MAX_CONTENT_SIZE {
                         // File too large - skip content pattern check (fail the rule)
                         // This prevents memory exhaustion attacks
-                        return false;
+                        return Some("content_pattern");
                     }
 
                     // Read file content and check pattern
                     // Note: This is relatively expensive, so we do it after other checks
                     if let Ok(content) = std::fs::read_to_string(&module.path) {
                         if !regex.is_match(&content) {
-                            return false;
+                            return Some("content_pattern");
                         }
                     } else {
                         // File can't be read - fail the rule (conservative approach)
-                        return false;
+                        return Some("content_pattern");
                     }
                 } else {
                     // Can't get metadata - fail the rule
-                    return false;
+                    return Some("content_pattern");
                 }
             }
         }
@@ -462,11 +557,11 @@ impl CompiledMatcher {
             let _ = module; // Suppress unused warning
         }
 
-        true
+        None
     }
 
     /// Check all negation conditions
-    fn check_negation_conditions(&self, module: &Module, export: &Export) -> bool {
+    fn check_negation_conditions(&self, module: &Module, export: &Export) -> Option<&'static str> {
         let path_str = module.path.to_string_lossy();
 
         // Check NOT import_from
@@ -475,36 +570,36 @@ impl CompiledMatcher {
                 .iter()
                 .any(|source| module_imports_from(module, source))
             {
-                return false;
+                return Some("not_import_from");
             }
         }
 
         // Check NOT export name
         if let Some(ref not_names) = self.not_export_names {
             if not_names.contains(&export.name) {
-                return false;
+                return Some("not_export_name");
             }
         }
 
         // Check NOT export pattern
         if let Some(ref regex) = self.not_export_regex {
             if regex.is_match(&export.name) {
-                return false;
+                return Some("not_export_pattern");
             }
         }
 
         // Check NOT path pattern
         if let Some(ref regex) = self.not_path_regex {
             if regex.is_match(&path_str) {
-                return false;
+                return Some("not_path_pattern");
             }
         }
 
-        true
+        None
     }
 
     /// Check usage count thresholds
-    fn check_usage_count_conditions(&self, export: &Export) -> bool {
+    fn check_usage_count_conditions(&self, export: &Export) -> Option<&'static str> {
         // Usage count matching uses a conservative approach:
         // - If usage_count is None (not computed), we reject the match to avoid false positives
         // - If usage_count is Some(n), we check against min/max thresholds
@@ -513,33 +608,93 @@ impl CompiledMatcher {
                 None => {
                     // Usage count not available - fail conservatively
                     // This prevents false positives when usage data hasn't been computed
-                    return false;
+                    return Some("usage_count (not computed)");
                 }
                 Some(count) => {
                     // Check minimum usage count
                     if let Some(min) = self.min_usage_count {
                         if count < min {
-                            return false;
+                            return Some("min_usage_count");
                         }
                     }
 
                     // Check maximum usage count
                     if let Some(max) = self.max_usage_count {
                         if count > max {
-                            return false;
+                            return Some("max_usage_count");
                         }
                     }
                 }
             }
         }
 
-        true
+        None
+    }
+
+    /// Find the import that satisfies this matcher's import conditions
+    ///
+    /// Used to locate file-level matches such as "don't import moment".
+    /// Returns `None` when neither the matcher nor its `any`/`all` groups
+    /// have an import source condition.
+    pub fn matching_import<'a>(&self, module: &'a Module) -> Option<&'a fob::graph::Import> {
+        if self.import_from.is_none() && self.import_from_regex.is_none() {
+            return self
+                .any
+                .iter()
+                .chain(&self.all)
+                .find_map(|sub| sub.matching_import(module));
+        }
+
+        module.imports.iter().find(|import| {
+            self.import_from
+                .as_ref()
+                .is_none_or(|sources| sources.contains(&import.source))
+                && self
+                    .import_from_regex
+                    .as_ref()
+                    .is_none_or(|regex| regex.is_match(&import.source))
+                && self.check_required_specifiers(import)
+        })
+    }
+
+    /// Check `any`/`all`/`not` groups
+    fn check_group_conditions(&self, module: &Module, export: &Export) -> bool {
+        (self.any.is_empty() || self.any.iter().any(|sub| sub.matches(module, export)))
+            && self.all.iter().all(|sub| sub.matches(module, export))
+            && !self
+                .not
+                .as_ref()
+                .is_some_and(|sub| sub.matches(module, export))
+    }
+
+    /// Name the group condition that rejects a module and export
+    fn failed_group_condition(&self, module: &Module, export: &Export) -> Option<String> {
+        if !self.any.is_empty() && !self.any.iter().any(|sub| sub.matches(module, export)) {
+            return Some("any".to_string());
+        }
+
+        for (index, sub) in self.all.iter().enumerate() {
+            if let Some(condition) = sub.failed_condition(module, export) {
+                return Some(format!("all[{}].{}", index, condition));
+            }
+        }
+
+        if self
+            .not
+            .as_ref()
+            .is_some_and(|sub| sub.matches(module, export))
+        {
+            return Some("not".to_string());
+        }
+
+        None
     }
 
     /// Check if this matcher only applies to files (no export checking needed)
     pub fn is_file_only(&self) -> bool {
         // If no export-specific conditions, it's file-only
         // Usage count conditions are export-specific, so they make it export-level
+        // A group with any export-level sub-matcher makes the whole matcher export-level
         self.export_regex.is_none()
             && self.export_names.is_none()
             && self.export_type.is_none()
@@ -547,6 +702,9 @@ impl CompiledMatcher {
             && self.not_export_names.is_none()
             && self.min_usage_count.is_none()
             && self.max_usage_count.is_none()
+            && self.any.iter().all(CompiledMatcher::is_file_only)
+            && self.all.iter().all(CompiledMatcher::is_file_only)
+            && self.not.as_ref().is_none_or(|sub| sub.is_file_only())
     }
 }
 
@@ -1191,4 +1349,161 @@ mod tests {
         );
         assert!(!compiled.matches(&module_wrong_source, &create_test_export("test")));
     }
+
+    fn compile_toml(toml: &str) -> Result<CompiledMatcher> {
+        let matcher: RuleMatcher = toml::from_str(toml).unwrap();
+        CompiledMatcher::from_toml(&matcher)
+    }
+
+    #[test]
+    fn test_any_group() {
+        let compiled = compile_toml(
+            r#"
+export_pattern = "^(get|generate)"
+any = [
+    { path_starts_with = ["pages/"] },
+    { path_starts_with = ["app/"] },
+]
+"#,
+        )
+        .unwrap();
+
+        let export = create_test_export("getServerSideProps");
+        assert!(compiled.matches(&create_test_module("pages/index.tsx", vec![]), &export));
+        assert!(compiled.matches(&create_test_module("app/page.tsx", vec![]), &export));
+        assert!(!compiled.matches(&create_test_module("src/page.tsx", vec![]), &export));
+        assert!(!compiled.matches(
+            &create_test_module("pages/index.tsx", vec![]),
+            &create_test_export("Page")
+        ));
+        assert!(!compiled.is_file_only());
+    }
+
+    #[test]
+    fn test_all_and_not_groups() {
+        let compiled = compile_toml(
+            r#"
+[[all]]
+path_starts_with = ["src/"]
+
+[[all]]
+any = [{ import_from = "react" }, { import_from = "preact" }]
+
+[not]
+any = [{ path_ends_with = [".test.tsx"] }, { export_name = ["default"] }]
+"#,
+        )
+        .unwrap();
+
+        let module = create_test_module("src/Button.tsx", vec!["preact"]);
+        assert!(compiled.matches(&module, &create_test_export("Button")));
+        assert!(!compiled.matches(&module, &create_test_export("default")));
+        assert!(!compiled.matches(
+            &create_test_module("src/Button.test.tsx", vec!["react"]),
+            &create_test_export("Button")
+        ));
+        assert!(!compiled.matches(
+            &create_test_module("src/Button.tsx", vec!["vue"]),
+            &create_test_export("Button")
+        ));
+        // `export_name` inside `not` makes the rule export-level
+        assert!(!compiled.is_file_only());
+    }
+
+    #[test]
+    fn test_groups_without_export_conditions_are_file_only() {
+        let compiled = compile_toml(
+            r#"
+any = [{ import_from = "moment" }, { import_from = "moment-timezone" }]
+"#,
+        )
+        .unwrap();
+
+        assert!(compiled.is_file_only());
+        let module = create_test_module("src/date.ts", vec!["lodash", "moment-timezone"]);
+        assert!(compiled.matches(&module, &create_test_export("")));
+        assert_eq!(
+            compiled.matching_import(&module).map(|i| i.source.as_str()),
+            Some("moment-timezone")
+        );
+    }
+
+    #[test]
+    fn test_group_patterns_use_safety_limits() {
+        let long_pattern = "a".repeat(MAX_REGEX_LENGTH + 1);
+        let result = compile_toml(&format!(
+            "any = [{{ export_pattern = \"{long_pattern}\" }}]"
+        ));
+        assert!(matches!(result, Err(RuleError::InvalidPattern(_))));
+
+        let result = compile_toml(r#"not = { path_pattern = "[" }"#);
+        assert!(matches!(result, Err(RuleError::InvalidPattern(_))));
+    }
+
+    #[test]
+    fn test_group_nesting_depth_limit() {
+        let nested = |depth: usize| {
+            let mut matcher = RuleMatcher {
+                export_pattern: Some("^x".to_string()),
+                ..Default::default()
+            };
+            for _ in 0..depth {
+                matcher = RuleMatcher {
+                    not: Some(Box::new(matcher)),
+                    ..Default::default()
+                };
+            }
+            matcher
+        };
+
+        assert!(CompiledMatcher::from_toml(&nested(MAX_MATCHER_DEPTH)).is_ok());
+        assert!(matches!(
+            CompiledMatcher::from_toml(&nested(MAX_MATCHER_DEPTH + 1)),
+            Err(RuleError::InvalidPattern(_))
+        ));
+    }
+
+    #[test]
+    fn test_export_type_uses_parsed_declarations() {
+        let mut module = create_test_module("src/components.tsx", vec!["react"]);
+        module.source = r#"
+            export function Button() { return <button />; }
+            export function useCounter() { return useState(0); }
+            export const formatDate = (date) => date.toISOString();
+            export const THEME = { dark: true };
+            export type ButtonProps = { label: string };
+            "#
+        .into();
+
+        let hook = compile_toml(r#"export_type = "hook""#).unwrap();
+        assert!(hook.matches(&module, &create_test_export("useCounter")));
+        assert!(!hook.matches(&module, &create_test_export("Button")));
+
+        let component = compile_toml(r#"export_type = "component""#).unwrap();
+        assert!(component.matches(&module, &create_test_export("Button")));
+        assert!(!component.matches(&module, &create_test_export("formatDate")));
+
+        let function = compile_toml(r#"export_type = "function""#).unwrap();
+        assert!(function.matches(&module, &create_test_export("formatDate")));
+        assert!(!function.matches(&module, &create_test_export("THEME")));
+        assert_eq!(
+            function.failed_condition(&module, &create_test_export("ButtonProps")),
+            Some("export_type".to_string())
+        );
+
+        let type_alias = compile_toml(r#"export_type = "type""#).unwrap();
+        assert!(type_alias.matches(&module, &create_test_export("ButtonProps")));
+    }
+
+    #[test]
+    fn test_export_type_without_parsable_source() {
+        let module = create_test_module("src/App.vue", vec!["vue"]);
+        let function = compile_toml(r#"export_type = "function""#).unwrap();
+        assert!(!function.matches(&module, &create_test_export("useThing")));
+
+        let mut type_export = create_test_export("Props");
+        type_export.is_type_only = true;
+        let type_alias = compile_toml(r#"export_type = "type""#).unwrap();
+        assert!(type_alias.matches(&module, &type_export));
+    }
 }
@@ -0,0 +1,391 @@
+//! Fixture tests for rule files
+//!
+//! Rule files can carry `[[tests]]` tables with inline source files and the
+//! exports their rules should (or shouldn't) mark as used:
+//!
+//! ```toml
+//! [[tests]]
+//! name = "data fetching"
+//! rule = "pages-data-fetching"
+//! expect_used = ["src/pages/index.tsx#getServerSideProps"]
+//! expect_unused = ["src/pages/index.tsx#helper"]
+//!
+//! [tests.files]
+//! "src/pages/index.tsx" = "export function getServerSideProps() {}\nexport const helper = 1;"
+//! ```
+//!
+//! Each test is analyzed on its own, with only the rules of its file.
+
+use crate::{Result, RuleError, RuleTest, TomlFrameworkRule, TomlRuleFile};
+use danny_fs::FileSystem;
+use fob::graph::FrameworkRule;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Component, Path, PathBuf};
+
+/// Outcome of one `[[tests]]` entry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleTestResult {
+    /// Test name
+    pub name: String,
+    /// Rule under test (`None` when the test applies every rule in the file)
+    pub rule: Option<String>,
+    /// Failed expectations (empty when the test passed)
+    pub failures: Vec<RuleTestFailure>,
+}
+
+impl RuleTestResult {
+    /// Whether every expectation held
+    pub fn passed(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// A failed rule test expectation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RuleTestFailure {
+    /// The test names a rule the file doesn't define
+    UnknownRule(String),
+    /// A fixture path is absolute or escapes the fixture root
+    InvalidFixturePath(String),
+    /// An expectation isn't in `path#export` form
+    InvalidExpectation(String),
+    /// The fixtures have no such export
+    ExportNotFound(String),
+    /// The export should have been marked used but wasn't
+    NotMarkedUsed(String),
+    /// The export was marked used but shouldn't have been
+    UnexpectedlyMarkedUsed { export: String, rule: String },
+}
+
+impl fmt::Display for RuleTestFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownRule(rule) => write!(f, "no rule named '{}' in this file", rule),
+            Self::InvalidFixturePath(path) => {
+                write!(
+                    f,
+                    "fixture path '{}' must be relative to the fixture root",
+                    path
+                )
+            }
+            Self::InvalidExpectation(target) => {
+                write!(f, "expectation '{}' is not in 'path#export' form", target)
+            }
+            Self::ExportNotFound(target) => write!(f, "{} does not exist in the fixtures", target),
+            Self::NotMarkedUsed(target) => write!(f, "{} was not marked used", target),
+            Self::UnexpectedlyMarkedUsed { export, rule } => {
+                write!(f, "{} was marked used by '{}'", export, rule)
+            }
+        }
+    }
+}
+
+/// Run every `[[tests]]` entry of a rule file
+///
+/// Fixtures are written through `fs`, each test in its own directory under
+/// the project root, so the filesystem should be a scratch location.
+/// `framework` names the rules in findings, like the file's `[framework]`.
+pub async fn run_rule_tests<F: FileSystem>(
+    fs: &F,
+    framework: &str,
+    file: &TomlRuleFile,
+) -> Result<Vec<RuleTestResult>> {
+    let mut results = Vec::new();
+
+    for (index, test) in file.tests.iter().enumerate() {
+        let root = fs.project_root().join(format!("test-{}", index + 1));
+        let name = test
+            .name
+            .clone()
+            .unwrap_or_else(|| format!("test #{}", index + 1));
+
+        let failures = run_rule_test(fs, &root, framework, file, test).await?;
+        results.push(RuleTestResult {
+            name,
+            rule: test.rule.clone(),
+            failures,
+        });
+    }
+
+    Ok(results)
+}
+
+/// Run one test, returning its failed expectations
+async fn run_rule_test<F: FileSystem>(
+    fs: &F,
+    root: &Path,
+    framework: &str,
+    file: &TomlRuleFile,
+    test: &RuleTest,
+) -> Result<Vec<RuleTestFailure>> {
+    let rules: Vec<_> = file
+        .rules
+        .iter()
+        .filter(|rule| test.rule.as_ref().is_none_or(|name| &rule.name == name))
+        .cloned()
+        .collect();
+    if let Some(name) = &test.rule {
+        if rules.is_empty() {
+            return Ok(vec![RuleTestFailure::UnknownRule(name.clone())]);
+        }
+    }
+
+    let invalid_paths: Vec<_> = test
+        .files
+        .keys()
+        .chain(test.entry_points.iter().flatten())
+        .filter(|path| !is_fixture_path(path))
+        .map(|path| RuleTestFailure::InvalidFixturePath(path.clone()))
+        .collect();
+    if !invalid_paths.is_empty() {
+        return Ok(invalid_paths);
+    }
+
+    for (path, contents) in &test.files {
+        let path = root.join(path);
+        if let Some(parent) = path.parent() {
+            fs.create_dir_all(parent).await?;
+        }
+        fs.write(&path, contents).await?;
+    }
+
+    let entry_points: Vec<PathBuf> = match &test.entry_points {
+        Some(entries) => entries.iter().map(|entry| root.join(entry)).collect(),
+        None => test.files.keys().map(|path| root.join(path)).collect(),
+    };
+
+    let exports = marked_exports(root, framework, rules, &entry_points).await?;
+
+    let mut failures = Vec::new();
+    for target in &test.expect_used {
+        match lookup(&exports, target) {
+            Err(failure) => failures.push(failure),
+            Ok(None) => failures.push(RuleTestFailure::NotMarkedUsed(target.clone())),
+            Ok(Some(_)) => {}
+        }
+    }
+    for target in &test.expect_unused {
+        match lookup(&exports, target) {
+            Err(failure) => failures.push(failure),
+            Ok(Some(rule)) => failures.push(RuleTestFailure::UnexpectedlyMarkedUsed {
+                export: target.clone(),
+                rule: rule.to_string(),
+            }),
+            Ok(None) => {}
+        }
+    }
+
+    Ok(failures)
+}
+
+/// Analyze the fixtures and map each export (`path#export`) to the rule
+/// that marked it used, if any
+async fn marked_exports(
+    root: &Path,
+    framework: &str,
+    rules: Vec<crate::TomlRule>,
+    entry_points: &[PathBuf],
+) -> Result<HashMap<String, Option<String>>> {
+    let framework_rule = TomlFrameworkRule::new(framework.to_string(), String::new(), rules)?;
+    let options = fob::analysis::AnalyzeOptions {
+        framework_rules: vec![framework_rule.clone_box()],
+        // Usage counts let fixtures exercise min/max_usage_count
+        compute_usage_counts: true,
+    };
+
+    let analysis = fob::analysis::analyze_with_options(entry_points, options)
+        .await
+        .map_err(|e| RuleError::LoadError {
+            path: root.display().to_string(),
+            source: Box::new(e),
+        })?;
+    let modules = analysis
+        .graph
+        .modules()
+        .await
+        .map_err(|e| RuleError::LoadError {
+            path: root.display().to_string(),
+            source: Box::new(e),
+        })?;
+
+    // Fob may report canonical paths (e.g. /private/var on macOS)
+    let canonical_root = std::fs::canonicalize(root).ok();
+
+    let mut exports = HashMap::new();
+    for module in &modules {
+        let Some(relative) = module.path.strip_prefix(root).ok().or_else(|| {
+            canonical_root
+                .as_ref()
+                .and_then(|canonical| module.path.strip_prefix(canonical).ok())
+        }) else {
+            continue;
+        };
+        let relative = relative.to_string_lossy().replace('\\', "/");
+
+        for export in &module.exports {
+            let marked_by = export.is_framework_used.then(|| {
+                framework_rule
+                    .engine()
+                    .matching_rule(module, export)
+                    .map_or_else(|| framework.to_string(), |(rule, _)| rule.to_string())
+            });
+            exports.insert(format!("{}#{}", relative, export.name), marked_by);
+        }
+    }
+
+    Ok(exports)
+}
+
+/// Look up an expectation, returning the rule that marked it used
+fn lookup<'a>(
+    exports: &'a HashMap<String, Option<String>>,
+    target: &str,
+) -> std::result::Result<Option<&'a str>, RuleTestFailure> {
+    if !target.contains('#') {
+        return Err(RuleTestFailure::InvalidExpectation(target.to_string()));
+    }
+
+    exports
+        .get(target)
+        .map(Option::as_deref)
+        .ok_or_else(|| RuleTestFailure::ExportNotFound(target.to_string()))
+}
+
+/// Fixture paths must stay inside the fixture root
+fn is_fixture_path(path: &str) -> bool {
+    !path.is_empty()
+        && Path::new(path)
+            .components()
+            .all(|component| matches!(component, Component::Normal(_) | Component::CurDir))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rule_tests() {
+        let file: TomlRuleFile = toml::from_str(
+            r#"
+[[rules]]
+name = "pages-data-fetching"
+[rules.match]
+export_pattern = "^getServerSideProps$"
+[rules.action]
+mark_used = true
+
+[[tests]]
+name = "data fetching"
+rule = "pages-data-fetching"
+expect_used = ["src/pages/index.tsx#getServerSideProps"]
+expect_unused = ["src/pages/index.tsx#helper"]
+
+[tests.files]
+"src/pages/index.tsx" = "export function getServerSideProps() {}"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(file.tests.len(), 1);
+        let test = &file.tests[0];
+        assert_eq!(test.rule.as_deref(), Some("pages-data-fetching"));
+        assert_eq!(test.files.len(), 1);
+        assert_eq!(test.expect_used, ["src/pages/index.tsx#getServerSideProps"]);
+        assert_eq!(test.entry_points, None);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_run_rule_tests() {
+        let file: TomlRuleFile = toml::from_str(
+            r#"
+[[rules]]
+name = "loaders"
+[rules.match]
+export_pattern = "^loader$"
+[rules.action]
+mark_used = true
+
+[[rules]]
+name = "everything"
+[rules.match]
+export_pattern = ".*"
+[rules.action]
+mark_used = true
+
+[[tests]]
+name = "loaders only"
+rule = "loaders"
+expect_used = ["routes/home.ts#loader"]
+expect_unused = ["routes/home.ts#helper"]
+[tests.files]
+"routes/home.ts" = "export function loader() {}\nexport const helper = 1;"
+
+[[tests]]
+expect_unused = ["routes/home.ts#helper", "routes/home.ts#missing"]
+[tests.files]
+"routes/home.ts" = "export function loader() {}\nexport const helper = 1;"
+
+[[tests]]
+rule = "actions"
+[tests.files]
+"routes/home.ts" = ""
+"#,
+        )
+        .unwrap();
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let fs = danny_fs::NativeFileSystem::new(temp_dir.path()).unwrap();
+        let results = run_rule_tests(&fs, "Router", &file).await.unwrap();
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].name, "loaders only");
+        assert!(results[0].passed(), "{:?}", results[0].failures);
+
+        assert_eq!(results[1].name, "test #2");
+        assert_eq!(
+            results[1].failures,
+            vec![
+                RuleTestFailure::UnexpectedlyMarkedUsed {
+                    export: "routes/home.ts#helper".to_string(),
+                    rule: "everything".to_string(),
+                },
+                RuleTestFailure::ExportNotFound("routes/home.ts#missing".to_string()),
+            ]
+        );
+
+        assert_eq!(
+            results[2].failures,
+            vec![RuleTestFailure::UnknownRule("actions".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_lookup() {
+        let exports = HashMap::from([
+            ("a.ts#used".to_string(), Some("rule".to_string())),
+            ("a.ts#unused".to_string(), None),
+        ]);
+
+        assert_eq!(lookup(&exports, "a.ts#used"), Ok(Some("rule")));
+        assert_eq!(lookup(&exports, "a.ts#unused"), Ok(None));
+        assert_eq!(
+            lookup(&exports, "a.ts#missing"),
+            Err(RuleTestFailure::ExportNotFound("a.ts#missing".to_string()))
+        );
+        assert_eq!(
+            lookup(&exports, "a.ts"),
+            Err(RuleTestFailure::InvalidExpectation("a.ts".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_fixture_paths_stay_inside_root() {
+        assert!(is_fixture_path("src/pages/index.tsx"));
+        assert!(is_fixture_path("./index.ts"));
+        assert!(!is_fixture_path("../outside.ts"));
+        assert!(!is_fixture_path("src/../../outside.ts"));
+        assert!(!is_fixture_path("/etc/passwd"));
+        assert!(!is_fixture_path(""));
+    }
+}
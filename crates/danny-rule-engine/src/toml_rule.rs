@@ -2,7 +2,9 @@
 //!
 //! This module defines the structure of rules as they appear in TOML files.
 
+use crate::declarations::{DeclarationKind, ExportDeclaration, VariableKind};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// A complete TOML rule file
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -18,6 +20,10 @@ pub struct TomlRuleFile {
     /// List of entry point patterns (for file discovery BEFORE analysis)
     #[serde(default)]
     pub entry_points: Vec<EntryPointPattern>,
+
+    /// Fixture tests for the rules in this file (run by `danny rules test`)
+    #[serde(default)]
+    pub tests: Vec<RuleTest>,
 }
 
 /// Framework metadata
@@ -104,6 +110,11 @@ pub struct TomlRule {
     /// Priority (higher = evaluated first)
     #[serde(default)]
     pub priority: Option<u32>,
+
+    /// Framework the rule belongs to (set by the loader from the file's
+    /// `[framework]` table)
+    #[serde(skip)]
+    pub framework: Option<String>,
 }
 
 /// Rule matching conditions
@@ -181,6 +192,19 @@ pub struct RuleMatcher {
     /// Export must be used at most this many times
     #[serde(default)]
     pub max_usage_count: Option<usize>,
+
+    // Boolean groups
+    /// At least one of these sub-matchers must match
+    #[serde(default)]
+    pub any: Option<Vec<RuleMatcher>>,
+
+    /// Every one of these sub-matchers must match
+    #[serde(default)]
+    pub all: Option<Vec<RuleMatcher>>,
+
+    /// This sub-matcher must NOT match
+    #[serde(default)]
+    pub not: Option<Box<RuleMatcher>>,
 }
 
 /// Custom deserializer for import_from that accepts both String and Vec<String>
@@ -283,6 +307,16 @@ pub enum Severity {
     Info,
 }
 
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warn => write!(f, "warn"),
+            Severity::Info => write!(f, "info"),
+        }
+    }
+}
+
 impl RuleActionConfig {
     /// Convert to executable RuleAction
     pub fn to_action(&self) -> RuleAction {
@@ -290,14 +324,14 @@ impl RuleActionConfig {
             RuleAction::Skip
         } else if self.warn == Some(true) {
             RuleAction::Warn {
-                message: self.message.clone().unwrap_or_else(|| {
-                    self.reason
-                        .clone()
-                        .unwrap_or_else(|| "Rule matched".to_string())
-                }),
+                message: self.report_message(),
+                severity: self.severity.unwrap_or(Severity::Warn),
             }
         } else if let Some(severity) = self.severity {
-            RuleAction::SetSeverity { level: severity }
+            RuleAction::SetSeverity {
+                level: severity,
+                message: self.report_message(),
+            }
         } else if self.mark_used == Some(true) {
             RuleAction::MarkUsed {
                 reason: self.reason.clone(),
@@ -309,6 +343,15 @@ impl RuleActionConfig {
             }
         }
     }
+
+    /// Message reported by `warn`/`severity` actions
+    fn report_message(&self) -> String {
+        self.message.clone().unwrap_or_else(|| {
+            self.reason
+                .clone()
+                .unwrap_or_else(|| "Rule matched".to_string())
+        })
+    }
 }
 
 /// Executable rule action
@@ -320,108 +363,110 @@ pub enum RuleAction {
     /// Skip this file/export
     Skip,
 
-    /// Warn but don't error
-    Warn { message: String },
+    /// Warn but don't error (severity defaults to `warn`)
+    Warn { message: String, severity: Severity },
 
-    /// Override severity level
-    SetSeverity { level: Severity },
+    /// Report a match at the given severity level
+    SetSeverity { level: Severity, message: String },
+}
+
+impl std::fmt::Display for RuleAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RuleAction::MarkUsed { reason: None } => write!(f, "mark used"),
+            RuleAction::MarkUsed {
+                reason: Some(reason),
+            } => write!(f, "mark used ({})", reason),
+            RuleAction::Skip => write!(f, "skip"),
+            RuleAction::Warn { message, severity } => write!(f, "{}: {}", severity, message),
+            RuleAction::SetSeverity { level, message } => write!(f, "{}: {}", level, message),
+        }
+    }
+}
+
+impl RuleAction {
+    /// Severity and message for actions that report a finding
+    ///
+    /// Returns `None` for `MarkUsed` and `Skip`, which only affect analysis.
+    pub fn report(&self) -> Option<(Severity, &str)> {
+        match self {
+            RuleAction::Warn { message, severity } => Some((*severity, message)),
+            RuleAction::SetSeverity { level, message } => Some((*level, message)),
+            RuleAction::MarkUsed { .. } | RuleAction::Skip => None,
+        }
+    }
 }
 
 /// Export type filter for matching specific kinds of exports
 ///
-/// # ⚠️ IMPORTANT LIMITATIONS
-///
-/// This uses **naming heuristics**, NOT actual type checking from the AST.
-/// The matching may produce **false positives**:
-///
-/// - `Function`: Accepts any non-empty export name (may match classes/constants)
-/// - `Class`: Matches PascalCase exports (may match React components that are functions)
-/// - `Const`: Matches camelCase or UPPER_SNAKE_CASE (may miss some cases)
-/// - `Enum`: Matches PascalCase exports (same issue as Class)
-///
-/// ## Why Heuristics?
-///
-/// Accurate type detection requires access to Fob's symbol table with async graph operations.
-/// This is planned for a future release.
-///
-/// ## Recommendation
-///
-/// For precise filtering, use `export_name` or `export_pattern` instead of `export_type`.
+/// Matched against the export's declaration in the parsed module source
+/// (see [`crate::declarations`]), so `function` only matches functions and
+/// arrow-function consts, not every export. Modules oxc can't parse (e.g.
+/// `.vue` components) only match `type`/`interface` for type-only exports.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
-#[serde(rename_all = "lowercase")]
+#[serde(rename_all = "snake_case")]
 pub enum ExportType {
+    /// Function declaration or const bound to an arrow/function expression
     Function,
+    /// Function declared `async`
+    AsyncFunction,
+    /// Function (or `memo`/`forwardRef` wrapper) returning JSX, class
+    /// extending `Component`/`PureComponent`, or wrapped component like
+    /// `memo(Inner)`
+    Component,
+    /// Function named `use` + PascalCase
+    Hook,
     Class,
     Const,
     Let,
     Var,
+    /// Type alias or interface
     Type,
     Interface,
     Enum,
 }
 
 impl ExportType {
-    /// Check if a Fob export kind matches this type
+    /// Check if an export's parsed declaration is of this type
     ///
-    /// Uses heuristics based on export name patterns and metadata.
-    /// Note: This is a best-effort approach. For accurate type detection,
-    /// we would need access to Fob's symbol table, which requires async
-    /// access to the module graph. This can be enhanced in the future.
-    pub fn matches_fob_export(&self, export: &fob::graph::Export) -> bool {
-        // Check type-only exports first (most reliable)
-        if export.is_type_only {
-            return matches!(self, ExportType::Type | ExportType::Interface);
-        }
-
-        // Use naming conventions as heuristics
-        // Note: This is imperfect but better than accepting all exports
+    /// `declaration` is `None` when the module couldn't be parsed or the
+    /// export is re-exported from another module.
+    pub fn matches_declaration(
+        &self,
+        export: &fob::graph::Export,
+        declaration: Option<&ExportDeclaration>,
+    ) -> bool {
+        let Some(declaration) = declaration else {
+            return export.is_type_only && matches!(self, ExportType::Type | ExportType::Interface);
+        };
+
+        let is_function = declaration.kind == DeclarationKind::Function;
         match self {
-            ExportType::Type | ExportType::Interface => {
-                // Type-only exports are already handled above
-                // For non-type-only, we can't reliably detect without symbol info
-                false
-            }
-            ExportType::Function => {
-                // Functions typically start with lowercase or are PascalCase
-                // But classes also use PascalCase, so this is imperfect
-                let name = &export.name;
-                // Common patterns: camelCase, PascalCase (but could be class)
-                // We'll be lenient and accept most patterns
-                !name.is_empty()
-            }
-            ExportType::Class => {
-                // Classes typically use PascalCase
-                let name = &export.name;
-                if name.is_empty() {
-                    return false;
-                }
-                // Check if first character is uppercase (PascalCase)
-                name.chars().next().is_some_and(|c| c.is_uppercase())
-            }
-            ExportType::Const | ExportType::Let | ExportType::Var => {
-                // Constants/variables typically use camelCase or UPPER_SNAKE_CASE
-                let name = &export.name;
-                if name.is_empty() {
-                    return false;
-                }
-                // Accept if not clearly a class (doesn't start with uppercase)
-                // or if it's UPPER_SNAKE_CASE (constants)
-                name.chars().next().is_some_and(|c| !c.is_uppercase())
-                    || name.contains('_') && name.chars().all(|c| c.is_uppercase() || c == '_')
-            }
-            ExportType::Enum => {
-                // Enums typically use PascalCase
-                let name = &export.name;
-                if name.is_empty() {
-                    return false;
-                }
-                // Check if first character is uppercase
-                name.chars().next().is_some_and(|c| c.is_uppercase())
-            }
+            ExportType::Function => is_function,
+            ExportType::AsyncFunction => is_function && declaration.is_async,
+            ExportType::Component => declaration.is_component(),
+            ExportType::Hook => is_function && is_hook_name(&export.name),
+            ExportType::Class => declaration.kind == DeclarationKind::Class,
+            ExportType::Const => declaration.binding == Some(VariableKind::Const),
+            ExportType::Let => declaration.binding == Some(VariableKind::Let),
+            ExportType::Var => declaration.binding == Some(VariableKind::Var),
+            ExportType::Type => matches!(
+                declaration.kind,
+                DeclarationKind::TypeAlias | DeclarationKind::Interface
+            ),
+            ExportType::Interface => declaration.kind == DeclarationKind::Interface,
+            ExportType::Enum => declaration.kind == DeclarationKind::Enum,
         }
     }
 }
 
+/// React hook naming convention: `use` followed by an uppercase letter
+fn is_hook_name(name: &str) -> bool {
+    name.strip_prefix("use")
+        .and_then(|rest| rest.chars().next())
+        .is_some_and(|c| c.is_ascii_uppercase())
+}
+
 /// Entry point pattern for file discovery BEFORE analysis
 ///
 /// Entry points are discovered using glob patterns and used to seed the dependency graph.
@@ -448,10 +493,73 @@ pub struct EntryPointPattern {
     pub priority: Option<u32>,
 }
 
+/// Fixture test for the rules in a file
+///
+/// The fixture files are analyzed with the file's rules, then each
+/// expectation names an export as `path#export`, with `path` relative to
+/// the fixture root.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RuleTest {
+    /// Test name (defaults to the test's position in the file)
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// Only apply this rule (defaults to every rule in the file)
+    #[serde(default)]
+    pub rule: Option<String>,
+
+    /// Fixture files, keyed by path relative to the fixture root
+    pub files: BTreeMap<String, String>,
+
+    /// Entry points (defaults to every fixture file)
+    #[serde(default)]
+    pub entry_points: Option<Vec<String>>,
+
+    /// Exports the rules must mark as used
+    #[serde(default)]
+    pub expect_used: Vec<String>,
+
+    /// Exports the rules must NOT mark as used
+    #[serde(default)]
+    pub expect_unused: Vec<String>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_reporting_actions() {
+        let warn: RuleActionConfig = toml::from_str(
+            r#"
+warn = true
+reason = "Legacy module"
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            warn.to_action(),
+            RuleAction::Warn {
+                message: "Legacy module".to_string(),
+                severity: Severity::Warn,
+            }
+        );
+
+        let error: RuleActionConfig = toml::from_str(
+            r#"
+severity = "error"
+message = "Don't import moment"
+"#,
+        )
+        .unwrap();
+        let action = error.to_action();
+        assert_eq!(
+            action.report(),
+            Some((Severity::Error, "Don't import moment"))
+        );
+        assert_eq!(RuleAction::Skip.report(), None);
+    }
+
     #[test]
     fn test_parse_simple_rule() {
         let toml = r#"
@@ -478,6 +586,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_export_types() {
+        let parse = |value: &str| {
+            let matcher: RuleMatcher =
+                toml::from_str(&format!("export_type = \"{}\"", value)).unwrap();
+            matcher.export_type
+        };
+
+        assert_eq!(parse("hook"), Some(ExportType::Hook));
+        assert_eq!(parse("component"), Some(ExportType::Component));
+        assert_eq!(parse("async_function"), Some(ExportType::AsyncFunction));
+        assert_eq!(parse("type"), Some(ExportType::Type));
+    }
+
     #[test]
     fn test_parse_framework_metadata() {
         let toml = r#"